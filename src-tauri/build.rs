@@ -1,3 +1,6 @@
 fn main() {
-    tauri_build::build()
+    // core 만 빌드할 때 (`--no-default-features`) 는 Tauri 설정이 필요 없다
+    if std::env::var_os("CARGO_FEATURE_APP").is_some() {
+        tauri_build::build()
+    }
 }
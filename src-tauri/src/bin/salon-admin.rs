@@ -0,0 +1,267 @@
+//! 살롱 데이터베이스 관리 도구
+//!
+//! 앱을 열지 않고 `database.db` 경로만으로 예약 내보내기, 백업 만들기·검증, 통계 보고,
+//! 무결성 검사, 고객 가져오기를 한다. 암호화 키는 `--key-file`/`SALON_DB_KEY` 로 주거나
+//! (앱의 설정 > 백업 > 복구 키), 없으면 앱과 같이 키체인이나 데이터베이스 옆의 키 파일에서 찾는다.
+//!
+//! 앱과 달리 데이터베이스를 암호화하거나 마이그레이션하지 않는다. 읽기만 하는 명령은 읽기 전용으로
+//! 열고, 데이터를 바꾸는 명령은 스키마 버전이 이 도구와 같을 때만 실행한다.
+//! 앱이 같은 데이터베이스를 쓰는 동안에도 돌릴 수 있다.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use sisters_salon_reservation_app_lib::db::encryption::{self, DatabaseKey};
+use sisters_salon_reservation_app_lib::db::{migrations, Database};
+use sisters_salon_reservation_app_lib::services::backup::manager::BackupManager;
+use sisters_salon_reservation_app_lib::services::backup::preview::{self, BackupSource};
+use sisters_salon_reservation_app_lib::services::backup::{self, CloudService};
+use sisters_salon_reservation_app_lib::services::{cloudkit, customers, excel, statistics};
+
+#[derive(Parser)]
+#[command(name = "salon-admin", version, about = "살롱 데이터베이스 관리 도구")]
+struct Cli {
+    /// 데이터베이스 파일 (앱 데이터 폴더의 database.db)
+    #[arg(long, short, env = "SALON_DB", default_value = "database.db")]
+    db: PathBuf,
+
+    /// 데이터베이스 키 파일 (앱의 설정 > 백업 > 복구 키를 저장한 파일)
+    #[arg(long, env = "SALON_DB_KEY_FILE")]
+    key_file: Option<PathBuf>,
+
+    /// 데이터베이스 키 (hex). 셸 기록에 남지 않도록 환경 변수로 주는 것이 좋다
+    #[arg(long, env = "SALON_DB_KEY", hide_env_values = true, conflicts_with = "key_file")]
+    key: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 예약을 엑셀(xlsx) 또는 CSV 로 내보낸다
+    Export {
+        #[arg(long, short, value_enum, default_value_t = ExportFormat::Xlsx)]
+        format: ExportFormat,
+        /// this_month, last_3_months, all
+        #[arg(long, short, default_value = "this_month")]
+        period: String,
+        /// 저장할 파일 (없으면 현재 폴더에 reservations_<시각>.<형식>)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// 백업 만들기·확인
+    #[command(subcommand)]
+    Backup(BackupCommand),
+    /// 기간 통계 요약과 디자이너별 실적
+    Stats {
+        /// 7d, 30d, 90d, 365d
+        #[arg(long, short, default_value = "30d")]
+        period: String,
+        /// JSON 으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+    /// 데이터베이스 무결성과 스키마 버전을 확인한다 (마이그레이션하지 않는다)
+    Check,
+    /// CSV 로 고객을 한꺼번에 등록한다 (헤더: 이름, 연락처, 이메일, 생년월일, 성별, 선호 서비스, 알레르기, 메모)
+    ImportCustomers {
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum BackupCommand {
+    /// 백업을 만든다. 로컬 백업은 데이터베이스 옆의 backups 폴더에 저장된다
+    Create {
+        /// local, icloud, external
+        #[arg(long, short, default_value = "local")]
+        service: String,
+        /// 주면 비밀번호로 암호화한 아카이브(.ssbak)를 만든다 (첨부 파일 포함)
+        #[arg(long, env = "SALON_BACKUP_PASSPHRASE", hide_env_values = true)]
+        passphrase: Option<String>,
+    },
+    /// 백업 목록
+    List {
+        #[arg(long, short, default_value = "local")]
+        service: String,
+    },
+    /// 백업 파일을 열어 무결성을 검사하고 내용을 요약한다 (복원하지 않는다)
+    Verify {
+        file: PathBuf,
+        #[arg(long, env = "SALON_BACKUP_PASSPHRASE", hide_env_values = true)]
+        passphrase: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    Xlsx,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("오류: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// `--key-file`/`SALON_DB_KEY` 로 준 키, 없으면 키체인이나 데이터베이스 옆의 키 파일
+fn database_key(cli: &Cli) -> Result<Option<DatabaseKey>, String> {
+    if let Some(path) = &cli.key_file {
+        let hex = std::fs::read_to_string(path).map_err(|e| format!("키 파일을 읽을 수 없습니다: {}", e))?;
+        return DatabaseKey::from_hex(&hex).map(Some).map_err(|_| "키 파일의 형식이 올바르지 않습니다".to_string());
+    }
+    if let Some(hex) = &cli.key {
+        return DatabaseKey::from_hex(hex).map(Some).map_err(|_| "SALON_DB_KEY 형식이 올바르지 않습니다".to_string());
+    }
+    encryption::load_key(&data_dir(&cli.db))
+}
+
+/// 기존 데이터베이스를 마이그레이션 없이 연다 (경로를 잘못 주었을 때 빈 데이터베이스를 만들지 않도록)
+fn open_database(path: &Path, key: Option<&DatabaseKey>, read_only: bool) -> Result<Database, String> {
+    if key.is_none() && path.is_file() && !encryption::is_plaintext(path) {
+        return Err("데이터베이스 암호화 키를 찾을 수 없습니다. 앱의 설정 > 백업에서 복구 키를 저장해 \
+                    --key-file 또는 SALON_DB_KEY 로 주세요"
+            .to_string());
+    }
+    Database::open_existing(path, key, read_only)
+}
+
+/// 데이터를 바꾸기 전에 스키마 버전이 이 도구와 같은지 확인한다
+fn ensure_current_schema(db: &Database) -> Result<(), String> {
+    let current = migrations::current_version(db.conn())?;
+    let latest = migrations::latest_version();
+    if current != latest {
+        return Err(format!(
+            "데이터베이스 스키마 v{} 는 이 도구(v{})와 달라 바꿀 수 없습니다. 같은 버전의 앱으로 먼저 열어주세요",
+            current, latest
+        ));
+    }
+    Ok(())
+}
+
+fn data_dir(db_path: &Path) -> PathBuf {
+    db_path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")).to_path_buf()
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let key = database_key(&cli)?;
+
+    match cli.command {
+        Command::Export { format, period, output } => {
+            let db = open_database(&cli.db, key.as_ref(), true)?;
+            let extension = match format {
+                ExportFormat::Xlsx => "xlsx",
+                ExportFormat::Csv => "csv",
+            };
+            let output = output.unwrap_or_else(|| {
+                PathBuf::from(format!("reservations_{}.{}", chrono::Local::now().format("%Y%m%d_%H%M%S"), extension))
+            });
+            let path = match format {
+                ExportFormat::Xlsx => excel::export_reservations(db.conn(), &period, output)?,
+                ExportFormat::Csv => excel::export_to_csv(db.conn(), &period, output)?,
+            };
+            println!("{}", path.display());
+        }
+        Command::Backup(command) => run_backup(&cli.db, key, command)?,
+        Command::Stats { period, json } => {
+            let db = open_database(&cli.db, key.as_ref(), true)?;
+            let summary = statistics::get_statistics_summary(db.conn(), period.clone())?;
+            let designers = statistics::get_designer_statistics(db.conn(), &period)?;
+            if json {
+                let report = serde_json::json!({ "summary": summary, "designers": designers });
+                println!("{}", serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?);
+                return Ok(());
+            }
+
+            println!("기간: {}", summary.period);
+            println!("전체 예약: {}건 (완료 {}, 취소 {}, 노쇼 {})", summary.total_reservations, summary.completed, summary.cancelled, summary.no_show);
+            println!("완료율: {:.1}%  일평균: {:.1}건", summary.completion_rate, summary.average_per_day);
+            println!("가장 바쁜 요일: {}", summary.busiest_day.as_deref().unwrap_or("-"));
+            println!("가장 바쁜 시간: {}", summary.busiest_hour.as_deref().unwrap_or("-"));
+            if let Some(top) = &summary.top_designer {
+                println!("최다 예약 디자이너: {} ({}건)", top.name, top.count);
+            }
+            if let Some(top) = &summary.top_service {
+                println!("최다 서비스: {} ({}건)", top.name, top.count);
+            }
+            if !designers.is_empty() {
+                println!();
+                println!("디자이너별");
+                for designer in designers {
+                    println!(
+                        "  {}: {}건, 완료 {}건 ({:.1}%)",
+                        designer.designer_name, designer.total, designer.completed, designer.completion_rate
+                    );
+                }
+            }
+        }
+        Command::Check => {
+            let db = open_database(&cli.db, key.as_ref(), true)?;
+            backup::check_integrity(db.conn())?;
+            println!("무결성 검사: 정상");
+            let current = migrations::current_version(db.conn())?;
+            let latest = migrations::latest_version();
+            println!("스키마 버전: {} (이 버전이 지원하는 최신 {})", current, latest);
+            if current < latest {
+                println!("앱으로 열면 v{} 로 마이그레이션됩니다", latest);
+            } else if current > latest {
+                println!("더 새로운 버전의 앱에서 만든 데이터베이스입니다. 도구를 업데이트해주세요");
+            }
+        }
+        Command::ImportCustomers { file } => {
+            let db = open_database(&cli.db, key.as_ref(), false)?;
+            ensure_current_schema(&db)?;
+            let content = std::fs::read_to_string(&file).map_err(|e| format!("파일을 읽을 수 없습니다: {}", e))?;
+            let result = customers::import_customers_csv(db.conn(), &content)?;
+            println!("등록: {}명, 건너뜀: {}명", result.imported, result.skipped.len());
+            for row in result.skipped {
+                println!("  {}번째 줄 {}: {}", row.line, row.name, row.reason);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_backup(db_path: &Path, key: Option<DatabaseKey>, command: BackupCommand) -> Result<(), String> {
+    let data_dir = data_dir(db_path);
+    let manager = |db| BackupManager { db, data_dir: &data_dir, icloud: cloudkit::store() };
+
+    match command {
+        BackupCommand::Create { service, passphrase } => {
+            let service = CloudService::parse(&service)?;
+            // 아카이브는 메모리 데이터베이스를 붙여 내보내므로 쓰기 연결이 필요하다 (데이터는 바꾸지 않음)
+            let db = Mutex::new(open_database(db_path, key.as_ref(), passphrase.is_none())?);
+            let created = manager(&db).create(&service, passphrase.as_deref())?;
+            println!("{} ({} bytes)", created.filename, created.size);
+        }
+        BackupCommand::List { service } => {
+            let db = Mutex::new(open_database(db_path, key.as_ref(), true)?);
+            for info in manager(&db).list(&CloudService::parse(&service)?)? {
+                println!("{}\t{}\t{} bytes", info.created_at.format("%Y-%m-%d %H:%M"), info.filename, info.size);
+            }
+        }
+        BackupCommand::Verify { file, passphrase } => {
+            // 백업 파일만 열므로 데이터베이스는 필요 없고 키만 쓴다
+            let source = BackupSource::open(&file, key.as_ref(), passphrase.as_deref())?;
+            let summary = preview::verify_backup(&source)?;
+            println!("무결성 검사: 정상");
+            println!("스키마 버전: {}", summary.schema_version);
+            if let (Some(from), Some(to)) = (&summary.reservation_date_from, &summary.reservation_date_to) {
+                println!("예약 기간: {} ~ {}", from, to);
+            }
+            for table in summary.tables {
+                println!("  {}: {}", table.table, table.rows);
+            }
+        }
+    }
+    Ok(())
+}
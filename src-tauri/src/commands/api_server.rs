@@ -0,0 +1,50 @@
+use tauri::State;
+
+use crate::db::DbState;
+use crate::services::api::{self, keys, ApiServerSettings, ApiServerStatus};
+
+#[tauri::command]
+pub fn get_api_server_settings(db: State<DbState>) -> Result<ApiServerSettings, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    api::get_settings(db.conn())
+}
+
+/// 설정을 저장하고 서버를 켜거나 끈다
+#[tauri::command]
+pub fn update_api_server_settings(
+    settings: ApiServerSettings,
+    db: State<DbState>,
+) -> Result<ApiServerStatus, String> {
+    {
+        let db = db.0.lock().map_err(|e| e.to_string())?;
+        api::save_settings(db.conn(), &settings)?;
+    }
+    api::apply_settings(db.0.clone(), &settings)
+}
+
+#[tauri::command]
+pub fn get_api_server_status() -> Result<ApiServerStatus, String> {
+    api::status()
+}
+
+#[tauri::command]
+pub fn list_api_keys(db: State<DbState>) -> Result<Vec<keys::ApiKey>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    keys::list_keys(db.conn())
+}
+
+#[tauri::command]
+pub fn create_api_key(
+    name: String,
+    scopes: Vec<keys::ApiScope>,
+    db: State<DbState>,
+) -> Result<keys::CreatedApiKey, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    keys::create_key(db.conn(), &name, &scopes)
+}
+
+#[tauri::command]
+pub fn revoke_api_key(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    keys::revoke_key(db.conn(), &id)
+}
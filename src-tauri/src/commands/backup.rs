@@ -1,312 +1,223 @@
-use tauri::{AppHandle, Manager};
-
-use crate::services::backup::{self, BackupInfo, CloudService};
+use std::path::{Path, PathBuf};
+
+use tauri::{AppHandle, Manager, State};
+
+use crate::db::DbState;
+use crate::services::auth;
+use crate::services::backup::auto::{self, AutoBackupSettings, BackupRunStatus};
+use crate::services::backup::manager::BackupManager;
+use crate::services::backup::merge::{SelectiveRestoreOptions, SelectiveRestoreReport};
+use crate::services::backup::migration::{self, MigrationExport, MigrationImportResult};
+use crate::services::backup::preview::BackupPreview;
+use crate::services::backup::target::{self, BackupTargetConfig};
+use crate::services::backup::{self, archive, BackupInfo, CloudService, RestoreResult};
 use crate::services::cloudkit;
 
-// Maximum number of backups to keep in CloudKit
-const MAX_CLOUDKIT_BACKUPS: usize = 10;
-
-#[tauri::command]
-pub fn list_backups(app: AppHandle, service: String) -> Result<Vec<BackupInfo>, String> {
-    println!("[Backup] list_backups called with service: {}", service);
-
-    let cloud_service = parse_cloud_service(&service)?;
-
-    // For iCloud, fetch from CloudKit
-    if cloud_service == CloudService::Icloud {
-        return list_cloudkit_backups_as_info();
-    }
-
-    let app_data_dir = app.path().app_data_dir().map_err(|e| {
-        let err = format!("Failed to get app_data_dir: {}", e);
-        println!("[Backup] Error: {}", err);
-        err
-    })?;
-
-    println!("[Backup] app_data_dir: {:?}", app_data_dir);
-
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
-    println!("[Backup] backup_dir: {:?}", backup_dir);
-    println!("[Backup] backup_dir exists: {}", backup_dir.exists());
-
-    let result = backup::list_backups(&backup_dir, &cloud_service);
-    match &result {
-        Ok(backups) => println!("[Backup] Found {} backups", backups.len()),
-        Err(e) => println!("[Backup] Error listing backups: {}", e),
-    }
-
-    result
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    crate::runtime::data_dir(app).map_err(|e| format!("Failed to get app_data_dir: {}", e))
 }
 
-/// Convert CloudKit JSON to BackupInfo list
-fn list_cloudkit_backups_as_info() -> Result<Vec<BackupInfo>, String> {
-    let json_str = cloudkit::list_cloudkit_backups()?;
-    println!("[Backup] CloudKit returned: {}", json_str);
-
-    // Parse JSON
-    let items: Vec<serde_json::Value> = serde_json::from_str(&json_str)
-        .map_err(|e| format!("JSON 파싱 오류: {}", e))?;
-
-    let mut backups = Vec::new();
-    for item in items {
-        let id = item["id"].as_str().unwrap_or("").to_string();
-        let filename = item["filename"].as_str().unwrap_or(&id).to_string();
-        let size = item["size"].as_u64().unwrap_or(0);
-        let created_at_str = item["createdAt"].as_str().unwrap_or("");
-
-        let created_at = if !created_at_str.is_empty() {
-            chrono::DateTime::parse_from_rfc3339(created_at_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .unwrap_or_else(|_| chrono::Utc::now())
-        } else {
-            chrono::Utc::now()
-        };
-
-        backups.push(BackupInfo {
-            id,
-            service: CloudService::Icloud,
-            filename,
-            size,
-            created_at,
-        });
-    }
-
-    println!("[Backup] Parsed {} CloudKit backups", backups.len());
-    Ok(backups)
+fn manager<'a>(db: &'a DbState, data_dir: &'a Path) -> BackupManager<'a> {
+    BackupManager { db: &db.0, data_dir, icloud: cloudkit::store() }
 }
 
 #[tauri::command]
-pub fn create_backup(app: AppHandle, service: String) -> Result<BackupInfo, String> {
-    println!("[Backup] create_backup called with service: {}", service);
-
-    let cloud_service = parse_cloud_service(&service)?;
-
-    let app_data_dir = app.path().app_data_dir().map_err(|e| {
-        let err = format!("Failed to get app_data_dir: {}", e);
-        println!("[Backup] Error: {}", err);
-        err
-    })?;
-
-    println!("[Backup] app_data_dir: {:?}", app_data_dir);
-
-    let db_path = app_data_dir.join("database.db");
-    println!("[Backup] db_path: {:?}", db_path);
-    println!("[Backup] db_path exists: {}", db_path.exists());
-
-    if !db_path.exists() {
-        let err = format!("데이터베이스 파일이 없습니다: {:?}", db_path);
-        println!("[Backup] Error: {}", err);
-        return Err(err);
-    }
-
-    // Get database file size for debugging
-    if let Ok(metadata) = std::fs::metadata(&db_path) {
-        println!("[Backup] db file size: {} bytes", metadata.len());
-    }
-
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
-    println!("[Backup] backup_dir: {:?}", backup_dir);
-
-    // Create backup
-    let backup_info = backup::create_backup(&db_path, &backup_dir, &cloud_service).map_err(|e| {
-        println!("[Backup] Error creating backup: {}", e);
-        e
-    })?;
-
-    println!("[Backup] Backup created successfully: {}", backup_info.filename);
-
-    // Verify the backup file was created
-    let backup_file_path = backup_dir.join(&backup_info.filename);
-    if backup_file_path.exists() {
-        if let Ok(metadata) = std::fs::metadata(&backup_file_path) {
-            println!("[Backup] Backup file size: {} bytes", metadata.len());
-        }
-    } else {
-        println!("[Backup] Warning: Backup file not found after creation!");
-    }
-
-    // For iCloud, also upload to CloudKit
-    if cloud_service == CloudService::Icloud {
-        let backup_path_str = backup_file_path.to_string_lossy().to_string();
-
-        match cloudkit::upload_to_cloudkit(&backup_path_str) {
-            Ok(record_id) => {
-                println!("[Backup] CloudKit upload successful: {}", record_id);
-
-                // Auto-cleanup: delete old backups if we have more than MAX_CLOUDKIT_BACKUPS
-                if let Err(e) = cleanup_old_cloudkit_backups() {
-                    println!("[Backup] CloudKit cleanup warning: {}", e);
-                }
-            }
-            Err(e) => {
-                println!("[Backup] CloudKit upload failed (local backup saved): {}", e);
-            }
-        }
-    }
-
-    Ok(backup_info)
+pub fn list_backups(app: AppHandle, db: State<DbState>, service: String) -> Result<Vec<BackupInfo>, String> {
+    println!("[Backup] list_backups called with service: {}", service);
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).list(&CloudService::parse(&service)?)
 }
 
-/// Auto-cleanup old CloudKit backups, keeping only MAX_CLOUDKIT_BACKUPS
-fn cleanup_old_cloudkit_backups() -> Result<(), String> {
-    println!("[Backup] Checking CloudKit backup count...");
-
-    let json_str = cloudkit::list_cloudkit_backups()?;
-    let items: Vec<serde_json::Value> = serde_json::from_str(&json_str)
-        .map_err(|e| format!("JSON 파싱 오류: {}", e))?;
-
-    let count = items.len();
-    println!("[Backup] Current CloudKit backup count: {}", count);
-
-    if count <= MAX_CLOUDKIT_BACKUPS {
-        println!("[Backup] No cleanup needed (count <= {})", MAX_CLOUDKIT_BACKUPS);
-        return Ok(());
-    }
-
-    // Items are sorted by createdDate descending, so older ones are at the end
-    let to_delete = count - MAX_CLOUDKIT_BACKUPS;
-    println!("[Backup] Will delete {} old backup(s)", to_delete);
-
-    for item in items.iter().skip(MAX_CLOUDKIT_BACKUPS) {
-        if let Some(id) = item["id"].as_str() {
-            println!("[Backup] Deleting old backup: {}", id);
-            if let Err(e) = cloudkit::delete_cloudkit_backup(id) {
-                println!("[Backup] Failed to delete {}: {}", id, e);
-            }
-        }
-    }
-
-    println!("[Backup] CloudKit cleanup completed");
-    Ok(())
+/// `passphrase` 를 주면 비밀번호로 암호화한 아카이브(.ssbak)를, 없으면 기기 키로 암호화된
+/// 데이터베이스 복사본(.db)을 만든다.
+#[tauri::command]
+pub fn create_backup(
+    app: AppHandle,
+    db: State<DbState>,
+    service: String,
+    passphrase: Option<String>,
+) -> Result<BackupInfo, String> {
+    println!("[Backup] create_backup called with service: {}", service);
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).create(&CloudService::parse(&service)?, passphrase.as_deref())
 }
 
+/// 백업으로 복원한다. 열려 있는 데이터베이스 연결을 바로 교체하므로 앱을 다시 시작할 필요가 없고,
+/// 복원 직전 데이터는 로컬 백업 폴더에 자동 백업된다.
+/// `passphrase` 는 아카이브면 백업 비밀번호, 다른 기기에서 만든 .db 백업이면 그 기기의 복구 키다.
 #[tauri::command]
 pub fn restore_backup(
     app: AppHandle,
+    db: State<DbState>,
     backup_filename: String,
     service: String,
-) -> Result<(), String> {
+    passphrase: Option<String>,
+) -> Result<RestoreResult, String> {
     println!("[Backup] restore_backup called: filename={}, service={}", backup_filename, service);
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).restore(&CloudService::parse(&service)?, &backup_filename, passphrase.as_deref())
+}
 
-    let cloud_service = parse_cloud_service(&service)?;
-
-    let app_data_dir = app.path().app_data_dir().map_err(|e| {
-        let err = format!("Failed to get app_data_dir: {}", e);
-        println!("[Backup] Error: {}", err);
-        err
-    })?;
+/// 백업을 읽기 전용으로 열어 내용을 요약한다. 복원 전에 어떤 데이터가 들어 있는지 확인하는 용도.
+#[tauri::command]
+pub fn preview_backup(
+    app: AppHandle,
+    db: State<DbState>,
+    backup_filename: String,
+    service: String,
+    passphrase: Option<String>,
+) -> Result<BackupPreview, String> {
+    println!("[Backup] preview_backup called: filename={}, service={}", backup_filename, service);
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).preview(&CloudService::parse(&service)?, &backup_filename, passphrase.as_deref())
+}
 
-    let db_path = app_data_dir.join("database.db");
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
+/// 백업에서 고른 테이블(또는 기간의 예약)만 현재 데이터에 합친다.
+/// 같은 id 의 데이터는 `options.overwrite` 에 따라 유지하거나 덮어쓰고 결과에 충돌로 보고한다.
+#[tauri::command]
+pub fn restore_backup_selective(
+    app: AppHandle,
+    db: State<DbState>,
+    backup_filename: String,
+    service: String,
+    passphrase: Option<String>,
+    options: SelectiveRestoreOptions,
+) -> Result<SelectiveRestoreReport, String> {
+    println!("[Backup] restore_backup_selective called: filename={}, tables={:?}", backup_filename, options.tables);
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).restore_selective(
+        &CloudService::parse(&service)?,
+        &backup_filename,
+        passphrase.as_deref(),
+        &options,
+    )
+}
 
-    println!("[Backup] db_path: {:?}", db_path);
-    println!("[Backup] backup_dir: {:?}", backup_dir);
+/// 데이터베이스·첨부 파일·화면 설정을 기기 이전 번들 하나로 저장한다.
+/// `dest` 를 주지 않으면 다운로드 폴더에 저장한다.
+#[tauri::command]
+pub fn export_migration_bundle(
+    app: AppHandle,
+    db: State<DbState>,
+    passphrase: String,
+    dest: Option<String>,
+    client_settings: Option<serde_json::Value>,
+) -> Result<MigrationExport, String> {
+    let app_data_dir = crate::runtime::data_dir(&app).map_err(|e| e.to_string())?;
+    let dest = match dest.filter(|d| !d.trim().is_empty()) {
+        Some(dest) => PathBuf::from(dest.trim()),
+        None => app.path().download_dir().map_err(|e| e.to_string())?,
+    };
+    let path = migration::bundle_path(&dest);
+
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let attachments_dir = app_data_dir.join(archive::ATTACHMENTS_DIR);
+    let manifest = migration::export_bundle(db.conn(), &attachments_dir, &path, &passphrase, client_settings.as_ref())?;
+    println!("[Backup] Migration bundle exported: {:?}", path);
+
+    Ok(MigrationExport { path: path.to_string_lossy().to_string(), manifest })
+}
 
-    let backup_file_path = backup_dir.join(&backup_filename);
-    println!("[Backup] backup_file_path: {:?}", backup_file_path);
+/// 가져오기 전에 번들을 검증하고 내용(건수, 버전)을 보여준다
+#[tauri::command]
+pub fn inspect_migration_bundle(
+    db: State<DbState>,
+    path: String,
+    passphrase: String,
+) -> Result<archive::BackupManifest, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    migration::inspect_bundle(Path::new(path.trim()), &passphrase, db.conn())
+}
 
-    // For iCloud, download from CloudKit first if not exists locally
-    if cloud_service == CloudService::Icloud && !backup_file_path.exists() {
-        println!("[Backup] Downloading from CloudKit...");
+/// 어느 경로의 번들이든 가져와 현재 데이터를 교체한다. 잠금 PIN 은 옮겨지지 않으므로
+/// 이 기기에 남아 있던 PIN 도 지우고 다시 설정하도록 한다.
+#[tauri::command]
+pub fn import_migration_bundle(
+    app: AppHandle,
+    db: State<DbState>,
+    path: String,
+    passphrase: String,
+) -> Result<MigrationImportResult, String> {
+    println!("[Backup] import_migration_bundle called: {}", path);
+
+    let app_data_dir = crate::runtime::data_dir(&app).map_err(|e| e.to_string())?;
+    let snapshot_dir = backup::get_backup_dir_for_service(&app_data_dir, &CloudService::Local)?;
+    let attachments_dir = app_data_dir.join(archive::ATTACHMENTS_DIR);
+
+    let mut db = db.0.lock().map_err(|e| e.to_string())?;
+    let result = migration::import_bundle(&mut db, Path::new(path.trim()), &passphrase, &attachments_dir, &snapshot_dir)?;
+    let _ = auth::remove_pin();
+    println!("[Backup] Migration bundle imported (snapshot: {})", result.restore.snapshot_filename);
+
+    Ok(result)
+}
 
-        // Ensure backup directory exists
-        if !backup_dir.exists() {
-            std::fs::create_dir_all(&backup_dir).map_err(|e| {
-                format!("백업 디렉토리 생성 실패: {}", e)
-            })?;
-        }
+#[tauri::command]
+pub fn get_auto_backup_settings(db: State<DbState>) -> Result<AutoBackupSettings, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    auto::get_settings(db.conn())
+}
 
-        let dest_path = backup_file_path.to_string_lossy().to_string();
-        cloudkit::download_cloudkit_backup(&backup_filename, &dest_path)?;
-        println!("[Backup] CloudKit download successful");
-    }
+#[tauri::command]
+pub fn update_auto_backup_settings(settings: AutoBackupSettings, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    auto::save_settings(db.conn(), &settings)
+}
 
-    println!("[Backup] backup_file exists: {}", backup_file_path.exists());
+/// 서비스별 마지막 자동 백업 결과
+#[tauri::command]
+pub fn get_auto_backup_status(db: State<DbState>) -> Result<Vec<BackupRunStatus>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    auto::get_status(db.conn())
+}
 
-    if !backup_file_path.exists() {
-        let err = format!("백업 파일을 찾을 수 없습니다: {:?}", backup_file_path);
-        println!("[Backup] Error: {}", err);
-        return Err(err);
-    }
+/// 외부 저장소 설정 (폴더, WebDAV, S3)
+#[tauri::command]
+pub fn get_backup_target(db: State<DbState>) -> Result<BackupTargetConfig, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    target::get_config(db.conn())
+}
 
-    let result = backup::restore_backup(&backup_filename, &backup_dir, &db_path);
-    match &result {
-        Ok(_) => println!("[Backup] Restore successful"),
-        Err(e) => println!("[Backup] Restore failed: {}", e),
-    }
+#[tauri::command]
+pub fn update_backup_target(config: BackupTargetConfig, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    target::save_config(db.conn(), &config)
+}
 
-    result
+/// 저장하기 전에 설정으로 접속해 본다. 찾은 백업 개수를 돌려준다
+#[tauri::command]
+pub fn test_backup_target(config: BackupTargetConfig) -> Result<usize, String> {
+    let target = config.build()?;
+    let count = target.list()?.len();
+    println!("[Backup] Backup target {} reachable ({} backups)", target.name(), count);
+    Ok(count)
 }
 
 #[tauri::command]
-pub fn delete_backup(app: AppHandle, backup_filename: String, service: String) -> Result<(), String> {
+pub fn delete_backup(app: AppHandle, db: State<DbState>, backup_filename: String, service: String) -> Result<(), String> {
     println!("[Backup] delete_backup called: filename={}, service={}", backup_filename, service);
-
-    let cloud_service = parse_cloud_service(&service)?;
-
-    // For iCloud, delete from CloudKit using the filename as record ID
-    if cloud_service == CloudService::Icloud {
-        println!("[Backup] Deleting from CloudKit: {}", backup_filename);
-        return cloudkit::delete_cloudkit_backup(&backup_filename);
-    }
-
-    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
-
-    let result = backup::delete_backup(&backup_filename, &backup_dir);
-    match &result {
-        Ok(_) => println!("[Backup] Delete successful"),
-        Err(e) => println!("[Backup] Delete failed: {}", e),
-    }
-
-    result
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).delete(&CloudService::parse(&service)?, &backup_filename)
 }
 
 #[tauri::command]
-pub fn cleanup_old_backups(app: AppHandle, keep_count: usize, service: String) -> Result<(), String> {
+pub fn cleanup_old_backups(app: AppHandle, db: State<DbState>, keep_count: usize, service: String) -> Result<(), String> {
     println!("[Backup] cleanup_old_backups called: keep_count={}, service={}", keep_count, service);
-
-    let cloud_service = parse_cloud_service(&service)?;
-
-    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
-
-    backup::cleanup_old_backups(&backup_dir, keep_count)
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).cleanup(&CloudService::parse(&service)?, keep_count)
 }
 
 /// Check if iCloud/CloudKit is available on this device
 #[tauri::command]
 pub fn is_icloud_available() -> bool {
-    let result = cloudkit::is_cloudkit_available();
+    let result = cloudkit::store().is_available();
     println!("[Backup] is_icloud_available: {}", result);
     result
 }
 
 /// Debug command to get current paths
 #[tauri::command]
-pub fn get_backup_debug_info(app: AppHandle, service: String) -> Result<String, String> {
-    let cloud_service = parse_cloud_service(&service)?;
-    let app_data_dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
-    let backup_dir = backup::get_backup_dir_for_service(&app_data_dir, &cloud_service)?;
-    let db_path = app_data_dir.join("database.db");
-
-    let info = format!(
-        "app_data_dir: {:?}\nbackup_dir: {:?}\nbackup_dir_exists: {}\ndb_path: {:?}\ndb_exists: {}",
-        app_data_dir,
-        backup_dir,
-        backup_dir.exists(),
-        db_path,
-        db_path.exists()
-    );
-
-    Ok(info)
-}
-
-fn parse_cloud_service(service: &str) -> Result<CloudService, String> {
-    match service.to_lowercase().as_str() {
-        "icloud" => Ok(CloudService::Icloud),
-        "google_drive" | "googledrive" => Ok(CloudService::GoogleDrive),
-        "local" => Ok(CloudService::Local),
-        _ => Err(format!("Invalid cloud service: {}", service)),
-    }
+pub fn get_backup_debug_info(app: AppHandle, db: State<DbState>, service: String) -> Result<String, String> {
+    let data_dir = app_data_dir(&app)?;
+    manager(&db, &data_dir).debug_info(&CloudService::parse(&service)?)
 }
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use tauri::State;
 use crate::db::DbState;
+use crate::services::holidays::{self, CalendarType, HolidayOccurrence};
+use crate::services::lunar::{self, LunarDate};
+use crate::services::schedule::{self, DayHours, EffectiveHours};
+use chrono::Datelike;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +29,8 @@ pub struct UpdateBusinessHoursInput {
     pub is_closed: bool,
 }
 
+const DAY_NAMES: [&str; 7] = ["일요일", "월요일", "화요일", "수요일", "목요일", "금요일", "토요일"];
+
 #[tauri::command]
 pub fn get_business_hours(db: State<DbState>) -> Result<Vec<BusinessHours>, String> {
     let db = db.0.lock().map_err(|e| e.to_string())?;
@@ -59,11 +65,33 @@ pub fn update_business_hours(
     data: Vec<UpdateBusinessHoursInput>,
     db: State<DbState>,
 ) -> Result<(), String> {
+    // 하나라도 잘못되면 아무것도 저장하지 않는다
+    let data = data
+        .into_iter()
+        .map(|hours| {
+            let day = DayHours {
+                open_time: hours.open_time,
+                close_time: hours.close_time,
+                break_start: hours.break_start,
+                break_end: hours.break_end,
+            }
+            .validate(hours.is_closed)
+            .map_err(|e| format!("{}: {}", DAY_NAMES.get(hours.day_of_week as usize).unwrap_or(&"?"), e))?;
+            Ok(UpdateBusinessHoursInput {
+                open_time: day.open_time,
+                close_time: day.close_time,
+                break_start: day.break_start,
+                break_end: day.break_end,
+                ..hours
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
     let db = db.0.lock().map_err(|e| e.to_string())?;
-    let conn = db.conn();
+    let tx = db.conn().unchecked_transaction().map_err(|e| e.to_string())?;
 
     for hours in data {
-        conn.execute(
+        tx.execute(
             "UPDATE business_hours SET open_time = ?2, close_time = ?3, break_start = ?4, break_end = ?5, is_closed = ?6 WHERE day_of_week = ?1",
             rusqlite::params![
                 hours.day_of_week,
@@ -76,6 +104,7 @@ pub fn update_business_hours(
         )
         .map_err(|e| e.to_string())?;
     }
+    tx.commit().map_err(|e| e.to_string())?;
 
     Ok(())
 }
@@ -88,6 +117,7 @@ pub struct Holiday {
     pub date: String,
     pub description: Option<String>,
     pub is_recurring: bool,
+    pub calendar_type: CalendarType,
 }
 
 #[derive(Debug, Deserialize)]
@@ -96,6 +126,15 @@ pub struct CreateHolidayInput {
     pub date: String,
     pub description: Option<String>,
     pub is_recurring: bool,
+    #[serde(default)]
+    pub calendar_type: Option<CalendarType>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayImportResult {
+    pub added: usize,
+    pub updated: usize,
 }
 
 #[tauri::command]
@@ -104,17 +143,19 @@ pub fn get_holidays(db: State<DbState>) -> Result<Vec<Holiday>, String> {
     let conn = db.conn();
 
     let mut stmt = conn
-        .prepare("SELECT id, date, description, is_recurring FROM holidays ORDER BY date")
+        .prepare("SELECT id, date, description, is_recurring, calendar_type FROM holidays ORDER BY date")
         .map_err(|e| e.to_string())?;
 
     let rows = stmt
         .query_map([], |row| {
             let is_recurring_int: i32 = row.get(3)?;
+            let calendar_type: String = row.get(4)?;
             Ok(Holiday {
                 id: row.get(0)?,
                 date: row.get(1)?,
                 description: row.get(2)?,
                 is_recurring: is_recurring_int == 1,
+                calendar_type: CalendarType::parse(&calendar_type).unwrap_or(CalendarType::Solar),
             })
         })
         .map_err(|e| e.to_string())?;
@@ -125,18 +166,22 @@ pub fn get_holidays(db: State<DbState>) -> Result<Vec<Holiday>, String> {
 
 #[tauri::command]
 pub fn add_holiday(data: CreateHolidayInput, db: State<DbState>) -> Result<Holiday, String> {
+    let calendar_type = data.calendar_type.unwrap_or(CalendarType::Solar);
+    holidays::validate_date(&data.date, calendar_type)?;
+
     let db = db.0.lock().map_err(|e| e.to_string())?;
     let conn = db.conn();
 
     let id = uuid::Uuid::new_v4().to_string();
 
     conn.execute(
-        "INSERT INTO holidays (id, date, description, is_recurring) VALUES (?1, ?2, ?3, ?4)",
+        "INSERT INTO holidays (id, date, description, is_recurring, calendar_type) VALUES (?1, ?2, ?3, ?4, ?5)",
         rusqlite::params![
             id,
             data.date,
             data.description,
             if data.is_recurring { 1 } else { 0 },
+            calendar_type.as_str(),
         ],
     )
     .map_err(|e| e.to_string())?;
@@ -146,6 +191,7 @@ pub fn add_holiday(data: CreateHolidayInput, db: State<DbState>) -> Result<Holid
         date: data.date,
         description: data.description,
         is_recurring: data.is_recurring,
+        calendar_type,
     })
 }
 
@@ -159,3 +205,292 @@ pub fn delete_holiday(id: String, db: State<DbState>) -> Result<(), String> {
 
     Ok(())
 }
+
+/// 해당 연도에 실제로 쉬는 날짜 목록 (매년 반복, 음력 휴일을 양력으로 변환)
+#[tauri::command]
+pub fn get_holidays_for_year(year: i32, db: State<DbState>) -> Result<Vec<HolidayOccurrence>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    holidays::occurrences_in_year(db.conn(), year)
+}
+
+/// 내장된 대한민국 공휴일 목록을 매년 반복 휴일로 등록
+#[tauri::command]
+pub fn import_korean_holidays(db: State<DbState>) -> Result<HolidayImportResult, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let year = chrono::Local::now().year();
+    let (added, updated) = holidays::import_korean_public_holidays(conn, year)?;
+    println!("[Holidays] Imported Korean public holidays: {} added, {} updated", added, updated);
+
+    Ok(HolidayImportResult { added, updated })
+}
+
+#[tauri::command]
+pub fn convert_solar_to_lunar(date: String) -> Result<LunarDate, String> {
+    lunar::solar_to_lunar(schedule::parse_date(&date)?)
+}
+
+#[tauri::command]
+pub fn convert_lunar_to_solar(
+    year: i32,
+    month: u32,
+    day: u32,
+    is_leap_month: bool,
+) -> Result<Option<String>, String> {
+    Ok(lunar::lunar_to_solar(year, month, day, is_leap_month)?
+        .map(|d| d.format("%Y-%m-%d").to_string()))
+}
+
+// 특정일 영업시간 예외
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusinessHourOverride {
+    pub id: String,
+    pub date: String,
+    pub open_time: Option<String>,
+    pub close_time: Option<String>,
+    pub break_start: Option<String>,
+    pub break_end: Option<String>,
+    pub is_closed: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetBusinessHourOverrideInput {
+    pub date: String,
+    pub open_time: Option<String>,
+    pub close_time: Option<String>,
+    pub break_start: Option<String>,
+    pub break_end: Option<String>,
+    pub is_closed: bool,
+    pub description: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_business_hour_overrides(
+    date_from: Option<String>,
+    date_to: Option<String>,
+    db: State<DbState>,
+) -> Result<Vec<BusinessHourOverride>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, date, open_time, close_time, break_start, break_end, is_closed, description
+             FROM business_hour_overrides
+             WHERE (?1 IS NULL OR date >= ?1) AND (?2 IS NULL OR date <= ?2)
+             ORDER BY date",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![date_from, date_to], |row| {
+            let is_closed_int: i32 = row.get(6)?;
+            Ok(BusinessHourOverride {
+                id: row.get(0)?,
+                date: row.get(1)?,
+                open_time: row.get(2)?,
+                close_time: row.get(3)?,
+                break_start: row.get(4)?,
+                break_end: row.get(5)?,
+                is_closed: is_closed_int == 1,
+                description: row.get(7)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let overrides: Vec<BusinessHourOverride> = rows.filter_map(|r| r.ok()).collect();
+    Ok(overrides)
+}
+
+/// 특정일 예외 등록 (같은 날짜가 있으면 덮어쓴다)
+#[tauri::command]
+pub fn set_business_hour_override(
+    data: SetBusinessHourOverrideInput,
+    db: State<DbState>,
+) -> Result<BusinessHourOverride, String> {
+    schedule::parse_date(&data.date)?;
+    let hours = DayHours {
+        open_time: data.open_time,
+        close_time: data.close_time,
+        break_start: data.break_start,
+        break_end: data.break_end,
+    }
+    .validate(data.is_closed)?;
+    let data = SetBusinessHourOverrideInput {
+        open_time: hours.open_time,
+        close_time: hours.close_time,
+        break_start: hours.break_start,
+        break_end: hours.break_end,
+        ..data
+    };
+
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let existing_id: Option<String> = conn
+        .query_row(
+            "SELECT id FROM business_hour_overrides WHERE date = ?1",
+            [&data.date],
+            |row| row.get(0),
+        )
+        .ok();
+    let id = existing_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    conn.execute(
+        "INSERT INTO business_hour_overrides
+            (id, date, open_time, close_time, break_start, break_end, is_closed, description, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)
+         ON CONFLICT(date) DO UPDATE SET
+            open_time = excluded.open_time,
+            close_time = excluded.close_time,
+            break_start = excluded.break_start,
+            break_end = excluded.break_end,
+            is_closed = excluded.is_closed,
+            description = excluded.description,
+            updated_at = excluded.updated_at",
+        rusqlite::params![
+            id,
+            data.date,
+            data.open_time,
+            data.close_time,
+            data.break_start,
+            data.break_end,
+            if data.is_closed { 1 } else { 0 },
+            data.description,
+            now,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(BusinessHourOverride {
+        id,
+        date: data.date,
+        open_time: data.open_time,
+        close_time: data.close_time,
+        break_start: data.break_start,
+        break_end: data.break_end,
+        is_closed: data.is_closed,
+        description: data.description,
+    })
+}
+
+#[tauri::command]
+pub fn delete_business_hour_override(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    conn.execute("DELETE FROM business_hour_overrides WHERE id = ?1", rusqlite::params![id])
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// 휴무 기간
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosurePeriod {
+    pub id: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateClosurePeriodInput {
+    pub start_date: String,
+    pub end_date: String,
+    pub description: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_closure_periods(db: State<DbState>) -> Result<Vec<ClosurePeriod>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let mut stmt = conn
+        .prepare("SELECT id, start_date, end_date, description FROM closure_periods ORDER BY start_date")
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(ClosurePeriod {
+                id: row.get(0)?,
+                start_date: row.get(1)?,
+                end_date: row.get(2)?,
+                description: row.get(3)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let periods: Vec<ClosurePeriod> = rows.filter_map(|r| r.ok()).collect();
+    Ok(periods)
+}
+
+#[tauri::command]
+pub fn add_closure_period(
+    data: CreateClosurePeriodInput,
+    db: State<DbState>,
+) -> Result<ClosurePeriod, String> {
+    let start = schedule::parse_date(&data.start_date)?;
+    let end = schedule::parse_date(&data.end_date)?;
+    if end < start {
+        return Err("종료일은 시작일보다 빠를 수 없습니다".to_string());
+    }
+
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let id = uuid::Uuid::new_v4().to_string();
+
+    conn.execute(
+        "INSERT INTO closure_periods (id, start_date, end_date, description) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![id, data.start_date, data.end_date, data.description],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(ClosurePeriod {
+        id,
+        start_date: data.start_date,
+        end_date: data.end_date,
+        description: data.description,
+    })
+}
+
+#[tauri::command]
+pub fn delete_closure_period(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    conn.execute("DELETE FROM closure_periods WHERE id = ?1", rusqlite::params![id])
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// 실제 적용 영업시간 (예외, 휴무 기간, 휴일, 요일별 영업시간 순으로 적용)
+#[tauri::command]
+pub fn get_effective_hours(date: String, db: State<DbState>) -> Result<EffectiveHours, String> {
+    let date = schedule::parse_date(&date)?;
+
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    schedule::effective_hours_for_date(db.conn(), date)
+}
+
+#[tauri::command]
+pub fn get_effective_hours_range(
+    start_date: String,
+    end_date: String,
+    db: State<DbState>,
+) -> Result<Vec<EffectiveHours>, String> {
+    let start = schedule::parse_date(&start_date)?;
+    let end = schedule::parse_date(&end_date)?;
+
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    schedule::effective_hours_for_range(db.conn(), start, end)
+}
@@ -1,7 +1,7 @@
 use tauri::{AppHandle, Manager, State};
 
 use crate::db::DbState;
-use crate::services::excel;
+use crate::services::{excel, ical};
 
 #[tauri::command]
 pub fn export_to_excel(
@@ -49,6 +49,38 @@ pub fn export_to_csv(
     Ok(result_path.to_string_lossy().to_string())
 }
 
+/// 디자이너 한 명 또는 살롱 전체의 예약을 iCalendar(.ics) 파일로 내보낸다.
+/// 파일명이 고정되어 있어 같은 위치에 다시 내보내면 구독 중인 캘린더가 갱신된다.
+#[tauri::command]
+pub fn export_to_ics(
+    app: AppHandle,
+    designer_id: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+    db: State<DbState>,
+) -> Result<String, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let conn = db.conn();
+
+    let date_from = date_from.unwrap_or_else(|| ical::DEFAULT_DATE_FROM.to_string());
+    let date_to = date_to.unwrap_or_else(|| ical::DEFAULT_DATE_TO.to_string());
+
+    // 다운로드 디렉토리 가져오기
+    let download_dir = app
+        .path()
+        .download_dir()
+        .map_err(|e| e.to_string())?;
+
+    let filename = match designer_id.as_deref() {
+        Some(id) => format!("sisters_salon_{}.ics", id),
+        None => "sisters_salon.ics".to_string(),
+    };
+    let output_path = download_dir.join(&filename);
+
+    let result_path = ical::export_ics(conn, designer_id.as_deref(), &date_from, &date_to, output_path)?;
+    Ok(result_path.to_string_lossy().to_string())
+}
+
 #[tauri::command]
 pub fn get_export_path(app: AppHandle) -> Result<String, String> {
     let download_dir = app
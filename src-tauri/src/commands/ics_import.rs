@@ -0,0 +1,28 @@
+use tauri::{AppHandle, State};
+
+use crate::db::DbState;
+use crate::services::backup::auto;
+use crate::services::ical_import::{self, IcsCalendarSummary, IcsFileInput, IcsImportInput, IcsImportResult};
+
+/// 가져올 .ics 파일을 분석해 캘린더별 일정 수와 추천 디자이너를 돌려준다 (매핑 단계)
+#[tauri::command]
+pub fn inspect_ics_files(
+    files: Vec<IcsFileInput>,
+    db: State<DbState>,
+) -> Result<Vec<IcsCalendarSummary>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    ical_import::inspect_files(db.conn(), &files)
+}
+
+/// .ics 일정을 예약으로 가져온다. `dryRun` 이면 저장하지 않고 미리보기만 돌려준다.
+#[tauri::command]
+pub fn import_ics(
+    app: AppHandle,
+    data: IcsImportInput,
+    db: State<DbState>,
+) -> Result<IcsImportResult, String> {
+    let data_dir = crate::runtime::data_dir(&app).map_err(|e| e.to_string())?;
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    auto::backup_before_operation(&db, &data_dir, "ics_import")?;
+    ical_import::import_calendars(db.conn(), &data)
+}
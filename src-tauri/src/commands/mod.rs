@@ -1,9 +1,16 @@
 pub mod reservations;
 pub mod designers;
+pub mod time_blocks;
 pub mod business_hours;
 pub mod statistics;
 pub mod export;
+pub mod ics_import;
 pub mod backup;
 pub mod customers;
 pub mod security;
+pub mod api_server;
+pub mod webhooks;
+pub mod reminders;
+pub mod privacy;
+pub mod sync;
 pub mod utils;
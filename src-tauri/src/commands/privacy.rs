@@ -0,0 +1,90 @@
+use tauri::{AppHandle, Manager, State};
+
+use crate::db::DbState;
+use crate::services::dossier;
+use crate::services::privacy::{
+    self, AnonymizeResult, AuditReport, ConsentRecord, PrivacySettings, PurgeReport, RetentionCandidate,
+};
+
+#[tauri::command]
+pub fn get_privacy_settings(db: State<DbState>) -> Result<PrivacySettings, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    privacy::get_settings(db.conn())
+}
+
+#[tauri::command]
+pub fn update_privacy_settings(settings: PrivacySettings, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    privacy::save_settings(db.conn(), &settings)
+}
+
+#[tauri::command]
+pub fn get_customer_consent_history(
+    customer_id: String,
+    db: State<DbState>,
+) -> Result<Vec<ConsentRecord>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    privacy::get_consent_history(db.conn(), &customer_id)
+}
+
+/// 지금 실행하면 익명 처리될 고객 미리보기
+#[tauri::command]
+pub fn preview_retention_purge(db: State<DbState>) -> Result<Vec<RetentionCandidate>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let settings = privacy::get_settings(db.conn())?;
+    privacy::find_retention_candidates(db.conn(), settings.retention_years, chrono::Local::now().date_naive())
+}
+
+/// 보관 기간이 지난 고객을 익명 처리한다. 지운 개인정보가 사본으로 남지 않도록
+/// 작업 전 자동 백업은 하지 않는다.
+#[tauri::command]
+pub fn run_retention_purge(db: State<DbState>) -> Result<PurgeReport, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let settings = privacy::get_settings(db.conn())?;
+    privacy::purge_inactive_customers(
+        db.conn(),
+        &settings,
+        chrono::Local::now().date_naive(),
+        privacy::TRIGGER_MANUAL,
+    )
+}
+
+#[tauri::command]
+pub fn get_privacy_audit_report(
+    date_from: Option<String>,
+    date_to: Option<String>,
+    db: State<DbState>,
+) -> Result<AuditReport, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    privacy::get_audit_report(db.conn(), date_from.as_deref(), date_to.as_deref())
+}
+
+/// 고객 한 명의 열람 자료를 다운로드 폴더에 저장 (format: json | pdf)
+#[tauri::command]
+pub fn export_customer_dossier(
+    app: AppHandle,
+    customer_id: String,
+    format: String,
+    db: State<DbState>,
+) -> Result<String, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let dossier = dossier::build_dossier(db.conn(), &customer_id)?;
+
+    // 다운로드 디렉토리 가져오기
+    let download_dir = app.path().download_dir().map_err(|e| e.to_string())?;
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let path = match format.as_str() {
+        "json" => dossier::write_json(&dossier, &download_dir.join(format!("customer_{}_{}.json", customer_id, timestamp)))?,
+        "pdf" => dossier::write_pdf(&dossier, &download_dir.join(format!("customer_{}_{}.pdf", customer_id, timestamp)))?,
+        other => return Err(format!("지원하지 않는 형식입니다: {}", other)),
+    };
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 고객의 삭제 요청: 개인정보를 지우고 예약은 익명으로 남긴다.
+/// 지운 개인정보가 사본으로 남지 않도록 작업 전 자동 백업은 하지 않는다.
+#[tauri::command]
+pub fn erase_customer_data(customer_id: String, db: State<DbState>) -> Result<AnonymizeResult, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    privacy::erase_customer(db.conn(), &customer_id)
+}
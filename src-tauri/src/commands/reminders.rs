@@ -0,0 +1,68 @@
+use tauri::{AppHandle, State};
+
+use crate::db::DbState;
+use crate::services::reminders::{self, Reminder, ReminderSettings};
+
+#[tauri::command]
+pub fn get_reminder_settings(db: State<DbState>) -> Result<ReminderSettings, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::get_settings(db.conn())
+}
+
+#[tauri::command]
+pub fn update_reminder_settings(settings: ReminderSettings, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::save_settings(db.conn(), &settings)
+}
+
+/// 이 기기가 알림을 보내는 기기인지 (동기화하는 기기 중 한 곳에서만 보낸다)
+#[tauri::command]
+pub fn is_reminder_sender(db: State<DbState>) -> Result<bool, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::is_sender(db.conn())
+}
+
+#[tauri::command]
+pub fn claim_reminder_sender(db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::claim_sender(db.conn())
+}
+
+/// 저장하지 않은 설정으로 예시 문구 만들기
+#[tauri::command]
+pub fn preview_reminder_message(settings: ReminderSettings) -> Result<String, String> {
+    Ok(reminders::preview(&settings))
+}
+
+#[tauri::command]
+pub fn send_test_reminder(
+    app: AppHandle,
+    settings: ReminderSettings,
+    phone: String,
+) -> Result<Option<String>, String> {
+    let data_dir = crate::runtime::data_dir(&app).map_err(|e| e.to_string())?;
+    reminders::send_test(&settings, &data_dir, &phone)
+}
+
+#[tauri::command]
+pub fn get_reminders(
+    date_from: Option<String>,
+    date_to: Option<String>,
+    status: Option<String>,
+    db: State<DbState>,
+) -> Result<Vec<Reminder>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::get_reminders(db.conn(), date_from.as_deref(), date_to.as_deref(), status.as_deref())
+}
+
+#[tauri::command]
+pub fn cancel_reminder(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::cancel_reminder(db.conn(), &id)
+}
+
+#[tauri::command]
+pub fn retry_reminder(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    reminders::retry_reminder(db.conn(), &id)
+}
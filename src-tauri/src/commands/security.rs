@@ -107,6 +107,28 @@ pub fn change_lock_pin(db: State<DbState>, old_pin: String, new_pin: String) ->
     Ok(())
 }
 
+/// Exports the database encryption key as a hex recovery key, used to restore device-key
+/// backups (.db) on another device or to open the database with `salon-admin --key-file`.
+/// Requires the lock PIN when the app lock is enabled.
+#[tauri::command]
+pub fn export_database_key(db: State<DbState>, pin: Option<String>) -> Result<String, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    let settings = auth::get_settings(db.conn())?;
+
+    if settings.is_enabled {
+        let pin = pin.unwrap_or_default();
+        let valid = matches!(auth::verify_pin(&pin), Ok(true))
+            || settings.pin_hash.as_deref().is_some_and(|hash| bcrypt::verify(&pin, hash).unwrap_or(false));
+        if !valid {
+            return Err("PIN is incorrect".to_string());
+        }
+    }
+
+    db.key()
+        .map(|key| key.to_hex().to_string())
+        .ok_or_else(|| "암호화되지 않은 데이터베이스입니다".to_string())
+}
+
 #[tauri::command]
 pub fn is_lock_enabled(db: State<DbState>) -> Result<bool, String> {
     let db = db.0.lock().map_err(|e| e.to_string())?;
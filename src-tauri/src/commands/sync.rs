@@ -0,0 +1,72 @@
+use tauri::State;
+
+use crate::db::DbState;
+use crate::services::sync::lan::{self, discovery::DiscoveredDevice, LanStatus, PairedDevice, PairingCode};
+use crate::services::sync::{self, SyncReport, SyncSettings, SyncStatus};
+
+#[tauri::command]
+pub fn get_sync_settings(db: State<DbState>) -> Result<SyncSettings, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    sync::get_settings(db.conn())
+}
+
+/// 설정을 저장하고 같은 네트워크 동기화 서버를 켜거나 끈다
+#[tauri::command]
+pub fn update_sync_settings(settings: SyncSettings, db: State<DbState>) -> Result<LanStatus, String> {
+    {
+        let db = db.0.lock().map_err(|e| e.to_string())?;
+        sync::save_settings(db.conn(), &settings)?;
+    }
+    lan::apply_settings(db.0.clone(), &settings)
+}
+
+/// 마지막 동기화 결과와 충돌 보고서
+#[tauri::command]
+pub fn get_sync_status(db: State<DbState>) -> Result<Option<SyncStatus>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    sync::get_status(db.conn())
+}
+
+/// 공유 폴더·페어링한 기기와 지금 동기화
+#[tauri::command]
+pub fn run_sync(db: State<DbState>) -> Result<SyncReport, String> {
+    sync::run_sync(&db.0)
+}
+
+#[tauri::command]
+pub fn get_lan_sync_status() -> Result<LanStatus, String> {
+    lan::status()
+}
+
+/// 다른 기기에 입력할 페어링 코드를 띄운다
+#[tauri::command]
+pub fn start_lan_pairing() -> Result<PairingCode, String> {
+    lan::start_pairing()
+}
+
+#[tauri::command]
+pub fn cancel_lan_pairing() -> Result<(), String> {
+    lan::cancel_pairing()
+}
+
+#[tauri::command]
+pub fn discover_lan_devices(db: State<DbState>) -> Result<Vec<DiscoveredDevice>, String> {
+    lan::discover(&db.0)
+}
+
+#[tauri::command]
+pub fn pair_lan_device(address: String, code: String, db: State<DbState>) -> Result<PairedDevice, String> {
+    lan::pair(&db.0, address.trim(), &code)
+}
+
+#[tauri::command]
+pub fn get_paired_devices(db: State<DbState>) -> Result<Vec<PairedDevice>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    lan::paired_devices(db.conn())
+}
+
+#[tauri::command]
+pub fn unpair_lan_device(node_id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    lan::unpair(db.conn(), &node_id)
+}
@@ -0,0 +1,40 @@
+use tauri::State;
+use crate::db::DbState;
+use crate::services::time_blocks::{self, CreateTimeBlockInput, TimeBlock};
+
+#[tauri::command]
+pub fn get_time_blocks(
+    date: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+    designer_id: Option<String>,
+    db: State<DbState>,
+) -> Result<Vec<TimeBlock>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    time_blocks::get_time_blocks(db.conn(), date, date_from, date_to, designer_id)
+}
+
+#[tauri::command]
+pub fn create_time_block(
+    data: CreateTimeBlockInput,
+    db: State<DbState>,
+) -> Result<TimeBlock, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    time_blocks::create_time_block(db.conn(), data)
+}
+
+#[tauri::command]
+pub fn update_time_block(
+    id: String,
+    data: CreateTimeBlockInput,
+    db: State<DbState>,
+) -> Result<TimeBlock, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    time_blocks::update_time_block(db.conn(), &id, data)
+}
+
+#[tauri::command]
+pub fn delete_time_block(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    time_blocks::delete_time_block(db.conn(), &id)
+}
@@ -1,6 +1,10 @@
-use tauri::State;
+use tauri::{Manager, State};
 use tauri_plugin_opener::OpenerExt;
+use crate::runtime::StartupError;
 use crate::db::DbState;
+use crate::services::api::{self, ApiServerSettings};
+use crate::services::backup::auto;
+use crate::services::sync::{lan, SyncSettings};
 
 #[tauri::command]
 pub fn open_external_url(app: tauri::AppHandle, url: String) -> Result<(), String> {
@@ -9,18 +13,38 @@ pub fn open_external_url(app: tauri::AppHandle, url: String) -> Result<(), Strin
         .map_err(|e| e.to_string())
 }
 
+/// 데이터베이스를 열지 못하고 시작했으면 그 이유
 #[tauri::command]
-pub fn reset_all_data(db: State<DbState>) -> Result<(), String> {
-    let db = db.0.lock().map_err(|e| e.to_string())?;
-    let conn = db.conn();
+pub fn get_startup_error(app: tauri::AppHandle) -> Option<StartupError> {
+    app.try_state::<StartupError>().map(|error| error.inner().clone())
+}
+
+#[tauri::command]
+pub fn reset_all_data(app: tauri::AppHandle, db: State<DbState>) -> Result<(), String> {
+    let data_dir = crate::runtime::data_dir(&app).map_err(|e| e.to_string())?;
+    let guard = db.0.lock().map_err(|e| e.to_string())?;
+    auto::backup_before_operation(&guard, &data_dir, "reset")?;
+    let conn = guard.conn();
 
     conn.execute_batch(
-        "DELETE FROM reservation_status_history;
+        "DELETE FROM webhook_deliveries;
+         DELETE FROM webhook_outbox;
+         DELETE FROM webhook_endpoints;
+         DELETE FROM reminders;
+         DELETE FROM messages;
+         DELETE FROM customer_consent_log;
+         DELETE FROM privacy_audit_log;
+         DELETE FROM reservation_status_history;
          DELETE FROM reservations;
+         DELETE FROM time_blocks;
          DELETE FROM customers;
          DELETE FROM designers;
          DELETE FROM holidays;
+         DELETE FROM business_hour_overrides;
+         DELETE FROM closure_periods;
          DELETE FROM app_settings;
+         DELETE FROM shared_settings;
+         DELETE FROM api_keys;
          DELETE FROM business_hours;
          INSERT OR IGNORE INTO business_hours (id, day_of_week, open_time, close_time, is_closed) VALUES
             (0, 0, NULL, NULL, 1),
@@ -29,9 +53,20 @@ pub fn reset_all_data(db: State<DbState>) -> Result<(), String> {
             (3, 3, '10:00', '20:00', 0),
             (4, 4, '10:00', '20:00', 0),
             (5, 5, '10:00', '20:00', 0),
-            (6, 6, '10:00', '18:00', 0);"
+            (6, 6, '10:00', '18:00', 0);
+         -- 동기화 기록도 지워, 다시 동기화하면 새 기기로 참여해 다른 기기의 데이터를 받는다
+         DELETE FROM sync_dirty;
+         DELETE FROM sync_fields;
+         DELETE FROM sync_tombstones;
+         DELETE FROM sync_state;
+         DELETE FROM sync_peers;"
     )
     .map_err(|e| e.to_string())?;
+    drop(guard);
+
+    // 설정이 지워졌으므로 로컬 API 서버와 동기화 서버도 중지
+    api::apply_settings(db.0.clone(), &ApiServerSettings::default())?;
+    lan::apply_settings(db.0.clone(), &SyncSettings::default())?;
 
     Ok(())
 }
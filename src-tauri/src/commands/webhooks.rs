@@ -0,0 +1,59 @@
+use tauri::State;
+
+use crate::db::DbState;
+use crate::services::webhooks::{
+    self, CreateWebhookEndpointInput, UpdateWebhookEndpointInput, WebhookDelivery, WebhookEndpoint,
+};
+
+#[tauri::command]
+pub fn get_webhook_endpoints(db: State<DbState>) -> Result<Vec<WebhookEndpoint>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::get_endpoints(db.conn())
+}
+
+#[tauri::command]
+pub fn create_webhook_endpoint(
+    data: CreateWebhookEndpointInput,
+    db: State<DbState>,
+) -> Result<WebhookEndpoint, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::create_endpoint(db.conn(), data)
+}
+
+#[tauri::command]
+pub fn update_webhook_endpoint(
+    id: String,
+    data: UpdateWebhookEndpointInput,
+    db: State<DbState>,
+) -> Result<WebhookEndpoint, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::update_endpoint(db.conn(), &id, data)
+}
+
+#[tauri::command]
+pub fn delete_webhook_endpoint(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::delete_endpoint(db.conn(), &id)
+}
+
+#[tauri::command]
+pub fn send_test_webhook(id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::send_test(db.conn(), &id)
+}
+
+#[tauri::command]
+pub fn get_webhook_deliveries(
+    endpoint_id: Option<String>,
+    limit: Option<u32>,
+    db: State<DbState>,
+) -> Result<Vec<WebhookDelivery>, String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::get_deliveries(db.conn(), endpoint_id.as_deref(), limit.unwrap_or(50))
+}
+
+#[tauri::command]
+pub fn retry_webhook_delivery(outbox_id: String, db: State<DbState>) -> Result<(), String> {
+    let db = db.0.lock().map_err(|e| e.to_string())?;
+    webhooks::retry(db.conn(), &outbox_id)
+}
@@ -0,0 +1,375 @@
+//! 데이터베이스 암호화 (SQLCipher)
+//!
+//! `database.db` 는 SQLCipher 로 암호화된다. 256비트 키는 처음 실행할 때 만들어
+//! OS 키체인(macOS/iOS Keychain, Windows 자격 증명 관리자)에 보관한다.
+//! 키체인을 쓸 수 없는 플랫폼에서는 앱 데이터 폴더의 `database.key` (소유자만 읽기)에 둔다.
+//! 키 파일은 데이터베이스와 같은 폴더에 평문으로 있으므로, 폴더째 복사되면 함께 넘어간다.
+//! 키체인보다 약한 보호이며 같은 계정의 다른 프로그램·디스크 접근만 막는다.
+//!
+//! 키는 기기마다 다르므로 기기 키로 암호화된 `.db` 백업은 다른 기기에서 열 수 없다.
+//! 다른 기기에서 복원하려면 비밀번호 아카이브를 쓰거나, 원래 기기에서 내보낸 키
+//! (`DatabaseKey::to_hex`, 설정의 "복구 키")를 복원할 때 함께 입력한다.
+//!
+//! 이전 버전에서 만든 암호화되지 않은 데이터베이스는 열 때 자동으로 암호화본으로 바꾼다.
+
+use rusqlite::{ffi, Connection};
+use std::ffi::CString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const SERVICE_NAME: &str = "com.sisters-salon.app";
+const DB_KEY_ENTRY: &str = "database_key";
+const KEY_FILE: &str = "database.key";
+
+/// 암호화되지 않은 SQLite 파일의 헤더
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// SQLCipher raw 키 (32바이트, hex 64자)
+#[derive(Clone, PartialEq)]
+pub struct DatabaseKey(String);
+
+impl std::fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DatabaseKey(..)")
+    }
+}
+
+impl DatabaseKey {
+    pub fn generate() -> Result<Self, String> {
+        let mut bytes = [0u8; 32];
+        getrandom::getrandom(&mut bytes).map_err(|e| format!("암호화 키 생성 실패: {}", e))?;
+        Ok(Self(bytes.iter().map(|b| format!("{:02x}", b)).collect()))
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err("저장된 데이터베이스 키 형식이 올바르지 않습니다".to_string());
+        }
+        Ok(Self(hex.to_ascii_lowercase()))
+    }
+
+    /// 복구 키로 내보내는 hex 문자열 (`from_hex` 로 다시 읽는다)
+    pub fn to_hex(&self) -> &str {
+        &self.0
+    }
+
+    /// `PRAGMA key` / `ATTACH ... KEY` 에 넣는 값 (`"x'...'"`)
+    fn sql_literal(&self) -> String {
+        format!("\"x'{}'\"", self.0)
+    }
+}
+
+/// 키체인 → 키 파일 순으로 키를 찾고, 없으면 새로 만든다.
+pub fn load_or_create_key(app_dir: &Path) -> Result<DatabaseKey, String> {
+    if let Some(key) = load_key(app_dir)? {
+        return Ok(key);
+    }
+
+    let key = DatabaseKey::generate()?;
+    if store_in_keyring(&key) {
+        println!("[DB] 데이터베이스 키를 OS 키체인에 저장했습니다");
+    } else {
+        println!("[DB] OS 키체인을 사용할 수 없어 키 파일에 저장합니다");
+        write_key_file(&app_dir.join(KEY_FILE), &key)?;
+    }
+    Ok(key)
+}
+
+/// 저장된 키 (없으면 None)
+pub fn load_key(app_dir: &Path) -> Result<Option<DatabaseKey>, String> {
+    if let Ok(entry) = keyring::Entry::new(SERVICE_NAME, DB_KEY_ENTRY) {
+        match entry.get_password() {
+            Ok(hex) => return DatabaseKey::from_hex(&hex).map(Some),
+            Err(keyring::Error::NoEntry) => {}
+            Err(e) => println!("[DB] 키체인 조회 실패: {}", e),
+        }
+    }
+
+    read_key_file(&app_dir.join(KEY_FILE))
+}
+
+fn read_key_file(path: &Path) -> Result<Option<DatabaseKey>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let hex = std::fs::read_to_string(path).map_err(|e| format!("키 파일을 읽을 수 없습니다: {}", e))?;
+    DatabaseKey::from_hex(&hex).map(Some)
+}
+
+/// 키체인에 저장하고 새 항목으로 다시 읽어 실제로 보관되는지 확인한다.
+/// (백엔드가 없는 플랫폼의 keyring 은 메모리에만 두므로 다시 읽으면 비어 있다)
+fn store_in_keyring(key: &DatabaseKey) -> bool {
+    let stored = keyring::Entry::new(SERVICE_NAME, DB_KEY_ENTRY).and_then(|entry| entry.set_password(&key.0));
+    if stored.is_err() {
+        return false;
+    }
+    matches!(
+        keyring::Entry::new(SERVICE_NAME, DB_KEY_ENTRY).and_then(|entry| entry.get_password()),
+        Ok(hex) if hex == key.0
+    )
+}
+
+fn write_key_file(path: &Path, key: &DatabaseKey) -> Result<(), String> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| format!("키 파일을 만들 수 없습니다: {}", e))?;
+    std::io::Write::write_all(&mut file, key.0.as_bytes()).map_err(|e| format!("키 파일을 저장할 수 없습니다: {}", e))
+}
+
+/// 연결에 키를 적용한다. 다른 어떤 문장보다 먼저 호출해야 한다.
+pub fn apply_key(conn: &Connection, key: &DatabaseKey) -> rusqlite::Result<()> {
+    conn.execute_batch(&format!("PRAGMA key = {};", key.sql_literal()))
+}
+
+/// 키로 열 수 있는지 확인 (틀린 키면 첫 조회에서 "file is not a database")
+pub fn verify_key(conn: &Connection) -> Result<(), String> {
+    conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| row.get::<_, i64>(0))
+        .map(|_| ())
+        .map_err(|_| "데이터베이스를 열 수 없습니다. 암호화 키가 맞지 않습니다".to_string())
+}
+
+/// 파일이 암호화되지 않은 SQLite 데이터베이스인지
+pub fn is_plaintext(path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .map(|_| &header == SQLITE_HEADER)
+        .unwrap_or(false)
+}
+
+fn quote_path(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', "''"))
+}
+
+/// 암호화되지 않은 `source` 를 `dest` 에 암호화본으로 내보낸다.
+pub fn export_encrypted(source: &Path, dest: &Path, key: &DatabaseKey) -> Result<(), String> {
+    let conn = Connection::open(source).map_err(|e| e.to_string())?;
+    export_connection_encrypted(&conn, dest, key)
+}
+
+/// 열려 있는 암호화되지 않은 연결(`open_image` 로 연 아카이브 데이터베이스 등)을 `dest` 에 암호화본으로 내보낸다.
+pub fn export_connection_encrypted(conn: &Connection, dest: &Path, key: &DatabaseKey) -> Result<(), String> {
+    let _ = std::fs::remove_file(dest);
+    let user_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| format!("데이터베이스를 읽을 수 없습니다: {}", e))?;
+    conn.execute_batch(&format!(
+        "ATTACH DATABASE {} AS encrypted KEY {};
+         SELECT sqlcipher_export('encrypted');
+         PRAGMA encrypted.user_version = {};
+         DETACH DATABASE encrypted;",
+        quote_path(dest),
+        key.sql_literal(),
+        user_version
+    ))
+    .map_err(|e| format!("암호화 변환 실패: {}", e))?;
+
+    // 새 파일이 키로 열리고 손상되지 않았는지 확인
+    let check = Connection::open(dest).map_err(|e| e.to_string())?;
+    apply_key(&check, key).map_err(|e| e.to_string())?;
+    verify_key(&check)?;
+    let result: String = check
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    if result != "ok" {
+        return Err(format!("암호화된 데이터베이스 검증 실패: {}", result));
+    }
+    Ok(())
+}
+
+/// 열려 있는 연결의 데이터베이스를 암호화하지 않은 SQLite 이미지로 꺼낸다.
+/// (비밀번호로 따로 암호화하는 백업 아카이브용. 평문은 메모리에만 둔다)
+pub fn export_plaintext(conn: &Connection) -> Result<Vec<u8>, String> {
+    let user_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    let exported = conn
+        .execute_batch(&format!(
+            "ATTACH DATABASE ':memory:' AS plaintext KEY '';
+             SELECT sqlcipher_export('plaintext');
+             PRAGMA plaintext.user_version = {};",
+            user_version
+        ))
+        .map_err(|e| e.to_string())
+        .and_then(|_| serialize(conn, "plaintext"));
+    let _ = conn.execute_batch("DETACH DATABASE plaintext;");
+    exported.map_err(|e| format!("데이터베이스 내보내기 실패: {}", e))
+}
+
+/// 평문 SQLite 이미지를 메모리 데이터베이스로 연다
+pub fn open_image(image: &[u8]) -> Result<Connection, String> {
+    if image.is_empty() {
+        return Err("백업 데이터베이스가 비어 있습니다".to_string());
+    }
+    let conn = Connection::open_in_memory().map_err(|e| e.to_string())?;
+    let schema = CString::new("main").map_err(|e| e.to_string())?;
+    // sqlite3_deserialize 는 sqlite3_malloc 으로 할당한 버퍼를 가져가 연결을 닫을 때 해제한다
+    let rc = unsafe {
+        let buffer = ffi::sqlite3_malloc64(image.len() as u64) as *mut u8;
+        if buffer.is_null() {
+            return Err("메모리가 부족합니다".to_string());
+        }
+        std::ptr::copy_nonoverlapping(image.as_ptr(), buffer, image.len());
+        ffi::sqlite3_deserialize(
+            conn.handle(),
+            schema.as_ptr(),
+            buffer,
+            image.len() as i64,
+            image.len() as i64,
+            (ffi::SQLITE_DESERIALIZE_FREEONCLOSE | ffi::SQLITE_DESERIALIZE_RESIZEABLE) as _,
+        )
+    };
+    if rc != ffi::SQLITE_OK {
+        return Err(format!("백업 데이터베이스를 열 수 없습니다 (SQLite 오류 {})", rc));
+    }
+    verify_key(&conn).map_err(|_| "백업 데이터베이스가 손상되었습니다".to_string())?;
+    Ok(conn)
+}
+
+/// 연결에 붙은 `schema` 데이터베이스의 이미지
+fn serialize(conn: &Connection, schema: &str) -> Result<Vec<u8>, String> {
+    let schema = CString::new(schema).map_err(|e| e.to_string())?;
+    let mut size: i64 = 0;
+    unsafe {
+        let data = ffi::sqlite3_serialize(conn.handle(), schema.as_ptr(), &mut size, 0);
+        if data.is_null() {
+            return Err("데이터베이스 이미지를 만들 수 없습니다".to_string());
+        }
+        let image = std::slice::from_raw_parts(data, size as usize).to_vec();
+        ffi::sqlite3_free(data as *mut std::os::raw::c_void);
+        Ok(image)
+    }
+}
+
+/// 다른 데이터베이스 파일을 `schema` 이름으로 붙인다. `key` 가 None 이면 암호화되지 않은 파일.
+pub fn attach(conn: &Connection, path: &Path, schema: &str, key: Option<&DatabaseKey>) -> Result<(), String> {
+    let key_literal = key.map(|k| k.sql_literal()).unwrap_or_else(|| "''".to_string());
+    conn.execute_batch(&format!("ATTACH DATABASE {} AS {} KEY {};", quote_path(path), schema, key_literal))
+        .map_err(|e| format!("백업 파일을 열 수 없습니다: {}", e))
+}
+
+/// 암호화되지 않은 데이터베이스를 제자리에서 암호화한다.
+///
+/// 임시 파일에 암호화본을 만들고 검증한 뒤 원본을 교체하므로, 중간에 실패해도
+/// 원본은 그대로 남는다.
+pub fn encrypt_in_place(db_path: &Path, key: &DatabaseKey) -> Result<(), String> {
+    // WAL 에만 있는 변경분을 본 파일로 옮긴 뒤 변환
+    {
+        let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
+        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);").map_err(|e| e.to_string())?;
+    }
+
+    let tmp = sibling(db_path, "encrypting");
+    if let Err(e) = export_encrypted(db_path, &tmp, key) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e);
+    }
+
+    std::fs::rename(&tmp, db_path).map_err(|e| format!("암호화된 데이터베이스로 교체 실패: {}", e))?;
+    for suffix in ["-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", db_path.to_string_lossy(), suffix));
+    }
+    println!("[DB] 기존 데이터베이스를 암호화했습니다: {:?}", db_path);
+    Ok(())
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", suffix));
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("salon-enc-{}-{}", name, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_encrypt_plaintext_database() {
+        let dir = temp_dir("migrate");
+        let path = dir.join("database.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "PRAGMA journal_mode=WAL;
+                 PRAGMA user_version = 3;
+                 CREATE TABLE customers (id TEXT PRIMARY KEY, phone TEXT);
+                 INSERT INTO customers VALUES ('c1', '010-1234-5678');",
+            )
+            .unwrap();
+        }
+        assert!(is_plaintext(&path));
+
+        let key = DatabaseKey::generate().unwrap();
+        encrypt_in_place(&path, &key).unwrap();
+
+        assert!(!is_plaintext(&path));
+        let raw = std::fs::read(&path).unwrap();
+        assert!(!raw.windows(13).any(|w| w == b"010-1234-5678"));
+
+        let conn = Connection::open(&path).unwrap();
+        apply_key(&conn, &key).unwrap();
+        let phone: String = conn.query_row("SELECT phone FROM customers", [], |row| row.get(0)).unwrap();
+        assert_eq!(phone, "010-1234-5678");
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, 3);
+        drop(conn);
+
+        // 다른 키로는 열리지 않음
+        let conn = Connection::open(&path).unwrap();
+        apply_key(&conn, &DatabaseKey::generate().unwrap()).unwrap();
+        assert!(verify_key(&conn).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_key_file_fallback() {
+        let dir = temp_dir("key");
+        let key = DatabaseKey::generate().unwrap();
+        let path = dir.join(KEY_FILE);
+        assert_eq!(read_key_file(&path).unwrap(), None);
+        write_key_file(&path, &key).unwrap();
+        assert_eq!(read_key_file(&path).unwrap(), Some(key));
+        assert!(DatabaseKey::from_hex("abcd").is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_plaintext_image_round_trip_in_memory() {
+        let dir = temp_dir("image");
+        let key = DatabaseKey::generate().unwrap();
+        let source = Connection::open(dir.join("database.db")).unwrap();
+        apply_key(&source, &key).unwrap();
+        source
+            .execute_batch("CREATE TABLE customers (id TEXT, name TEXT); INSERT INTO customers VALUES ('c1', '김민지'); PRAGMA user_version = 7;")
+            .unwrap();
+
+        let image = export_plaintext(&source).unwrap();
+        assert!(image.starts_with(SQLITE_HEADER));
+        let opened = open_image(&image).unwrap();
+        let name: String = opened.query_row("SELECT name FROM customers", [], |row| row.get(0)).unwrap();
+        assert_eq!(name, "김민지");
+
+        let dest = dir.join("restored.db");
+        export_connection_encrypted(&opened, &dest, &key).unwrap();
+        assert!(!is_plaintext(&dest));
+        // 평문 사본은 폴더에 남지 않는다
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(files.len(), 2, "{:?}", files);
+
+        assert!(open_image(b"not a database").is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+//! 번호를 붙인 스키마 마이그레이션
+//!
+//! 마지막으로 적용한 번호는 `PRAGMA user_version` 에, 적용 기록은 schema_version 테이블에 남긴다.
+//! 마이그레이션마다 한 트랜잭션 안에서 `up` 을 실행하고 `verify` 로 결과를 확인한 뒤 커밋하므로,
+//! 중간에 실패하면 그 마이그레이션 전 상태로 돌아가고 어느 단계에서 왜 실패했는지 알린다.
+//!
+//! `schema::SCHEMA` 는 1번 마이그레이션으로 만드는 기본 테이블이다. 이후 변경은 목록 끝에 다음 번호로
+//! 추가하고, 이미 배포한 마이그레이션은 고치지 않는다. 번호를 쓰기 전의 데이터베이스(user_version 0)는
+//! 일부 컬럼이 이미 있을 수 있으므로 초기 마이그레이션은 있는지 확인한 뒤 추가한다.
+
+use rusqlite::{Connection, OptionalExtension};
+
+use super::schema;
+
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    up: fn(&Connection) -> rusqlite::Result<()>,
+    verify: fn(&Connection) -> Result<(), String>,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, name: "initial_schema", up: initial_schema, verify: verify_initial_schema },
+    Migration { version: 2, name: "customer_profile_columns", up: customer_profile_columns, verify: verify_customer_profile_columns },
+    Migration { version: 3, name: "reservation_customer_link", up: reservation_customer_link, verify: verify_reservation_customer_link },
+    Migration { version: 4, name: "holidays_calendar_type", up: holidays_calendar_type, verify: verify_holidays_calendar_type },
+    Migration { version: 5, name: "sync_tracking", up: sync_tracking, verify: verify_sync_tracking },
+    Migration { version: 6, name: "ical_events", up: ical_events, verify: verify_ical_events },
+    Migration { version: 7, name: "shared_settings", up: shared_settings, verify: verify_shared_settings },
+];
+
+const HISTORY_SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS schema_version (
+    version INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+);";
+
+/// 이 앱이 아는 가장 높은 스키마 번호
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+pub fn current_version(conn: &Connection) -> Result<u32, String> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(|e| e.to_string())
+}
+
+/// 아직 적용하지 않은 마이그레이션을 순서대로 적용하고 적용한 번호들을 돌려준다
+pub fn run(conn: &Connection) -> Result<Vec<u32>, String> {
+    let current = current_version(conn)?;
+    let latest = latest_version();
+    if current > latest {
+        return Err(format!(
+            "더 새로운 버전의 앱에서 만든 데이터베이스입니다 (스키마 v{}, 이 앱은 v{}까지 지원). 앱을 업데이트해주세요",
+            current, latest
+        ));
+    }
+    conn.execute_batch(HISTORY_SCHEMA).map_err(|e| e.to_string())?;
+
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        apply(conn, migration)
+            .map_err(|e| format!("마이그레이션 {} ({}) 실패: {}", migration.version, migration.name, e))?;
+        println!("[DB] Applied migration {} ({})", migration.version, migration.name);
+        applied.push(migration.version);
+    }
+    Ok(applied)
+}
+
+fn apply(conn: &Connection, migration: &Migration) -> Result<(), String> {
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    (migration.up)(&tx).map_err(|e| e.to_string())?;
+    (migration.verify)(&tx).map_err(|e| format!("확인 단계: {}", e))?;
+    tx.execute(
+        "INSERT OR REPLACE INTO schema_version (version, name) VALUES (?1, ?2)",
+        rusqlite::params![migration.version, migration.name],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.pragma_update(None, "user_version", migration.version).map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+fn columns(conn: &Connection, table: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info(?1)")?;
+    let names = stmt.query_map([table], |row| row.get(0))?.collect();
+    names
+}
+
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, definition: &str) -> rusqlite::Result<()> {
+    if !columns(conn, table)?.iter().any(|c| c == column) {
+        conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition))?;
+    }
+    Ok(())
+}
+
+fn require_columns(conn: &Connection, table: &str, required: &[&str]) -> Result<(), String> {
+    let existing = columns(conn, table).map_err(|e| e.to_string())?;
+    let missing: Vec<&str> = required.iter().copied().filter(|c| !existing.iter().any(|e| e == c)).collect();
+    if !missing.is_empty() {
+        return Err(format!("{} 테이블에 컬럼이 없습니다: {}", table, missing.join(", ")));
+    }
+    Ok(())
+}
+
+fn require_object(conn: &Connection, kind: &str, name: &str) -> Result<(), String> {
+    let found: Option<String> = conn
+        .query_row("SELECT name FROM sqlite_master WHERE type = ?1 AND name = ?2", [kind, name], |row| row.get(0))
+        .optional()
+        .map_err(|e| e.to_string())?;
+    match found {
+        Some(_) => Ok(()),
+        None => Err(format!("{} {} 이(가) 없습니다", kind, name)),
+    }
+}
+
+fn initial_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(schema::SCHEMA)
+}
+
+fn verify_initial_schema(conn: &Connection) -> Result<(), String> {
+    for table in ["designers", "reservations", "customers", "business_hours", "holidays", "app_settings"] {
+        require_object(conn, "table", table)?;
+    }
+    let days: i64 = conn
+        .query_row("SELECT COUNT(*) FROM business_hours", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    if days != 7 {
+        return Err(format!("요일별 영업시간이 {}개입니다 (7개여야 함)", days));
+    }
+    Ok(())
+}
+
+/// 고객 상세 정보·알림 수신 거부·개인정보 동의 컬럼 (초기 버전의 customers 테이블용)
+const CUSTOMER_PROFILE_COLUMNS: &[(&str, &str)] = &[
+    ("birthdate", "TEXT"),
+    ("gender", "TEXT"),
+    ("preferred_designer_id", "TEXT"),
+    ("preferred_service", "TEXT"),
+    ("allergies", "TEXT"),
+    ("total_visits", "INTEGER DEFAULT 0"),
+    ("last_visit_date", "TEXT"),
+    ("updated_at", "TEXT"),
+    ("reminder_opt_out", "INTEGER DEFAULT 0"),
+    ("consent_service", "INTEGER DEFAULT 0"),
+    ("consent_service_at", "TEXT"),
+    ("consent_marketing", "INTEGER DEFAULT 0"),
+    ("consent_marketing_at", "TEXT"),
+    ("consent_photo", "INTEGER DEFAULT 0"),
+    ("consent_photo_at", "TEXT"),
+    ("consent_version", "TEXT"),
+    ("anonymized_at", "TEXT"),
+];
+
+fn customer_profile_columns(conn: &Connection) -> rusqlite::Result<()> {
+    for (column, definition) in CUSTOMER_PROFILE_COLUMNS {
+        add_column_if_missing(conn, "customers", column, definition)?;
+    }
+    // ALTER TABLE 은 datetime('now') 기본값을 받지 않으므로 기존 행은 만든 시각으로 채운다
+    conn.execute("UPDATE customers SET updated_at = created_at WHERE updated_at IS NULL", [])?;
+    Ok(())
+}
+
+fn verify_customer_profile_columns(conn: &Connection) -> Result<(), String> {
+    let required: Vec<&str> = CUSTOMER_PROFILE_COLUMNS.iter().map(|(column, _)| *column).collect();
+    require_columns(conn, "customers", &required)
+}
+
+fn reservation_customer_link(conn: &Connection) -> rusqlite::Result<()> {
+    add_column_if_missing(conn, "reservations", "customer_id", "TEXT REFERENCES customers(id)")?;
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS idx_reservations_customer ON reservations(customer_id)")
+}
+
+fn verify_reservation_customer_link(conn: &Connection) -> Result<(), String> {
+    require_columns(conn, "reservations", &["customer_id"])?;
+    require_object(conn, "index", "idx_reservations_customer")
+}
+
+/// date 단일 UNIQUE 를 (date, calendar_type) 으로 변경 (음력 휴일 지원)
+fn holidays_calendar_type(conn: &Connection) -> rusqlite::Result<()> {
+    if columns(conn, "holidays")?.iter().any(|c| c == "calendar_type") {
+        return Ok(());
+    }
+    conn.execute_batch(
+        "CREATE TABLE holidays_new (
+             id TEXT PRIMARY KEY,
+             date TEXT NOT NULL,
+             description TEXT,
+             is_recurring INTEGER DEFAULT 0,
+             calendar_type TEXT NOT NULL DEFAULT 'solar' CHECK(calendar_type IN ('solar', 'lunar')),
+             UNIQUE(date, calendar_type)
+         );
+         INSERT INTO holidays_new (id, date, description, is_recurring)
+             SELECT id, date, description, is_recurring FROM holidays;
+         DROP TABLE holidays;
+         ALTER TABLE holidays_new RENAME TO holidays;",
+    )
+}
+
+fn verify_holidays_calendar_type(conn: &Connection) -> Result<(), String> {
+    require_columns(conn, "holidays", &["id", "date", "description", "is_recurring", "calendar_type"])?;
+    // 같은 날짜의 양력·음력 휴일을 함께 둘 수 있어야 한다
+    let unique_on_date_only: Option<String> = conn
+        .query_row(
+            "SELECT il.name FROM pragma_index_list('holidays') il
+             WHERE il.\"unique\" = 1
+               AND (SELECT COUNT(*) FROM pragma_index_info(il.name)) = 1
+               AND (SELECT name FROM pragma_index_info(il.name)) = 'date'",
+            [],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    match unique_on_date_only {
+        Some(index) => Err(format!("holidays 에 날짜만으로 된 UNIQUE 제약({})이 남아 있습니다", index)),
+        None => Ok(()),
+    }
+}
+
+/// 마이그레이션 5 에서 트리거를 만든 동기화 대상 테이블. 이후에 추가한 테이블은 그 테이블을
+/// 만드는 마이그레이션에서 트리거를 만든다.
+const SYNC_TRACKING_TABLES: &[&str] = &[
+    "designers",
+    "customers",
+    "reservations",
+    "reservation_status_history",
+    "time_blocks",
+    "business_hours",
+    "business_hour_overrides",
+    "holidays",
+    "closure_periods",
+    "customer_consent_log",
+];
+
+/// 기기 간 동기화 기록 (holidays 재생성 뒤에 트리거를 만든다)
+fn sync_tracking(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(schema::SYNC_SCHEMA)?;
+    conn.execute_batch(&schema::sync_triggers(SYNC_TRACKING_TABLES))
+}
+
+fn verify_sync_tracking(conn: &Connection) -> Result<(), String> {
+    for table in ["sync_state", "sync_dirty", "sync_fields", "sync_tombstones", "sync_peers"] {
+        require_object(conn, "table", table)?;
+    }
+    for table in SYNC_TRACKING_TABLES {
+        for event in ["insert", "update", "delete"] {
+            require_object(conn, "trigger", &format!("sync_{}_{}", table, event))?;
+        }
+    }
+    Ok(())
+}
+
+/// 캘린더 내보내기용 예약별 SEQUENCE 와 삭제된 예약 기록
+///
+/// 기존 예약은 이전 버전이 내보낸 SEQUENCE(생성 후 수정까지 걸린 초)에서 이어 가야
+/// 이미 구독 중인 캘린더가 다음 수정을 무시하지 않는다.
+fn ical_events(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(schema::ICAL_EVENTS_SCHEMA)?;
+    conn.execute_batch(
+        "INSERT OR IGNORE INTO ical_events (reservation_id, sequence)
+             SELECT id, MAX(0, COALESCE(CAST(strftime('%s', updated_at) AS INTEGER) - CAST(strftime('%s', created_at) AS INTEGER), 0))
+             FROM reservations",
+    )
+}
+
+fn verify_ical_events(conn: &Connection) -> Result<(), String> {
+    require_columns(conn, "ical_events", &["reservation_id", "sequence", "designer_id", "date", "time", "deleted_at"])?;
+    for event in ["update", "delete", "insert"] {
+        require_object(conn, "trigger", &format!("ical_reservations_{}", event))?;
+    }
+    Ok(())
+}
+
+/// 모든 기기가 함께 쓰는 설정 (예약 알림을 보낼 기기 등)
+fn shared_settings(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(schema::SHARED_SETTINGS_SCHEMA)?;
+    conn.execute_batch(&schema::sync_triggers(&["shared_settings"]))
+}
+
+fn verify_shared_settings(conn: &Connection) -> Result<(), String> {
+    require_columns(conn, "shared_settings", &["id", "value", "updated_at"])?;
+    for event in ["insert", "update", "delete"] {
+        require_object(conn, "trigger", &format!("sync_shared_settings_{}", event))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_are_numbered_in_order() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version as usize, index + 1, "{}", migration.name);
+        }
+    }
+
+    #[test]
+    fn test_upgrades_legacy_database_without_version() {
+        // 번호를 쓰기 전 첫 배포 버전의 테이블 (일부 컬럼이 없고 휴일은 날짜만 UNIQUE)
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE customers (id TEXT PRIMARY KEY, name TEXT NOT NULL, phone TEXT UNIQUE, email TEXT, notes TEXT,
+                 created_at TEXT DEFAULT (datetime('now')), consent_service INTEGER DEFAULT 0);
+             CREATE TABLE holidays (id TEXT PRIMARY KEY, date TEXT NOT NULL UNIQUE, description TEXT, is_recurring INTEGER DEFAULT 0);
+             INSERT INTO customers (id, name, phone, created_at) VALUES ('c1', '김민지', '010-1111-2222', '2024-01-01 10:00:00');
+             INSERT INTO holidays (id, date, description) VALUES ('h1', '2024-05-05', '어린이날');",
+        )
+        .unwrap();
+
+        assert_eq!(run(&conn).unwrap(), vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+        for table in schema::SYNC_TABLES {
+            require_object(&conn, "trigger", &format!("sync_{}_insert", table)).unwrap();
+        }
+        let updated_at: String = conn.query_row("SELECT updated_at FROM customers WHERE id = 'c1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(updated_at, "2024-01-01 10:00:00");
+        conn.execute("INSERT INTO holidays (id, date, calendar_type) VALUES ('h2', '2024-05-05', 'lunar')", []).unwrap();
+        let history: i64 = conn.query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(history, MIGRATIONS.len() as i64);
+
+        // 다시 열면 아무것도 적용하지 않는다
+        assert!(run(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_failed_migration_rolls_back_and_newer_schema_is_refused() {
+        let conn = Connection::open_in_memory().unwrap();
+        // 영업시간 행이 모자라면 1번 확인 단계에서 실패하고 아무것도 남기지 않는다
+        conn.execute_batch(
+            "CREATE TABLE business_hours (id INTEGER PRIMARY KEY, day_of_week INTEGER NOT NULL UNIQUE, open_time TEXT,
+                 close_time TEXT, is_closed INTEGER DEFAULT 0, break_start TEXT, break_end TEXT);
+             CREATE TRIGGER drop_defaults AFTER INSERT ON business_hours BEGIN DELETE FROM business_hours WHERE id = 6; END;",
+        )
+        .unwrap();
+        let error = run(&conn).unwrap_err();
+        assert!(error.contains("마이그레이션 1 (initial_schema)"), "{}", error);
+        assert_eq!(current_version(&conn).unwrap(), 0);
+        assert!(require_object(&conn, "table", "designers").is_err());
+
+        let conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", latest_version() + 1).unwrap();
+        assert!(run(&conn).unwrap_err().contains("앱을 업데이트"));
+    }
+}
@@ -1,77 +1,180 @@
-use rusqlite::{Connection, Result};
-use tauri::AppHandle;
-use tauri::Manager;
+use rusqlite::{Connection, OpenFlags};
+use std::path::{Path, PathBuf};
 
+pub mod encryption;
+pub mod migrations;
 pub mod schema;
 
+use encryption::DatabaseKey;
+
 pub struct Database {
     conn: Connection,
+    /// SQLCipher 키 (메모리 데이터베이스는 None)
+    key: Option<DatabaseKey>,
+    /// 파일 경로 (메모리 데이터베이스는 None)
+    path: Option<PathBuf>,
 }
 
+/// 앱 데이터 폴더 안의 데이터베이스 파일 이름
+pub const DB_FILENAME: &str = "database.db";
+
 impl Database {
-    pub fn new(app_handle: &AppHandle) -> Result<Self> {
-        let app_dir = app_handle
-            .path()
-            .app_data_dir()
-            .expect("Failed to get app data dir");
+    /// `db_path` 의 데이터베이스를 연다. 키는 키체인이나 같은 폴더의 키 파일에서 찾고,
+    /// 데이터베이스가 아직 없으면 새 키로 만든다.
+    pub fn open(db_path: &Path) -> std::result::Result<Self, String> {
+        let app_dir = db_path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        std::fs::create_dir_all(app_dir).map_err(|e| format!("데이터 폴더를 만들 수 없습니다: {}", e))?;
+
+        let key = match encryption::load_key(app_dir)? {
+            Some(key) => key,
+            // 암호화된 데이터베이스가 있는데 키가 없으면 새 키를 만들지 않는다
+            None if is_encrypted_file(db_path) => {
+                return Err("데이터베이스 암호화 키를 찾을 수 없습니다. 백업에서 복원해주세요".to_string())
+            }
+            None => encryption::load_or_create_key(app_dir)?,
+        };
+
+        Self::open_encrypted(db_path, &key)
+    }
 
-        std::fs::create_dir_all(&app_dir).expect("Failed to create app data dir");
+    /// SQLCipher 키로 데이터베이스를 연다. 암호화되지 않은 기존 파일이면 먼저 암호화한다.
+    pub fn open_encrypted(db_path: &Path, key: &DatabaseKey) -> std::result::Result<Self, String> {
+        if encryption::is_plaintext(db_path) {
+            encryption::encrypt_in_place(db_path, key)?;
+        }
 
-        let db_path = app_dir.join("database.db");
-        let conn = Connection::open(db_path)?;
+        let conn = Connection::open(db_path).map_err(|e| format!("데이터베이스를 열 수 없습니다: {}", e))?;
+        encryption::apply_key(&conn, key).map_err(|e| e.to_string())?;
+        encryption::verify_key(&conn)?;
 
         // WAL 모드 활성화 (성능 향상)
-        conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA foreign_keys=ON;")
+            .map_err(|e| e.to_string())?;
 
-        let db = Database { conn };
-        db.migrate()?;
+        let db = Database {
+            conn,
+            key: Some(key.clone()),
+            path: Some(db_path.to_path_buf()),
+        };
+        db.migrate().map_err(|e| format!("데이터베이스를 최신 형식으로 바꾸지 못했습니다. {}", e))?;
 
         Ok(db)
     }
 
-    fn migrate(&self) -> Result<()> {
-        self.conn.execute_batch(schema::SCHEMA)?;
-
-        // 기존 customers 테이블에 새 필드 추가 (마이그레이션)
-        let migrations = vec![
-            "ALTER TABLE customers ADD COLUMN birthdate TEXT",
-            "ALTER TABLE customers ADD COLUMN gender TEXT",
-            "ALTER TABLE customers ADD COLUMN preferred_designer_id TEXT",
-            "ALTER TABLE customers ADD COLUMN preferred_service TEXT",
-            "ALTER TABLE customers ADD COLUMN allergies TEXT",
-            "ALTER TABLE customers ADD COLUMN total_visits INTEGER DEFAULT 0",
-            "ALTER TABLE customers ADD COLUMN last_visit_date TEXT",
-            "ALTER TABLE customers ADD COLUMN updated_at TEXT DEFAULT (datetime('now'))",
-            // reservations 테이블에 customer_id 추가
-            "ALTER TABLE reservations ADD COLUMN customer_id TEXT REFERENCES customers(id)",
-        ];
-
-        for migration in migrations {
-            // 이미 존재하는 컬럼이면 에러 무시
-            let _ = self.conn.execute(migration, []);
+    /// 이미 있는 데이터베이스 파일을 그대로 연다 (관리 도구용).
+    ///
+    /// `open` 과 달리 파일을 암호화하거나 마이그레이션하거나 저널 모드를 바꾸지 않으므로,
+    /// 다른 버전의 앱이 쓰는 데이터베이스도 건드리지 않고 읽을 수 있다. 암호화되지 않은 파일이면
+    /// `key` 는 무시한다. 스키마 버전이 다를 때 쓰기를 막는 것은 호출하는 쪽이 한다.
+    pub fn open_existing(db_path: &Path, key: Option<&DatabaseKey>, read_only: bool) -> std::result::Result<Self, String> {
+        if !db_path.is_file() {
+            return Err(format!("데이터베이스 파일이 없습니다: {}", db_path.display()));
+        }
+        let key = key.filter(|_| !encryption::is_plaintext(db_path));
+        if key.is_none() && is_encrypted_file(db_path) {
+            return Err("암호화된 데이터베이스인데 키가 없습니다".to_string());
         }
 
-        // customer_id 인덱스 추가 시도
-        let _ = self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_reservations_customer ON reservations(customer_id)",
-            []
-        );
+        let access = if read_only { OpenFlags::SQLITE_OPEN_READ_ONLY } else { OpenFlags::SQLITE_OPEN_READ_WRITE };
+        let conn = Connection::open_with_flags(db_path, access | OpenFlags::SQLITE_OPEN_URI | OpenFlags::SQLITE_OPEN_NO_MUTEX)
+            .map_err(|e| format!("데이터베이스를 열 수 없습니다: {}", e))?;
+        if let Some(key) = key {
+            encryption::apply_key(&conn, key).map_err(|e| e.to_string())?;
+        }
+        encryption::verify_key(&conn)?;
+        conn.execute_batch("PRAGMA foreign_keys=ON;").map_err(|e| e.to_string())?;
+
+        Ok(Database { conn, key: key.cloned(), path: Some(db_path.to_path_buf()) })
+    }
+
+    /// 파일 없이 쓰는 메모리 데이터베이스 (테스트, 도구용)
+    pub fn open_in_memory() -> std::result::Result<Self, String> {
+        let conn = Connection::open_in_memory().map_err(|e| e.to_string())?;
+        conn.execute_batch("PRAGMA foreign_keys=ON;").map_err(|e| e.to_string())?;
+
+        let db = Database { conn, key: None, path: None };
+        db.migrate()?;
+
+        Ok(db)
+    }
 
+    /// 아직 적용하지 않은 번호 붙은 마이그레이션을 적용한다 (`migrations` 참고)
+    fn migrate(&self) -> std::result::Result<(), String> {
+        migrations::run(&self.conn)?;
         Ok(())
     }
 
     pub fn conn(&self) -> &Connection {
         &self.conn
     }
+
+    pub fn key(&self) -> Option<&DatabaseKey> {
+        self.key.as_ref()
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 데이터베이스 파일을 `source` 로 바꾸고 연결을 다시 연다 (앱 재시작 없이).
+    ///
+    /// `source` 는 같은 키로 암호화되어 있고 같은 폴더에 있어야 한다 (rename 으로 교체).
+    /// 연결을 닫아 WAL 을 정리한 뒤 파일을 바꾸고, 다시 열면서 마이그레이션을 적용한다.
+    /// 새 파일을 열 수 없으면 원래 파일로 되돌려 다시 연다.
+    pub fn replace_file(&mut self, source: &Path) -> std::result::Result<(), String> {
+        let path = self.path.clone().ok_or("메모리 데이터베이스는 교체할 수 없습니다")?;
+        let key = self.key.clone().ok_or("암호화 키가 없는 데이터베이스는 교체할 수 없습니다")?;
+
+        let placeholder = Connection::open_in_memory().map_err(|e| e.to_string())?;
+        let old = std::mem::replace(&mut self.conn, placeholder);
+        if let Err((conn, e)) = old.close() {
+            self.conn = conn;
+            return Err(format!("데이터베이스 연결을 닫을 수 없습니다: {}", e));
+        }
+        remove_wal_files(&path);
+
+        let previous = path.with_extension("db.previous");
+        let swapped = std::fs::rename(&path, &previous)
+            .map_err(|e| format!("데이터베이스 파일을 옮길 수 없습니다: {}", e))
+            .and_then(|_| {
+                std::fs::rename(source, &path).map_err(|e| {
+                    let _ = std::fs::rename(&previous, &path);
+                    format!("데이터베이스 파일을 교체할 수 없습니다: {}", e)
+                })
+            })
+            .and_then(|_| Database::open_encrypted(&path, &key));
+
+        match swapped {
+            Ok(db) => {
+                *self = db;
+                let _ = std::fs::remove_file(&previous);
+                Ok(())
+            }
+            Err(e) => {
+                if previous.exists() {
+                    remove_wal_files(&path);
+                    let _ = std::fs::rename(&previous, &path);
+                }
+                *self = Database::open_encrypted(&path, &key)
+                    .map_err(|reopen| format!("{} (원래 데이터베이스도 다시 열 수 없습니다: {})", e, reopen))?;
+                Err(e)
+            }
+        }
+    }
 }
 
 // 전역 데이터베이스 상태
-use std::sync::Mutex;
+// 로컬 API 서버 스레드와 공유하기 위해 Arc 로 감싼다
+use std::sync::{Arc, Mutex};
 
-pub struct DbState(pub Mutex<Database>);
+pub struct DbState(pub Arc<Mutex<Database>>);
+
+fn remove_wal_files(db_path: &Path) {
+    for suffix in ["-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", db_path.to_string_lossy(), suffix));
+    }
+}
 
-pub fn init_database(app: &AppHandle) -> Result<()> {
-    let db = Database::new(app)?;
-    app.manage(DbState(Mutex::new(db)));
-    Ok(())
+fn is_encrypted_file(path: &Path) -> bool {
+    std::fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false) && !encryption::is_plaintext(path)
 }
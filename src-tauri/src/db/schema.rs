@@ -29,6 +29,22 @@ CREATE TABLE IF NOT EXISTS designers (
     updated_at TEXT DEFAULT (datetime('now'))
 );
 
+-- 시간 차단 테이블 (교육, 개인 용무 등 예약이 아닌 디자이너 일정)
+CREATE TABLE IF NOT EXISTS time_blocks (
+    id TEXT PRIMARY KEY,
+    designer_id TEXT NOT NULL,
+    date TEXT NOT NULL,
+    start_time TEXT NOT NULL,
+    end_time TEXT NOT NULL,
+    reason TEXT,
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (designer_id) REFERENCES designers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_time_blocks_date ON time_blocks(date);
+CREATE INDEX IF NOT EXISTS idx_time_blocks_designer ON time_blocks(designer_id);
+
 -- 영업시간 테이블
 CREATE TABLE IF NOT EXISTS business_hours (
     id INTEGER PRIMARY KEY,
@@ -40,14 +56,41 @@ CREATE TABLE IF NOT EXISTS business_hours (
     is_closed INTEGER DEFAULT 0
 );
 
--- 휴일 테이블
+-- 휴일 테이블 (calendar_type = 'lunar' 이면 date 는 음력 날짜)
 CREATE TABLE IF NOT EXISTS holidays (
+    id TEXT PRIMARY KEY,
+    date TEXT NOT NULL,
+    description TEXT,
+    is_recurring INTEGER DEFAULT 0,
+    calendar_type TEXT NOT NULL DEFAULT 'solar' CHECK(calendar_type IN ('solar', 'lunar')),
+    UNIQUE(date, calendar_type)
+);
+
+-- 특정일 영업시간 예외 (연장 영업, 단축 영업, 임시 휴무)
+CREATE TABLE IF NOT EXISTS business_hour_overrides (
     id TEXT PRIMARY KEY,
     date TEXT NOT NULL UNIQUE,
+    open_time TEXT,
+    close_time TEXT,
+    break_start TEXT,
+    break_end TEXT,
+    is_closed INTEGER DEFAULT 0,
+    description TEXT,
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now'))
+);
+
+-- 휴무 기간 (여름 휴가 등 여러 날에 걸친 휴무)
+CREATE TABLE IF NOT EXISTS closure_periods (
+    id TEXT PRIMARY KEY,
+    start_date TEXT NOT NULL,
+    end_date TEXT NOT NULL,
     description TEXT,
-    is_recurring INTEGER DEFAULT 0
+    created_at TEXT DEFAULT (datetime('now'))
 );
 
+CREATE INDEX IF NOT EXISTS idx_closure_periods_range ON closure_periods(start_date, end_date);
+
 -- 고객 테이블
 CREATE TABLE IF NOT EXISTS customers (
     id TEXT PRIMARY KEY,
@@ -63,7 +106,16 @@ CREATE TABLE IF NOT EXISTS customers (
     last_visit_date TEXT,
     notes TEXT,
     created_at TEXT DEFAULT (datetime('now')),
-    updated_at TEXT DEFAULT (datetime('now'))
+    updated_at TEXT DEFAULT (datetime('now')),
+    reminder_opt_out INTEGER DEFAULT 0,
+    consent_service INTEGER DEFAULT 0,
+    consent_service_at TEXT,
+    consent_marketing INTEGER DEFAULT 0,
+    consent_marketing_at TEXT,
+    consent_photo INTEGER DEFAULT 0,
+    consent_photo_at TEXT,
+    consent_version TEXT,
+    anonymized_at TEXT
 );
 
 CREATE INDEX IF NOT EXISTS idx_customers_phone ON customers(phone);
@@ -86,6 +138,132 @@ CREATE TABLE IF NOT EXISTS app_settings (
     updated_at TEXT DEFAULT (datetime('now'))
 );
 
+-- 로컬 REST API 키 (키 원문은 저장하지 않고 SHA-256 해시만 보관)
+CREATE TABLE IF NOT EXISTS api_keys (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    key_prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE,
+    scopes TEXT NOT NULL DEFAULT 'read',
+    created_at TEXT DEFAULT (datetime('now')),
+    last_used_at TEXT,
+    revoked_at TEXT
+);
+
+-- 웹훅 수신 주소 (events: 쉼표로 구분한 이벤트 이름, '*' 는 전체)
+CREATE TABLE IF NOT EXISTS webhook_endpoints (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    events TEXT NOT NULL DEFAULT '*',
+    is_active INTEGER DEFAULT 1,
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now'))
+);
+
+-- 웹훅 발송 대기열 (이벤트 발생 시 기록, 백그라운드 작업이 전송)
+CREATE TABLE IF NOT EXISTS webhook_outbox (
+    id TEXT PRIMARY KEY,
+    endpoint_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    status TEXT DEFAULT 'pending' CHECK(status IN ('pending', 'delivered', 'failed')),
+    attempts INTEGER DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    last_error TEXT,
+    created_at TEXT DEFAULT (datetime('now')),
+    delivered_at TEXT,
+    FOREIGN KEY (endpoint_id) REFERENCES webhook_endpoints(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_outbox_due ON webhook_outbox(status, next_attempt_at);
+
+-- 웹훅 전송 기록 (시도마다 한 줄)
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id TEXT PRIMARY KEY,
+    outbox_id TEXT NOT NULL,
+    endpoint_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    status_code INTEGER,
+    success INTEGER NOT NULL,
+    error TEXT,
+    duration_ms INTEGER,
+    attempted_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (outbox_id) REFERENCES webhook_outbox(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_attempted ON webhook_deliveries(attempted_at);
+
+-- 예약 알림 (reservation_at, scheduled_at 은 현지 시각 YYYY-MM-DD HH:MM)
+CREATE TABLE IF NOT EXISTS reminders (
+    id TEXT PRIMARY KEY,
+    reservation_id TEXT NOT NULL UNIQUE,
+    recipient TEXT NOT NULL,
+    reservation_at TEXT NOT NULL,
+    scheduled_at TEXT NOT NULL,
+    status TEXT DEFAULT 'pending' CHECK(status IN ('pending', 'sent', 'failed', 'cancelled', 'skipped')),
+    provider TEXT,
+    message TEXT,
+    attempts INTEGER DEFAULT 0,
+    provider_message_id TEXT,
+    error TEXT,
+    sent_at TEXT,
+    created_at TEXT DEFAULT (datetime('now')),
+    updated_at TEXT DEFAULT (datetime('now')),
+    FOREIGN KEY (reservation_id) REFERENCES reservations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_reminders_due ON reminders(status, scheduled_at);
+
+-- 고객에게 보낸 메시지 기록 (channel: 발송 수단, template: 메시지 종류)
+CREATE TABLE IF NOT EXISTS messages (
+    id TEXT PRIMARY KEY,
+    customer_id TEXT,
+    reservation_id TEXT,
+    channel TEXT NOT NULL,
+    template TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    body TEXT NOT NULL,
+    status TEXT DEFAULT 'sent' CHECK(status IN ('pending', 'sent', 'failed')),
+    provider_message_id TEXT,
+    error TEXT,
+    created_at TEXT DEFAULT (datetime('now')),
+    sent_at TEXT,
+    FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE,
+    FOREIGN KEY (reservation_id) REFERENCES reservations(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_messages_customer ON messages(customer_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_messages_reservation ON messages(reservation_id);
+
+-- 개인정보 동의 변경 이력 (consent_type: service, marketing, photo)
+CREATE TABLE IF NOT EXISTS customer_consent_log (
+    id TEXT PRIMARY KEY,
+    customer_id TEXT NOT NULL,
+    consent_type TEXT NOT NULL CHECK(consent_type IN ('service', 'marketing', 'photo')),
+    granted INTEGER NOT NULL,
+    version TEXT NOT NULL,
+    recorded_at TEXT NOT NULL,
+    FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_customer_consent_log_customer ON customer_consent_log(customer_id, recorded_at);
+
+-- 개인정보 파기 기록 (개인정보는 남기지 않고 무엇을 지웠는지만 기록)
+CREATE TABLE IF NOT EXISTS privacy_audit_log (
+    id TEXT PRIMARY KEY,
+    run_id TEXT NOT NULL,
+    action TEXT NOT NULL,
+    customer_id TEXT,
+    trigger TEXT NOT NULL,
+    details TEXT NOT NULL,
+    performed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_privacy_audit_log_performed ON privacy_audit_log(performed_at);
+
 -- 기본 영업시간 데이터
 INSERT OR IGNORE INTO business_hours (id, day_of_week, open_time, close_time, is_closed) VALUES
     (0, 0, NULL, NULL, 1),
@@ -96,3 +274,146 @@ INSERT OR IGNORE INTO business_hours (id, day_of_week, open_time, close_time, is
     (5, 5, '10:00', '20:00', 0),
     (6, 6, '10:00', '18:00', 0);
 "#;
+
+/// 기기 간 동기화 대상 테이블 (참조되는 테이블이 먼저 오도록 정렬)
+///
+/// 앱 설정, API 키, 웹훅, 알림, 메시지 기록처럼 기기마다 따로 두는 데이터는 동기화하지 않는다.
+/// 테이블을 추가할 때는 테이블과 트리거를 만드는 마이그레이션도 함께 추가한다.
+pub const SYNC_TABLES: &[&str] = &[
+    "designers",
+    "customers",
+    "reservations",
+    "reservation_status_history",
+    "time_blocks",
+    "business_hours",
+    "business_hour_overrides",
+    "holidays",
+    "closure_periods",
+    "customer_consent_log",
+    "shared_settings",
+];
+
+/// 동기화 변경 기록
+///
+/// - sync_dirty: 트리거가 기록하는 아직 버전을 매기지 않은 변경 행 (changed_ms: 변경 시각, 유닉스 ms)
+/// - sync_fields: 행의 필드마다 마지막으로 알려진 값(JSON)과 하이브리드 논리 시계(HLC) 버전
+/// - sync_tombstones: 삭제된 행과 삭제 시점의 HLC
+/// - seq: 이 기기에서 기록한 순서 (다른 기기에 보낸 뒤 바뀐 것만 보낼 때 사용)
+/// - sync_peers: 같은 네트워크에서 페어링한 기기 (public_key: X25519 공개 키, base64)
+pub const SYNC_SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS sync_state (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS sync_dirty (
+    table_name TEXT NOT NULL,
+    row_id TEXT NOT NULL,
+    changed_ms INTEGER NOT NULL,
+    PRIMARY KEY (table_name, row_id)
+);
+
+CREATE TABLE IF NOT EXISTS sync_fields (
+    table_name TEXT NOT NULL,
+    row_id TEXT NOT NULL,
+    column_name TEXT NOT NULL,
+    value TEXT NOT NULL,
+    hlc TEXT NOT NULL,
+    seq INTEGER NOT NULL,
+    PRIMARY KEY (table_name, row_id, column_name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sync_fields_seq ON sync_fields(seq);
+
+CREATE TABLE IF NOT EXISTS sync_tombstones (
+    table_name TEXT NOT NULL,
+    row_id TEXT NOT NULL,
+    hlc TEXT NOT NULL,
+    seq INTEGER NOT NULL,
+    PRIMARY KEY (table_name, row_id)
+);
+
+CREATE TABLE IF NOT EXISTS sync_peers (
+    node_id TEXT PRIMARY KEY,
+    device_name TEXT NOT NULL,
+    public_key TEXT NOT NULL,
+    address TEXT,
+    pulled_seq INTEGER NOT NULL DEFAULT 0,
+    pushed_seq INTEGER NOT NULL DEFAULT 0,
+    paired_at TEXT NOT NULL,
+    last_synced_at TEXT
+);
+"#;
+
+/// 캘린더(.ics) 내보내기 상태. 기기마다 따로 두며 동기화하지 않는다.
+///
+/// - sequence: 예약 내용이 바뀔 때마다 1씩 커지는 iCalendar SEQUENCE
+/// - deleted_at: 예약이 삭제된 시각. 삭제된 예약은 STATUS:CANCELLED 로 내보내 구독 중인 캘린더에서도
+///   지워지게 한다 (고객 정보는 남기지 않고 날짜·시간·디자이너만 기록)
+pub const ICAL_EVENTS_SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS ical_events (
+    reservation_id TEXT PRIMARY KEY,
+    sequence INTEGER NOT NULL DEFAULT 0,
+    designer_id TEXT,
+    date TEXT,
+    time TEXT,
+    deleted_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_ical_events_deleted ON ical_events(deleted_at, date);
+
+CREATE TRIGGER IF NOT EXISTS ical_reservations_update
+AFTER UPDATE OF date, time, status, customer_name, customer_phone, designer_id, service_type, notes ON reservations
+BEGIN
+    INSERT INTO ical_events (reservation_id, sequence) VALUES (NEW.id, 1)
+    ON CONFLICT(reservation_id) DO UPDATE SET sequence = sequence + 1;
+END;
+
+CREATE TRIGGER IF NOT EXISTS ical_reservations_delete AFTER DELETE ON reservations
+BEGIN
+    INSERT INTO ical_events (reservation_id, sequence, designer_id, date, time, deleted_at)
+    VALUES (OLD.id, 1, OLD.designer_id, OLD.date, OLD.time, datetime('now'))
+    ON CONFLICT(reservation_id) DO UPDATE SET
+        sequence = sequence + 1,
+        designer_id = excluded.designer_id,
+        date = excluded.date,
+        time = excluded.time,
+        deleted_at = excluded.deleted_at;
+END;
+
+-- 삭제된 예약이 복원·동기화로 다시 생기면 취소 기록을 거두고 SEQUENCE 를 올린다
+CREATE TRIGGER IF NOT EXISTS ical_reservations_insert AFTER INSERT ON reservations
+BEGIN
+    UPDATE ical_events SET sequence = sequence + 1, designer_id = NULL, date = NULL, time = NULL, deleted_at = NULL
+    WHERE reservation_id = NEW.id;
+END;
+"#;
+
+/// 모든 기기가 함께 쓰는 설정 (동기화 대상). 기기마다 따로 두는 설정은 app_settings 에 둔다.
+///
+/// - reminder_sender: 예약 알림을 보내는 기기의 동기화 기기 id
+pub const SHARED_SETTINGS_SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS shared_settings (
+    id TEXT PRIMARY KEY,
+    value TEXT NOT NULL,
+    updated_at TEXT DEFAULT (datetime('now'))
+);
+"#;
+
+/// 동기화 대상 테이블의 변경을 sync_dirty 에 기록하는 트리거
+pub fn sync_triggers(tables: &[&str]) -> String {
+    let now_ms = "CAST((julianday('now') - 2440587.5) * 86400000 AS INTEGER)";
+    let mut sql = String::new();
+    for table in tables {
+        for (event, row) in [("INSERT", "NEW"), ("UPDATE", "NEW"), ("DELETE", "OLD")] {
+            sql.push_str(&format!(
+                "CREATE TRIGGER IF NOT EXISTS sync_{table}_{event_lower} AFTER {event} ON {table} BEGIN
+                     INSERT INTO sync_dirty (table_name, row_id, changed_ms) VALUES ('{table}', CAST({row}.id AS TEXT), {now_ms})
+                     ON CONFLICT(table_name, row_id) DO UPDATE SET changed_ms = excluded.changed_ms;
+                 END;\n",
+                event_lower = event.to_lowercase(),
+            ));
+        }
+    }
+    sql
+}
@@ -1,9 +1,21 @@
+//! 예약 앱의 core(`db`, `services`)와 Tauri 앱.
+//!
+//! core 는 Tauri 없이 데이터베이스 경로만으로 쓸 수 있다. 커맨드(`commands`)는 상태에서
+//! 데이터베이스를 꺼내 서비스 함수를 부르는 얇은 층이고, `runtime` 이 둘을 잇는다.
+//! Tauri 에 기대는 부분(`runtime`, `commands`, `run`)은 기본 `app` 기능으로만 빌드되므로
+//! `salon-admin` 과 테스트는 `--no-default-features` 로 GTK/WebKit 없이 빌드할 수 있다.
+
+#[cfg(feature = "app")]
+mod runtime;
+#[cfg(feature = "app")]
 mod commands;
-mod db;
-mod services;
+pub mod db;
+pub mod services;
 
-use db::init_database;
+#[cfg(feature = "app")]
+use tauri::Manager;
 
+#[cfg(feature = "app")]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -12,7 +24,34 @@ pub fn run() {
         .setup(|app| {
             #[cfg(mobile)]
             app.handle().plugin(tauri_plugin_biometric::init())?;
-            init_database(&app.handle()).expect("Failed to initialize database");
+            if let Err(message) = runtime::init_database(app.handle()) {
+                // 패닉으로 창 없이 꺼지지 않도록, 원인을 기록하고 오류 화면을 띄운다
+                let data_dir = runtime::data_dir(app.handle()).ok().map(|dir| dir.display().to_string());
+                eprintln!("Failed to initialize database: {} (data dir: {:?})", message, data_dir);
+                app.manage(runtime::StartupError { message, data_dir });
+                return Ok(());
+            }
+            let db = app.state::<db::DbState>().0.clone();
+            if let Ok(guard) = db.lock() {
+                if let Err(e) = services::privacy::run_scheduled_purge(guard.conn(), chrono::Local::now().date_naive()) {
+                    eprintln!("Failed to purge expired customer data: {}", e);
+                }
+            }
+            services::webhooks::start_worker(db.clone());
+            services::sync::start_worker(db.clone());
+            match runtime::data_dir(app.handle()) {
+                Ok(data_dir) => {
+                    services::reminders::start_worker(db.clone(), data_dir.clone());
+                    services::backup::auto::start_worker(db.clone(), data_dir);
+                }
+                Err(e) => eprintln!("Failed to start background workers: {}", e),
+            }
+            if let Err(e) = services::sync::lan::start_if_enabled(db.clone()) {
+                eprintln!("Failed to start LAN sync: {}", e);
+            }
+            if let Err(e) = services::api::start_if_enabled(db) {
+                eprintln!("Failed to start API server: {}", e);
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -29,6 +68,11 @@ pub fn run() {
             commands::designers::create_designer,
             commands::designers::update_designer,
             commands::designers::delete_designer,
+            // 시간 차단
+            commands::time_blocks::get_time_blocks,
+            commands::time_blocks::create_time_block,
+            commands::time_blocks::update_time_block,
+            commands::time_blocks::delete_time_block,
             // 영업시간
             commands::business_hours::get_business_hours,
             commands::business_hours::update_business_hours,
@@ -36,6 +80,19 @@ pub fn run() {
             commands::business_hours::get_holidays,
             commands::business_hours::add_holiday,
             commands::business_hours::delete_holiday,
+            commands::business_hours::get_holidays_for_year,
+            commands::business_hours::import_korean_holidays,
+            commands::business_hours::convert_solar_to_lunar,
+            commands::business_hours::convert_lunar_to_solar,
+            // 영업시간 예외 / 휴무 기간
+            commands::business_hours::get_business_hour_overrides,
+            commands::business_hours::set_business_hour_override,
+            commands::business_hours::delete_business_hour_override,
+            commands::business_hours::get_closure_periods,
+            commands::business_hours::add_closure_period,
+            commands::business_hours::delete_closure_period,
+            commands::business_hours::get_effective_hours,
+            commands::business_hours::get_effective_hours_range,
             // 통계
             commands::statistics::get_statistics_summary,
             commands::statistics::get_daily_statistics,
@@ -44,11 +101,26 @@ pub fn run() {
             // 내보내기
             commands::export::export_to_excel,
             commands::export::export_to_csv,
+            commands::export::export_to_ics,
             commands::export::get_export_path,
+            // 가져오기
+            commands::ics_import::inspect_ics_files,
+            commands::ics_import::import_ics,
             // 백업
             commands::backup::list_backups,
             commands::backup::create_backup,
             commands::backup::restore_backup,
+            commands::backup::preview_backup,
+            commands::backup::restore_backup_selective,
+            commands::backup::export_migration_bundle,
+            commands::backup::inspect_migration_bundle,
+            commands::backup::import_migration_bundle,
+            commands::backup::get_auto_backup_settings,
+            commands::backup::update_auto_backup_settings,
+            commands::backup::get_auto_backup_status,
+            commands::backup::get_backup_target,
+            commands::backup::update_backup_target,
+            commands::backup::test_backup_target,
             commands::backup::delete_backup,
             commands::backup::cleanup_old_backups,
             commands::backup::is_icloud_available,
@@ -62,22 +134,78 @@ pub fn run() {
             commands::customers::search_customers,
             commands::customers::get_customer_by_phone,
             commands::customers::get_customer_reservations,
+            commands::customers::get_customer_messages,
             commands::customers::update_customer_visit_stats,
             // 보안
             commands::security::set_lock_pin,
             commands::security::verify_lock_pin,
             commands::security::remove_lock_pin,
             commands::security::change_lock_pin,
+            commands::security::export_database_key,
             commands::security::is_lock_enabled,
             commands::security::get_lock_settings,
             commands::security::update_lock_settings,
             commands::security::authenticate_biometric,
             commands::security::is_biometric_available,
             commands::security::get_biometric_type,
+            // 로컬 API
+            commands::api_server::get_api_server_settings,
+            commands::api_server::update_api_server_settings,
+            commands::api_server::get_api_server_status,
+            commands::api_server::list_api_keys,
+            commands::api_server::create_api_key,
+            commands::api_server::revoke_api_key,
+            // 웹훅
+            commands::webhooks::get_webhook_endpoints,
+            commands::webhooks::create_webhook_endpoint,
+            commands::webhooks::update_webhook_endpoint,
+            commands::webhooks::delete_webhook_endpoint,
+            commands::webhooks::send_test_webhook,
+            commands::webhooks::get_webhook_deliveries,
+            commands::webhooks::retry_webhook_delivery,
+            // 개인정보 보호
+            commands::privacy::get_privacy_settings,
+            commands::privacy::update_privacy_settings,
+            commands::privacy::get_customer_consent_history,
+            commands::privacy::preview_retention_purge,
+            commands::privacy::run_retention_purge,
+            commands::privacy::get_privacy_audit_report,
+            commands::privacy::export_customer_dossier,
+            commands::privacy::erase_customer_data,
+            // 예약 알림
+            commands::reminders::get_reminder_settings,
+            commands::reminders::update_reminder_settings,
+            commands::reminders::is_reminder_sender,
+            commands::reminders::claim_reminder_sender,
+            commands::reminders::preview_reminder_message,
+            commands::reminders::send_test_reminder,
+            commands::reminders::get_reminders,
+            commands::reminders::cancel_reminder,
+            commands::reminders::retry_reminder,
+            // 기기 간 동기화
+            commands::sync::get_sync_settings,
+            commands::sync::update_sync_settings,
+            commands::sync::get_sync_status,
+            commands::sync::run_sync,
+            commands::sync::get_lan_sync_status,
+            commands::sync::start_lan_pairing,
+            commands::sync::cancel_lan_pairing,
+            commands::sync::discover_lan_devices,
+            commands::sync::pair_lan_device,
+            commands::sync::get_paired_devices,
+            commands::sync::unpair_lan_device,
             // 유틸
             commands::utils::open_external_url,
+            commands::utils::get_startup_error,
             commands::utils::reset_all_data,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                if let (Some(db), Ok(data_dir)) = (app.try_state::<db::DbState>(), runtime::data_dir(app)) {
+                    services::backup::auto::run_on_exit(&db.0, &data_dir);
+                }
+            }
+        });
 }
@@ -0,0 +1,38 @@
+//! Tauri 앱과 core(`db`, `services`)를 잇는 부분
+//!
+//! 데이터 폴더 위치를 정하고 데이터베이스를 열어 Tauri 상태로 등록한다.
+//! core 는 경로만 받으므로 CLI 나 테스트에서는 이 모듈 없이 `Database::open` 을 쓴다.
+
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager};
+
+use crate::db::{Database, DbState, DB_FILENAME};
+
+/// 이 환경 변수가 있으면 앱 데이터 폴더 대신 그 폴더를 쓴다.
+/// 한 컴퓨터에서 앱을 두 개 띄워 기기 간 동기화를 시험할 때 쓴다.
+pub const DATA_DIR_ENV: &str = "SISTERS_SALON_DATA_DIR";
+
+/// 데이터베이스, 백업, 첨부 파일을 두는 폴더
+pub fn data_dir(app: &AppHandle) -> tauri::Result<PathBuf> {
+    match std::env::var_os(DATA_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => Ok(PathBuf::from(dir)),
+        _ => app.path().app_data_dir(),
+    }
+}
+
+/// 데이터베이스를 열지 못했을 때 화면에 알릴 내용 (이때는 DbState 가 없다)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupError {
+    pub message: String,
+    pub data_dir: Option<String>,
+}
+
+pub fn init_database(app: &AppHandle) -> Result<(), String> {
+    let dir = data_dir(app).map_err(|e| format!("앱 데이터 폴더를 찾을 수 없습니다: {}", e))?;
+    let db = Database::open(&dir.join(DB_FILENAME))?;
+    app.manage(DbState(Arc::new(Mutex::new(db))));
+    Ok(())
+}
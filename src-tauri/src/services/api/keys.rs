@@ -0,0 +1,155 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+const KEY_PREFIX: &str = "ssk_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiScope {
+    Read,
+    Write,
+}
+
+impl ApiScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiScope::Read => "read",
+            ApiScope::Write => "write",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<ApiScope>,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+impl ApiKey {
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+/// 새로 만든 키. `secret` 은 이때 한 번만 보여주고 저장하지 않는다.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedApiKey {
+    pub key: ApiKey,
+    pub secret: String,
+}
+
+fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn parse_scopes(value: &str) -> Vec<ApiScope> {
+    value
+        .split(',')
+        .filter_map(|s| match s.trim() {
+            "read" => Some(ApiScope::Read),
+            "write" => Some(ApiScope::Write),
+            _ => None,
+        })
+        .collect()
+}
+
+fn row_to_api_key(row: &rusqlite::Row) -> rusqlite::Result<ApiKey> {
+    let scopes: String = row.get(3)?;
+    Ok(ApiKey {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        key_prefix: row.get(2)?,
+        scopes: parse_scopes(&scopes),
+        created_at: row.get(4)?,
+        last_used_at: row.get(5)?,
+        revoked_at: row.get(6)?,
+    })
+}
+
+const SELECT_API_KEY: &str =
+    "SELECT id, name, key_prefix, scopes, created_at, last_used_at, revoked_at FROM api_keys";
+
+pub fn list_keys(conn: &Connection) -> Result<Vec<ApiKey>, String> {
+    let mut stmt = conn
+        .prepare(&format!("{} ORDER BY created_at DESC", SELECT_API_KEY))
+        .map_err(|e| e.to_string())?;
+    let rows = stmt.query_map([], row_to_api_key).map_err(|e| e.to_string())?;
+
+    let keys: Vec<ApiKey> = rows.filter_map(|r| r.ok()).collect();
+    Ok(keys)
+}
+
+pub fn create_key(conn: &Connection, name: &str, scopes: &[ApiScope]) -> Result<CreatedApiKey, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("키 이름을 입력해주세요".to_string());
+    }
+    if scopes.is_empty() {
+        return Err("권한을 하나 이상 선택해주세요".to_string());
+    }
+
+    // uuid v4 두 개 = 244비트 난수
+    let secret = format!("{}{}{}", KEY_PREFIX, Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let key_prefix = secret[..KEY_PREFIX.len() + 6].to_string();
+    let id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let mut scope_names: Vec<&str> = scopes.iter().map(|s| s.as_str()).collect();
+    scope_names.sort();
+    scope_names.dedup();
+
+    conn.execute(
+        "INSERT INTO api_keys (id, name, key_prefix, key_hash, scopes, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        rusqlite::params![id, name, key_prefix, hash_secret(&secret), scope_names.join(","), now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    let key = conn
+        .query_row(&format!("{} WHERE id = ?1", SELECT_API_KEY), [&id], row_to_api_key)
+        .map_err(|e| e.to_string())?;
+    Ok(CreatedApiKey { key, secret })
+}
+
+pub fn revoke_key(conn: &Connection, id: &str) -> Result<(), String> {
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    conn.execute(
+        "UPDATE api_keys SET revoked_at = ?2 WHERE id = ?1 AND revoked_at IS NULL",
+        rusqlite::params![id, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 요청에 담긴 키를 확인한다. 폐기되지 않은 키면 마지막 사용 시각을 갱신해 돌려준다.
+pub fn authenticate(conn: &Connection, secret: &str) -> Result<Option<ApiKey>, String> {
+    let key = conn
+        .query_row(
+            &format!("{} WHERE key_hash = ?1 AND revoked_at IS NULL", SELECT_API_KEY),
+            [hash_secret(secret)],
+            row_to_api_key,
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+
+    if let Some(ref key) = key {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        conn.execute(
+            "UPDATE api_keys SET last_used_at = ?2 WHERE id = ?1",
+            rusqlite::params![key.id, now],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(key)
+}
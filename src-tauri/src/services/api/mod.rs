@@ -0,0 +1,319 @@
+//! 로컬 REST API 서버
+//!
+//! 기본적으로 꺼져 있으며, 설정에서 켜면 `/api/v1` 아래에 예약/고객/디자이너/
+//! 예약 가능 시간/통계를 JSON 으로 제공한다. 모든 처리는 Tauri 커맨드와 같은
+//! 서비스 함수를 거친다.
+
+pub mod keys;
+pub mod openapi;
+mod routes;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, UdpSocket};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+use crate::db::Database;
+
+const SETTINGS_KEY: &str = "api_server";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiServerSettings {
+    pub enabled: bool,
+    pub port: u16,
+    /// false 면 127.0.0.1 에만 바인딩
+    pub allow_lan: bool,
+}
+
+impl Default for ApiServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 8787,
+            allow_lan: false,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiServerStatus {
+    pub running: bool,
+    pub address: Option<String>,
+    /// 같은 네트워크의 다른 기기에서 접속할 주소
+    pub lan_url: Option<String>,
+}
+
+/// Get API server settings from app_settings table
+pub fn get_settings(conn: &Connection) -> Result<ApiServerSettings, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(ApiServerSettings::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Save API server settings to app_settings table
+pub fn save_settings(conn: &Connection, settings: &ApiServerSettings) -> Result<(), String> {
+    if settings.port < 1024 {
+        return Err("포트는 1024 이상이어야 합니다".to_string());
+    }
+
+    let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![SETTINGS_KEY, json, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// 실행 중인 서버. `stop` 하거나 drop 되면 종료된다.
+pub struct ApiServerHandle {
+    addr: SocketAddr,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl ApiServerHandle {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn stop(mut self) {
+        self.shutdown_and_join();
+    }
+
+    fn shutdown_and_join(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ApiServerHandle {
+    fn drop(&mut self) {
+        self.shutdown_and_join();
+    }
+}
+
+/// 전용 스레드의 tokio 런타임에서 서버 실행
+pub fn spawn(db: Arc<Mutex<Database>>, ip: IpAddr, port: u16) -> Result<ApiServerHandle, String> {
+    let listener = TcpListener::bind((ip, port))
+        .map_err(|e| format!("API 서버를 시작할 수 없습니다 ({}:{}): {}", ip, port, e))?;
+    listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .thread_name("api-server")
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let app = routes::router(routes::ApiState { db });
+
+    let thread = std::thread::Builder::new()
+        .name("api-server".to_string())
+        .spawn(move || {
+            runtime.block_on(async move {
+                let listener = match tokio::net::TcpListener::from_std(listener) {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        eprintln!("API server error: {}", e);
+                        return;
+                    }
+                };
+                let server = axum::serve(listener, app).with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                });
+                if let Err(e) = server.await {
+                    eprintln!("API server error: {}", e);
+                }
+            });
+        })
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiServerHandle {
+        addr,
+        shutdown: Some(shutdown_tx),
+        thread: Some(thread),
+    })
+}
+
+// 앱 전역에서 하나만 실행
+static RUNNING: Mutex<Option<ApiServerHandle>> = Mutex::new(None);
+
+/// 설정에 맞춰 서버를 (재)시작하거나 중지
+pub fn apply_settings(db: Arc<Mutex<Database>>, settings: &ApiServerSettings) -> Result<ApiServerStatus, String> {
+    let mut running = RUNNING.lock().map_err(|e| e.to_string())?;
+    if let Some(handle) = running.take() {
+        handle.stop();
+    }
+
+    if settings.enabled {
+        let ip = if settings.allow_lan {
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+        } else {
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        };
+        *running = Some(spawn(db, ip, settings.port)?);
+    }
+
+    Ok(status_of(running.as_ref()))
+}
+
+/// 앱 시작 시 설정이 켜져 있으면 서버 실행
+pub fn start_if_enabled(db: Arc<Mutex<Database>>) -> Result<(), String> {
+    let settings = {
+        let guard = db.lock().map_err(|e| e.to_string())?;
+        get_settings(guard.conn())?
+    };
+    if settings.enabled {
+        apply_settings(db, &settings)?;
+    }
+    Ok(())
+}
+
+pub fn status() -> Result<ApiServerStatus, String> {
+    let running = RUNNING.lock().map_err(|e| e.to_string())?;
+    Ok(status_of(running.as_ref()))
+}
+
+fn status_of(handle: Option<&ApiServerHandle>) -> ApiServerStatus {
+    match handle {
+        Some(handle) => {
+            let addr = handle.addr();
+            let lan_url = if addr.ip().is_unspecified() {
+                local_ip().map(|ip| format!("http://{}:{}/api/v1", ip, addr.port()))
+            } else {
+                None
+            };
+            ApiServerStatus {
+                running: true,
+                address: Some(format!("http://127.0.0.1:{}/api/v1", addr.port())),
+                lan_url,
+            }
+        }
+        None => ApiServerStatus {
+            running: false,
+            address: None,
+            lan_url: None,
+        },
+    }
+}
+
+/// 기본 경로로 나가는 인터페이스의 IP (실제로 패킷을 보내지는 않음)
+fn local_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    fn request(addr: SocketAddr, method: &str, path: &str, key: Option<&str>, body: Option<&str>) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let mut req = format!("{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n", method, path);
+        if let Some(key) = key {
+            req.push_str(&format!("Authorization: Bearer {}\r\n", key));
+        }
+        let body = body.unwrap_or("");
+        if !body.is_empty() {
+            req.push_str("Content-Type: application/json\r\n");
+        }
+        req.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+        stream.write_all(req.as_bytes()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap_or("").to_string();
+        (status, body)
+    }
+
+    #[test]
+    fn test_api_key_scopes() {
+        let db = Database::open_in_memory().unwrap();
+        let (read_key, write_key) = {
+            let conn = db.conn();
+            let read = keys::create_key(conn, "reader", &[keys::ApiScope::Read]).unwrap();
+            let write = keys::create_key(conn, "writer", &[keys::ApiScope::Read, keys::ApiScope::Write]).unwrap();
+            (read.secret, write.secret)
+        };
+
+        let handle = spawn(Arc::new(Mutex::new(db)), IpAddr::V4(Ipv4Addr::LOCALHOST), 0).unwrap();
+        let addr = handle.addr();
+
+        assert_eq!(request(addr, "GET", "/api/v1/health", None, None).0, 200);
+        assert_eq!(request(addr, "GET", "/api/v1/openapi.json", None, None).0, 200);
+        assert_eq!(request(addr, "GET", "/api/v1/customers", None, None).0, 401);
+        assert_eq!(request(addr, "GET", "/api/v1/customers", Some("ssk_wrong"), None).0, 401);
+        assert_eq!(request(addr, "GET", "/api/v1/customers", Some(&read_key), None).0, 200);
+
+        let body = r#"{"name":"김민지","phone":"010-1234-5678"}"#;
+        assert_eq!(request(addr, "POST", "/api/v1/customers", Some(&read_key), Some(body)).0, 403);
+        let (status, created) = request(addr, "POST", "/api/v1/customers", Some(&write_key), Some(body));
+        assert_eq!(status, 201);
+        assert!(created.contains("김민지"));
+
+        assert_eq!(request(addr, "GET", "/api/v1/reservations/missing", Some(&read_key), None).0, 404);
+
+        handle.stop();
+    }
+
+    #[test]
+    fn test_designer_calendar_feed() {
+        let db = Database::open_in_memory().unwrap();
+        let read_key = {
+            let conn = db.conn();
+            conn.execute_batch(
+                "INSERT INTO designers (id, name) VALUES ('d1', '수진'), ('d2', '민호');
+                 INSERT INTO reservations (id, customer_name, date, time, designer_id, status)
+                     VALUES ('r1', '김민지', '2025-03-14', '14:30', 'd1', 'confirmed'),
+                            ('r2', '박서연', '2025-03-14', '15:00', 'd2', 'confirmed');",
+            )
+            .unwrap();
+            keys::create_key(conn, "calendar", &[keys::ApiScope::Read]).unwrap().secret
+        };
+
+        let handle = spawn(Arc::new(Mutex::new(db)), IpAddr::V4(Ipv4Addr::LOCALHOST), 0).unwrap();
+        let addr = handle.addr();
+
+        let (status, body) = request(addr, "GET", "/api/v1/designers/d1/calendar.ics", Some(&read_key), None);
+        assert_eq!(status, 200);
+        assert!(body.starts_with("BEGIN:VCALENDAR"));
+        assert!(body.contains("X-WR-CALNAME:Sisters Salon - 수진"));
+        assert!(body.contains("r1") && !body.contains("r2"));
+
+        // 캘린더 앱은 헤더 대신 구독 주소의 key 로 인증한다 (.ics 주소에서만)
+        let feed = format!("/api/v1/calendar.ics?key={}", read_key);
+        let (status, body) = request(addr, "GET", &feed, None, None);
+        assert_eq!(status, 200);
+        assert!(body.contains("r1") && body.contains("r2"));
+        assert_eq!(request(addr, "GET", &format!("/api/v1/customers?key={}", read_key), None, None).0, 401);
+        assert_eq!(request(addr, "GET", "/api/v1/calendar.ics?key=ssk_wrong", None, None).0, 401);
+        assert_eq!(request(addr, "GET", "/api/v1/designers/missing/calendar.ics", Some(&read_key), None).0, 404);
+
+        handle.stop();
+    }
+}
@@ -0,0 +1,459 @@
+use serde_json::{json, Value};
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn nullable_string() -> Value {
+    json!({ "type": "string", "nullable": true })
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema } }
+    })
+}
+
+fn list_of(name: &str) -> Value {
+    json!({ "type": "array", "items": schema_ref(name) })
+}
+
+fn json_body(name: &str) -> Value {
+    json!({
+        "required": true,
+        "content": { "application/json": { "schema": schema_ref(name) } }
+    })
+}
+
+fn query_param(name: &str, required: bool, description: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "query",
+        "required": required,
+        "description": description,
+        "schema": string()
+    })
+}
+
+fn id_param() -> Value {
+    json!({ "name": "id", "in": "path", "required": true, "schema": string() })
+}
+
+fn period_param() -> Value {
+    json!({
+        "name": "period",
+        "in": "query",
+        "required": false,
+        "description": "7d, 30d, 90d, all (기본값 30d)",
+        "schema": { "type": "string", "default": "30d" }
+    })
+}
+
+/// .ics 구독 주소: 캘린더 앱이 헤더를 붙일 수 없으므로 `key` 쿼리로도 인증한다
+fn calendar_get(summary: &str) -> Value {
+    json!({
+        "summary": summary,
+        "security": [{ "bearerAuth": [] }, { "apiKeyHeader": [] }, { "apiKeyQuery": [] }],
+        "parameters": [
+            query_param("dateFrom", false, "시작일 (YYYY-MM-DD, 없으면 처음부터)"),
+            query_param("dateTo", false, "종료일 (YYYY-MM-DD, 없으면 끝까지)")
+        ],
+        "responses": responses(json!({
+            "200": {
+                "description": "iCalendar (RFC 5545). 삭제된 예약은 STATUS:CANCELLED 로 포함",
+                "content": { "text/calendar": { "schema": string() } }
+            }
+        }))
+    })
+}
+
+fn errors() -> Value {
+    json!({
+        "400": json_response("잘못된 요청", schema_ref("Error")),
+        "401": json_response("API 키가 없거나 유효하지 않음", schema_ref("Error")),
+        "403": json_response("권한(scope) 부족", schema_ref("Error")),
+        "404": json_response("대상을 찾을 수 없음", schema_ref("Error"))
+    })
+}
+
+/// `responses` 에 공통 오류 응답을 합친다
+fn responses(ok: Value) -> Value {
+    let mut all = errors();
+    if let (Some(all), Some(ok)) = (all.as_object_mut(), ok.as_object()) {
+        for (status, response) in ok {
+            all.insert(status.clone(), response.clone());
+        }
+    }
+    all
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+fn schemas() -> Value {
+    json!({
+        "Error": object(&["error"], json!({ "error": string() })),
+        "Reservation": object(
+            &["id", "date", "time", "status", "createdAt", "updatedAt"],
+            json!({
+                "id": string(),
+                "customerName": nullable_string(),
+                "customerPhone": nullable_string(),
+                "date": { "type": "string", "format": "date" },
+                "time": { "type": "string", "example": "14:30" },
+                "designerId": nullable_string(),
+                "serviceType": nullable_string(),
+                "status": {
+                    "type": "string",
+                    "enum": ["pending", "confirmed", "completed", "cancelled", "no_show"]
+                },
+                "notes": nullable_string(),
+                "createdAt": string(),
+                "updatedAt": string()
+            }),
+        ),
+        "ReservationInput": object(
+            &["date", "time"],
+            json!({
+                "customerName": nullable_string(),
+                "customerPhone": nullable_string(),
+                "date": { "type": "string", "format": "date" },
+                "time": { "type": "string", "example": "14:30" },
+                "designerId": nullable_string(),
+                "serviceType": nullable_string(),
+                "notes": nullable_string()
+            }),
+        ),
+        "StatusInput": object(
+            &["status"],
+            json!({
+                "status": {
+                    "type": "string",
+                    "enum": ["pending", "confirmed", "completed", "cancelled", "no_show"]
+                }
+            }),
+        ),
+        "Customer": object(
+            &["id", "name", "totalVisits", "createdAt", "updatedAt"],
+            json!({
+                "id": string(),
+                "name": string(),
+                "phone": nullable_string(),
+                "email": nullable_string(),
+                "birthdate": nullable_string(),
+                "gender": nullable_string(),
+                "preferredDesignerId": nullable_string(),
+                "preferredService": nullable_string(),
+                "allergies": nullable_string(),
+                "totalVisits": { "type": "integer" },
+                "lastVisitDate": nullable_string(),
+                "notes": nullable_string(),
+                "createdAt": string(),
+                "updatedAt": string(),
+                "reminderOptOut": { "type": "boolean" },
+                "consentService": { "type": "boolean" },
+                "consentServiceAt": nullable_string(),
+                "consentMarketing": { "type": "boolean" },
+                "consentMarketingAt": nullable_string(),
+                "consentPhoto": { "type": "boolean" },
+                "consentPhotoAt": nullable_string(),
+                "consentVersion": nullable_string(),
+                "anonymizedAt": nullable_string()
+            }),
+        ),
+        "CustomerInput": object(
+            &["name"],
+            json!({
+                "name": string(),
+                "phone": nullable_string(),
+                "email": nullable_string(),
+                "birthdate": nullable_string(),
+                "gender": nullable_string(),
+                "preferredDesignerId": nullable_string(),
+                "preferredService": nullable_string(),
+                "allergies": nullable_string(),
+                "notes": nullable_string(),
+                "reminderOptOut": { "type": "boolean", "nullable": true },
+                "consentService": { "type": "boolean", "nullable": true },
+                "consentMarketing": { "type": "boolean", "nullable": true },
+                "consentPhoto": { "type": "boolean", "nullable": true }
+            }),
+        ),
+        "CustomerReservation": object(
+            &["id", "date", "time", "status"],
+            json!({
+                "id": string(),
+                "date": string(),
+                "time": string(),
+                "serviceType": nullable_string(),
+                "designerName": nullable_string(),
+                "status": string(),
+                "notes": nullable_string()
+            }),
+        ),
+        "Designer": object(
+            &["id", "name", "isActive", "createdAt", "updatedAt"],
+            json!({
+                "id": string(),
+                "name": string(),
+                "specialty": nullable_string(),
+                "isActive": { "type": "boolean" },
+                "createdAt": string(),
+                "updatedAt": string()
+            }),
+        ),
+        "EffectiveHours": object(
+            &["date", "dayOfWeek", "isClosed", "source"],
+            json!({
+                "date": string(),
+                "dayOfWeek": { "type": "integer" },
+                "openTime": nullable_string(),
+                "closeTime": nullable_string(),
+                "breakStart": nullable_string(),
+                "breakEnd": nullable_string(),
+                "isClosed": { "type": "boolean" },
+                "source": string(),
+                "description": nullable_string()
+            }),
+        ),
+        "Availability": object(
+            &["date", "hours", "bookedTimes", "availableTimes"],
+            json!({
+                "date": string(),
+                "designerId": nullable_string(),
+                "hours": schema_ref("EffectiveHours"),
+                "bookedTimes": { "type": "array", "items": string() },
+                "availableTimes": { "type": "array", "items": string() }
+            }),
+        ),
+        "StatisticsSummary": object(
+            &["totalReservations", "completed", "cancelled", "noShow", "completionRate", "averagePerDay", "period"],
+            json!({
+                "totalReservations": { "type": "integer" },
+                "completed": { "type": "integer" },
+                "cancelled": { "type": "integer" },
+                "noShow": { "type": "integer" },
+                "completionRate": { "type": "number" },
+                "averagePerDay": { "type": "number" },
+                "busiestDay": nullable_string(),
+                "busiestHour": nullable_string(),
+                "topDesigner": {
+                    "type": "object",
+                    "nullable": true,
+                    "properties": { "name": string(), "count": { "type": "integer" } }
+                },
+                "topService": {
+                    "type": "object",
+                    "nullable": true,
+                    "properties": { "name": string(), "count": { "type": "integer" } }
+                },
+                "period": string()
+            }),
+        ),
+        "DailyStatistic": object(
+            &["date", "total", "completed", "cancelled"],
+            json!({
+                "date": string(),
+                "total": { "type": "integer" },
+                "completed": { "type": "integer" },
+                "cancelled": { "type": "integer" }
+            }),
+        ),
+        "HourlyStatistic": object(
+            &["hour", "count"],
+            json!({ "hour": string(), "count": { "type": "integer" } }),
+        ),
+        "DesignerStatistic": object(
+            &["designerId", "designerName", "total", "completed", "completionRate"],
+            json!({
+                "designerId": string(),
+                "designerName": string(),
+                "total": { "type": "integer" },
+                "completed": { "type": "integer" },
+                "completionRate": { "type": "number" }
+            }),
+        )
+    })
+}
+
+/// `/api/v1/openapi.json` 으로 제공하는 OpenAPI 3 문서
+pub fn document() -> Value {
+    let public = json!([]);
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Sisters Salon API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "예약/고객/디자이너/통계 로컬 API. GET 요청은 read, 그 외 요청은 write 권한의 API 키가 필요합니다."
+        },
+        "servers": [{ "url": "/api/v1" }],
+        "security": [{ "bearerAuth": [] }, { "apiKeyHeader": [] }],
+        "paths": {
+            "/health": {
+                "get": {
+                    "summary": "서버 상태",
+                    "security": public,
+                    "responses": { "200": { "description": "정상" } }
+                }
+            },
+            "/openapi.json": {
+                "get": {
+                    "summary": "이 문서",
+                    "security": public,
+                    "responses": { "200": { "description": "OpenAPI 문서" } }
+                }
+            },
+            "/reservations": {
+                "get": {
+                    "summary": "예약 목록",
+                    "parameters": [
+                        query_param("date", false, "특정 날짜 (YYYY-MM-DD)"),
+                        query_param("dateFrom", false, "시작일 (YYYY-MM-DD)"),
+                        query_param("dateTo", false, "종료일 (YYYY-MM-DD)")
+                    ],
+                    "responses": responses(json!({ "200": json_response("예약 목록", list_of("Reservation")) }))
+                },
+                "post": {
+                    "summary": "예약 생성",
+                    "requestBody": json_body("ReservationInput"),
+                    "responses": responses(json!({ "201": json_response("생성된 예약", schema_ref("Reservation")) }))
+                }
+            },
+            "/reservations/{id}": {
+                "parameters": [id_param()],
+                "get": {
+                    "summary": "예약 조회",
+                    "responses": responses(json!({ "200": json_response("예약", schema_ref("Reservation")) }))
+                },
+                "put": {
+                    "summary": "예약 수정",
+                    "requestBody": json_body("ReservationInput"),
+                    "responses": responses(json!({ "200": json_response("수정된 예약", schema_ref("Reservation")) }))
+                },
+                "delete": {
+                    "summary": "예약 삭제",
+                    "responses": responses(json!({ "204": { "description": "삭제됨" } }))
+                }
+            },
+            "/reservations/{id}/status": {
+                "parameters": [id_param()],
+                "put": {
+                    "summary": "예약 상태 변경",
+                    "requestBody": json_body("StatusInput"),
+                    "responses": responses(json!({ "200": json_response("변경된 예약", schema_ref("Reservation")) }))
+                }
+            },
+            "/customers": {
+                "get": {
+                    "summary": "고객 목록 (q 가 있으면 이름/전화번호 검색)",
+                    "parameters": [query_param("q", false, "검색어")],
+                    "responses": responses(json!({ "200": json_response("고객 목록", list_of("Customer")) }))
+                },
+                "post": {
+                    "summary": "고객 등록",
+                    "requestBody": json_body("CustomerInput"),
+                    "responses": responses(json!({ "201": json_response("등록된 고객", schema_ref("Customer")) }))
+                }
+            },
+            "/customers/{id}": {
+                "parameters": [id_param()],
+                "get": {
+                    "summary": "고객 조회",
+                    "responses": responses(json!({ "200": json_response("고객", schema_ref("Customer")) }))
+                },
+                "put": {
+                    "summary": "고객 수정 (보낸 필드만 변경)",
+                    "requestBody": json_body("CustomerInput"),
+                    "responses": responses(json!({ "200": json_response("수정된 고객", schema_ref("Customer")) }))
+                },
+                "delete": {
+                    "summary": "고객 삭제",
+                    "responses": responses(json!({ "204": { "description": "삭제됨" } }))
+                }
+            },
+            "/customers/{id}/reservations": {
+                "parameters": [id_param()],
+                "get": {
+                    "summary": "고객의 예약 이력",
+                    "responses": responses(json!({ "200": json_response("예약 이력", list_of("CustomerReservation")) }))
+                }
+            },
+            "/designers": {
+                "get": {
+                    "summary": "디자이너 목록",
+                    "parameters": [{
+                        "name": "active",
+                        "in": "query",
+                        "required": false,
+                        "description": "true 면 활성 디자이너만",
+                        "schema": { "type": "boolean" }
+                    }],
+                    "responses": responses(json!({ "200": json_response("디자이너 목록", list_of("Designer")) }))
+                }
+            },
+            "/designers/{id}/calendar.ics": {
+                "parameters": [id_param()],
+                "get": calendar_get("디자이너 예약 캘린더 구독")
+            },
+            "/calendar.ics": {
+                "get": calendar_get("살롱 전체 예약 캘린더 구독")
+            },
+            "/availability": {
+                "get": {
+                    "summary": "예약 가능 시간",
+                    "parameters": [
+                        query_param("date", true, "날짜 (YYYY-MM-DD)"),
+                        query_param("designerId", false, "디자이너 ID")
+                    ],
+                    "responses": responses(json!({ "200": json_response("예약 가능 시간", schema_ref("Availability")) }))
+                }
+            },
+            "/statistics/summary": {
+                "get": {
+                    "summary": "통계 요약",
+                    "parameters": [period_param()],
+                    "responses": responses(json!({ "200": json_response("통계 요약", schema_ref("StatisticsSummary")) }))
+                }
+            },
+            "/statistics/daily": {
+                "get": {
+                    "summary": "일별 통계",
+                    "parameters": [
+                        query_param("startDate", true, "시작일 (YYYY-MM-DD)"),
+                        query_param("endDate", true, "종료일 (YYYY-MM-DD)")
+                    ],
+                    "responses": responses(json!({ "200": json_response("일별 통계", list_of("DailyStatistic")) }))
+                }
+            },
+            "/statistics/hourly": {
+                "get": {
+                    "summary": "시간대별 통계",
+                    "parameters": [period_param()],
+                    "responses": responses(json!({ "200": json_response("시간대별 통계", list_of("HourlyStatistic")) }))
+                }
+            },
+            "/statistics/designers": {
+                "get": {
+                    "summary": "디자이너별 통계",
+                    "parameters": [period_param()],
+                    "responses": responses(json!({ "200": json_response("디자이너별 통계", list_of("DesignerStatistic")) }))
+                }
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+                "apiKeyHeader": { "type": "apiKey", "in": "header", "name": "X-API-Key" },
+                "apiKeyQuery": { "type": "apiKey", "in": "query", "name": "key" }
+            },
+            "schemas": schemas()
+        }
+    })
+}
@@ -0,0 +1,433 @@
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{header, HeaderMap, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, put};
+use axum::{Json, Router};
+use rusqlite::Connection;
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+use crate::db::Database;
+use crate::services::api::{keys, openapi};
+use crate::services::customers::{self, CreateCustomerInput, Customer, CustomerReservation, UpdateCustomerInput};
+use crate::services::designers::{self, Designer};
+use crate::services::ical;
+use crate::services::reservations::{self, CreateReservationInput, Reservation};
+use crate::services::schedule::{self, Availability};
+use crate::services::statistics::{self, DailyStatistic, DesignerStatistic, HourlyStatistic, StatisticsSummary};
+
+#[derive(Clone)]
+pub struct ApiState {
+    pub db: Arc<Mutex<Database>>,
+}
+
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+
+    /// 서비스 함수의 문자열 오류를 HTTP 상태로 변환
+    fn from_service(message: String) -> Self {
+        if message == rusqlite::Error::QueryReturnedNoRows.to_string() {
+            Self::new(StatusCode::NOT_FOUND, "Not found")
+        } else {
+            Self::new(StatusCode::BAD_REQUEST, message)
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(serde_json::json!({ "error": self.message }))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+/// Tauri 커맨드와 같은 서비스 함수를 블로킹 스레드에서 실행
+async fn with_conn<T, F>(state: &ApiState, f: F) -> Result<T, ApiError>
+where
+    T: Send + 'static,
+    F: FnOnce(&Connection) -> Result<T, String> + Send + 'static,
+{
+    let db = state.db.clone();
+    tokio::task::spawn_blocking(move || {
+        let db = db.lock().map_err(|e| e.to_string())?;
+        f(db.conn())
+    })
+    .await
+    .map_err(|e| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+    .map_err(ApiError::from_service)
+}
+
+fn api_key_from_headers(headers: &HeaderMap) -> Option<String> {
+    if let Some(value) = headers.get("x-api-key").and_then(|v| v.to_str().ok()) {
+        return Some(value.trim().to_string());
+    }
+    headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|v| v.trim().to_string())
+}
+
+/// 캘린더 앱은 구독 주소에 헤더를 붙일 수 없으므로 .ics 주소에 한해 `?key=` 로도 받는다
+fn api_key_from_calendar_query(request: &Request) -> Option<String> {
+    if !request.uri().path().ends_with(".ics") {
+        return None;
+    }
+    request
+        .uri()
+        .query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("key="))
+        .map(|v| v.trim().to_string())
+}
+
+/// GET 은 read, 그 외 메서드는 write 권한이 필요하다
+async fn require_api_key(State(state): State<ApiState>, request: Request, next: Next) -> Response {
+    let secret = api_key_from_headers(request.headers()).or_else(|| api_key_from_calendar_query(&request));
+    let Some(secret) = secret else {
+        return ApiError::new(StatusCode::UNAUTHORIZED, "API key required").into_response();
+    };
+
+    let key = match with_conn(&state, move |conn| keys::authenticate(conn, &secret)).await {
+        Ok(Some(key)) => key,
+        Ok(None) => return ApiError::new(StatusCode::UNAUTHORIZED, "Invalid API key").into_response(),
+        Err(e) => return e.into_response(),
+    };
+
+    let required = if request.method() == Method::GET || request.method() == Method::HEAD {
+        keys::ApiScope::Read
+    } else {
+        keys::ApiScope::Write
+    };
+    if !key.has_scope(required) {
+        return ApiError::new(
+            StatusCode::FORBIDDEN,
+            format!("API key lacks '{}' scope", required.as_str()),
+        )
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+pub fn router(state: ApiState) -> Router {
+    let protected = Router::new()
+        .route("/reservations", get(list_reservations).post(create_reservation))
+        .route(
+            "/reservations/:id",
+            get(get_reservation).put(update_reservation).delete(delete_reservation),
+        )
+        .route("/reservations/:id/status", put(update_reservation_status))
+        .route("/customers", get(list_customers).post(create_customer))
+        .route(
+            "/customers/:id",
+            get(get_customer).put(update_customer).delete(delete_customer),
+        )
+        .route("/customers/:id/reservations", get(get_customer_reservations))
+        .route("/designers", get(list_designers))
+        .route("/designers/:id/calendar.ics", get(get_designer_calendar))
+        .route("/calendar.ics", get(get_salon_calendar))
+        .route("/availability", get(get_availability))
+        .route("/statistics/summary", get(get_statistics_summary))
+        .route("/statistics/daily", get(get_daily_statistics))
+        .route("/statistics/hourly", get(get_hourly_statistics))
+        .route("/statistics/designers", get(get_designer_statistics))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_api_key));
+
+    let v1 = Router::new()
+        .route("/health", get(health))
+        .route("/openapi.json", get(openapi_document))
+        .merge(protected);
+
+    Router::new()
+        .nest("/api/v1", v1)
+        .fallback(not_found)
+        .with_state(state)
+}
+
+async fn not_found() -> ApiError {
+    ApiError::new(StatusCode::NOT_FOUND, "Not found")
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") }))
+}
+
+async fn openapi_document() -> Json<serde_json::Value> {
+    Json(openapi::document())
+}
+
+// ===== 예약 =====
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReservationQuery {
+    date: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StatusInput {
+    status: String,
+}
+
+async fn list_reservations(
+    State(state): State<ApiState>,
+    Query(query): Query<ReservationQuery>,
+) -> ApiResult<Vec<Reservation>> {
+    with_conn(&state, move |conn| {
+        reservations::get_reservations(conn, query.date, query.date_from, query.date_to)
+    })
+    .await
+    .map(Json)
+}
+
+async fn get_reservation(State(state): State<ApiState>, Path(id): Path<String>) -> ApiResult<Reservation> {
+    with_conn(&state, move |conn| reservations::get_reservation(conn, &id))
+        .await
+        .map(Json)
+}
+
+async fn create_reservation(
+    State(state): State<ApiState>,
+    Json(data): Json<CreateReservationInput>,
+) -> Result<(StatusCode, Json<Reservation>), ApiError> {
+    with_conn(&state, move |conn| reservations::create_reservation(conn, data))
+        .await
+        .map(|r| (StatusCode::CREATED, Json(r)))
+}
+
+async fn update_reservation(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+    Json(data): Json<CreateReservationInput>,
+) -> ApiResult<Reservation> {
+    with_conn(&state, move |conn| reservations::update_reservation(conn, &id, data))
+        .await
+        .map(Json)
+}
+
+async fn update_reservation_status(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+    Json(data): Json<StatusInput>,
+) -> ApiResult<Reservation> {
+    with_conn(&state, move |conn| reservations::update_reservation_status(conn, &id, &data.status))
+        .await
+        .map(Json)
+}
+
+async fn delete_reservation(State(state): State<ApiState>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    with_conn(&state, move |conn| reservations::delete_reservation(conn, &id))
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+}
+
+// ===== 고객 =====
+
+#[derive(Deserialize)]
+struct CustomerQuery {
+    q: Option<String>,
+}
+
+async fn list_customers(
+    State(state): State<ApiState>,
+    Query(query): Query<CustomerQuery>,
+) -> ApiResult<Vec<Customer>> {
+    with_conn(&state, move |conn| match query.q.filter(|q| !q.is_empty()) {
+        Some(q) => customers::search_customers(conn, &q),
+        None => customers::get_customers(conn),
+    })
+    .await
+    .map(Json)
+}
+
+async fn get_customer(State(state): State<ApiState>, Path(id): Path<String>) -> ApiResult<Customer> {
+    with_conn(&state, move |conn| customers::get_customer(conn, &id))
+        .await
+        .map(Json)
+}
+
+async fn create_customer(
+    State(state): State<ApiState>,
+    Json(data): Json<CreateCustomerInput>,
+) -> Result<(StatusCode, Json<Customer>), ApiError> {
+    with_conn(&state, move |conn| customers::create_customer(conn, data))
+        .await
+        .map(|c| (StatusCode::CREATED, Json(c)))
+}
+
+async fn update_customer(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+    Json(data): Json<UpdateCustomerInput>,
+) -> ApiResult<Customer> {
+    with_conn(&state, move |conn| customers::update_customer(conn, &id, data))
+        .await
+        .map(Json)
+}
+
+async fn delete_customer(State(state): State<ApiState>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    with_conn(&state, move |conn| customers::delete_customer(conn, &id))
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+}
+
+async fn get_customer_reservations(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> ApiResult<Vec<CustomerReservation>> {
+    with_conn(&state, move |conn| customers::get_customer_reservations(conn, &id))
+        .await
+        .map(Json)
+}
+
+// ===== 디자이너 / 예약 가능 시간 =====
+
+#[derive(Deserialize)]
+struct DesignerQuery {
+    #[serde(default)]
+    active: bool,
+}
+
+async fn list_designers(
+    State(state): State<ApiState>,
+    Query(query): Query<DesignerQuery>,
+) -> ApiResult<Vec<Designer>> {
+    with_conn(&state, move |conn| {
+        if query.active {
+            designers::get_active_designers(conn)
+        } else {
+            designers::get_designers(conn)
+        }
+    })
+    .await
+    .map(Json)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AvailabilityQuery {
+    date: String,
+    designer_id: Option<String>,
+}
+
+async fn get_availability(
+    State(state): State<ApiState>,
+    Query(query): Query<AvailabilityQuery>,
+) -> ApiResult<Availability> {
+    with_conn(&state, move |conn| {
+        schedule::availability(conn, &query.date, query.designer_id.as_deref())
+    })
+    .await
+    .map(Json)
+}
+
+// ===== 캘린더 구독 =====
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CalendarQuery {
+    date_from: Option<String>,
+    date_to: Option<String>,
+}
+
+fn calendar_response(content: String) -> Response {
+    ([(header::CONTENT_TYPE, "text/calendar; charset=utf-8")], content).into_response()
+}
+
+async fn render_calendar(state: &ApiState, designer_id: Option<String>, query: CalendarQuery) -> Result<Response, ApiError> {
+    with_conn(state, move |conn| {
+        let date_from = query.date_from.unwrap_or_else(|| ical::DEFAULT_DATE_FROM.to_string());
+        let date_to = query.date_to.unwrap_or_else(|| ical::DEFAULT_DATE_TO.to_string());
+        if let Some(id) = designer_id.as_deref() {
+            conn.query_row("SELECT 1 FROM designers WHERE id = ?1", [id], |_| Ok(()))
+                .map_err(|e| e.to_string())?;
+        }
+        ical::render_ics(conn, designer_id.as_deref(), &date_from, &date_to)
+    })
+    .await
+    .map(calendar_response)
+}
+
+async fn get_designer_calendar(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+    Query(query): Query<CalendarQuery>,
+) -> Result<Response, ApiError> {
+    render_calendar(&state, Some(id), query).await
+}
+
+async fn get_salon_calendar(
+    State(state): State<ApiState>,
+    Query(query): Query<CalendarQuery>,
+) -> Result<Response, ApiError> {
+    render_calendar(&state, None, query).await
+}
+
+// ===== 통계 =====
+
+fn default_period() -> String {
+    "30d".to_string()
+}
+
+#[derive(Deserialize)]
+struct PeriodQuery {
+    #[serde(default = "default_period")]
+    period: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DateRangeQuery {
+    start_date: String,
+    end_date: String,
+}
+
+async fn get_statistics_summary(
+    State(state): State<ApiState>,
+    Query(query): Query<PeriodQuery>,
+) -> ApiResult<StatisticsSummary> {
+    with_conn(&state, move |conn| statistics::get_statistics_summary(conn, query.period))
+        .await
+        .map(Json)
+}
+
+async fn get_daily_statistics(
+    State(state): State<ApiState>,
+    Query(query): Query<DateRangeQuery>,
+) -> ApiResult<Vec<DailyStatistic>> {
+    with_conn(&state, move |conn| {
+        statistics::get_daily_statistics(conn, &query.start_date, &query.end_date)
+    })
+    .await
+    .map(Json)
+}
+
+async fn get_hourly_statistics(
+    State(state): State<ApiState>,
+    Query(query): Query<PeriodQuery>,
+) -> ApiResult<Vec<HourlyStatistic>> {
+    with_conn(&state, move |conn| statistics::get_hourly_statistics(conn, &query.period))
+        .await
+        .map(Json)
+}
+
+async fn get_designer_statistics(
+    State(state): State<ApiState>,
+    Query(query): Query<PeriodQuery>,
+) -> ApiResult<Vec<DesignerStatistic>> {
+    with_conn(&state, move |conn| statistics::get_designer_statistics(conn, &query.period))
+        .await
+        .map(Json)
+}
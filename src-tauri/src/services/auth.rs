@@ -94,7 +94,7 @@ pub fn is_lock_enabled() -> Result<bool, String> {
 /// Validate PIN format (4-6 digits)
 fn is_valid_pin(pin: &str) -> bool {
     let len = pin.len();
-    len >= 4 && len <= 6 && pin.chars().all(|c| c.is_ascii_digit())
+    (4..=6).contains(&len) && pin.chars().all(|c| c.is_ascii_digit())
 }
 
 /// Get lock settings from app_settings table
@@ -0,0 +1,380 @@
+//! 비밀번호로 암호화한 백업 아카이브 (`.ssbak`)
+//!
+//! 데이터베이스, 첨부 파일, manifest.json 을 zip(deflate)으로 묶은 뒤
+//! 사용자 비밀번호에서 Argon2id 로 유도한 키로 XChaCha20-Poly1305 암호화한다.
+//! 기기 키와 무관하므로 비밀번호만 알면 다른 기기에서도 복원할 수 있다.
+//!
+//! 파일 구조 (정수는 little-endian):
+//!
+//! | 크기 | 내용 |
+//! |------|------|
+//! | 8    | `SSBACKUP` |
+//! | 1    | 형식 버전 (1) |
+//! | 4×3  | Argon2 m_cost(KiB), t_cost, p_cost |
+//! | 16   | salt |
+//! | 24   | nonce |
+//! | 나머지 | 암호문 + 태그 (위 헤더 전체를 AAD 로 인증) |
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::db::encryption;
+
+pub const ARCHIVE_EXTENSION: &str = "ssbak";
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+pub const MANIFEST_ENTRY: &str = "manifest.json";
+pub const DATABASE_ENTRY: &str = "database.db";
+/// 앱 데이터 폴더 아래 첨부 파일 폴더 (아카이브 안에서도 같은 이름)
+pub const ATTACHMENTS_DIR: &str = "attachments";
+
+const MAGIC: &[u8; 8] = b"SSBACKUP";
+const FORMAT_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = 8 + 1 + 12 + SALT_LEN + NONCE_LEN;
+
+/// Argon2id 비용 인자
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KdfParams {
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    /// OWASP 권장값 (19 MiB, 2회, 병렬 1)
+    fn default() -> Self {
+        Self { m_cost: 19 * 1024, t_cost: 2, p_cost: 1 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestFile {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestCounts {
+    pub customers: i64,
+    pub reservations: i64,
+    pub designers: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    /// 데이터베이스 `PRAGMA user_version`
+    pub schema_version: i64,
+    pub counts: ManifestCounts,
+    pub files: Vec<ManifestFile>,
+}
+
+/// 복호화하고 해시까지 확인한 아카이브 내용
+pub struct ArchiveContents {
+    pub manifest: BackupManifest,
+    /// 암호화되지 않은 SQLite 파일
+    pub database: Vec<u8>,
+    /// (첨부 폴더 기준 상대 경로, 내용)
+    pub attachments: Vec<(String, Vec<u8>)>,
+    /// 데이터베이스·첨부 파일 외에 함께 담은 파일 (기기 이전 번들의 설정 등)
+    pub extras: Vec<(String, Vec<u8>)>,
+}
+
+pub fn is_archive(filename: &str) -> bool {
+    filename.ends_with(&format!(".{}", ARCHIVE_EXTENSION))
+}
+
+pub fn validate_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(format!("백업 비밀번호는 {}자 이상이어야 합니다", MIN_PASSPHRASE_LEN));
+    }
+    Ok(())
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn count(conn: &Connection, table: &str) -> i64 {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+        .unwrap_or(0)
+}
+
+/// 열려 있는 데이터베이스와 첨부 폴더를 `dest` 아카이브로 저장한다.
+pub fn write_archive(
+    conn: &Connection,
+    attachments_dir: &Path,
+    dest: &Path,
+    passphrase: &str,
+) -> Result<BackupManifest, String> {
+    write_archive_with(conn, attachments_dir, dest, passphrase, KdfParams::default())
+}
+
+pub(crate) fn write_archive_with(
+    conn: &Connection,
+    attachments_dir: &Path,
+    dest: &Path,
+    passphrase: &str,
+    params: KdfParams,
+) -> Result<BackupManifest, String> {
+    write_archive_entries(conn, attachments_dir, Vec::new(), dest, passphrase, params)
+}
+
+/// `extras` 는 (아카이브 안 경로, 내용). 데이터베이스·첨부 파일과 함께 manifest 로 검증된다.
+pub(crate) fn write_archive_entries(
+    conn: &Connection,
+    attachments_dir: &Path,
+    extras: Vec<(String, Vec<u8>)>,
+    dest: &Path,
+    passphrase: &str,
+    params: KdfParams,
+) -> Result<BackupManifest, String> {
+    validate_passphrase(passphrase)?;
+    if let Some((name, _)) = extras.iter().find(|(name, _)| {
+        name == MANIFEST_ENTRY || name == DATABASE_ENTRY || name.starts_with(&format!("{}/", ATTACHMENTS_DIR))
+    }) {
+        return Err(format!("예약된 아카이브 경로입니다: {}", name));
+    }
+
+    // 기기 키로 암호화된 데이터베이스를 평문 SQLite 이미지로 풀어 아카이브에 담는다 (디스크에는 쓰지 않음)
+    let database = encryption::export_plaintext(conn)?;
+    super::check_integrity(&encryption::open_image(&database)?)?;
+
+    let mut entries: Vec<(String, Vec<u8>)> = vec![(DATABASE_ENTRY.to_string(), database)];
+    if attachments_dir.is_dir() {
+        collect_files(attachments_dir, attachments_dir, &mut entries)?;
+    }
+    entries.extend(extras);
+
+    let manifest = BackupManifest {
+        format_version: FORMAT_VERSION as u32,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        schema_version: conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap_or(0),
+        counts: ManifestCounts {
+            customers: count(conn, "customers"),
+            reservations: count(conn, "reservations"),
+            designers: count(conn, "designers"),
+        },
+        files: entries
+            .iter()
+            .map(|(path, data)| ManifestFile { path: path.clone(), size: data.len() as u64, sha256: sha256_hex(data) })
+            .collect(),
+    };
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let manifest_json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+    for (name, data) in std::iter::once((MANIFEST_ENTRY, &manifest_json)).chain(entries.iter().map(|(p, d)| (p.as_str(), d))) {
+        zip.start_file(name, options).map_err(|e| format!("아카이브 생성 실패: {}", e))?;
+        zip.write_all(data).map_err(|e| format!("아카이브 생성 실패: {}", e))?;
+    }
+    let packed = zip.finish().map_err(|e| format!("아카이브 생성 실패: {}", e))?.into_inner();
+
+    let sealed = seal(&packed, passphrase, params)?;
+    std::fs::write(dest, sealed).map_err(|e| format!("백업 파일 저장 실패: {}", e))?;
+    Ok(manifest)
+}
+
+fn collect_files(root: &Path, dir: &Path, entries: &mut Vec<(String, Vec<u8>)>) -> Result<(), String> {
+    let read_dir = std::fs::read_dir(dir).map_err(|e| format!("첨부 폴더를 읽을 수 없습니다: {}", e))?;
+    for entry in read_dir {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            collect_files(root, &path, entries)?;
+        } else if path.is_file() {
+            let relative = path.strip_prefix(root).map_err(|e| e.to_string())?;
+            let name = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/");
+            let data = std::fs::read(&path).map_err(|e| format!("첨부 파일을 읽을 수 없습니다: {}", e))?;
+            entries.push((format!("{}/{}", ATTACHMENTS_DIR, name), data));
+        }
+    }
+    Ok(())
+}
+
+/// 아카이브를 복호화하고 manifest 의 크기·SHA-256 과 모든 파일을 대조한다.
+/// 비밀번호가 틀리거나 파일이 변조·손상되었으면 오류를 돌려준다.
+pub fn read_archive(path: &Path, passphrase: &str) -> Result<ArchiveContents, String> {
+    let data = std::fs::read(path).map_err(|e| format!("백업 파일을 읽을 수 없습니다: {}", e))?;
+    let packed = open(&data, passphrase)?;
+
+    let mut zip = ZipArchive::new(Cursor::new(packed)).map_err(|e| format!("백업 아카이브가 손상되었습니다: {}", e))?;
+    let mut manifest: Option<BackupManifest> = None;
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    for i in 0..zip.len() {
+        let mut file = zip.by_index(i).map_err(|e| format!("백업 아카이브가 손상되었습니다: {}", e))?;
+        if file.is_dir() {
+            continue;
+        }
+        // 경로 조작(../) 방지
+        let name = file
+            .enclosed_name()
+            .map(|p| p.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
+            .ok_or_else(|| format!("허용되지 않는 경로가 포함되어 있습니다: {}", file.name()))?;
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).map_err(|e| format!("백업 아카이브가 손상되었습니다: {}", e))?;
+        if name == MANIFEST_ENTRY {
+            manifest = Some(serde_json::from_slice(&content).map_err(|e| format!("manifest 를 읽을 수 없습니다: {}", e))?);
+        } else {
+            files.push((name, content));
+        }
+    }
+
+    let manifest = manifest.ok_or("백업에 manifest.json 이 없습니다")?;
+    if manifest.format_version > FORMAT_VERSION as u32 {
+        return Err("더 새로운 버전의 앱에서 만든 백업입니다. 앱을 업데이트해주세요".to_string());
+    }
+    if manifest.files.len() != files.len() {
+        return Err("백업 파일 목록이 manifest 와 일치하지 않습니다".to_string());
+    }
+    for (name, content) in &files {
+        let expected = manifest
+            .files
+            .iter()
+            .find(|f| &f.path == name)
+            .ok_or_else(|| format!("manifest 에 없는 파일입니다: {}", name))?;
+        if expected.size != content.len() as u64 || expected.sha256 != sha256_hex(content) {
+            return Err(format!("파일 무결성 검증 실패: {}", name));
+        }
+    }
+
+    let mut database = None;
+    let mut attachments = Vec::new();
+    let mut extras = Vec::new();
+    let attachment_prefix = format!("{}/", ATTACHMENTS_DIR);
+    for (name, content) in files {
+        if name == DATABASE_ENTRY {
+            database = Some(content);
+        } else if let Some(relative) = name.strip_prefix(&attachment_prefix) {
+            attachments.push((relative.to_string(), content));
+        } else {
+            extras.push((name, content));
+        }
+    }
+
+    Ok(ArchiveContents {
+        manifest,
+        database: database.ok_or("백업에 데이터베이스가 없습니다")?,
+        attachments,
+        extras,
+    })
+}
+
+pub(crate) fn derive_key(passphrase: &str, salt: &[u8], params: KdfParams) -> Result<[u8; 32], String> {
+    let argon_params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32))
+        .map_err(|e| format!("잘못된 KDF 설정: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, argon_params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("키 유도 실패: {}", e))?;
+    Ok(key)
+}
+
+pub(crate) fn seal(plaintext: &[u8], passphrase: &str, params: KdfParams) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    getrandom::getrandom(&mut salt).map_err(|e| e.to_string())?;
+    getrandom::getrandom(&mut nonce).map_err(|e| e.to_string())?;
+
+    let mut out = Vec::with_capacity(HEADER_LEN + plaintext.len() + 16);
+    out.extend_from_slice(MAGIC);
+    out.push(FORMAT_VERSION);
+    out.extend_from_slice(&params.m_cost.to_le_bytes());
+    out.extend_from_slice(&params.t_cost.to_le_bytes());
+    out.extend_from_slice(&params.p_cost.to_le_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+
+    let key = derive_key(passphrase, &salt, params)?;
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(&key));
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad: &out })
+        .map_err(|_| "백업 암호화 실패".to_string())?;
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+pub(crate) fn open(data: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    if data.len() < HEADER_LEN || &data[..8] != MAGIC {
+        return Err("암호화된 백업 아카이브가 아닙니다".to_string());
+    }
+    if data[8] != FORMAT_VERSION {
+        return Err(format!("지원하지 않는 백업 형식 버전입니다: {}", data[8]));
+    }
+    let u32_at = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    let params = KdfParams { m_cost: u32_at(9), t_cost: u32_at(13), p_cost: u32_at(17) };
+    // 조작된 헤더로 과도한 메모리를 쓰지 않도록 제한
+    if params.m_cost > 1024 * 1024 || params.t_cost > 16 || params.p_cost > 16 {
+        return Err("백업 헤더가 올바르지 않습니다".to_string());
+    }
+    let salt = &data[21..21 + SALT_LEN];
+    let nonce = &data[21 + SALT_LEN..HEADER_LEN];
+
+    let key = derive_key(passphrase, salt, params)?;
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(&key));
+    cipher
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: &data[HEADER_LEN..], aad: &data[..HEADER_LEN] })
+        .map_err(|_| "비밀번호가 틀렸거나 백업 파일이 손상되었습니다".to_string())
+}
+
+#[cfg(test)]
+pub(crate) const TEST_KDF: KdfParams = KdfParams { m_cost: 64, t_cost: 1, p_cost: 1 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    #[test]
+    fn test_archive_roundtrip_and_tampering() {
+        let dir = std::env::temp_dir().join(format!("salon-archive-{}", uuid::Uuid::new_v4()));
+        let attachments = dir.join(ATTACHMENTS_DIR);
+        std::fs::create_dir_all(attachments.join("photos")).unwrap();
+        std::fs::write(attachments.join("photos/c1.jpg"), b"jpeg").unwrap();
+
+        let db = Database::open_in_memory().unwrap();
+        db.conn()
+            .execute("INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-1111')", [])
+            .unwrap();
+
+        let path = dir.join("salon_backup_test.ssbak");
+        let manifest = write_archive_with(db.conn(), &attachments, &path, "correct horse", TEST_KDF).unwrap();
+        assert_eq!(manifest.counts.customers, 1);
+        assert_eq!(manifest.files.len(), 2);
+
+        let raw = std::fs::read(&path).unwrap();
+        assert!(raw.starts_with(MAGIC));
+        assert!(!raw.windows(13).any(|w| w == b"010-1111-1111"));
+
+        let contents = read_archive(&path, "correct horse").unwrap();
+        assert_eq!(contents.attachments, vec![("photos/c1.jpg".to_string(), b"jpeg".to_vec())]);
+        assert!(contents.database.starts_with(b"SQLite format 3\0"));
+
+        assert!(read_archive(&path, "wrong horse").is_err());
+
+        // 한 바이트만 바뀌어도 인증 실패
+        let mut tampered = raw.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        std::fs::write(&path, tampered).unwrap();
+        assert!(read_archive(&path, "correct horse").is_err());
+
+        assert!(write_archive_with(db.conn(), &attachments, &path, "short", TEST_KDF).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,638 @@
+//! 자동 백업
+//!
+//! 설정한 주기(매일/매주)마다 백그라운드 작업이 백업을 만들고, 앱을 종료할 때와 되돌리기
+//! 어려운 작업(전체 초기화, 일정 가져오기) 직전에도 백업한다. 고객 정보 삭제와 보관 기간 파기는
+//! 지운 개인정보가 백업에 남으므로 작업 전 백업을 하지 않는다.
+//! 주기·종료 시 백업(`_auto`)은 서비스별로 GFS(일 7 · 주 4 · 월 12) 규칙에 따라 정리하고,
+//! 직접 만든 백업과 작업 직전 백업(`_before_*`)은 지우지 않는다.
+//! 마지막 실행 결과는 서비스별로 `app_settings` 에 남긴다.
+
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::target::{self, directory::DirectoryTarget, BackupTarget};
+use super::{create_named_backup, get_backup_dir_for_service, push_to_target, BackupInfo, CloudService};
+use crate::db::Database;
+use crate::services::cloudkit::{self, RemoteBackupStore};
+
+const SETTINGS_KEY: &str = "auto_backup_settings";
+const STATUS_KEY: &str = "auto_backup_status";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const FILENAME_PREFIX: &str = "salon_backup_";
+const FILENAME_TIMESTAMP: &str = "%Y%m%d_%H%M%S";
+/// 주기·종료 시 백업 파일 이름 끝. 이 이름의 백업만 보관 규칙으로 정리한다
+const AUTO_SUFFIX: &str = "_auto";
+/// 실패하면 이만큼 지난 뒤 다시 시도한다
+const RETRY_MINUTES: i64 = 60;
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupFrequency {
+    Daily,
+    Weekly,
+}
+
+impl BackupFrequency {
+    fn interval(self) -> chrono::Duration {
+        match self {
+            BackupFrequency::Daily => chrono::Duration::days(1),
+            BackupFrequency::Weekly => chrono::Duration::weeks(1),
+        }
+    }
+}
+
+/// 일·주·월 단위로 각각 가장 최근 백업을 몇 개씩 남길지
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    pub daily: u32,
+    pub weekly: u32,
+    pub monthly: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self { daily: 7, weekly: 4, monthly: 12 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupSettings {
+    /// 주기 백업 사용 여부
+    pub enabled: bool,
+    pub frequency: BackupFrequency,
+    pub services: Vec<CloudService>,
+    /// 앱을 종료할 때도 백업
+    pub on_exit: bool,
+    /// 전체 초기화·일정 가져오기 같은 작업 직전에 로컬 백업 (고객 정보 삭제는 제외)
+    pub before_risky_operations: bool,
+    pub retention: RetentionPolicy,
+}
+
+impl Default for AutoBackupSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            frequency: BackupFrequency::Daily,
+            services: vec![CloudService::Local],
+            on_exit: false,
+            before_risky_operations: true,
+            retention: RetentionPolicy::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupTrigger {
+    Scheduled,
+    Exit,
+    BeforeOperation,
+}
+
+/// 서비스별 마지막 자동 백업 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRunStatus {
+    pub service: CloudService,
+    pub trigger: BackupTrigger,
+    /// 작업 직전 백업이면 그 작업 이름
+    pub operation: Option<String>,
+    pub attempted_at: String,
+    pub success: bool,
+    pub filename: Option<String>,
+    pub error: Option<String>,
+    pub last_success_at: Option<String>,
+    /// 보관 규칙으로 지운 백업 수
+    pub pruned: u32,
+}
+
+pub fn get_settings(conn: &Connection) -> Result<AutoBackupSettings, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(AutoBackupSettings::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn save_settings(conn: &Connection, settings: &AutoBackupSettings) -> Result<(), String> {
+    if settings.enabled && settings.services.is_empty() {
+        return Err("자동 백업할 저장 위치를 선택해주세요".to_string());
+    }
+    if settings.services.contains(&CloudService::GoogleDrive) {
+        return Err("Google Drive 백업은 아직 지원되지 않습니다".to_string());
+    }
+    if settings.services.contains(&CloudService::External) && target::get_config(conn)? == target::BackupTargetConfig::None {
+        return Err("외부 저장소를 먼저 설정해주세요".to_string());
+    }
+    let retention = &settings.retention;
+    if retention.daily == 0 || retention.daily > 31 || retention.weekly > 52 || retention.monthly > 120 {
+        return Err("보관 개수는 일 1~31개, 주 0~52개, 월 0~120개로 설정해주세요".to_string());
+    }
+
+    save_value(conn, SETTINGS_KEY, &serde_json::to_string(settings).map_err(|e| e.to_string())?)
+}
+
+pub fn get_status(conn: &Connection) -> Result<Vec<BackupRunStatus>, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [STATUS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(Vec::new()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn record_status(conn: &Connection, mut status: BackupRunStatus) -> Result<(), String> {
+    let mut statuses = get_status(conn)?;
+    if let Some(previous) = statuses.iter().position(|s| s.service == status.service) {
+        let previous = statuses.remove(previous);
+        if !status.success {
+            status.last_success_at = previous.last_success_at;
+        }
+    }
+    statuses.push(status);
+    save_value(conn, STATUS_KEY, &serde_json::to_string(&statuses).map_err(|e| e.to_string())?)
+}
+
+fn save_value(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![key, value, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 마지막 결과를 보고 지금 주기 백업을 해야 하는지 판단한다
+pub fn is_due(last: Option<&BackupRunStatus>, frequency: BackupFrequency, now: NaiveDateTime) -> bool {
+    let Some(last) = last else {
+        return true;
+    };
+    let parse = |value: &str| NaiveDateTime::parse_from_str(value, DATETIME_FORMAT).ok();
+
+    if !last.success {
+        if let Some(attempted_at) = parse(&last.attempted_at) {
+            if now - attempted_at < chrono::Duration::minutes(RETRY_MINUTES) {
+                return false;
+            }
+        }
+    }
+    match last.last_success_at.as_deref().and_then(parse) {
+        Some(success_at) => now - success_at >= frequency.interval(),
+        None => true,
+    }
+}
+
+/// 잠금을 잡은 채로 만든 백업 파일과, 잠금을 푼 뒤 올리고 정리하는 데 필요한 것
+struct PreparedBackup {
+    info: BackupInfo,
+    backup_dir: PathBuf,
+    external: Option<Box<dyn BackupTarget>>,
+    /// 보관 규칙으로 정리할 때만 (주기·종료 시 백업)
+    retention: Option<RetentionPolicy>,
+}
+
+fn backup_filename(operation: Option<&str>) -> String {
+    let suffix = match operation {
+        Some(operation) => format!("_before_{}", operation),
+        None => AUTO_SUFFIX.to_string(),
+    };
+    format!("{}{}{}.db", FILENAME_PREFIX, chrono::Utc::now().format(FILENAME_TIMESTAMP), suffix)
+}
+
+/// 설정을 읽고 백업 파일을 만든다 (데이터베이스 잠금 안에서)
+fn prepare_backup(
+    db: &Database,
+    data_dir: &Path,
+    service: &CloudService,
+    trigger: BackupTrigger,
+    filename: String,
+) -> Result<PreparedBackup, String> {
+    let backup_dir = get_backup_dir_for_service(data_dir, service)?;
+    // 외부 저장소는 설정을 먼저 확인해 백업을 만들기 전에 실패하게 한다
+    let external = match service {
+        CloudService::External => Some(target::get_config(db.conn())?.build()?),
+        _ => None,
+    };
+    let retention = match trigger {
+        BackupTrigger::BeforeOperation => None,
+        _ => Some(get_settings(db.conn())?.retention),
+    };
+    let info = create_named_backup(db.conn(), db.key(), &backup_dir, service, filename)?;
+    Ok(PreparedBackup { info, backup_dir, external, retention })
+}
+
+/// 만든 백업을 외부 저장소·CloudKit 에 올리고 보관 규칙을 적용한다 (잠금 밖에서).
+/// 정리한 백업 수를 돌려준다.
+fn finish_backup(prepared: &PreparedBackup, service: &CloudService) -> Result<u32, String> {
+    if let Some(external) = &prepared.external {
+        push_to_target(external.as_ref(), &prepared.backup_dir, &prepared.info.filename)?;
+    }
+    let Some(retention) = &prepared.retention else {
+        return Ok(0);
+    };
+    if *service == CloudService::Icloud {
+        upload_to_cloudkit(cloudkit::store(), &prepared.backup_dir.join(&prepared.info.filename), retention);
+    }
+    let pruned = match &prepared.external {
+        Some(external) => apply_retention(external.as_ref(), retention)?,
+        None => apply_retention(&DirectoryTarget::new(prepared.backup_dir.clone()), retention)?,
+    };
+    Ok(pruned.len() as u32)
+}
+
+fn record_result(
+    db: &Database,
+    service: &CloudService,
+    trigger: BackupTrigger,
+    operation: Option<&str>,
+    attempted_at: String,
+    result: &Result<(BackupInfo, u32), String>,
+) {
+    let status = BackupRunStatus {
+        service: service.clone(),
+        trigger,
+        operation: operation.map(str::to_string),
+        success: result.is_ok(),
+        filename: result.as_ref().ok().map(|(info, _)| info.filename.clone()),
+        error: result.as_ref().err().cloned(),
+        last_success_at: result.is_ok().then(|| attempted_at.clone()),
+        pruned: result.as_ref().map(|(_, pruned)| *pruned).unwrap_or(0),
+        attempted_at,
+    };
+    if let Err(e) = record_status(db.conn(), status) {
+        eprintln!("Failed to record auto backup status: {}", e);
+    }
+}
+
+/// 백업 하나를 만들고 결과를 기록한다. 주기·종료 시 백업이면 보관 규칙도 적용한다.
+///
+/// 데이터베이스는 파일을 만드는 동안과 결과를 기록할 때만 잠근다. 외부 저장소·CloudKit 업로드와
+/// 정리는 네트워크를 쓰므로 잠금 밖에서 해, 그동안 다른 명령과 API 서버가 멈추지 않게 한다.
+pub fn run_backup(
+    db: &Mutex<Database>,
+    data_dir: &Path,
+    service: &CloudService,
+    trigger: BackupTrigger,
+    operation: Option<&str>,
+) -> Result<BackupInfo, String> {
+    let attempted_at = chrono::Local::now().format(DATETIME_FORMAT).to_string();
+    let prepared = {
+        let guard = db.lock().map_err(|e| e.to_string())?;
+        prepare_backup(&guard, data_dir, service, trigger, backup_filename(operation))
+    };
+    let result = prepared.and_then(|prepared| {
+        let pruned = finish_backup(&prepared, service)?;
+        Ok((prepared.info, pruned))
+    });
+
+    let guard = db.lock().map_err(|e| e.to_string())?;
+    record_result(&guard, service, trigger, operation, attempted_at, &result);
+    result.map(|(info, _)| info)
+}
+
+/// iCloud 자동 백업은 CloudKit 에도 올리고 같은 규칙으로 정리한다 (CloudKit 을 쓸 수 없으면 건너뜀)
+fn upload_to_cloudkit(store: &dyn RemoteBackupStore, path: &Path, retention: &RetentionPolicy) {
+    if !store.is_available() {
+        return;
+    }
+    if let Err(e) = store.upload(path) {
+        println!("[AutoBackup] CloudKit upload failed (local backup saved): {}", e);
+        return;
+    }
+
+    let records = match store.list() {
+        Ok(records) => records,
+        Err(e) => {
+            println!("[AutoBackup] CloudKit list failed: {}", e);
+            return;
+        }
+    };
+    let candidates: Vec<(String, DateTime<Local>)> = records
+        .into_iter()
+        .filter_map(|record| {
+            let time = auto_backup_time(&record.filename)?;
+            Some((record.id, time))
+        })
+        .collect();
+    for id in select_expired(&candidates, retention) {
+        if let Err(e) = store.delete(&id) {
+            println!("[AutoBackup] Failed to delete CloudKit backup {}: {}", id, e);
+        }
+    }
+}
+
+/// 저장소의 자동 백업 중 보관 규칙에 들지 않는 것을 지우고 그 이름을 돌려준다
+pub fn apply_retention(target: &dyn BackupTarget, retention: &RetentionPolicy) -> Result<Vec<String>, String> {
+    let candidates: Vec<(String, DateTime<Local>)> = target
+        .list()?
+        .into_iter()
+        .filter_map(|backup| {
+            let time = auto_backup_time(&backup.filename)?;
+            Some((backup.filename, time))
+        })
+        .collect();
+
+    let expired = select_expired(&candidates, retention);
+    for filename in &expired {
+        target.delete(filename).map_err(|e| format!("오래된 백업 삭제 실패: {}", e))?;
+    }
+    if !expired.is_empty() {
+        println!("[AutoBackup] Pruned {} backup(s) in {}", expired.len(), target.name());
+    }
+    Ok(expired)
+}
+
+/// 보관 규칙으로 정리하는 자동 백업(주기·종료 시)인지
+pub fn is_auto_backup(filename: &str) -> bool {
+    auto_backup_time(filename).is_some()
+}
+
+/// 자동 백업 파일이면 이름에 담긴 생성 시각 (UTC 로 기록되어 있다)
+fn auto_backup_time(filename: &str) -> Option<DateTime<Local>> {
+    let stamp = filename.strip_prefix(FILENAME_PREFIX)?.strip_suffix(".db")?.strip_suffix(AUTO_SUFFIX)?;
+    let naive = NaiveDateTime::parse_from_str(stamp, FILENAME_TIMESTAMP).ok()?;
+    Some(Utc.from_utc_datetime(&naive).with_timezone(&Local))
+}
+
+/// GFS 보관: 최근 날짜·주·월마다 가장 최근 백업 하나씩을 남기고 나머지를 고른다
+fn select_expired<T: TimeZone>(backups: &[(String, DateTime<T>)], retention: &RetentionPolicy) -> Vec<String> {
+    let mut sorted: Vec<&(String, DateTime<T>)> = backups.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut keep: HashSet<&str> = HashSet::new();
+    keep_newest_per(&sorted, retention.daily, |t| (t.year(), t.month(), t.day()), &mut keep);
+    keep_newest_per(&sorted, retention.weekly, |t| (t.iso_week().year(), t.iso_week().week(), 0), &mut keep);
+    keep_newest_per(&sorted, retention.monthly, |t| (t.year(), t.month(), 0), &mut keep);
+
+    sorted
+        .iter()
+        .filter(|(name, _)| !keep.contains(name.as_str()))
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// 최신순으로 정렬된 `sorted` 에서 기간(`period`)마다 가장 최근 것을 `limit` 개 기간만큼 남긴다
+fn keep_newest_per<'a, T: TimeZone, K: Eq + std::hash::Hash>(
+    sorted: &[&'a (String, DateTime<T>)],
+    limit: u32,
+    period: impl Fn(&DateTime<T>) -> K,
+    keep: &mut HashSet<&'a str>,
+) {
+    let mut periods = HashSet::new();
+    for (name, time) in sorted {
+        if periods.len() >= limit as usize {
+            break;
+        }
+        if periods.insert(period(time)) {
+            keep.insert(name.as_str());
+        }
+    }
+}
+
+/// 주기가 된 서비스마다 자동 백업을 만든다
+pub fn run_due(db: &Arc<Mutex<Database>>, data_dir: &Path, now: NaiveDateTime) -> Result<(), String> {
+    let (settings, statuses) = {
+        let guard = db.lock().map_err(|e| e.to_string())?;
+        (get_settings(guard.conn())?, get_status(guard.conn())?)
+    };
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    for service in &settings.services {
+        let last = statuses.iter().find(|s| &s.service == service);
+        if !is_due(last, settings.frequency, now) {
+            continue;
+        }
+        match run_backup(db, data_dir, service, BackupTrigger::Scheduled, None) {
+            Ok(info) => println!("[AutoBackup] Scheduled backup created: {}", info.filename),
+            Err(e) => eprintln!("Scheduled backup to {:?} failed: {}", service, e),
+        }
+    }
+    Ok(())
+}
+
+/// 앱 종료 시 백업 (설정한 경우)
+pub fn run_on_exit(db: &Arc<Mutex<Database>>, data_dir: &Path) {
+    let settings = match db.lock().map_err(|e| e.to_string()).and_then(|guard| get_settings(guard.conn())) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Failed to load auto backup settings: {}", e);
+            return;
+        }
+    };
+    if !settings.on_exit {
+        return;
+    }
+    for service in &settings.services {
+        if let Err(e) = run_backup(db, data_dir, service, BackupTrigger::Exit, None) {
+            eprintln!("Backup on exit to {:?} failed: {}", service, e);
+        }
+    }
+}
+
+/// 되돌리기 어려운 작업 직전에 로컬 백업을 만든다. 설정에서 끈 경우 `None`.
+///
+/// 작업하는 쪽이 이미 잠금을 잡고 있으므로 `db` 를 그대로 받는다 (로컬 파일만 만들고 올리지 않음).
+/// 백업에 실패하면 작업을 진행하지 않도록 오류를 돌려준다.
+pub fn backup_before_operation(db: &Database, data_dir: &Path, operation: &str) -> Result<Option<String>, String> {
+    if !get_settings(db.conn())?.before_risky_operations {
+        return Ok(None);
+    }
+    let attempted_at = chrono::Local::now().format(DATETIME_FORMAT).to_string();
+    let service = CloudService::Local;
+    let trigger = BackupTrigger::BeforeOperation;
+    let result = prepare_backup(db, data_dir, &service, trigger, backup_filename(Some(operation)))
+        .map(|prepared| (prepared.info, 0));
+    record_result(db, &service, trigger, Some(operation), attempted_at, &result);
+    result
+        .map(|(info, _)| Some(info.filename))
+        .map_err(|e| format!("작업 전 자동 백업에 실패해 작업을 중단했습니다: {}", e))
+}
+
+pub fn start_worker(db: Arc<Mutex<Database>>, data_dir: PathBuf) {
+    let spawned = std::thread::Builder::new()
+        .name("backup-worker".to_string())
+        .spawn(move || loop {
+            if let Err(e) = run_due(&db, &data_dir, chrono::Local::now().naive_local()) {
+                eprintln!("Auto backup failed: {}", e);
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("Failed to start backup worker: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::encryption::DatabaseKey;
+    use crate::services::cloudkit::memory::MemoryStore;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, DATETIME_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn test_gfs_retention_keeps_newest_per_day_week_month() {
+        // 2025-01-01 부터 매일 두 번(09시, 21시) 400일치
+        let start = at("2025-01-01 09:00:00");
+        let backups: Vec<(String, DateTime<Utc>)> = (0..800)
+            .map(|i| {
+                let time = start + chrono::Duration::hours(12 * i);
+                (time.format(FILENAME_TIMESTAMP).to_string(), Utc.from_utc_datetime(&time))
+            })
+            .collect();
+
+        let expired: HashSet<String> = select_expired(&backups, &RetentionPolicy::default()).into_iter().collect();
+        let kept: Vec<&String> = backups.iter().map(|(name, _)| name).filter(|name| !expired.contains(*name)).collect();
+
+        // 최근 7일의 마지막 백업 + 그 이전 주 4개 중 겹치지 않는 것 + 월 12개 중 겹치지 않는 것
+        assert!(kept.contains(&&"20260204_210000".to_string()));
+        assert!(kept.contains(&&"20260129_210000".to_string()));
+        assert!(!kept.contains(&&"20260204_090000".to_string()));
+        assert!(kept.contains(&&"20250331_210000".to_string()));
+        assert!(!kept.contains(&&"20250101_090000".to_string()));
+        assert!(kept.len() <= 7 + 4 + 12);
+
+        // 하루에 여러 개면 그날의 마지막 것만 남긴다
+        assert_eq!(
+            select_expired(&backups[..4], &RetentionPolicy::default()),
+            vec!["20250102_090000".to_string(), "20250101_090000".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_scheduled_backup_records_status_and_prunes() {
+        let dir = std::env::temp_dir().join(format!("salon-auto-backup-{}", uuid::Uuid::new_v4()));
+        let backup_dir = dir.join("backups");
+        std::fs::create_dir_all(&backup_dir).unwrap();
+        for day in 1..=9 {
+            std::fs::write(backup_dir.join(format!("salon_backup_202501{:02}_120000_auto.db", day)), b"old").unwrap();
+        }
+        std::fs::write(backup_dir.join("salon_backup_20250101_120000.db"), b"manual").unwrap();
+        std::fs::write(backup_dir.join("salon_backup_20250101_130000_before_reset.db"), b"snapshot").unwrap();
+
+        let db = Database::open_encrypted(&dir.join("database.db"), &DatabaseKey::generate().unwrap()).unwrap();
+        let retention = RetentionPolicy { daily: 3, weekly: 0, monthly: 0 };
+        save_settings(db.conn(), &AutoBackupSettings { enabled: true, retention, ..Default::default() }).unwrap();
+
+        let db = Mutex::new(db);
+        let info = run_backup(&db, &dir, &CloudService::Local, BackupTrigger::Scheduled, None).unwrap();
+        assert!(info.filename.ends_with("_auto.db"));
+        let db = db.into_inner().unwrap();
+
+        let mut remaining: Vec<String> = std::fs::read_dir(&backup_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            vec![
+                "salon_backup_20250101_120000.db".to_string(),
+                "salon_backup_20250101_130000_before_reset.db".to_string(),
+                "salon_backup_20250108_120000_auto.db".to_string(),
+                "salon_backup_20250109_120000_auto.db".to_string(),
+                info.filename.clone(),
+            ]
+        );
+
+        let status = get_status(db.conn()).unwrap();
+        assert_eq!(status.len(), 1);
+        assert!(status[0].success);
+        assert_eq!(status[0].pruned, 7);
+        assert_eq!(status[0].filename.as_deref(), Some(info.filename.as_str()));
+
+        // 방금 성공했으므로 다음 주기 전까지는 하지 않는다
+        let now = NaiveDateTime::parse_from_str(&status[0].attempted_at, DATETIME_FORMAT).unwrap();
+        assert!(!is_due(status.first(), BackupFrequency::Daily, now + chrono::Duration::hours(23)));
+        assert!(is_due(status.first(), BackupFrequency::Daily, now + chrono::Duration::hours(24)));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cloudkit_upload_prunes_auto_backups() {
+        let dir = std::env::temp_dir().join(format!("salon-auto-cloudkit-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = MemoryStore::new();
+        for day in 1..=5 {
+            store.insert(&format!("salon_backup_202501{:02}_120000_auto.db", day), b"old", Utc::now());
+        }
+        store.insert("salon_backup_20250101_120000.db", b"manual", Utc::now());
+
+        let local = dir.join("salon_backup_20250106_120000_auto.db");
+        std::fs::write(&local, b"new").unwrap();
+        upload_to_cloudkit(&store, &local, &RetentionPolicy { daily: 2, weekly: 0, monthly: 0 });
+
+        let mut filenames = store.filenames();
+        filenames.sort();
+        assert_eq!(
+            filenames,
+            vec![
+                "salon_backup_20250101_120000.db".to_string(),
+                "salon_backup_20250105_120000_auto.db".to_string(),
+                "salon_backup_20250106_120000_auto.db".to_string(),
+            ]
+        );
+
+        // CloudKit 을 쓸 수 없으면 아무것도 하지 않는다
+        let unavailable = MemoryStore::default();
+        upload_to_cloudkit(&unavailable, &local, &RetentionPolicy::default());
+        assert!(unavailable.filenames().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_backup_uploads_and_prunes_on_target() {
+        let dir = std::env::temp_dir().join(format!("salon-auto-external-{}", uuid::Uuid::new_v4()));
+        let external_dir = dir.join("usb");
+        std::fs::create_dir_all(&external_dir).unwrap();
+        for day in 1..=4 {
+            std::fs::write(external_dir.join(format!("salon_backup_202501{:02}_120000_auto.db", day)), b"old").unwrap();
+        }
+
+        let db = Database::open_encrypted(&dir.join("database.db"), &DatabaseKey::generate().unwrap()).unwrap();
+        let settings = AutoBackupSettings { enabled: true, services: vec![CloudService::External], ..Default::default() };
+        assert!(save_settings(db.conn(), &settings).is_err());
+
+        let config = target::BackupTargetConfig::Directory { path: external_dir.to_string_lossy().to_string() };
+        target::save_config(db.conn(), &config).unwrap();
+        let retention = RetentionPolicy { daily: 2, weekly: 0, monthly: 0 };
+        save_settings(db.conn(), &AutoBackupSettings { retention, ..settings }).unwrap();
+
+        let db = Mutex::new(db);
+        let info = run_backup(&db, &dir, &CloudService::External, BackupTrigger::Scheduled, None).unwrap();
+        let db = db.into_inner().unwrap();
+        assert!(external_dir.join(&info.filename).exists());
+        // 올린 뒤 임시 사본은 남기지 않는다
+        assert!(!dir.join("backups").join("external").join(&info.filename).exists());
+        assert_eq!(std::fs::read_dir(&external_dir).unwrap().count(), 2);
+        assert_eq!(get_status(db.conn()).unwrap()[0].pruned, 3);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
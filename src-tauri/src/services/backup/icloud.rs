@@ -0,0 +1,132 @@
+//! iCloud 백업
+//!
+//! 로컬 iCloud 백업 폴더에 만든 백업을 CloudKit 에 올리고, 목록·정리·복원용 내려받기를 맡는다.
+//! 저장소는 `RemoteBackupStore` 로 받으므로 테스트에서는 메모리 저장소를 쓴다.
+
+use std::path::Path;
+
+use super::{archive, auto, BackupInfo, CloudService};
+use crate::services::cloudkit::RemoteBackupStore;
+
+/// 직접 만든 iCloud 백업은 이 개수만 남긴다 (자동 백업은 보관 규칙으로 따로 정리)
+pub const MAX_BACKUPS: usize = 10;
+
+pub fn list_backups(store: &dyn RemoteBackupStore) -> Result<Vec<BackupInfo>, String> {
+    let backups: Vec<BackupInfo> = store
+        .list()?
+        .into_iter()
+        .map(|record| BackupInfo {
+            id: record.id,
+            service: CloudService::Icloud,
+            passphrase_protected: archive::is_archive(&record.filename),
+            filename: record.filename,
+            size: record.size,
+            created_at: record.created_at,
+        })
+        .collect();
+    println!("[Backup] Found {} CloudKit backups", backups.len());
+    Ok(backups)
+}
+
+/// 백업을 올리고 오래된 수동 백업을 정리한다. 정리 실패는 업로드 결과에 영향을 주지 않는다.
+pub fn upload_backup(store: &dyn RemoteBackupStore, local_path: &Path) -> Result<String, String> {
+    let record_id = store.upload(local_path)?;
+    println!("[Backup] CloudKit upload successful: {}", record_id);
+
+    if let Err(e) = cleanup_old_backups(store, MAX_BACKUPS) {
+        println!("[Backup] CloudKit cleanup warning: {}", e);
+    }
+    Ok(record_id)
+}
+
+/// 자동 백업을 뺀 백업 중 최신 `keep` 개만 남기고 지운 레코드 id 를 돌려준다
+pub fn cleanup_old_backups(store: &dyn RemoteBackupStore, keep: usize) -> Result<Vec<String>, String> {
+    let records: Vec<_> = store
+        .list()?
+        .into_iter()
+        .filter(|record| !auto::is_auto_backup(&record.filename))
+        .collect();
+    println!("[Backup] Current CloudKit backup count: {}", records.len());
+
+    let mut deleted = Vec::new();
+    for record in records.iter().skip(keep) {
+        println!("[Backup] Deleting old backup: {}", record.id);
+        match store.delete(&record.id) {
+            Ok(()) => deleted.push(record.id.clone()),
+            Err(e) => println!("[Backup] Failed to delete {}: {}", record.id, e),
+        }
+    }
+    Ok(deleted)
+}
+
+/// 로컬 iCloud 폴더에 없는 백업을 CloudKit 에서 내려받는다
+pub fn download_if_missing(store: &dyn RemoteBackupStore, record_id: &str, dest: &Path) -> Result<(), String> {
+    if dest.exists() {
+        return Ok(());
+    }
+    println!("[Backup] Downloading from CloudKit...");
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("백업 디렉토리 생성 실패: {}", e))?;
+    }
+    store.download(record_id, dest)?;
+    println!("[Backup] CloudKit download successful");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::cloudkit::memory::MemoryStore;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_upload_keeps_newest_manual_backups() {
+        let dir = std::env::temp_dir().join(format!("salon-icloud-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = MemoryStore::new();
+        let start = Utc::now() - Duration::days(30);
+        for day in 0..MAX_BACKUPS as i64 {
+            store.insert(&format!("salon_backup_manual_{:02}.db", day), b"old", start + Duration::days(day));
+        }
+        store.insert("salon_backup_20250101_000000_auto.db", b"auto", start - Duration::days(1));
+
+        let local = dir.join("salon_backup_new.db");
+        std::fs::write(&local, b"new").unwrap();
+        assert_eq!(upload_backup(&store, &local).unwrap(), "salon_backup_new.db");
+
+        let filenames = store.filenames();
+        assert_eq!(filenames.len(), MAX_BACKUPS + 1);
+        assert_eq!(filenames[0], "salon_backup_new.db");
+        // 가장 오래된 수동 백업만 지우고 자동 백업은 남긴다
+        assert!(!filenames.contains(&"salon_backup_manual_00.db".to_string()));
+        assert!(filenames.contains(&"salon_backup_20250101_000000_auto.db".to_string()));
+
+        let listed = list_backups(&store).unwrap();
+        assert_eq!(listed[0].service, CloudService::Icloud);
+        assert_eq!(listed[0].size, 3);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_if_missing() {
+        let dir = std::env::temp_dir().join(format!("salon-icloud-{}", uuid::Uuid::new_v4()));
+        let store = MemoryStore::new();
+        store.insert("salon_backup_a.db", b"remote", Utc::now());
+
+        let dest = dir.join("icloud").join("salon_backup_a.db");
+        download_if_missing(&store, "salon_backup_a.db", &dest).unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"remote");
+
+        // 이미 있으면 내려받지 않는다
+        std::fs::write(&dest, b"local").unwrap();
+        download_if_missing(&store, "salon_backup_a.db", &dest).unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"local");
+
+        assert!(download_if_missing(&store, "missing.db", &dir.join("missing.db")).is_err());
+        let failing = MemoryStore { fail_uploads: true, ..MemoryStore::new() };
+        assert!(upload_backup(&failing, &dest).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
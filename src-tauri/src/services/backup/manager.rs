@@ -0,0 +1,247 @@
+//! 저장 위치(로컬, iCloud, 외부 저장소)를 가리지 않는 백업 작업
+//!
+//! 위치별 폴더를 찾고, iCloud 는 CloudKit 에 올리거나 내려받고, 외부 저장소는 임시 폴더를 거쳐
+//! 주고받는다. 데이터 폴더와 데이터베이스만 받으므로 앱, CLI, 테스트가 같은 경로를 쓴다.
+//! 업로드·다운로드 동안에는 데이터베이스를 잠그지 않는다.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::merge::{SelectiveRestoreOptions, SelectiveRestoreReport};
+use super::preview::{self, BackupPreview, BackupSource};
+use super::target::{self, BackupTarget};
+use super::{archive, icloud, BackupInfo, CloudService, RestoreResult};
+use crate::db::{Database, DB_FILENAME};
+use crate::services::cloudkit::RemoteBackupStore;
+
+pub struct BackupManager<'a> {
+    pub db: &'a Mutex<Database>,
+    /// 앱 데이터 폴더 (데이터베이스, 첨부 파일, 로컬 백업 폴더가 있는 곳)
+    pub data_dir: &'a Path,
+    pub icloud: &'a dyn RemoteBackupStore,
+}
+
+impl BackupManager<'_> {
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Database>, String> {
+        self.db.lock().map_err(|e| e.to_string())
+    }
+
+    fn backup_dir(&self, service: &CloudService) -> Result<PathBuf, String> {
+        super::get_backup_dir_for_service(self.data_dir, service)
+    }
+
+    fn attachments_dir(&self) -> PathBuf {
+        self.data_dir.join(archive::ATTACHMENTS_DIR)
+    }
+
+    /// 설정된 외부 저장소
+    fn external_target(&self) -> Result<Box<dyn BackupTarget>, String> {
+        target::get_config(self.lock()?.conn())?.build()
+    }
+
+    pub fn list(&self, service: &CloudService) -> Result<Vec<BackupInfo>, String> {
+        match service {
+            CloudService::Icloud => icloud::list_backups(self.icloud),
+            CloudService::External => super::list_target_backups(self.external_target()?.as_ref()),
+            _ => {
+                let backup_dir = self.backup_dir(service)?;
+                let result = super::list_backups(&backup_dir, service);
+                match &result {
+                    Ok(backups) => println!("[Backup] Found {} backups", backups.len()),
+                    Err(e) => println!("[Backup] Error listing backups: {}", e),
+                }
+                result
+            }
+        }
+    }
+
+    /// `passphrase` 를 주면 비밀번호로 암호화한 아카이브(.ssbak)를, 없으면 기기 키로 암호화된
+    /// 데이터베이스 복사본(.db)을 만든다. iCloud 는 CloudKit 에, 외부 저장소는 설정한 곳에 올린다.
+    pub fn create(&self, service: &CloudService, passphrase: Option<&str>) -> Result<BackupInfo, String> {
+        let backup_dir = self.backup_dir(service)?;
+
+        // 외부 저장소 설정이 잘못됐으면 백업을 만들기 전에 알린다
+        let external = match service {
+            CloudService::External => Some(self.external_target()?),
+            _ => None,
+        };
+
+        // 열려 있는 연결에서 일관된 시점으로
+        let created = {
+            let db = self.lock()?;
+            match passphrase.filter(|p| !p.is_empty()) {
+                Some(passphrase) => {
+                    super::create_archive_backup(db.conn(), &self.attachments_dir(), &backup_dir, service, passphrase)
+                }
+                None => super::create_backup(db.conn(), db.key(), &backup_dir, service),
+            }
+        };
+        let backup_info = created.inspect_err(|e| println!("[Backup] Error creating backup: {}", e))?;
+        println!("[Backup] Backup created successfully: {}", backup_info.filename);
+
+        let backup_file_path = backup_dir.join(&backup_info.filename);
+        // 오래된 iCloud 백업은 icloud::MAX_BACKUPS 개만 남기고 정리된다
+        if *service == CloudService::Icloud {
+            if let Err(e) = icloud::upload_backup(self.icloud, &backup_file_path) {
+                println!("[Backup] CloudKit upload failed (local backup saved): {}", e);
+            }
+        }
+        if let Some(external) = external {
+            super::push_to_target(external.as_ref(), &backup_dir, &backup_info.filename)?;
+        }
+
+        Ok(backup_info)
+    }
+
+    /// 백업 파일 경로를 찾는다. iCloud 백업이 로컬에 없으면 CloudKit 에서 먼저 내려받고,
+    /// 외부 저장소 백업은 항상 임시 폴더로 내려받는다.
+    fn locate(&self, service: &CloudService, filename: &str) -> Result<PathBuf, String> {
+        let backup_file_path = self.backup_dir(service)?.join(filename);
+        match service {
+            CloudService::External => {
+                self.external_target()?.download(filename, &backup_file_path)?;
+                println!("[Backup] Downloaded from backup target");
+            }
+            CloudService::Icloud => icloud::download_if_missing(self.icloud, filename, &backup_file_path)?,
+            _ => {}
+        }
+
+        if !backup_file_path.exists() {
+            let err = format!("백업 파일을 찾을 수 없습니다: {:?}", backup_file_path);
+            println!("[Backup] Error: {}", err);
+            return Err(err);
+        }
+        Ok(backup_file_path)
+    }
+
+    /// 외부 저장소에서 내려받은 임시 사본을 지운다
+    fn discard_download(service: &CloudService, path: &Path) {
+        if *service == CloudService::External {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    /// 백업으로 복원한다. 열려 있는 연결을 바로 교체하고, 복원 직전 데이터는 로컬 백업 폴더에 남긴다.
+    /// .db 백업에 `passphrase` 를 주면 다른 기기에서 내보낸 복구 키로 연다.
+    pub fn restore(&self, service: &CloudService, filename: &str, passphrase: Option<&str>) -> Result<RestoreResult, String> {
+        let backup_file_path = self.locate(service, filename)?;
+        let snapshot_dir = self.backup_dir(&CloudService::Local)?;
+
+        // 복원하는 동안 다른 명령·백그라운드 작업이 데이터베이스를 쓰지 못하도록 잠근다
+        let mut db = self.lock()?;
+        let result = if archive::is_archive(filename) {
+            match passphrase.filter(|p| !p.is_empty()) {
+                Some(passphrase) => {
+                    super::restore_archive(&mut db, &backup_file_path, passphrase, &self.attachments_dir(), &snapshot_dir)
+                }
+                None => Err("이 백업을 복원하려면 백업 비밀번호가 필요합니다".to_string()),
+            }
+        } else {
+            // .db 백업에 준 값은 다른 기기에서 내보낸 복구 키
+            passphrase
+                .filter(|p| !p.is_empty())
+                .map(preview::parse_recovery_key)
+                .transpose()
+                .and_then(|recovery_key| {
+                    super::restore_backup(&mut db, &backup_file_path, &snapshot_dir, recovery_key.as_ref())
+                })
+        };
+        match &result {
+            Ok(restored) => println!("[Backup] Restore successful (snapshot: {})", restored.snapshot_filename),
+            Err(e) => println!("[Backup] Restore failed: {}", e),
+        }
+        Self::discard_download(service, &backup_file_path);
+        result
+    }
+
+    /// 백업을 읽기 전용으로 열어 내용을 요약한다
+    pub fn preview(&self, service: &CloudService, filename: &str, passphrase: Option<&str>) -> Result<BackupPreview, String> {
+        let backup_file_path = self.locate(service, filename)?;
+        let device_key = self.lock()?.key().cloned();
+        let result = BackupSource::open(&backup_file_path, device_key.as_ref(), passphrase)
+            .and_then(|source| preview::preview_backup(&source));
+        Self::discard_download(service, &backup_file_path);
+        result
+    }
+
+    /// 백업에서 고른 테이블(또는 기간의 예약)만 현재 데이터에 합친다
+    pub fn restore_selective(
+        &self,
+        service: &CloudService,
+        filename: &str,
+        passphrase: Option<&str>,
+        options: &SelectiveRestoreOptions,
+    ) -> Result<SelectiveRestoreReport, String> {
+        let backup_file_path = self.locate(service, filename)?;
+        let snapshot_dir = self.backup_dir(&CloudService::Local)?;
+
+        let result = {
+            let db = self.lock()?;
+            super::restore_selective(&db, &backup_file_path, passphrase, options, &snapshot_dir)
+        };
+        match &result {
+            Ok(report) => println!("[Backup] Selective restore finished with {} conflict(s)", report.conflicts.len()),
+            Err(e) => println!("[Backup] Selective restore failed: {}", e),
+        }
+        Self::discard_download(service, &backup_file_path);
+        result
+    }
+
+    pub fn delete(&self, service: &CloudService, filename: &str) -> Result<(), String> {
+        match service {
+            // iCloud 는 파일 이름이 레코드 id
+            CloudService::Icloud => self.icloud.delete(filename),
+            CloudService::External => self.external_target()?.delete(filename),
+            _ => super::delete_backup(filename, &self.backup_dir(service)?),
+        }
+    }
+
+    pub fn cleanup(&self, service: &CloudService, keep_count: usize) -> Result<(), String> {
+        super::cleanup_old_backups(&self.backup_dir(service)?, keep_count)
+    }
+
+    /// 백업 문제를 확인할 때 보는 경로 정보
+    pub fn debug_info(&self, service: &CloudService) -> Result<String, String> {
+        let backup_dir = self.backup_dir(service)?;
+        let db_path = self.data_dir.join(DB_FILENAME);
+        Ok(format!(
+            "app_data_dir: {:?}\nbackup_dir: {:?}\nbackup_dir_exists: {}\ndb_path: {:?}\ndb_exists: {}",
+            self.data_dir,
+            backup_dir,
+            backup_dir.exists(),
+            db_path,
+            db_path.exists()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::cloudkit::memory::MemoryStore;
+
+    #[test]
+    fn test_backup_round_trip_through_icloud_store() {
+        let dir = std::env::temp_dir().join(format!("salon-manager-{}", uuid::Uuid::new_v4()));
+        let db = Mutex::new(Database::open_in_memory().unwrap());
+        let store = MemoryStore::new();
+        let manager = BackupManager { db: &db, data_dir: &dir, icloud: &store };
+
+        db.lock().unwrap().conn().execute("INSERT INTO designers (id, name) VALUES ('d1', '수진')", []).unwrap();
+        let created = manager.create(&CloudService::Icloud, Some("correct horse")).unwrap();
+        assert!(created.passphrase_protected);
+        assert_eq!(store.filenames(), vec![created.filename.clone()]);
+
+        // 로컬 사본이 없어도 CloudKit 에서 내려받아 미리 본다
+        std::fs::remove_dir_all(&dir).unwrap();
+        let preview = manager.preview(&CloudService::Icloud, &created.filename, Some("correct horse")).unwrap();
+        assert!(preview.tables.iter().any(|t| t.table == "designers" && t.rows == 1));
+        assert!(manager.restore(&CloudService::Icloud, &created.filename, None).is_err());
+
+        manager.delete(&CloudService::Icloud, &created.filename).unwrap();
+        assert!(manager.list(&CloudService::Icloud).unwrap().is_empty());
+        assert!(manager.locate(&CloudService::Local, "missing.db").is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
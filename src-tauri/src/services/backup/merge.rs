@@ -0,0 +1,230 @@
+//! 선택 복원
+//!
+//! 백업 전체로 덮어쓰지 않고, 고른 테이블(기간을 주면 그 기간의 예약만)을 현재
+//! 데이터베이스에 합친다. 같은 id 가 이미 있으면 현재 값을 유지하거나(기본) 백업 값으로
+//! 덮어쓰고, 어느 쪽이든 충돌 목록에 남긴다.
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use super::preview::{table_columns, BackupSource};
+
+const BACKUP_SCHEMA: &str = "restore_source";
+
+struct MergeTable {
+    name: &'static str,
+    /// 기간을 지정했을 때 백업 쪽 행을 거르는 조건 (?1 = 시작일, ?2 = 종료일)
+    date_filter: Option<&'static str>,
+}
+
+/// 합칠 수 있는 테이블 (외래 키 순서대로)
+const MERGE_TABLES: &[MergeTable] = &[
+    MergeTable { name: "designers", date_filter: None },
+    MergeTable { name: "customers", date_filter: None },
+    MergeTable { name: "reservations", date_filter: Some("date BETWEEN ?1 AND ?2") },
+    MergeTable {
+        name: "reservation_status_history",
+        date_filter: Some("reservation_id IN (SELECT id FROM restore_source.reservations WHERE date BETWEEN ?1 AND ?2)"),
+    },
+    MergeTable { name: "time_blocks", date_filter: Some("date BETWEEN ?1 AND ?2") },
+    MergeTable { name: "holidays", date_filter: None },
+    MergeTable { name: "business_hour_overrides", date_filter: Some("date BETWEEN ?1 AND ?2") },
+    MergeTable { name: "closure_periods", date_filter: Some("end_date >= ?1 AND start_date <= ?2") },
+];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectiveRestoreOptions {
+    pub tables: Vec<String>,
+    /// 예약 날짜 기준 (YYYY-MM-DD)
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+    /// 같은 id 가 있을 때 백업 값으로 덮어쓸지 (기본: 현재 값 유지)
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableMergeResult {
+    pub table: String,
+    pub inserted: u32,
+    pub updated: u32,
+    pub unchanged: u32,
+    pub skipped: u32,
+    pub failed: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConflict {
+    pub table: String,
+    pub id: String,
+    /// kept (현재 값 유지) | overwritten (백업 값으로 덮어씀) | failed (제약 조건 위반 등)
+    pub resolution: String,
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectiveRestoreReport {
+    /// 선택 복원 직전 데이터를 담은 자동 백업
+    pub snapshot_filename: String,
+    pub tables: Vec<TableMergeResult>,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+pub fn validate_options(options: &SelectiveRestoreOptions) -> Result<(), String> {
+    if options.tables.is_empty() {
+        return Err("복원할 테이블을 선택해주세요".to_string());
+    }
+    for table in &options.tables {
+        if !MERGE_TABLES.iter().any(|t| t.name == table) {
+            return Err(format!("선택 복원할 수 없는 테이블입니다: {}", table));
+        }
+    }
+    for date in [&options.date_from, &options.date_to].into_iter().flatten() {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("잘못된 날짜입니다: {}", date))?;
+    }
+    Ok(())
+}
+
+/// 백업의 선택한 테이블을 현재 데이터베이스에 합친다. 하나의 트랜잭션으로 처리한다.
+pub fn merge_from_backup(
+    conn: &Connection,
+    source: &BackupSource,
+    options: &SelectiveRestoreOptions,
+) -> Result<(Vec<TableMergeResult>, Vec<MergeConflict>), String> {
+    validate_options(options)?;
+    source.attach(conn, BACKUP_SCHEMA)?;
+
+    let result = conn
+        .execute_batch("BEGIN")
+        .map_err(|e| e.to_string())
+        .and_then(|_| merge_tables(conn, options));
+    match &result {
+        Ok(_) => conn.execute_batch("COMMIT").map_err(|e| e.to_string())?,
+        Err(_) => {
+            let _ = conn.execute_batch("ROLLBACK");
+        }
+    }
+    let _ = conn.execute_batch(&format!("DETACH DATABASE {}", BACKUP_SCHEMA));
+    result
+}
+
+fn merge_tables(
+    conn: &Connection,
+    options: &SelectiveRestoreOptions,
+) -> Result<(Vec<TableMergeResult>, Vec<MergeConflict>), String> {
+    let ranged = options.date_from.is_some() || options.date_to.is_some();
+    let from = options.date_from.clone().unwrap_or_else(|| "0000-01-01".to_string());
+    let to = options.date_to.clone().unwrap_or_else(|| "9999-12-31".to_string());
+
+    let mut results = Vec::new();
+    let mut conflicts = Vec::new();
+    for table in MERGE_TABLES.iter().filter(|t| options.tables.iter().any(|name| name == t.name)) {
+        let filter = table.date_filter.filter(|_| ranged);
+        results.push(merge_table(conn, table.name, filter, (&from, &to), options.overwrite, &mut conflicts)?);
+    }
+    Ok((results, conflicts))
+}
+
+fn merge_table(
+    conn: &Connection,
+    table: &str,
+    filter: Option<&str>,
+    (from, to): (&str, &str),
+    overwrite: bool,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Result<TableMergeResult, String> {
+    let mut result = TableMergeResult { table: table.to_string(), ..Default::default() };
+
+    // 백업이 이전 스키마여도 양쪽에 모두 있는 컬럼만 옮긴다
+    let current = table_columns(conn, "main", table)?;
+    let columns: Vec<String> = table_columns(conn, BACKUP_SCHEMA, table)?
+        .into_iter()
+        .filter(|c| current.contains(c))
+        .collect();
+    if !columns.iter().any(|c| c == "id") {
+        return Ok(result);
+    }
+    let column_list = columns.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", ");
+    let value_columns = columns
+        .iter()
+        .filter(|c| *c != "id")
+        .map(|c| format!("\"{}\"", c))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let ids: Vec<String> = match filter {
+        Some(filter) => conn
+            .prepare(&format!("SELECT id FROM {}.\"{}\" WHERE {} ORDER BY id", BACKUP_SCHEMA, table, filter))
+            .and_then(|mut stmt| stmt.query_map([from, to], |row| row.get(0))?.collect()),
+        None => conn
+            .prepare(&format!("SELECT id FROM {}.\"{}\" ORDER BY id", BACKUP_SCHEMA, table))
+            .and_then(|mut stmt| stmt.query_map([], |row| row.get(0))?.collect()),
+    }
+    .map_err(|e| e.to_string())?;
+
+    let exists_sql = format!("SELECT EXISTS(SELECT 1 FROM main.\"{}\" WHERE id = ?1)", table);
+    let same_sql = format!(
+        "SELECT EXISTS(SELECT {cols} FROM main.\"{t}\" WHERE id = ?1 INTERSECT SELECT {cols} FROM {s}.\"{t}\" WHERE id = ?1)",
+        cols = column_list,
+        t = table,
+        s = BACKUP_SCHEMA
+    );
+    let insert_sql = format!(
+        "INSERT INTO main.\"{t}\" ({cols}) SELECT {cols} FROM {s}.\"{t}\" WHERE id = ?1",
+        cols = column_list,
+        t = table,
+        s = BACKUP_SCHEMA
+    );
+    let update_sql = format!(
+        "UPDATE main.\"{t}\" SET ({vals}) = (SELECT {vals} FROM {s}.\"{t}\" WHERE id = ?1) WHERE id = ?1",
+        vals = value_columns,
+        t = table,
+        s = BACKUP_SCHEMA
+    );
+
+    let conflict = |id: &str, resolution: &str, detail: Option<String>| MergeConflict {
+        table: table.to_string(),
+        id: id.to_string(),
+        resolution: resolution.to_string(),
+        detail,
+    };
+
+    for id in ids {
+        let exists: bool = conn.query_row(&exists_sql, [&id], |row| row.get(0)).map_err(|e| e.to_string())?;
+        if !exists {
+            match conn.execute(&insert_sql, [&id]) {
+                Ok(_) => result.inserted += 1,
+                Err(e) => {
+                    result.failed += 1;
+                    conflicts.push(conflict(&id, "failed", Some(e.to_string())));
+                }
+            }
+            continue;
+        }
+
+        let same: bool = conn.query_row(&same_sql, [&id], |row| row.get(0)).map_err(|e| e.to_string())?;
+        if same {
+            result.unchanged += 1;
+        } else if !overwrite || value_columns.is_empty() {
+            result.skipped += 1;
+            conflicts.push(conflict(&id, "kept", None));
+        } else {
+            match conn.execute(&update_sql, [&id]) {
+                Ok(_) => {
+                    result.updated += 1;
+                    conflicts.push(conflict(&id, "overwritten", None));
+                }
+                Err(e) => {
+                    result.failed += 1;
+                    conflicts.push(conflict(&id, "failed", Some(e.to_string())));
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,216 @@
+//! 기기 이전 번들
+//!
+//! 데이터베이스(앱 설정 포함), 첨부 파일, 화면 설정을 비밀번호 아카이브(`.ssbak`) 하나로 묶어
+//! 원하는 위치에 저장하고, 새 기기에서는 어느 경로의 파일이든 가져온다.
+//! 잠금 PIN 처럼 기기 키체인에 있는 비밀 정보는 옮기지 않으며, 가져온 뒤 다시 설정하도록 안내한다.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::archive::{self, BackupManifest, KdfParams};
+use super::{restore_contents, RestoreResult};
+use crate::db::Database;
+use crate::services::{auth, sync};
+
+pub const BUNDLE_PREFIX: &str = "sisters_salon_migration_";
+/// 프론트엔드(localStorage)에 있는 화면 설정
+const CLIENT_SETTINGS_ENTRY: &str = "client_settings.json";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationExport {
+    pub path: String,
+    pub manifest: BackupManifest,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationImportResult {
+    #[serde(flatten)]
+    pub restore: RestoreResult,
+    /// 번들에 담긴 화면 설정. 프론트엔드가 그대로 되돌려 놓는다
+    pub client_settings: Option<serde_json::Value>,
+    /// 이전 기기에서 잠금을 쓰고 있었으면 true. PIN 은 옮기지 않으므로 다시 설정해야 한다
+    pub pin_reset_required: bool,
+}
+
+/// `dest` 가 폴더면 그 안에 날짜가 붙은 이름으로, 파일 경로면 그 이름으로 저장할 경로
+pub fn bundle_path(dest: &Path) -> PathBuf {
+    if dest.is_dir() {
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        dest.join(format!("{}{}.{}", BUNDLE_PREFIX, timestamp, archive::ARCHIVE_EXTENSION))
+    } else if archive::is_archive(&dest.to_string_lossy()) {
+        dest.to_path_buf()
+    } else {
+        dest.with_extension(archive::ARCHIVE_EXTENSION)
+    }
+}
+
+pub fn export_bundle(
+    conn: &rusqlite::Connection,
+    attachments_dir: &Path,
+    dest: &Path,
+    passphrase: &str,
+    client_settings: Option<&serde_json::Value>,
+) -> Result<BackupManifest, String> {
+    export_bundle_with(conn, attachments_dir, dest, passphrase, client_settings, KdfParams::default())
+}
+
+fn export_bundle_with(
+    conn: &rusqlite::Connection,
+    attachments_dir: &Path,
+    dest: &Path,
+    passphrase: &str,
+    client_settings: Option<&serde_json::Value>,
+    params: KdfParams,
+) -> Result<BackupManifest, String> {
+    let mut extras = Vec::new();
+    if let Some(settings) = client_settings {
+        extras.push((CLIENT_SETTINGS_ENTRY.to_string(), serde_json::to_vec(settings).map_err(|e| e.to_string())?));
+    }
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| format!("저장할 폴더를 만들 수 없습니다: {}", e))?;
+    }
+    archive::write_archive_entries(conn, attachments_dir, extras, dest, passphrase, params)
+}
+
+/// 번들을 열어 검증하고 이 앱에서 가져올 수 있는지 확인한다. 가져오기 전에 내용을 보여줄 때 쓴다.
+pub fn inspect_bundle(path: &Path, passphrase: &str, conn: &rusqlite::Connection) -> Result<BackupManifest, String> {
+    let contents = archive::read_archive(path, passphrase)?;
+    check_version(&contents.manifest, current_schema_version(conn))?;
+    Ok(contents.manifest)
+}
+
+/// 번들로 현재 데이터를 교체한다. 교체 직전 데이터는 `snapshot_dir` 에 자동 백업된다.
+pub fn import_bundle(
+    db: &mut Database,
+    path: &Path,
+    passphrase: &str,
+    attachments_dir: &Path,
+    snapshot_dir: &Path,
+) -> Result<MigrationImportResult, String> {
+    if !path.is_file() {
+        return Err(format!("파일을 찾을 수 없습니다: {}", path.display()));
+    }
+    let mut contents = archive::read_archive(path, passphrase)?;
+    check_version(&contents.manifest, current_schema_version(db.conn()))?;
+
+    let client_settings = match contents.extras.iter().position(|(name, _)| name == CLIENT_SETTINGS_ENTRY) {
+        Some(index) => {
+            let (_, data) = contents.extras.swap_remove(index);
+            Some(serde_json::from_slice(&data).map_err(|e| format!("화면 설정을 읽을 수 없습니다: {}", e))?)
+        }
+        None => None,
+    };
+
+    let restore = restore_contents(db, contents, attachments_dir, snapshot_dir)?;
+    let pin_reset_required = reset_lock(db.conn())?;
+    // 이전 기기와 같은 동기화 id 를 쓰지 않게 한다
+    sync::changes::reset_node(db.conn())?;
+    Ok(MigrationImportResult { restore, client_settings, pin_reset_required })
+}
+
+/// 번들이 이 앱보다 새 버전에서 만들어졌으면 가져오지 않는다
+pub fn check_version(manifest: &BackupManifest, current_schema: i64) -> Result<(), String> {
+    if manifest.schema_version > current_schema {
+        return Err(format!(
+            "더 새로운 데이터 형식(v{})의 파일입니다. 앱을 업데이트한 뒤 가져와주세요",
+            manifest.schema_version
+        ));
+    }
+    let current_app = env!("CARGO_PKG_VERSION");
+    match (parse_version(&manifest.app_version), parse_version(current_app)) {
+        (Some(bundle), Some(current)) if bundle > current => Err(format!(
+            "더 새로운 버전({})의 앱에서 만든 파일입니다. 앱을 {} 이상으로 업데이트해주세요",
+            manifest.app_version, manifest.app_version
+        )),
+        (None, _) => Err(format!("앱 버전을 알 수 없는 파일입니다: {}", manifest.app_version)),
+        _ => Ok(()),
+    }
+}
+
+fn current_schema_version(conn: &rusqlite::Connection) -> i64 {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap_or(0)
+}
+
+/// `1.2.3` → (1, 2, 3). 뒤에 붙은 `-beta` 등은 무시한다
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let core = version.trim().split(['-', '+']).next()?;
+    let mut parts = core.split('.').map(|part| part.parse::<u32>().ok());
+    Some((parts.next()??, parts.next().flatten().unwrap_or(0), parts.next().flatten().unwrap_or(0)))
+}
+
+/// 가져온 잠금 설정을 끈다. PIN 해시는 이전 기기의 것이므로 지운다.
+fn reset_lock(conn: &rusqlite::Connection) -> Result<bool, String> {
+    let mut settings = auth::get_settings(conn)?;
+    let was_enabled = settings.is_enabled;
+    if was_enabled || settings.pin_hash.is_some() {
+        settings.is_enabled = false;
+        settings.use_biometric = false;
+        settings.pin_hash = None;
+        auth::save_settings(conn, &settings)?;
+    }
+    Ok(was_enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::encryption::DatabaseKey;
+
+    #[test]
+    fn test_bundle_round_trip_resets_lock() {
+        let dir = std::env::temp_dir().join(format!("salon-migration-{}", uuid::Uuid::new_v4()));
+        let old_attachments = dir.join("old").join(archive::ATTACHMENTS_DIR);
+        std::fs::create_dir_all(&old_attachments).unwrap();
+        std::fs::write(old_attachments.join("photo.jpg"), b"jpeg").unwrap();
+
+        let old = Database::open_in_memory().unwrap();
+        old.conn().execute("INSERT INTO customers (id, name) VALUES ('c1', '김민지')", []).unwrap();
+        let lock = auth::LockSettings { is_enabled: true, pin_hash: Some("$2b$hash".to_string()), ..Default::default() };
+        auth::save_settings(old.conn(), &lock).unwrap();
+
+        let client_settings = serde_json::json!({ "displaySettings": { "theme": "dark" } });
+        let bundle = bundle_path(&dir);
+        assert!(bundle.file_name().unwrap().to_string_lossy().starts_with(BUNDLE_PREFIX));
+        export_bundle_with(old.conn(), &old_attachments, &bundle, "passphrase!", Some(&client_settings), archive::TEST_KDF)
+            .unwrap();
+
+        std::fs::create_dir_all(dir.join("new")).unwrap();
+        let mut new = Database::open_encrypted(&dir.join("new").join("database.db"), &DatabaseKey::generate().unwrap()).unwrap();
+        let new_attachments = dir.join("new").join(archive::ATTACHMENTS_DIR);
+        assert!(inspect_bundle(&bundle, "wrong pass", new.conn()).is_err());
+        assert_eq!(inspect_bundle(&bundle, "passphrase!", new.conn()).unwrap().counts.customers, 1);
+
+        let result = import_bundle(&mut new, &bundle, "passphrase!", &new_attachments, &dir.join("snapshots")).unwrap();
+        assert!(result.pin_reset_required);
+        assert_eq!(result.client_settings, Some(client_settings));
+        assert_eq!(std::fs::read(new_attachments.join("photo.jpg")).unwrap(), b"jpeg");
+
+        let name: String = new.conn().query_row("SELECT name FROM customers WHERE id = 'c1'", [], |row| row.get(0)).unwrap();
+        assert_eq!(name, "김민지");
+        let lock = auth::get_settings(new.conn()).unwrap();
+        assert!(!lock.is_enabled);
+        assert_eq!(lock.pin_hash, None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_version_check() {
+        let manifest = |app_version: &str, schema_version: i64| BackupManifest {
+            format_version: 1,
+            app_version: app_version.to_string(),
+            created_at: String::new(),
+            schema_version,
+            counts: Default::default(),
+            files: Vec::new(),
+        };
+        assert!(check_version(&manifest("0.0.1", 0), 0).is_ok());
+        assert!(check_version(&manifest(env!("CARGO_PKG_VERSION"), 0), 0).is_ok());
+        assert!(check_version(&manifest("999.0.0", 0), 0).is_err());
+        assert!(check_version(&manifest("0.0.1", 1), 0).is_err());
+        assert!(check_version(&manifest("unknown", 0), 0).is_err());
+        assert_eq!(parse_version("1.2.3-beta.1"), Some((1, 2, 3)));
+    }
+}
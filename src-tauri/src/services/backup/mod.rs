@@ -0,0 +1,799 @@
+pub mod archive;
+pub mod auto;
+pub mod icloud;
+pub mod manager;
+pub mod merge;
+pub mod migration;
+pub mod preview;
+pub mod target;
+
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::db::encryption::{self, DatabaseKey};
+use crate::db::Database;
+use crate::services::sync;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CloudService {
+    Icloud,
+    GoogleDrive,
+    Local,
+    /// 사용자가 설정한 외부 저장소 (폴더, WebDAV, S3 — `target::BackupTargetConfig`)
+    External,
+}
+
+impl CloudService {
+    /// 프런트엔드·CLI 에서 넘어온 서비스 이름
+    pub fn parse(service: &str) -> Result<Self, String> {
+        match service.to_lowercase().as_str() {
+            "icloud" => Ok(CloudService::Icloud),
+            "google_drive" | "googledrive" => Ok(CloudService::GoogleDrive),
+            "local" => Ok(CloudService::Local),
+            "external" => Ok(CloudService::External),
+            _ => Err(format!("Invalid cloud service: {}", service)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub id: String,
+    pub service: CloudService,
+    pub filename: String,
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+    /// 비밀번호로 암호화한 아카이브(.ssbak)인지. 복원할 때 비밀번호가 필요하다
+    pub passphrase_protected: bool,
+}
+
+/// Get the backup directory based on the cloud service
+pub fn get_backup_dir_for_service(app_data_dir: &Path, service: &CloudService) -> Result<PathBuf, String> {
+    println!("[BackupService] get_backup_dir_for_service called with service: {:?}", service);
+    println!("[BackupService] app_data_dir: {:?}", app_data_dir);
+
+    let backup_dir = match service {
+        CloudService::Icloud => {
+            #[cfg(target_os = "ios")]
+            {
+                // On iOS, use the app's Documents directory which is visible in Files app
+                // when UIFileSharingEnabled is true (set in Info.plist)
+                // Users can access via: Files app > On My iPhone > Sisters Salon
+                let home = std::env::var("HOME").map_err(|e| {
+                    println!("[BackupService] Failed to get HOME: {}", e);
+                    "Could not get HOME directory"
+                })?;
+                println!("[BackupService] HOME: {}", home);
+                let documents_path = PathBuf::from(home)
+                    .join("Documents")
+                    .join("Backups");
+                println!("[BackupService] iOS backup_dir: {:?}", documents_path);
+                documents_path
+            }
+            #[cfg(target_os = "macos")]
+            {
+                // On macOS, use iCloud Drive path: ~/Library/Mobile Documents/com~apple~CloudDocs/SistersSalon/
+                let home = std::env::var("HOME").map_err(|_| "Could not get HOME directory")?;
+                let icloud_path = PathBuf::from(home)
+                    .join("Library")
+                    .join("Mobile Documents")
+                    .join("com~apple~CloudDocs")
+                    .join("SistersSalon")
+                    .join("backups");
+                println!("[BackupService] macOS backup_dir: {:?}", icloud_path);
+                icloud_path
+            }
+            #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+            {
+                // Fallback to local on non-Apple platforms
+                let path = app_data_dir.join("backups");
+                println!("[BackupService] Fallback backup_dir: {:?}", path);
+                path
+            }
+        }
+        CloudService::GoogleDrive => {
+            // Google Drive is not implemented yet
+            return Err("Google Drive 백업은 아직 지원되지 않습니다. 추후 업데이트를 기다려주세요.".to_string());
+        }
+        CloudService::Local => {
+            let path = app_data_dir.join("backups");
+            println!("[BackupService] Local backup_dir: {:?}", path);
+            path
+        }
+        CloudService::External => {
+            // 외부 저장소로 올리기 전·내려받은 뒤 잠시 두는 폴더
+            let path = app_data_dir.join("backups").join("external");
+            println!("[BackupService] External staging dir: {:?}", path);
+            path
+        }
+    };
+
+    println!("[BackupService] Creating backup directory: {:?}", backup_dir);
+    std::fs::create_dir_all(&backup_dir).map_err(|e| {
+        let err = format!("백업 디렉토리 생성 실패: {} (path: {:?})", e, backup_dir);
+        println!("[BackupService] Error: {}", err);
+        err
+    })?;
+    println!("[BackupService] Backup directory created/exists: {}", backup_dir.exists());
+
+    Ok(backup_dir)
+}
+
+/// Legacy function for backward compatibility (defaults to local)
+#[allow(dead_code)]
+pub fn get_backup_dir(app_data_dir: &Path) -> Result<PathBuf, String> {
+    get_backup_dir_for_service(app_data_dir, &CloudService::Local)
+}
+
+/// 열려 있는 데이터베이스 연결에서 SQLite 온라인 백업 API 로 백업을 만든다.
+///
+/// WAL 에만 있는 최근 변경분까지 일관된 시점으로 복사되며, 결과 파일은 무결성 검사를
+/// 통과해야 백업 목록에 나타난다. 데이터베이스와 같은 키로 암호화된다.
+pub fn create_backup(
+    conn: &Connection,
+    key: Option<&DatabaseKey>,
+    backup_dir: &Path,
+    service: &CloudService,
+) -> Result<BackupInfo, String> {
+    println!("[BackupService] create_backup called");
+    let filename = format!("salon_backup_{}.db", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+    create_named_backup(conn, key, backup_dir, service, filename)
+}
+
+/// `create_backup` 과 같지만 파일 이름을 정해서 만든다 (자동 백업 등)
+pub(crate) fn create_named_backup(
+    conn: &Connection,
+    key: Option<&DatabaseKey>,
+    backup_dir: &Path,
+    service: &CloudService,
+    filename: String,
+) -> Result<BackupInfo, String> {
+    println!("[BackupService] backup_dir: {:?}", backup_dir);
+
+    let backup_id = uuid::Uuid::new_v4().to_string();
+    let dest_path = backup_dir.join(&filename);
+
+    println!("[BackupService] dest_path: {:?}", dest_path);
+
+    write_backup_file(conn, key, &dest_path).inspect_err(|e| println!("[BackupService] Error: {}", e))?;
+
+    let metadata = std::fs::metadata(&dest_path).map_err(|e| {
+        let err = format!("Failed to get metadata: {}", e);
+        println!("[BackupService] Error: {}", err);
+        err
+    })?;
+
+    println!("[BackupService] Backup created: {} ({} bytes)", filename, metadata.len());
+
+    Ok(BackupInfo {
+        id: backup_id,
+        service: service.clone(),
+        filename,
+        size: metadata.len(),
+        created_at: Utc::now(),
+        passphrase_protected: false,
+    })
+}
+
+/// 검사를 통과하기 전에는 .partial 로 두어 백업 목록에 나타나지 않게 한다
+fn write_backup_file(conn: &Connection, key: Option<&DatabaseKey>, dest_path: &Path) -> Result<(), String> {
+    let partial_path = dest_path.with_extension("db.partial");
+    if let Err(e) = backup_to_file(conn, key, &partial_path) {
+        let _ = std::fs::remove_file(&partial_path);
+        return Err(e);
+    }
+    std::fs::rename(&partial_path, dest_path).map_err(|e| format!("백업 파일 저장 실패: {}", e))
+}
+
+/// `conn` 의 main 데이터베이스를 `dest` 로 온라인 백업하고 무결성을 확인한다.
+pub fn backup_to_file(conn: &Connection, key: Option<&DatabaseKey>, dest: &Path) -> Result<(), String> {
+    let _ = std::fs::remove_file(dest);
+    let mut target = Connection::open(dest).map_err(|e| format!("백업 파일을 만들 수 없습니다: {}", e))?;
+    if let Some(key) = key {
+        encryption::apply_key(&target, key).map_err(|e| e.to_string())?;
+    }
+
+    {
+        let backup = rusqlite::backup::Backup::new(conn, &mut target).map_err(|e| format!("백업 시작 실패: {}", e))?;
+        backup
+            .run_to_completion(256, std::time::Duration::from_millis(5), None)
+            .map_err(|e| format!("백업 실패: {}", e))?;
+    }
+
+    check_integrity(&target)
+}
+
+/// 비밀번호로 암호화한 아카이브(.ssbak) 백업을 만든다.
+///
+/// 데이터베이스는 열려 있는 연결에서 내보내고, 앱 데이터 폴더의 첨부 파일도 함께 담는다.
+pub fn create_archive_backup(
+    conn: &Connection,
+    attachments_dir: &Path,
+    backup_dir: &Path,
+    service: &CloudService,
+    passphrase: &str,
+) -> Result<BackupInfo, String> {
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let filename = format!("salon_backup_{}.{}", timestamp, archive::ARCHIVE_EXTENSION);
+    let dest_path = backup_dir.join(&filename);
+
+    let manifest = archive::write_archive(conn, attachments_dir, &dest_path, passphrase)?;
+    let metadata = std::fs::metadata(&dest_path).map_err(|e| e.to_string())?;
+    println!(
+        "[BackupService] Archive created: {} ({} bytes, {} files)",
+        filename,
+        metadata.len(),
+        manifest.files.len()
+    );
+
+    Ok(BackupInfo {
+        id: uuid::Uuid::new_v4().to_string(),
+        service: service.clone(),
+        filename,
+        size: metadata.len(),
+        created_at: Utc::now(),
+        passphrase_protected: true,
+    })
+}
+
+pub fn check_integrity(conn: &Connection) -> Result<(), String> {
+    let result: String = conn
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(|e| format!("무결성 검사 실패: {}", e))?;
+    if result != "ok" {
+        return Err(format!("데이터베이스 무결성 검사 실패: {}", result));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreResult {
+    /// 복원 직전 데이터를 담은 자동 백업 (로컬 백업 폴더)
+    pub snapshot_filename: String,
+    /// 아카이브에서 복원한 경우 그 manifest
+    pub manifest: Option<archive::BackupManifest>,
+}
+
+/// 데이터베이스 백업(.db)을 복원한다.
+///
+/// 백업을 먼저 검증해 데이터베이스 옆에 준비한 뒤, 복원 직전 상태를 `snapshot_dir` 에
+/// 자동 백업하고 열려 있는 연결을 교체한다. 앱을 다시 시작할 필요가 없다.
+/// 암호화 이전 버전에서 만든 백업은 이 기기의 키로 암호화해서 복원한다.
+/// 다른 기기에서 만든 백업은 그 기기에서 내보낸 `recovery_key` 로 열어 이 기기의 키로 다시 암호화한다.
+pub fn restore_backup(
+    db: &mut Database,
+    backup_path: &Path,
+    snapshot_dir: &Path,
+    recovery_key: Option<&DatabaseKey>,
+) -> Result<RestoreResult, String> {
+    println!("[BackupService] restore_backup called: {:?}", backup_path);
+
+    if !backup_path.exists() {
+        let err = format!("Backup file not found: {:?}", backup_path);
+        println!("[BackupService] Error: {}", err);
+        return Err(err);
+    }
+
+    let (key, staged) = restore_target(db)?;
+    if let Err(e) = stage_backup_file(backup_path, &staged, &key, recovery_key) {
+        let _ = std::fs::remove_file(&staged);
+        return Err(e);
+    }
+    swap_in(db, &staged, snapshot_dir)
+}
+
+/// 비밀번호 아카이브(.ssbak)를 복원한다.
+///
+/// 복호화(인증 태그 확인), manifest 해시 대조, 데이터베이스 무결성 검사, 이 기기 키로의
+/// 재암호화까지 모두 통과한 뒤에만 현재 데이터베이스와 첨부 폴더를 바꾼다.
+pub fn restore_archive(
+    db: &mut Database,
+    archive_path: &Path,
+    passphrase: &str,
+    attachments_dir: &Path,
+    snapshot_dir: &Path,
+) -> Result<RestoreResult, String> {
+    let contents = archive::read_archive(archive_path, passphrase)?;
+    restore_contents(db, contents, attachments_dir, snapshot_dir)
+}
+
+/// 검증을 마친 아카이브 내용으로 데이터베이스와 첨부 폴더를 교체한다.
+pub(crate) fn restore_contents(
+    db: &mut Database,
+    contents: archive::ArchiveContents,
+    attachments_dir: &Path,
+    snapshot_dir: &Path,
+) -> Result<RestoreResult, String> {
+    let (key, staged) = restore_target(db)?;
+
+    // 평문 데이터베이스는 메모리에서만 열고 바로 기기 키로 암호화해 준비한다
+    let prepared = encryption::open_image(&contents.database).and_then(|conn| {
+        check_integrity(&conn)?;
+        encryption::export_connection_encrypted(&conn, &staged, &key)
+    });
+    if let Err(e) = prepared {
+        let _ = std::fs::remove_file(&staged);
+        return Err(e);
+    }
+
+    let result = swap_in(db, &staged, snapshot_dir)?;
+    replace_attachments(attachments_dir, &contents.attachments)?;
+    Ok(RestoreResult { manifest: Some(contents.manifest), ..result })
+}
+
+/// (데이터베이스 키, 교체할 파일을 준비할 경로)
+fn restore_target(db: &Database) -> Result<(DatabaseKey, PathBuf), String> {
+    let key = db.key().cloned().ok_or("암호화된 데이터베이스에만 복원할 수 있습니다")?;
+    let db_path = db.path().ok_or("메모리 데이터베이스에는 복원할 수 없습니다")?;
+    Ok((key, db_path.with_extension("db.restoring")))
+}
+
+/// 백업 파일을 검증하고 이 기기 키로 암호화된 사본을 `staged` 에 만든다.
+fn stage_backup_file(
+    backup_path: &Path,
+    staged: &Path,
+    key: &DatabaseKey,
+    recovery_key: Option<&DatabaseKey>,
+) -> Result<(), String> {
+    if encryption::is_plaintext(backup_path) {
+        println!("[BackupService] Encrypting legacy plaintext backup...");
+        check_integrity(&Connection::open(backup_path).map_err(|e| e.to_string())?)?;
+        return encryption::export_encrypted(backup_path, staged, key);
+    }
+
+    let conn = Connection::open(backup_path).map_err(|e| e.to_string())?;
+    encryption::apply_key(&conn, recovery_key.unwrap_or(key)).map_err(|e| e.to_string())?;
+    encryption::verify_key(&conn).map_err(|_| match recovery_key {
+        Some(_) => "복구 키로 열 수 없는 백업입니다".to_string(),
+        None => "이 기기의 암호화 키로 열 수 없는 백업입니다. 백업을 만든 기기의 복구 키를 입력하세요".to_string(),
+    })?;
+    match recovery_key {
+        // 키가 다르면 온라인 백업으로는 옮길 수 없어 이 기기 키로 다시 내보낸다
+        Some(recovery_key) if recovery_key != key => {
+            check_integrity(&conn)?;
+            encryption::export_connection_encrypted(&conn, staged, key)
+        }
+        _ => backup_to_file(&conn, Some(key), staged),
+    }
+}
+
+/// 백업의 일부 테이블만 현재 데이터베이스에 합친다 (선택 복원).
+///
+/// 합치기 전에 현재 상태를 `snapshot_dir` 에 자동 백업한다.
+pub fn restore_selective(
+    db: &Database,
+    backup_path: &Path,
+    passphrase: Option<&str>,
+    options: &merge::SelectiveRestoreOptions,
+    snapshot_dir: &Path,
+) -> Result<merge::SelectiveRestoreReport, String> {
+    merge::validate_options(options)?;
+    let source = preview::BackupSource::open(backup_path, db.key(), passphrase)?;
+    source.connect_read_only()?;
+
+    let snapshot_filename = take_snapshot(db, snapshot_dir)?;
+    let (tables, conflicts) = merge::merge_from_backup(db.conn(), &source, options)?;
+    Ok(merge::SelectiveRestoreReport { snapshot_filename, tables, conflicts })
+}
+
+/// 복원 직전 상태를 로컬 백업 폴더에 남긴다
+fn take_snapshot(db: &Database, snapshot_dir: &Path) -> Result<String, String> {
+    let snapshot_filename = format!("salon_backup_{}_before_restore.db", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+    std::fs::create_dir_all(snapshot_dir)
+        .map_err(|e| e.to_string())
+        .and_then(|_| write_backup_file(db.conn(), db.key(), &snapshot_dir.join(&snapshot_filename)))
+        .map_err(|e| format!("복원 전 자동 백업 실패: {}", e))?;
+    println!("[BackupService] Pre-restore snapshot: {}", snapshot_filename);
+    Ok(snapshot_filename)
+}
+
+/// 복원 직전 상태를 자동 백업한 뒤 준비된 파일로 연결을 교체한다.
+fn swap_in(db: &mut Database, staged: &Path, snapshot_dir: &Path) -> Result<RestoreResult, String> {
+    let node_before = sync::changes::node_id(db.conn());
+    let snapshot_filename = match take_snapshot(db, snapshot_dir) {
+        Ok(filename) => filename,
+        Err(e) => {
+            let _ = std::fs::remove_file(staged);
+            return Err(e);
+        }
+    };
+
+    let result = db.replace_file(staged);
+    let _ = std::fs::remove_file(staged);
+    result?;
+
+    // 다른 기기의 백업이면 그 기기의 동기화 id·시계·LAN 키와 페어링까지 함께 들어오므로,
+    // 두 기기가 같은 id 로 서로의 변경을 덮어쓰지 않도록 이 기기의 id 를 새로 만든다
+    if node_before.ok() != Some(sync::changes::node_id(db.conn())?) {
+        sync::changes::reset_node(db.conn())?;
+    }
+
+    println!("[BackupService] Restore successful");
+    Ok(RestoreResult { snapshot_filename, manifest: None })
+}
+
+/// 첨부 폴더를 아카이브의 내용으로 바꾼다. 새 폴더를 다 쓴 뒤에 기존 폴더와 교체한다.
+fn replace_attachments(attachments_dir: &Path, files: &[(String, Vec<u8>)]) -> Result<(), String> {
+    if files.is_empty() && !attachments_dir.exists() {
+        return Ok(());
+    }
+    let staging = attachments_dir.with_extension("restoring");
+    let old = attachments_dir.with_extension("old");
+    let _ = std::fs::remove_dir_all(&staging);
+    for (relative, data) in files {
+        let path = staging.join(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| format!("첨부 폴더 복원 실패: {}", e))?;
+        }
+        std::fs::write(&path, data).map_err(|e| format!("첨부 파일 복원 실패: {}", e))?;
+    }
+    std::fs::create_dir_all(&staging).map_err(|e| format!("첨부 폴더 복원 실패: {}", e))?;
+
+    let _ = std::fs::remove_dir_all(&old);
+    if attachments_dir.exists() {
+        std::fs::rename(attachments_dir, &old).map_err(|e| format!("첨부 폴더 교체 실패: {}", e))?;
+    }
+    std::fs::rename(&staging, attachments_dir).map_err(|e| format!("첨부 폴더 교체 실패: {}", e))?;
+    let _ = std::fs::remove_dir_all(&old);
+    Ok(())
+}
+
+pub fn list_backups(backup_dir: &Path, service: &CloudService) -> Result<Vec<BackupInfo>, String> {
+    println!("[BackupService] list_backups called");
+    println!("[BackupService] backup_dir: {:?}", backup_dir);
+    println!("[BackupService] backup_dir exists: {}", backup_dir.exists());
+
+    if !backup_dir.exists() {
+        println!("[BackupService] Backup directory does not exist, returning empty list");
+        return Ok(vec![]);
+    }
+
+    let entries = std::fs::read_dir(backup_dir).map_err(|e| {
+        let err = format!("Failed to read backup directory: {}", e);
+        println!("[BackupService] Error: {}", err);
+        err
+    })?;
+
+    let mut backups = Vec::new();
+
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        println!("[BackupService] Found file: {:?}", path);
+
+        if path.extension().map(|e| e == "db" || e == archive::ARCHIVE_EXTENSION).unwrap_or(false) {
+            let metadata = std::fs::metadata(&path).map_err(|e| e.to_string())?;
+            let filename = path.file_name().unwrap().to_string_lossy().to_string();
+
+            // 파일명에서 ID 생성 (파일명 기반)
+            let id = filename.replace("salon_backup_", "").replace(".db", "");
+
+            println!("[BackupService] Adding backup: {} ({} bytes)", filename, metadata.len());
+
+            backups.push(BackupInfo {
+                id,
+                service: service.clone(),
+                filename: filename.clone(),
+                size: metadata.len(),
+                created_at: metadata
+                    .modified()
+                    .map(DateTime::<Utc>::from)
+                    .unwrap_or_else(|_| Utc::now()),
+                passphrase_protected: archive::is_archive(&filename),
+            });
+        }
+    }
+
+    println!("[BackupService] Found {} backups", backups.len());
+
+    // 최신순 정렬
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.created_at));
+    Ok(backups)
+}
+
+pub fn delete_backup(backup_filename: &str, backup_dir: &Path) -> Result<(), String> {
+    let backup_path = backup_dir.join(backup_filename);
+
+    if !backup_path.exists() {
+        return Err("Backup file not found".to_string());
+    }
+
+    std::fs::remove_file(&backup_path).map_err(|e| format!("Failed to delete backup: {}", e))?;
+    Ok(())
+}
+
+/// 외부 저장소의 백업 목록 (최신순)
+pub fn list_target_backups(target: &dyn target::BackupTarget) -> Result<Vec<BackupInfo>, String> {
+    let mut backups: Vec<BackupInfo> = target
+        .list()?
+        .into_iter()
+        .map(|remote| BackupInfo {
+            id: remote.filename.replace("salon_backup_", "").replace(".db", ""),
+            service: CloudService::External,
+            passphrase_protected: archive::is_archive(&remote.filename),
+            filename: remote.filename,
+            size: remote.size,
+            created_at: remote.modified.unwrap_or_else(Utc::now),
+        })
+        .collect();
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.created_at));
+    Ok(backups)
+}
+
+/// `staging_dir` 에 만든 백업을 외부 저장소로 올리고 로컬 사본을 지운다
+pub fn push_to_target(target: &dyn target::BackupTarget, staging_dir: &Path, filename: &str) -> Result<(), String> {
+    let local_path = staging_dir.join(filename);
+    let result = target.upload(&local_path, filename);
+    let _ = std::fs::remove_file(&local_path);
+    result.inspect(|_| println!("[BackupService] Uploaded {} to {}", filename, target.name()))
+}
+
+// 오래된 백업 정리 (keep_count 개수만 유지)
+pub fn cleanup_old_backups(backup_dir: &Path, keep_count: usize) -> Result<(), String> {
+    let mut backups = list_backups(backup_dir, &CloudService::Local)?;
+
+    if backups.len() <= keep_count {
+        return Ok(());
+    }
+
+    // 삭제할 백업 (오래된 것부터)
+    backups.sort_by_key(|a| a.created_at);
+    let to_delete = backups.len() - keep_count;
+
+    for backup in backups.iter().take(to_delete) {
+        delete_backup(&backup.filename, backup_dir)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("salon-backup-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn customer_name(db: &Database, id: &str) -> Option<String> {
+        db.conn()
+            .query_row("SELECT name FROM customers WHERE id = ?1", [id], |row| row.get(0))
+            .ok()
+    }
+
+    #[test]
+    fn test_restore_swaps_live_connection() {
+        let dir = temp_dir();
+        let snapshots = dir.join("backups");
+        let key = DatabaseKey::generate().unwrap();
+
+        // 암호화 이전 버전에서 만든 백업
+        let legacy = dir.join("salon_backup_legacy.db");
+        let conn = Connection::open(&legacy).unwrap();
+        conn.execute_batch(crate::db::schema::SCHEMA).unwrap();
+        conn.execute("INSERT INTO customers (id, name) VALUES ('c1', '김민지')", []).unwrap();
+        drop(conn);
+
+        let mut db = Database::open_encrypted(&dir.join("database.db"), &key).unwrap();
+        db.conn()
+            .execute("INSERT INTO customers (id, name) VALUES ('c2', '박서연')", [])
+            .unwrap();
+
+        let result = restore_backup(&mut db, &legacy, &snapshots, None).unwrap();
+
+        // 다시 시작하지 않아도 같은 Database 로 복원된 데이터가 보인다 (마이그레이션 적용 포함)
+        assert_eq!(customer_name(&db, "c1").as_deref(), Some("김민지"));
+        assert_eq!(customer_name(&db, "c2"), None);
+        db.conn().query_row("SELECT consent_version FROM customers", [], |_| Ok(())).unwrap();
+        assert!(!encryption::is_plaintext(&dir.join("database.db")));
+        assert!(!dir.join("database.db.restoring").exists());
+        assert!(!dir.join("database.db.previous").exists());
+
+        // 복원 직전 상태는 자동 백업으로 남아 있고, 그걸로 되돌릴 수 있다
+        let snapshot = snapshots.join(&result.snapshot_filename);
+        assert!(!encryption::is_plaintext(&snapshot));
+        restore_backup(&mut db, &snapshot, &snapshots, None).unwrap();
+        assert_eq!(customer_name(&db, "c2").as_deref(), Some("박서연"));
+
+        // 다른 기기의 키로 암호화된 백업은 거부되고 현재 데이터는 그대로
+        let other = Database::open_encrypted(&dir.join("other.db"), &DatabaseKey::generate().unwrap()).unwrap();
+        let foreign = create_backup(other.conn(), other.key(), &dir, &CloudService::Local).unwrap();
+        let foreign_path = dir.join(&foreign.filename);
+        assert!(restore_backup(&mut db, &foreign_path, &snapshots, None).is_err());
+        assert_eq!(customer_name(&db, "c2").as_deref(), Some("박서연"));
+
+        // 그 기기에서 내보낸 복구 키를 주면 이 기기의 키로 다시 암호화해 복원한다
+        let recovery_key = DatabaseKey::from_hex(other.key().unwrap().to_hex()).unwrap();
+        restore_backup(&mut db, &foreign_path, &snapshots, Some(&recovery_key)).unwrap();
+        assert_eq!(customer_name(&db, "c2"), None);
+        let reopened = Database::open_encrypted(&dir.join("database.db"), &key).unwrap();
+        assert_eq!(customer_name(&reopened, "c2"), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_live_backup_includes_wal_changes() {
+        let dir = temp_dir();
+        let key = DatabaseKey::generate().unwrap();
+        let db = Database::open_encrypted(&dir.join("database.db"), &key).unwrap();
+        db.conn()
+            .execute("INSERT INTO customers (id, name) VALUES ('c1', '김민지')", [])
+            .unwrap();
+        // 체크포인트 전이라 변경분은 아직 WAL 에만 있다
+        assert!(std::fs::metadata(dir.join("database.db-wal")).unwrap().len() > 0);
+
+        let backup = create_backup(db.conn(), db.key(), &dir, &CloudService::Local).unwrap();
+        assert!(!dir.join(format!("{}.partial", backup.filename)).exists());
+
+        let backup_path = dir.join(&backup.filename);
+        assert!(!encryption::is_plaintext(&backup_path));
+        let copy = Connection::open(&backup_path).unwrap();
+        encryption::apply_key(&copy, &key).unwrap();
+        let count: i64 = copy.query_row("SELECT COUNT(*) FROM customers", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_restoring_another_devices_archive_gives_a_new_sync_identity() {
+        let dir = temp_dir();
+        let snapshots = dir.join("backups");
+        let attachments = dir.join(archive::ATTACHMENTS_DIR);
+
+        // A 기기: 동기화 id, LAN 키, 페어링한 기기가 있다
+        let device_a = Database::open_in_memory().unwrap();
+        let node_a = sync::changes::node_id(device_a.conn()).unwrap();
+        device_a
+            .conn()
+            .execute("INSERT INTO sync_state (key, value) VALUES (?1, 'a-identity')", [sync::lan::IDENTITY_KEY])
+            .unwrap();
+        device_a
+            .conn()
+            .execute(
+                "INSERT INTO sync_peers (node_id, device_name, public_key, paired_at) VALUES ('peer', '아이패드', 'key', '2025-01-01')",
+                [],
+            )
+            .unwrap();
+        let archive_a = dir.join("salon_backup_a.ssbak");
+        archive::write_archive_with(device_a.conn(), &attachments, &archive_a, "passphrase!", archive::TEST_KDF).unwrap();
+
+        // B 기기에 A 의 백업을 복원하면 데이터는 A 의 것이지만 동기화 id 는 새로 만든다
+        let mut device_b = Database::open_encrypted(&dir.join("database.db"), &DatabaseKey::generate().unwrap()).unwrap();
+        let node_b = sync::changes::node_id(device_b.conn()).unwrap();
+        restore_archive(&mut device_b, &archive_a, "passphrase!", &attachments, &snapshots).unwrap();
+        let restored_node = sync::changes::node_id(device_b.conn()).unwrap();
+        assert_ne!(restored_node, node_a);
+        assert_ne!(restored_node, node_b);
+        let count = |sql: &str| -> i64 { device_b.conn().query_row(sql, [], |row| row.get(0)).unwrap() };
+        assert_eq!(count("SELECT COUNT(*) FROM sync_state WHERE value = 'a-identity'"), 0);
+        assert_eq!(count("SELECT COUNT(*) FROM sync_peers"), 0);
+
+        // 이 기기에서 만든 백업을 복원하면 id 는 그대로다
+        let archive_b = dir.join("salon_backup_b.ssbak");
+        archive::write_archive_with(device_b.conn(), &attachments, &archive_b, "passphrase!", archive::TEST_KDF).unwrap();
+        restore_archive(&mut device_b, &archive_b, "passphrase!", &attachments, &snapshots).unwrap();
+        assert_eq!(sync::changes::node_id(device_b.conn()).unwrap(), restored_node);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_restore_archive_verifies_before_replacing() {
+        let dir = temp_dir();
+        let snapshots = dir.join("backups");
+        let key = DatabaseKey::generate().unwrap();
+        let attachments = dir.join(archive::ATTACHMENTS_DIR);
+
+        let source = Database::open_in_memory().unwrap();
+        source
+            .conn()
+            .execute("INSERT INTO customers (id, name) VALUES ('c1', '김민지')", [])
+            .unwrap();
+        let archive_path = dir.join("salon_backup_test.ssbak");
+        archive::write_archive_with(source.conn(), &attachments, &archive_path, "passphrase!", archive::TEST_KDF)
+            .unwrap();
+
+        // 미리보기는 이번에만 쓰는 키로 암호화한 사본을 연다
+        let source = preview::BackupSource::open(&archive_path, None, Some("passphrase!")).unwrap();
+        let summary = preview::preview_backup(&source).unwrap();
+        assert!(summary.tables.iter().any(|t| t.table == "customers" && t.rows == 1));
+        drop(source);
+
+        let mut db = Database::open_encrypted(&dir.join("database.db"), &key).unwrap();
+
+        // 비밀번호가 틀리면 현재 데이터베이스는 그대로이고 자동 백업도 만들지 않는다
+        assert!(restore_archive(&mut db, &archive_path, "wrong pass", &attachments, &snapshots).is_err());
+        assert_eq!(customer_name(&db, "c1"), None);
+        assert!(!snapshots.exists());
+
+        let result = restore_archive(&mut db, &archive_path, "passphrase!", &attachments, &snapshots).unwrap();
+        assert_eq!(result.manifest.unwrap().counts.customers, 1);
+        assert_eq!(customer_name(&db, "c1").as_deref(), Some("김민지"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_preview_and_selective_restore() {
+        let dir = temp_dir();
+        let snapshots = dir.join("backups");
+        let key = DatabaseKey::generate().unwrap();
+
+        let db = Database::open_encrypted(&dir.join("database.db"), &key).unwrap();
+        db.conn()
+            .execute_batch(
+                "INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-1111');
+                 INSERT INTO reservations (id, customer_name, date, time) VALUES ('r1', '김민지', '2025-03-01', '10:00');
+                 INSERT INTO reservations (id, customer_name, date, time) VALUES ('r2', '김민지', '2025-05-01', '10:00');
+                 UPDATE customers SET updated_at = '2099-05-02 09:00:00';",
+            )
+            .unwrap();
+        let backup = create_backup(db.conn(), db.key(), &dir, &CloudService::Local).unwrap();
+        let backup_path = dir.join(&backup.filename);
+
+        // 백업 이후 변경
+        db.conn()
+            .execute_batch(
+                "UPDATE customers SET name = '김민지(수정)' WHERE id = 'c1';
+                 DELETE FROM reservations;
+                 INSERT INTO customers (id, name, phone) VALUES ('c2', '박서연', '010-2222-2222');",
+            )
+            .unwrap();
+
+        let source = preview::BackupSource::open(&backup_path, db.key(), None).unwrap();
+        let summary = preview::preview_backup(&source).unwrap();
+        let rows = |table: &str| summary.tables.iter().find(|t| t.table == table).map(|t| t.rows);
+        assert_eq!(rows("customers"), Some(1));
+        assert_eq!(rows("reservations"), Some(2));
+        assert_eq!(summary.reservation_date_from.as_deref(), Some("2025-03-01"));
+        assert_eq!(summary.reservation_date_to.as_deref(), Some("2025-05-01"));
+        assert_eq!(summary.latest_updated_at.as_deref(), Some("2099-05-02 09:00:00"));
+        drop(source);
+
+        // 3월 예약과 고객만, 충돌 시 현재 값 유지
+        let options = merge::SelectiveRestoreOptions {
+            tables: vec!["customers".to_string(), "reservations".to_string()],
+            date_from: Some("2025-03-01".to_string()),
+            date_to: Some("2025-03-31".to_string()),
+            overwrite: false,
+        };
+        let report = restore_selective(&db, &backup_path, None, &options, &snapshots).unwrap();
+        assert!(snapshots.join(&report.snapshot_filename).exists());
+        assert_eq!(report.tables[0].skipped, 1);
+        assert_eq!(report.tables[1].inserted, 1);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].id, "c1");
+        assert_eq!(report.conflicts[0].resolution, "kept");
+        assert_eq!(customer_name(&db, "c1").as_deref(), Some("김민지(수정)"));
+        assert_eq!(customer_name(&db, "c2").as_deref(), Some("박서연"));
+
+        // 덮어쓰기
+        let options = merge::SelectiveRestoreOptions {
+            tables: vec!["customers".to_string()],
+            date_from: None,
+            date_to: None,
+            overwrite: true,
+        };
+        let report = restore_selective(&db, &backup_path, None, &options, &snapshots).unwrap();
+        assert_eq!(report.tables[0].updated, 1);
+        assert_eq!(report.conflicts[0].resolution, "overwritten");
+        assert_eq!(customer_name(&db, "c1").as_deref(), Some("김민지"));
+        let reservations: i64 = db.conn().query_row("SELECT COUNT(*) FROM reservations", [], |row| row.get(0)).unwrap();
+        assert_eq!(reservations, 1);
+
+        let invalid = merge::SelectiveRestoreOptions { tables: vec!["app_settings".to_string()], ..options };
+        assert!(restore_selective(&db, &backup_path, None, &invalid, &snapshots).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
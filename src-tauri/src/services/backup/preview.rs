@@ -0,0 +1,167 @@
+//! 백업 미리보기
+//!
+//! 복원하기 전에 백업 파일을 읽기 전용으로 열어 테이블별 행 수, 예약 기간,
+//! 스키마 버전, 가장 최근 수정 시각을 보여준다.
+
+use rusqlite::{Connection, OpenFlags};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::archive::{self, BackupManifest};
+use crate::db::encryption::{self, DatabaseKey};
+
+/// 미리보기·선택 복원을 위해 꺼낸 백업 데이터베이스
+///
+/// 아카이브(.ssbak)의 데이터베이스는 메모리에서 열어 이번에만 쓰는 무작위 키로 암호화한 임시 파일에
+/// 담고, 값이 사라질 때 지운다. 앱이 도중에 종료되어 파일이 남아도 키가 없어 읽을 수 없다.
+pub struct BackupSource {
+    path: PathBuf,
+    key: Option<DatabaseKey>,
+    temporary: bool,
+    pub manifest: Option<BackupManifest>,
+}
+
+impl BackupSource {
+    /// `device_key` 는 기기 키로 암호화된 .db 백업을, `passphrase` 는 아카이브를 여는 데 쓴다.
+    /// .db 백업에 `passphrase` 를 주면 다른 기기에서 내보낸 복구 키로 보고 기기 키 대신 쓴다.
+    pub fn open(backup_path: &Path, device_key: Option<&DatabaseKey>, passphrase: Option<&str>) -> Result<Self, String> {
+        if !backup_path.exists() {
+            return Err(format!("백업 파일을 찾을 수 없습니다: {:?}", backup_path));
+        }
+
+        let filename = backup_path.file_name().unwrap_or_default().to_string_lossy();
+        if archive::is_archive(&filename) {
+            let passphrase = passphrase
+                .filter(|p| !p.is_empty())
+                .ok_or("이 백업을 열려면 백업 비밀번호가 필요합니다")?;
+            let contents = archive::read_archive(backup_path, passphrase)?;
+            let path = std::env::temp_dir().join(format!("salon-preview-{}.db", uuid::Uuid::new_v4()));
+            let key = DatabaseKey::generate()?;
+            let staged = encryption::open_image(&contents.database)
+                .and_then(|conn| encryption::export_connection_encrypted(&conn, &path, &key));
+            if let Err(e) = staged {
+                let _ = std::fs::remove_file(&path);
+                return Err(e);
+            }
+            return Ok(Self { path, key: Some(key), temporary: true, manifest: Some(contents.manifest) });
+        }
+
+        let key = if encryption::is_plaintext(backup_path) {
+            None
+        } else {
+            match passphrase.filter(|p| !p.is_empty()) {
+                Some(recovery_key) => Some(parse_recovery_key(recovery_key)?),
+                None => Some(device_key.cloned().ok_or("암호화된 백업을 열 키가 없습니다")?),
+            }
+        };
+        Ok(Self { path: backup_path.to_path_buf(), key, temporary: false, manifest: None })
+    }
+
+    pub fn connect_read_only(&self) -> Result<Connection, String> {
+        let conn = Connection::open_with_flags(&self.path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX)
+            .map_err(|e| format!("백업 파일을 열 수 없습니다: {}", e))?;
+        if let Some(key) = &self.key {
+            encryption::apply_key(&conn, key).map_err(|e| e.to_string())?;
+        }
+        encryption::verify_key(&conn).map_err(|_| "이 기기의 암호화 키로 열 수 없는 백업입니다".to_string())?;
+        Ok(conn)
+    }
+
+    /// 열려 있는 연결에 `schema` 이름으로 붙인다
+    pub fn attach(&self, conn: &Connection, schema: &str) -> Result<(), String> {
+        encryption::attach(conn, &self.path, schema, self.key.as_ref())
+    }
+}
+
+/// 사용자가 입력한 복구 키 (다른 기기에서 내보낸 데이터베이스 키)
+pub fn parse_recovery_key(input: &str) -> Result<DatabaseKey, String> {
+    DatabaseKey::from_hex(input).map_err(|_| "복구 키 형식이 올바르지 않습니다 (64자리 16진수)".to_string())
+}
+
+impl Drop for BackupSource {
+    fn drop(&mut self) {
+        if self.temporary {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCount {
+    pub table: String,
+    pub rows: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPreview {
+    /// `PRAGMA user_version`
+    pub schema_version: i64,
+    pub tables: Vec<TableCount>,
+    pub reservation_date_from: Option<String>,
+    pub reservation_date_to: Option<String>,
+    /// `updated_at` 이 있는 테이블 중 가장 최근 값
+    pub latest_updated_at: Option<String>,
+    /// 아카이브인 경우 manifest
+    pub manifest: Option<BackupManifest>,
+}
+
+pub fn preview_backup(source: &BackupSource) -> Result<BackupPreview, String> {
+    let conn = source.connect_read_only()?;
+
+    let schema_version: i64 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+
+    let table_names: Vec<String> = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name")
+        .and_then(|mut stmt| stmt.query_map([], |row| row.get(0))?.collect())
+        .map_err(|e| e.to_string())?;
+
+    let mut tables = Vec::new();
+    let mut latest_updated_at: Option<String> = None;
+    for table in table_names {
+        let rows: i64 = conn
+            .query_row(&format!("SELECT COUNT(*) FROM \"{}\"", table), [], |row| row.get(0))
+            .map_err(|e| e.to_string())?;
+        if table_columns(&conn, "main", &table)?.iter().any(|c| c == "updated_at") {
+            let latest: Option<String> = conn
+                .query_row(&format!("SELECT MAX(updated_at) FROM \"{}\"", table), [], |row| row.get(0))
+                .map_err(|e| e.to_string())?;
+            if latest > latest_updated_at {
+                latest_updated_at = latest;
+            }
+        }
+        tables.push(TableCount { table, rows });
+    }
+
+    let (reservation_date_from, reservation_date_to) = if tables.iter().any(|t| t.table == "reservations") {
+        conn.query_row("SELECT MIN(date), MAX(date) FROM reservations", [], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| e.to_string())?
+    } else {
+        (None, None)
+    };
+
+    Ok(BackupPreview {
+        schema_version,
+        tables,
+        reservation_date_from,
+        reservation_date_to,
+        latest_updated_at,
+        manifest: source.manifest.clone(),
+    })
+}
+
+/// 백업을 열어 무결성 검사까지 마친 뒤 내용을 요약한다. 복원하지 않고 백업이 쓸 만한지 확인할 때 쓴다.
+pub fn verify_backup(source: &BackupSource) -> Result<BackupPreview, String> {
+    super::check_integrity(&source.connect_read_only()?)?;
+    preview_backup(source)
+}
+
+/// `schema.table` 의 컬럼 이름
+pub(crate) fn table_columns(conn: &Connection, schema: &str, table: &str) -> Result<Vec<String>, String> {
+    conn.prepare(&format!("PRAGMA \"{}\".table_info(\"{}\")", schema, table))
+        .and_then(|mut stmt| stmt.query_map([], |row| row.get(1))?.collect())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+
+use super::{check_filename, is_backup_filename, BackupTarget, RemoteBackup};
+
+/// 이 기기에서 접근할 수 있는 폴더 (USB 메모리, NAS 마운트 등)
+pub struct DirectoryTarget {
+    root: PathBuf,
+}
+
+impl DirectoryTarget {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+}
+
+impl BackupTarget for DirectoryTarget {
+    fn name(&self) -> &'static str {
+        "directory"
+    }
+
+    fn list(&self) -> Result<Vec<RemoteBackup>, String> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+        let entries = std::fs::read_dir(&self.root).map_err(|e| format!("폴더를 읽을 수 없습니다: {}", e))?;
+
+        let mut backups = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let filename = entry.file_name().to_string_lossy().to_string();
+            if !is_backup_filename(&filename) {
+                continue;
+            }
+            let metadata = entry.metadata().map_err(|e| e.to_string())?;
+            backups.push(RemoteBackup {
+                filename,
+                size: metadata.len(),
+                modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+        Ok(backups)
+    }
+
+    fn upload(&self, local_path: &Path, filename: &str) -> Result<(), String> {
+        check_filename(filename)?;
+        std::fs::create_dir_all(&self.root).map_err(|e| format!("폴더를 만들 수 없습니다: {}", e))?;
+
+        // 다 복사한 뒤에 이름을 바꿔 목록에 덜 쓴 파일이 보이지 않게 한다
+        let dest = self.root.join(filename);
+        let partial = self.root.join(format!("{}.partial", filename));
+        if let Err(e) = std::fs::copy(local_path, &partial) {
+            let _ = std::fs::remove_file(&partial);
+            return Err(format!("폴더에 복사하지 못했습니다: {}", e));
+        }
+        std::fs::rename(&partial, &dest).map_err(|e| format!("폴더에 저장하지 못했습니다: {}", e))
+    }
+
+    fn download(&self, filename: &str, dest: &Path) -> Result<(), String> {
+        check_filename(filename)?;
+        std::fs::copy(self.root.join(filename), dest)
+            .map(|_| ())
+            .map_err(|e| format!("폴더에서 가져오지 못했습니다: {}", e))
+    }
+
+    fn delete(&self, filename: &str) -> Result<(), String> {
+        check_filename(filename)?;
+        std::fs::remove_file(self.root.join(filename)).map_err(|e| format!("백업 삭제 실패: {}", e))
+    }
+}
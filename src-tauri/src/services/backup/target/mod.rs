@@ -0,0 +1,393 @@
+//! 외부 백업 저장소
+//!
+//! 기기 밖에 백업을 두기 위한 저장소(`BackupTarget`)와 그 설정. 사용자가 고른 폴더(USB·NAS
+//! 마운트), WebDAV(Nextcloud, Synology), S3 호환 저장소(MinIO 등)를 지원한다.
+//! 백업 파일은 먼저 앱 데이터 폴더에 만든 뒤 저장소로 올리고, 복원할 때는 내려받아서 쓴다.
+
+pub mod directory;
+pub mod s3;
+pub mod webdav;
+
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+use super::archive;
+
+const SETTINGS_KEY: &str = "backup_target";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 저장소에 있는 백업 파일 하나
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteBackup {
+    pub filename: String,
+    pub size: u64,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+/// 백업 파일을 올리고 내려받는 저장소
+pub trait BackupTarget: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// 백업 파일(.db, .ssbak)만 돌려준다
+    fn list(&self) -> Result<Vec<RemoteBackup>, String>;
+
+    fn upload(&self, local_path: &Path, filename: &str) -> Result<(), String>;
+
+    fn download(&self, filename: &str, dest: &Path) -> Result<(), String>;
+
+    fn delete(&self, filename: &str) -> Result<(), String>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BackupTargetConfig {
+    /// 설정하지 않음
+    #[default]
+    None,
+    /// 이 기기에서 접근할 수 있는 폴더 (USB, NAS 마운트 등)
+    Directory { path: String },
+    /// WebDAV 폴더 주소 (예: https://cloud.example.com/remote.php/dav/files/me/SistersSalon)
+    Webdav { url: String, username: String, password: String },
+    /// S3 호환 저장소. 경로 방식(`endpoint/bucket/key`)으로 접근한다
+    #[serde(rename_all = "camelCase")]
+    S3 {
+        endpoint: String,
+        region: String,
+        bucket: String,
+        prefix: String,
+        access_key: String,
+        secret_key: String,
+    },
+}
+
+impl BackupTargetConfig {
+    pub fn build(&self) -> Result<Box<dyn BackupTarget>, String> {
+        Ok(match self {
+            BackupTargetConfig::None => return Err("외부 저장소가 설정되지 않았습니다".to_string()),
+            BackupTargetConfig::Directory { path } => {
+                require("폴더 경로", path)?;
+                Box::new(directory::DirectoryTarget::new(path.into()))
+            }
+            BackupTargetConfig::Webdav { url, username, password } => {
+                require("WebDAV 주소", url)?;
+                Box::new(webdav::WebdavTarget::new(url, username, password)?)
+            }
+            BackupTargetConfig::S3 { endpoint, region, bucket, prefix, access_key, secret_key } => {
+                require("엔드포인트", endpoint)?;
+                require("버킷", bucket)?;
+                require("액세스 키", access_key)?;
+                require("시크릿 키", secret_key)?;
+                Box::new(s3::S3Target::new(s3::S3Config {
+                    endpoint: endpoint.clone(),
+                    region: if region.trim().is_empty() { "us-east-1".to_string() } else { region.clone() },
+                    bucket: bucket.clone(),
+                    prefix: prefix.clone(),
+                    access_key: access_key.clone(),
+                    secret_key: secret_key.clone(),
+                })?)
+            }
+        })
+    }
+}
+
+fn require(label: &str, value: &str) -> Result<(), String> {
+    if value.trim().is_empty() {
+        return Err(format!("{}을(를) 입력해주세요", label));
+    }
+    Ok(())
+}
+
+pub fn get_config(conn: &Connection) -> Result<BackupTargetConfig, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(BackupTargetConfig::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn save_config(conn: &Connection, config: &BackupTargetConfig) -> Result<(), String> {
+    if *config != BackupTargetConfig::None {
+        config.build()?;
+    }
+
+    let json = serde_json::to_string(config).map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![SETTINGS_KEY, json, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 목록에 보일 백업 파일인지 (`salon_backup_*.db` / `.ssbak`)
+pub(crate) fn is_backup_filename(filename: &str) -> bool {
+    filename.starts_with("salon_backup_")
+        && (filename.ends_with(".db") || archive::is_archive(filename))
+        && !filename.contains('/')
+}
+
+/// 저장소 안에 쓸 수 있는 이름인지 확인한다 (경로 조작 방지)
+fn check_filename(filename: &str) -> Result<(), String> {
+    if !is_backup_filename(filename) || filename.contains('\\') || filename.contains("..") {
+        return Err(format!("잘못된 백업 파일 이름입니다: {}", filename));
+    }
+    Ok(())
+}
+
+fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build()
+}
+
+fn http_error(action: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            let detail: String = body.chars().take(200).collect();
+            format!("{} 실패: HTTP {} {}", action, code, detail.trim())
+        }
+        other => format!("{} 실패: {}", action, other),
+    }
+}
+
+/// 응답 본문을 `dest` 에 저장한다. 다 받은 뒤에 이름을 바꿔 중간에 끊긴 파일이 남지 않게 한다
+fn save_response(response: ureq::Response, dest: &Path) -> Result<(), String> {
+    let partial = dest.with_extension("download");
+    let written = std::fs::File::create(&partial)
+        .and_then(|mut file| std::io::copy(&mut response.into_reader(), &mut file))
+        .map_err(|e| format!("내려받기 실패: {}", e));
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&partial);
+        return Err(e);
+    }
+    std::fs::rename(&partial, dest).map_err(|e| format!("내려받은 파일 저장 실패: {}", e))
+}
+
+#[cfg(test)]
+pub(crate) mod test_server {
+    //! WebDAV·S3 요청을 흉내 내는 로컬 서버 (메모리에 파일을 둔다)
+
+    use std::collections::BTreeMap;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub query: String,
+        pub headers: Vec<(String, String)>,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    pub struct FakeServer {
+        pub files: Arc<Mutex<BTreeMap<String, Vec<u8>>>>,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl FakeServer {
+        /// 서버를 띄우고 `http://127.0.0.1:port` 를 돌려준다
+        pub fn start(&self) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let server = self.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    server.handle(stream);
+                }
+            });
+            url
+        }
+
+        fn handle(&self, stream: TcpStream) {
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let mut parts = line.split_whitespace();
+            let method = parts.next().unwrap_or_default().to_string();
+            let target = parts.next().unwrap_or_default().to_string();
+            let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+            let (path, query) = (path.to_string(), query.to_string());
+
+            let mut headers = Vec::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some((k, v)) = header.split_once(':') {
+                    headers.push((k.trim().to_string(), v.trim().to_string()));
+                }
+            }
+            let request = Request { method, path, query, headers };
+            let length: usize = request.header("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+            let mut body = vec![0u8; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let (status, response) = self.respond(&request, body);
+            self.requests.lock().unwrap().push(request);
+            let mut stream = reader.into_inner();
+            let _ = stream.write_all(
+                format!("HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, response.len())
+                    .as_bytes(),
+            );
+            let _ = stream.write_all(&response);
+        }
+
+        fn respond(&self, request: &Request, body: Vec<u8>) -> (u16, Vec<u8>) {
+            let mut files = self.files.lock().unwrap();
+            match request.method.as_str() {
+                "PUT" => {
+                    files.insert(request.path.clone(), body);
+                    (201, Vec::new())
+                }
+                "GET" if request.query.contains("list-type=2") => {
+                    let prefix = format!("{}/", request.path.trim_end_matches('/'));
+                    let contents: String = files
+                        .iter()
+                        .filter_map(|(path, data)| {
+                            let key = path.strip_prefix(&prefix)?;
+                            Some(format!(
+                                "<Contents><Key>{}</Key><LastModified>2025-01-01T00:00:00.000Z</LastModified><Size>{}</Size></Contents>",
+                                key,
+                                data.len()
+                            ))
+                        })
+                        .collect();
+                    let xml = format!(
+                        "<?xml version=\"1.0\"?><ListBucketResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\"><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+                        contents
+                    );
+                    (200, xml.into_bytes())
+                }
+                "GET" => match files.get(&request.path) {
+                    Some(data) => (200, data.clone()),
+                    None => (404, Vec::new()),
+                },
+                "DELETE" => match files.remove(&request.path) {
+                    Some(_) => (204, Vec::new()),
+                    None => (404, Vec::new()),
+                },
+                "MKCOL" => (405, Vec::new()),
+                "PROPFIND" => {
+                    let dir = request.path.trim_end_matches('/').to_string();
+                    let mut xml = format!(
+                        "<?xml version=\"1.0\"?><d:multistatus xmlns:d=\"DAV:\"><d:response><d:href>{}/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat></d:response>",
+                        dir
+                    );
+                    for (path, data) in files.iter() {
+                        if path.rsplit_once('/').map(|(parent, _)| parent) == Some(dir.as_str()) {
+                            xml.push_str(&format!(
+                                "<d:response><d:href>{}</d:href><d:propstat><d:prop><d:resourcetype/><d:getcontentlength>{}</d:getcontentlength><d:getlastmodified>Wed, 01 Jan 2025 00:00:00 GMT</d:getlastmodified></d:prop></d:propstat></d:response>",
+                                path,
+                                data.len()
+                            ));
+                        }
+                    }
+                    xml.push_str("</d:multistatus>");
+                    (207, xml.into_bytes())
+                }
+                _ => (405, Vec::new()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 어떤 저장소든 올리기·목록·내려받기·삭제가 같은 결과를 내는지 확인한다
+    pub(crate) fn exercise_target(target: &dyn BackupTarget) {
+        let dir = std::env::temp_dir().join(format!("salon-target-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let local = dir.join("local.db");
+        std::fs::write(&local, b"backup data").unwrap();
+
+        target.upload(&local, "salon_backup_20250101_120000.db").unwrap();
+        target.upload(&local, "salon_backup_20250102_120000.ssbak").unwrap();
+        assert!(target.upload(&local, "../escape.db").is_err());
+
+        let mut listed: Vec<String> = target.list().unwrap().into_iter().map(|b| b.filename).collect();
+        listed.sort();
+        assert_eq!(listed, vec!["salon_backup_20250101_120000.db", "salon_backup_20250102_120000.ssbak"]);
+        assert_eq!(target.list().unwrap()[0].size, 11);
+
+        let downloaded = dir.join("downloaded.db");
+        target.download("salon_backup_20250101_120000.db", &downloaded).unwrap();
+        assert_eq!(std::fs::read(&downloaded).unwrap(), b"backup data");
+
+        target.delete("salon_backup_20250101_120000.db").unwrap();
+        assert_eq!(target.list().unwrap().len(), 1);
+        assert!(target.download("salon_backup_20250101_120000.db", &downloaded.with_extension("x")).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_directory_target() {
+        let dir = std::env::temp_dir().join(format!("salon-target-dir-{}", uuid::Uuid::new_v4()));
+        let config = BackupTargetConfig::Directory { path: dir.to_string_lossy().to_string() };
+        exercise_target(config.build().unwrap().as_ref());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_webdav_target() {
+        let server = test_server::FakeServer::default();
+        let url = format!("{}/dav/files/salon/backups", server.start());
+        let config = BackupTargetConfig::Webdav { url, username: "salon".to_string(), password: "secret".to_string() };
+        exercise_target(config.build().unwrap().as_ref());
+
+        let requests = server.requests.lock().unwrap();
+        let put = requests.iter().find(|r| r.method == "PUT").unwrap();
+        assert_eq!(put.path, "/dav/files/salon/backups/salon_backup_20250101_120000.db");
+        assert_eq!(put.header("authorization"), Some("Basic c2Fsb246c2VjcmV0"));
+    }
+
+    #[test]
+    fn test_s3_target() {
+        let server = test_server::FakeServer::default();
+        let config = BackupTargetConfig::S3 {
+            endpoint: server.start(),
+            region: String::new(),
+            bucket: "salon".to_string(),
+            prefix: "backups/".to_string(),
+            access_key: "minio".to_string(),
+            secret_key: "minio-secret".to_string(),
+        };
+        exercise_target(config.build().unwrap().as_ref());
+
+        let requests = server.requests.lock().unwrap();
+        let put = requests.iter().find(|r| r.method == "PUT").unwrap();
+        assert_eq!(put.path, "/salon/backups/salon_backup_20250101_120000.db");
+        assert!(put.header("authorization").unwrap().starts_with("AWS4-HMAC-SHA256 Credential=minio/"));
+        assert!(put.header("x-amz-content-sha256").is_some());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(BackupTargetConfig::None.build().is_err());
+        let webdav = BackupTargetConfig::Webdav { url: "ftp://nas".to_string(), username: String::new(), password: String::new() };
+        assert!(webdav.build().is_err());
+    }
+}
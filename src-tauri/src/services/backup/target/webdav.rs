@@ -0,0 +1,152 @@
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+use super::{agent, check_filename, http_error, is_backup_filename, save_response, BackupTarget, RemoteBackup};
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?><d:propfind xmlns:d="DAV:"><d:prop><d:resourcetype/><d:getcontentlength/><d:getlastmodified/></d:prop></d:propfind>"#;
+
+/// WebDAV 폴더 (Nextcloud, Synology WebDAV Server 등)
+pub struct WebdavTarget {
+    /// 끝에 `/` 가 붙은 폴더 주소
+    collection_url: String,
+    authorization: Option<String>,
+}
+
+impl WebdavTarget {
+    pub fn new(url: &str, username: &str, password: &str) -> Result<Self, String> {
+        let url = url.trim();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err("WebDAV 주소는 http:// 또는 https:// 로 시작해야 합니다".to_string());
+        }
+        let authorization = (!username.is_empty()).then(|| {
+            let credentials = format!("{}:{}", username, password);
+            format!("Basic {}", base64::engine::general_purpose::STANDARD.encode(credentials))
+        });
+        Ok(Self { collection_url: format!("{}/", url.trim_end_matches('/')), authorization })
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let request = agent().request(method, url);
+        match &self.authorization {
+            Some(authorization) => request.set("Authorization", authorization),
+            None => request,
+        }
+    }
+
+    fn file_url(&self, filename: &str) -> Result<String, String> {
+        check_filename(filename)?;
+        Ok(format!("{}{}", self.collection_url, filename))
+    }
+
+    /// 폴더가 없으면 만든다. 이미 있으면 서버가 405 를 돌려준다
+    fn ensure_collection(&self) -> Result<(), String> {
+        match self.request("MKCOL", &self.collection_url).call() {
+            Ok(_) | Err(ureq::Error::Status(405, _)) => Ok(()),
+            Err(e) => Err(http_error("WebDAV 폴더 만들기", e)),
+        }
+    }
+}
+
+impl BackupTarget for WebdavTarget {
+    fn name(&self) -> &'static str {
+        "webdav"
+    }
+
+    fn list(&self) -> Result<Vec<RemoteBackup>, String> {
+        let response = match self
+            .request("PROPFIND", &self.collection_url)
+            .set("Depth", "1")
+            .set("Content-Type", "application/xml; charset=utf-8")
+            .send_string(PROPFIND_BODY)
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => return Ok(Vec::new()),
+            Err(e) => return Err(http_error("WebDAV 목록 조회", e)),
+        };
+        let body = response.into_string().map_err(|e| e.to_string())?;
+        parse_multistatus(&body)
+    }
+
+    fn upload(&self, local_path: &Path, filename: &str) -> Result<(), String> {
+        let url = self.file_url(filename)?;
+        let data = std::fs::read(local_path).map_err(|e| format!("백업 파일을 읽을 수 없습니다: {}", e))?;
+        self.ensure_collection()?;
+        self.request("PUT", &url)
+            .set("Content-Type", "application/octet-stream")
+            .send_bytes(&data)
+            .map_err(|e| http_error("WebDAV 업로드", e))?;
+        Ok(())
+    }
+
+    fn download(&self, filename: &str, dest: &Path) -> Result<(), String> {
+        let response = self
+            .request("GET", &self.file_url(filename)?)
+            .call()
+            .map_err(|e| http_error("WebDAV 내려받기", e))?;
+        save_response(response, dest)
+    }
+
+    fn delete(&self, filename: &str) -> Result<(), String> {
+        self.request("DELETE", &self.file_url(filename)?)
+            .call()
+            .map_err(|e| http_error("WebDAV 삭제", e))?;
+        Ok(())
+    }
+}
+
+/// PROPFIND 응답(207 Multi-Status)에서 백업 파일만 골라낸다
+fn parse_multistatus(body: &str) -> Result<Vec<RemoteBackup>, String> {
+    let document = roxmltree::Document::parse(body).map_err(|e| format!("WebDAV 응답을 해석할 수 없습니다: {}", e))?;
+    let mut backups = Vec::new();
+    for response in document
+        .descendants()
+        .filter(|n| n.tag_name().namespace() == Some("DAV:") && n.tag_name().name() == "response")
+    {
+        if dav(&response, "collection").is_some() {
+            continue;
+        }
+        let Some(href) = dav(&response, "href").and_then(|n| n.text()) else {
+            continue;
+        };
+        let filename = percent_decode(href.trim_end_matches('/').rsplit('/').next().unwrap_or_default());
+        if !is_backup_filename(&filename) {
+            continue;
+        }
+        let size = dav(&response, "getcontentlength")
+            .and_then(|n| n.text())
+            .and_then(|text| text.trim().parse().ok())
+            .unwrap_or(0);
+        let modified = dav(&response, "getlastmodified")
+            .and_then(|n| n.text())
+            .and_then(|text| DateTime::parse_from_rfc2822(text.trim()).ok())
+            .map(|time| time.with_timezone(&Utc));
+        backups.push(RemoteBackup { filename, size, modified });
+    }
+    Ok(backups)
+}
+
+/// `node` 아래에서 DAV: 이름공간의 `name` 요소를 찾는다
+fn dav<'a, 'input>(node: &roxmltree::Node<'a, 'input>, name: &str) -> Option<roxmltree::Node<'a, 'input>> {
+    node.descendants()
+        .find(|n| n.tag_name().namespace() == Some("DAV:") && n.tag_name().name() == name)
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
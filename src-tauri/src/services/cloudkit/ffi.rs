@@ -1,6 +1,7 @@
 // CloudKit integration for iOS via Swift FFI
 //
-// Uses dlsym to find Swift functions at runtime to avoid link-time errors
+// Uses dlsym to find Swift functions at runtime to avoid link-time errors.
+// Callers go through `CloudKitStore` (see mod.rs), which wraps these raw calls.
 
 #[cfg(target_os = "ios")]
 use std::ffi::{CStr, CString};
@@ -153,7 +154,7 @@ fn get_cloudkit_functions() -> Option<&'static CloudKitFunctions> {
 
 /// Initialize CloudKit - call this at app startup
 #[allow(dead_code)]
-pub fn init_cloudkit() {
+pub(super) fn init_cloudkit() {
     #[cfg(target_os = "ios")]
     {
         println!("[CloudKit Rust] init_cloudkit called");
@@ -166,7 +167,7 @@ pub fn init_cloudkit() {
 }
 
 /// Check if CloudKit is available
-pub fn is_cloudkit_available() -> bool {
+pub(super) fn is_cloudkit_available() -> bool {
     println!("[CloudKit Rust] is_cloudkit_available called");
 
     #[cfg(target_os = "ios")]
@@ -201,7 +202,7 @@ pub fn is_cloudkit_available() -> bool {
 }
 
 /// Upload backup to CloudKit
-pub fn upload_to_cloudkit(local_path: &str) -> Result<String, String> {
+pub(super) fn upload_to_cloudkit(local_path: &str) -> Result<String, String> {
     println!("[CloudKit Rust] upload_to_cloudkit called: {}", local_path);
 
     #[cfg(target_os = "ios")]
@@ -258,7 +259,7 @@ pub fn upload_to_cloudkit(local_path: &str) -> Result<String, String> {
 
 /// List backups from CloudKit
 /// Returns JSON string: [{"id":"...", "filename":"...", "size":123, "createdAt":"..."}]
-pub fn list_cloudkit_backups() -> Result<String, String> {
+pub(super) fn list_cloudkit_backups() -> Result<String, String> {
     println!("[CloudKit Rust] list_cloudkit_backups called");
 
     #[cfg(target_os = "ios")]
@@ -288,7 +289,7 @@ pub fn list_cloudkit_backups() -> Result<String, String> {
 }
 
 /// Delete a backup from CloudKit by record ID
-pub fn delete_cloudkit_backup(record_id: &str) -> Result<(), String> {
+pub(super) fn delete_cloudkit_backup(record_id: &str) -> Result<(), String> {
     println!("[CloudKit Rust] delete_cloudkit_backup called: {}", record_id);
 
     #[cfg(target_os = "ios")]
@@ -326,7 +327,7 @@ pub fn delete_cloudkit_backup(record_id: &str) -> Result<(), String> {
 }
 
 /// Download a backup from CloudKit by record ID to a local path
-pub fn download_cloudkit_backup(record_id: &str, dest_path: &str) -> Result<(), String> {
+pub(super) fn download_cloudkit_backup(record_id: &str, dest_path: &str) -> Result<(), String> {
     println!("[CloudKit Rust] download_cloudkit_backup called: {} -> {}", record_id, dest_path);
 
     #[cfg(target_os = "ios")]
@@ -0,0 +1,211 @@
+//! iCloud(CloudKit) 백업 저장소
+//!
+//! 백업 코드는 `RemoteBackupStore` 만 알고, iOS 에서는 Swift FFI 를 감싼 `CloudKitStore` 가
+//! 이를 구현한다. 테스트에서는 메모리 저장소(`MemoryStore`)로 바꿔 기기 없이 시험한다.
+
+mod ffi;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// 원격 저장소에 올라간 백업 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteBackupRecord {
+    pub id: String,
+    pub filename: String,
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+pub trait RemoteBackupStore: Send + Sync {
+    fn is_available(&self) -> bool;
+
+    /// 파일을 올리고 레코드 id 를 돌려준다
+    fn upload(&self, local_path: &Path) -> Result<String, String>;
+
+    /// 최신순
+    fn list(&self) -> Result<Vec<RemoteBackupRecord>, String>;
+
+    fn delete(&self, record_id: &str) -> Result<(), String>;
+
+    fn download(&self, record_id: &str, dest: &Path) -> Result<(), String>;
+}
+
+/// Swift CloudKit 함수를 호출하는 저장소 (iOS 밖에서는 사용할 수 없다고 응답)
+pub struct CloudKitStore;
+
+impl RemoteBackupStore for CloudKitStore {
+    fn is_available(&self) -> bool {
+        ffi::is_cloudkit_available()
+    }
+
+    fn upload(&self, local_path: &Path) -> Result<String, String> {
+        ffi::upload_to_cloudkit(&local_path.to_string_lossy())
+    }
+
+    fn list(&self) -> Result<Vec<RemoteBackupRecord>, String> {
+        parse_records(&ffi::list_cloudkit_backups()?)
+    }
+
+    fn delete(&self, record_id: &str) -> Result<(), String> {
+        ffi::delete_cloudkit_backup(record_id)
+    }
+
+    fn download(&self, record_id: &str, dest: &Path) -> Result<(), String> {
+        ffi::download_cloudkit_backup(record_id, &dest.to_string_lossy())
+    }
+}
+
+/// 앱에서 쓰는 iCloud 저장소
+pub fn store() -> &'static dyn RemoteBackupStore {
+    &CloudKitStore
+}
+
+/// Swift 쪽 목록 JSON 의 레코드 하나
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WireRecord {
+    id: String,
+    filename: Option<String>,
+    size: u64,
+    created_at: DateTime<Utc>,
+}
+
+/// Swift 쪽 목록 JSON (`[{"id", "filename", "size", "createdAt"}]`) 을 최신순 레코드로 바꾼다.
+/// 파일 이름이 없으면 id 를 쓴다. 필드가 빠졌거나 날짜를 읽을 수 없는 레코드는 보관 규칙이
+/// 엉뚱한 날짜로 지우지 않도록 목록에서 빼고 로그로 남긴다.
+fn parse_records(json: &str) -> Result<Vec<RemoteBackupRecord>, String> {
+    let items: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| format!("JSON 파싱 오류: {}", e))?;
+
+    let mut records: Vec<RemoteBackupRecord> = items
+        .into_iter()
+        .filter_map(|item| match serde_json::from_value::<WireRecord>(item.clone()) {
+            Ok(wire) => Some(RemoteBackupRecord {
+                filename: wire.filename.unwrap_or_else(|| wire.id.clone()),
+                id: wire.id,
+                size: wire.size,
+                created_at: wire.created_at,
+            }),
+            Err(e) => {
+                eprintln!("Skipping invalid CloudKit backup record {}: {}", item, e);
+                None
+            }
+        })
+        .collect();
+    records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+    Ok(records)
+}
+
+#[cfg(test)]
+pub(crate) mod memory {
+    //! 메모리에 파일을 두는 `RemoteBackupStore` (CloudKit 처럼 파일 이름을 레코드 id 로 쓴다)
+
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    pub struct MemoryStore {
+        pub available: bool,
+        /// (레코드, 내용)
+        pub records: Mutex<Vec<(RemoteBackupRecord, Vec<u8>)>>,
+        /// true 면 업로드가 실패한다
+        pub fail_uploads: bool,
+    }
+
+    impl MemoryStore {
+        pub fn new() -> Self {
+            Self { available: true, ..Default::default() }
+        }
+
+        pub fn insert(&self, filename: &str, data: &[u8], created_at: DateTime<Utc>) {
+            let record = RemoteBackupRecord {
+                id: filename.to_string(),
+                filename: filename.to_string(),
+                size: data.len() as u64,
+                created_at,
+            };
+            self.records.lock().unwrap().push((record, data.to_vec()));
+        }
+
+        pub fn filenames(&self) -> Vec<String> {
+            self.list().unwrap().into_iter().map(|record| record.filename).collect()
+        }
+    }
+
+    impl RemoteBackupStore for MemoryStore {
+        fn is_available(&self) -> bool {
+            self.available
+        }
+
+        fn upload(&self, local_path: &Path) -> Result<String, String> {
+            if !self.available || self.fail_uploads {
+                return Err("CloudKit 을 사용할 수 없습니다".to_string());
+            }
+            let data = std::fs::read(local_path).map_err(|e| e.to_string())?;
+            let filename = local_path.file_name().unwrap().to_string_lossy().to_string();
+            self.records.lock().unwrap().retain(|(record, _)| record.id != filename);
+            self.insert(&filename, &data, Utc::now());
+            Ok(filename)
+        }
+
+        fn list(&self) -> Result<Vec<RemoteBackupRecord>, String> {
+            let mut records: Vec<RemoteBackupRecord> =
+                self.records.lock().unwrap().iter().map(|(record, _)| record.clone()).collect();
+            records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+            Ok(records)
+        }
+
+        fn delete(&self, record_id: &str) -> Result<(), String> {
+            let mut records = self.records.lock().unwrap();
+            let before = records.len();
+            records.retain(|(record, _)| record.id != record_id);
+            if records.len() == before {
+                return Err(format!("레코드를 찾을 수 없습니다: {}", record_id));
+            }
+            Ok(())
+        }
+
+        fn download(&self, record_id: &str, dest: &Path) -> Result<(), String> {
+            let records = self.records.lock().unwrap();
+            let (_, data) = records
+                .iter()
+                .find(|(record, _)| record.id == record_id)
+                .ok_or_else(|| format!("레코드를 찾을 수 없습니다: {}", record_id))?;
+            std::fs::write(dest, data).map_err(|e| e.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_records_sorts_newest_first() {
+        let json = r#"[
+            {"id": "a", "filename": "salon_backup_a.db", "size": 10, "createdAt": "2025-01-01T00:00:00Z"},
+            {"id": "salon_backup_b.db", "size": 20, "createdAt": "2025-03-01T09:00:00+09:00"}
+        ]"#;
+        let records = parse_records(json).unwrap();
+        assert_eq!(records[0].filename, "salon_backup_b.db");
+        assert_eq!(records[0].created_at.to_rfc3339(), "2025-03-01T00:00:00+00:00");
+        assert_eq!(records[1].id, "a");
+        assert_eq!(records[1].size, 10);
+        assert!(parse_records("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_records_skips_records_without_valid_date() {
+        let json = r#"[
+            {"id": "ok", "size": 10, "createdAt": "2025-01-01T00:00:00Z"},
+            {"id": "missing", "size": 10},
+            {"id": "invalid", "size": 10, "createdAt": "어제"},
+            {"filename": "no_id.db", "size": 10, "createdAt": "2025-01-01T00:00:00Z"}
+        ]"#;
+        let records = parse_records(json).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].id, "ok");
+    }
+}
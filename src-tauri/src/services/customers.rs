@@ -0,0 +1,497 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::{excel, privacy, webhooks};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Customer {
+    pub id: String,
+    pub name: String,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub birthdate: Option<String>,
+    pub gender: Option<String>,
+    pub preferred_designer_id: Option<String>,
+    pub preferred_service: Option<String>,
+    pub allergies: Option<String>,
+    pub total_visits: i32,
+    pub last_visit_date: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    /// 예약 알림 수신 거부
+    pub reminder_opt_out: bool,
+    /// 개인정보 수집·이용 동의 (필수)
+    pub consent_service: bool,
+    pub consent_service_at: Option<String>,
+    /// 마케팅 문자 수신 동의
+    pub consent_marketing: bool,
+    pub consent_marketing_at: Option<String>,
+    /// 시술 사진 촬영·활용 동의
+    pub consent_photo: bool,
+    pub consent_photo_at: Option<String>,
+    /// 마지막으로 동의를 받은 약관 버전
+    pub consent_version: Option<String>,
+    /// 보관 기간이 지나 익명 처리된 시각
+    pub anonymized_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomerInput {
+    pub name: String,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub birthdate: Option<String>,
+    pub gender: Option<String>,
+    pub preferred_designer_id: Option<String>,
+    pub preferred_service: Option<String>,
+    pub allergies: Option<String>,
+    pub notes: Option<String>,
+    pub reminder_opt_out: Option<bool>,
+    pub consent_service: Option<bool>,
+    pub consent_marketing: Option<bool>,
+    pub consent_photo: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCustomerInput {
+    pub name: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub birthdate: Option<String>,
+    pub gender: Option<String>,
+    pub preferred_designer_id: Option<String>,
+    pub preferred_service: Option<String>,
+    pub allergies: Option<String>,
+    pub notes: Option<String>,
+    pub reminder_opt_out: Option<bool>,
+    pub consent_service: Option<bool>,
+    pub consent_marketing: Option<bool>,
+    pub consent_photo: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerReservation {
+    pub id: String,
+    pub date: String,
+    pub time: String,
+    pub service_type: Option<String>,
+    pub designer_name: Option<String>,
+    pub status: String,
+    pub notes: Option<String>,
+}
+
+const CUSTOMER_COLUMNS: &str = "id, name, phone, email, birthdate, gender, preferred_designer_id,
+    preferred_service, allergies, total_visits, last_visit_date, notes, created_at, updated_at,
+    reminder_opt_out, consent_service, consent_service_at, consent_marketing, consent_marketing_at,
+    consent_photo, consent_photo_at, consent_version, anonymized_at";
+
+fn row_to_customer(row: &rusqlite::Row) -> rusqlite::Result<Customer> {
+    Ok(Customer {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        phone: row.get(2)?,
+        email: row.get(3)?,
+        birthdate: row.get(4)?,
+        gender: row.get(5)?,
+        preferred_designer_id: row.get(6)?,
+        preferred_service: row.get(7)?,
+        allergies: row.get(8)?,
+        total_visits: row.get::<_, Option<i32>>(9)?.unwrap_or(0),
+        last_visit_date: row.get(10)?,
+        notes: row.get(11)?,
+        created_at: row.get(12)?,
+        updated_at: row.get(13)?,
+        reminder_opt_out: row.get::<_, Option<i32>>(14)?.unwrap_or(0) == 1,
+        consent_service: row.get::<_, Option<i32>>(15)?.unwrap_or(0) == 1,
+        consent_service_at: row.get(16)?,
+        consent_marketing: row.get::<_, Option<i32>>(17)?.unwrap_or(0) == 1,
+        consent_marketing_at: row.get(18)?,
+        consent_photo: row.get::<_, Option<i32>>(19)?.unwrap_or(0) == 1,
+        consent_photo_at: row.get(20)?,
+        consent_version: row.get(21)?,
+        anonymized_at: row.get(22)?,
+    })
+}
+
+pub fn get_customers(conn: &Connection) -> Result<Vec<Customer>, String> {
+    let mut stmt = conn
+        .prepare(
+            &format!("SELECT {} FROM customers
+             ORDER BY name", CUSTOMER_COLUMNS)
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([], row_to_customer)
+        .map_err(|e| e.to_string())?;
+
+    let customers: Vec<Customer> = rows.filter_map(|r| r.ok()).collect();
+
+    Ok(customers)
+}
+
+pub fn get_customer(conn: &Connection, id: &str) -> Result<Customer, String> {
+    conn.query_row(
+        &format!("SELECT {} FROM customers WHERE id = ?1", CUSTOMER_COLUMNS),
+        [id],
+        row_to_customer,
+    )
+    .map_err(|e| e.to_string())
+}
+
+pub fn create_customer(
+    conn: &Connection,
+    data: CreateCustomerInput,
+) -> Result<Customer, String> {
+    // 웹훅 이벤트가 고객과 함께 저장되거나 함께 취소되도록 한 트랜잭션으로 묶는다
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let customer = insert_customer(&tx, data)?;
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(customer)
+}
+
+/// 고객 등록과 웹훅 이벤트 기록 (호출하는 쪽의 트랜잭션 안에서 실행)
+fn insert_customer(conn: &Connection, data: CreateCustomerInput) -> Result<Customer, String> {
+    // 전화번호 중복 검사
+    if let Some(ref phone) = data.phone {
+        if !phone.is_empty() {
+            let exists: bool = conn
+                .query_row(
+                    "SELECT EXISTS(SELECT 1 FROM customers WHERE phone = ?1)",
+                    [phone],
+                    |row| row.get(0),
+                )
+                .map_err(|e| e.to_string())?;
+
+            if exists {
+                return Err("이미 등록된 전화번호입니다.".to_string());
+            }
+        }
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO customers (id, name, phone, email, birthdate, gender, preferred_designer_id,
+                               preferred_service, allergies, total_visits, last_visit_date, notes,
+                               created_at, updated_at, reminder_opt_out)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 0, NULL, ?10, ?11, ?11, ?12)",
+        rusqlite::params![
+            id,
+            data.name,
+            data.phone,
+            data.email,
+            data.birthdate,
+            data.gender,
+            data.preferred_designer_id,
+            data.preferred_service,
+            data.allergies,
+            data.notes,
+            now,
+            data.reminder_opt_out.unwrap_or(false) as i32,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    privacy::apply_consents(
+        conn,
+        &id,
+        [data.consent_service, data.consent_marketing, data.consent_photo],
+        [false; 3],
+    )?;
+
+    let customer = get_customer(conn, &id)?;
+    webhooks::emit(conn, webhooks::EVENT_CUSTOMER_CREATED, &customer)?;
+
+    Ok(customer)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedCustomerRow {
+    /// CSV 에서의 레코드 번호 (헤더가 1)
+    pub line: usize,
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerImportResult {
+    pub imported: usize,
+    pub skipped: Vec<SkippedCustomerRow>,
+}
+
+/// CSV 헤더 이름 (한글 또는 필드 이름)을 입력 필드로
+fn import_column(header: &str) -> Option<&'static str> {
+    Some(match header.trim().to_lowercase().as_str() {
+        "이름" | "고객명" | "name" => "name",
+        "연락처" | "전화번호" | "phone" => "phone",
+        "이메일" | "email" => "email",
+        "생년월일" | "birthdate" => "birthdate",
+        "성별" | "gender" => "gender",
+        "선호 서비스" | "preferred_service" | "preferredservice" => "preferred_service",
+        "알레르기" | "allergies" => "allergies",
+        "메모" | "notes" => "notes",
+        _ => return None,
+    })
+}
+
+/// 첫 줄이 헤더인 CSV 로 고객을 한꺼번에 등록한다. 이름이 비었거나 이미 등록된 전화번호인
+/// 줄은 건너뛰고 이유를 남긴다. 데이터베이스 오류가 나면 아무것도 등록하지 않는다.
+pub fn import_customers_csv(conn: &Connection, content: &str) -> Result<CustomerImportResult, String> {
+    let mut records = excel::parse_csv(content).into_iter();
+    let columns: Vec<Option<&str>> = records
+        .next()
+        .ok_or("CSV 파일이 비어 있습니다")?
+        .iter()
+        .map(|h| import_column(h))
+        .collect();
+    if !columns.contains(&Some("name")) {
+        return Err("CSV 헤더에 이름(name) 열이 없습니다".to_string());
+    }
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut result = CustomerImportResult { imported: 0, skipped: Vec::new() };
+    for (index, record) in records.enumerate() {
+        let value = |column: &str| {
+            columns
+                .iter()
+                .position(|c| *c == Some(column))
+                .and_then(|i| record.get(i))
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let input = CreateCustomerInput {
+            name: value("name").unwrap_or_default(),
+            phone: value("phone"),
+            email: value("email"),
+            birthdate: value("birthdate"),
+            gender: value("gender"),
+            preferred_designer_id: None,
+            preferred_service: value("preferred_service"),
+            allergies: value("allergies"),
+            notes: value("notes"),
+            reminder_opt_out: None,
+            consent_service: None,
+            consent_marketing: None,
+            consent_photo: None,
+        };
+        let line = index + 2;
+        if input.name.is_empty() {
+            result.skipped.push(SkippedCustomerRow { line, name: String::new(), reason: "이름이 없습니다".to_string() });
+            continue;
+        }
+        if let Some(phone) = &input.phone {
+            if get_customer_by_phone(&tx, phone)?.is_some() {
+                let reason = "이미 등록된 전화번호입니다".to_string();
+                result.skipped.push(SkippedCustomerRow { line, name: input.name, reason });
+                continue;
+            }
+        }
+        insert_customer(&tx, input).map_err(|e| format!("{}번째 줄을 등록하지 못했습니다: {}", line, e))?;
+        result.imported += 1;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(result)
+}
+
+pub fn update_customer(
+    conn: &Connection,
+    id: &str,
+    data: UpdateCustomerInput,
+) -> Result<Customer, String> {
+    // 기존 데이터 조회
+    let current: Customer = conn
+        .query_row(
+            &format!("SELECT {} FROM customers WHERE id = ?1", CUSTOMER_COLUMNS),
+            [id],
+            row_to_customer,
+        )
+        .map_err(|e| e.to_string())?;
+
+    // 전화번호 변경 시 중복 검사
+    if let Some(ref new_phone) = data.phone {
+        if !new_phone.is_empty() && Some(new_phone.clone()) != current.phone {
+            let exists: bool = conn
+                .query_row(
+                    "SELECT EXISTS(SELECT 1 FROM customers WHERE phone = ?1 AND id != ?2)",
+                    rusqlite::params![new_phone, id],
+                    |row| row.get(0),
+                )
+                .map_err(|e| e.to_string())?;
+
+            if exists {
+                return Err("이미 등록된 전화번호입니다.".to_string());
+            }
+        }
+    }
+
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let name = data.name.unwrap_or(current.name);
+    let phone = data.phone.or(current.phone);
+    let email = data.email.or(current.email);
+    let birthdate = data.birthdate.or(current.birthdate);
+    let gender = data.gender.or(current.gender);
+    let preferred_designer_id = data.preferred_designer_id.or(current.preferred_designer_id);
+    let preferred_service = data.preferred_service.or(current.preferred_service);
+    let allergies = data.allergies.or(current.allergies);
+    let notes = data.notes.or(current.notes);
+    let reminder_opt_out = data.reminder_opt_out.unwrap_or(current.reminder_opt_out);
+
+    conn.execute(
+        "UPDATE customers SET name = ?2, phone = ?3, email = ?4, birthdate = ?5, gender = ?6,
+         preferred_designer_id = ?7, preferred_service = ?8, allergies = ?9, notes = ?10,
+         updated_at = ?11, reminder_opt_out = ?12 WHERE id = ?1",
+        rusqlite::params![
+            id, name, phone, email, birthdate, gender,
+            preferred_designer_id, preferred_service, allergies, notes, now,
+            reminder_opt_out as i32
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    privacy::apply_consents(
+        conn,
+        id,
+        [data.consent_service, data.consent_marketing, data.consent_photo],
+        [current.consent_service, current.consent_marketing, current.consent_photo],
+    )?;
+
+    get_customer(conn, id)
+}
+
+pub fn delete_customer(conn: &Connection, id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM customers WHERE id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub fn search_customers(conn: &Connection, query: &str) -> Result<Vec<Customer>, String> {
+    let search_pattern = format!("%{}%", query);
+
+    let mut stmt = conn
+        .prepare(
+            &format!("SELECT {} FROM customers
+             WHERE name LIKE ?1 OR phone LIKE ?1
+             ORDER BY name
+             LIMIT 10", CUSTOMER_COLUMNS)
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([&search_pattern], row_to_customer)
+        .map_err(|e| e.to_string())?;
+
+    let customers: Vec<Customer> = rows.filter_map(|r| r.ok()).collect();
+
+    Ok(customers)
+}
+
+pub fn get_customer_by_phone(conn: &Connection, phone: &str) -> Result<Option<Customer>, String> {
+    let result = conn.query_row(
+        &format!("SELECT {} FROM customers WHERE phone = ?1", CUSTOMER_COLUMNS),
+        [phone],
+        row_to_customer,
+    );
+
+    match result {
+        Ok(customer) => Ok(Some(customer)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn get_customer_reservations(
+    conn: &Connection,
+    customer_id: &str,
+) -> Result<Vec<CustomerReservation>, String> {
+    // 고객 이름 조회
+    let customer_name: String = conn
+        .query_row(
+            "SELECT name FROM customers WHERE id = ?1",
+            [customer_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    // customer_id 또는 customer_name으로 예약 조회
+    let mut stmt = conn
+        .prepare(
+            "SELECT r.id, r.date, r.time, r.service_type, d.name as designer_name, r.status, r.notes
+             FROM reservations r
+             LEFT JOIN designers d ON r.designer_id = d.id
+             WHERE r.customer_id = ?1 OR r.customer_name = ?2
+             ORDER BY r.date DESC, r.time DESC"
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![customer_id, customer_name], |row| {
+            Ok(CustomerReservation {
+                id: row.get(0)?,
+                date: row.get(1)?,
+                time: row.get(2)?,
+                service_type: row.get(3)?,
+                designer_name: row.get(4)?,
+                status: row.get(5)?,
+                notes: row.get(6)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let reservations: Vec<CustomerReservation> = rows.filter_map(|r| r.ok()).collect();
+
+    Ok(reservations)
+}
+
+pub fn update_customer_visit_stats(conn: &Connection, customer_id: &str) -> Result<(), String> {
+    // 고객 이름 조회
+    let customer_name: String = conn
+        .query_row(
+            "SELECT name FROM customers WHERE id = ?1",
+            [customer_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    // 완료된 예약 수 계산
+    let total_visits: i32 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM reservations
+             WHERE (customer_id = ?1 OR customer_name = ?2) AND status = 'completed'",
+            rusqlite::params![customer_id, customer_name],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    // 마지막 방문일 조회
+    let last_visit_date: Option<String> = conn
+        .query_row(
+            "SELECT date FROM reservations
+             WHERE (customer_id = ?1 OR customer_name = ?2) AND status = 'completed'
+             ORDER BY date DESC LIMIT 1",
+            rusqlite::params![customer_id, customer_name],
+            |row| row.get(0),
+        )
+        .ok();
+
+    // 고객 정보 업데이트
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    conn.execute(
+        "UPDATE customers SET total_visits = ?2, last_visit_date = ?3, updated_at = ?4 WHERE id = ?1",
+        rusqlite::params![customer_id, total_visits, last_visit_date, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
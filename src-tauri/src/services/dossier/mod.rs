@@ -0,0 +1,274 @@
+//! 고객 개인정보 열람 자료 (정보주체 열람 요청 대응)
+//!
+//! 고객 한 명에 대해 보관 중인 정보(프로필, 동의 이력, 예약·시술 기록, 보낸 메시지)를
+//! 한데 모아 JSON 또는 PDF 로 만든다.
+
+pub mod pdf;
+
+use rusqlite::Connection;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::services::customers::{self, Customer};
+use crate::services::messages::{self, Message};
+use crate::services::privacy::{self, ConsentRecord};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusChange {
+    pub old_status: Option<String>,
+    pub new_status: String,
+    pub changed_at: Option<String>,
+}
+
+/// 예약 한 건 = 시술 기록 한 건
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DossierReservation {
+    pub id: String,
+    pub date: String,
+    pub time: String,
+    pub designer_name: Option<String>,
+    pub service_type: Option<String>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub created_at: Option<String>,
+    pub status_history: Vec<StatusChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerDossier {
+    pub generated_at: String,
+    pub customer: Customer,
+    pub consents: Vec<ConsentRecord>,
+    pub reservations: Vec<DossierReservation>,
+    pub messages: Vec<Message>,
+}
+
+pub fn build_dossier(conn: &Connection, customer_id: &str) -> Result<CustomerDossier, String> {
+    let customer = customers::get_customer(conn, customer_id)?;
+
+    // 전화번호로만 연결된 예약도 포함
+    let mut stmt = conn
+        .prepare(
+            "SELECT r.id, r.date, r.time, d.name, r.service_type, r.status, r.notes, r.created_at
+             FROM reservations r
+             LEFT JOIN designers d ON d.id = r.designer_id
+             WHERE r.customer_id = ?1
+                OR (r.customer_id IS NULL AND ?2 IS NOT NULL AND r.customer_phone = ?2)
+             ORDER BY r.date DESC, r.time DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let mut reservations: Vec<DossierReservation> = stmt
+        .query_map(rusqlite::params![customer_id, customer.phone], |row| {
+            Ok(DossierReservation {
+                id: row.get(0)?,
+                date: row.get(1)?,
+                time: row.get(2)?,
+                designer_name: row.get(3)?,
+                service_type: row.get(4)?,
+                status: row.get(5)?,
+                notes: row.get(6)?,
+                created_at: row.get(7)?,
+                status_history: Vec::new(),
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut history_stmt = conn
+        .prepare(
+            "SELECT old_status, new_status, changed_at FROM reservation_status_history
+             WHERE reservation_id = ?1 ORDER BY changed_at",
+        )
+        .map_err(|e| e.to_string())?;
+    for reservation in &mut reservations {
+        reservation.status_history = history_stmt
+            .query_map([&reservation.id], |row| {
+                Ok(StatusChange {
+                    old_status: row.get(0)?,
+                    new_status: row.get(1)?,
+                    changed_at: row.get(2)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .filter_map(|r| r.ok())
+            .collect();
+    }
+
+    Ok(CustomerDossier {
+        generated_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        consents: privacy::get_consent_history(conn, customer_id)?,
+        messages: messages::get_customer_messages(conn, customer_id, Some(u32::MAX))?,
+        reservations,
+        customer,
+    })
+}
+
+pub fn write_json(dossier: &CustomerDossier, path: &Path) -> Result<PathBuf, String> {
+    let json = serde_json::to_string_pretty(dossier).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| format!("파일을 저장할 수 없습니다: {}", e))?;
+    Ok(path.to_path_buf())
+}
+
+fn or_dash(value: &Option<String>) -> &str {
+    value.as_deref().filter(|v| !v.is_empty()).unwrap_or("-")
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "동의" } else { "미동의" }
+}
+
+fn status_label(status: &str) -> &str {
+    match status {
+        "pending" => "대기",
+        "confirmed" => "확정",
+        "completed" => "완료",
+        "cancelled" => "취소",
+        "no_show" => "노쇼",
+        "sent" => "발송",
+        "failed" => "실패",
+        other => other,
+    }
+}
+
+pub fn render_pdf(dossier: &CustomerDossier) -> Vec<u8> {
+    let c = &dossier.customer;
+    let mut doc = pdf::PdfDocument::new();
+
+    doc.title("개인정보 열람 자료");
+    doc.line(&format!("작성일시: {}", dossier.generated_at));
+
+    doc.heading("1. 기본 정보");
+    doc.line(&format!("이름: {}", c.name));
+    doc.line(&format!("전화번호: {}", or_dash(&c.phone)));
+    doc.line(&format!("이메일: {}", or_dash(&c.email)));
+    doc.line(&format!("생년월일: {}", or_dash(&c.birthdate)));
+    doc.line(&format!("성별: {}", or_dash(&c.gender)));
+    doc.line(&format!("선호 시술: {}", or_dash(&c.preferred_service)));
+    doc.line(&format!("알레르기: {}", or_dash(&c.allergies)));
+    doc.line(&format!("메모: {}", or_dash(&c.notes)));
+    doc.line(&format!("방문 횟수: {}회 (마지막 방문 {})", c.total_visits, or_dash(&c.last_visit_date)));
+    doc.line(&format!("등록일: {}", c.created_at));
+    doc.line(&format!("예약 알림 수신: {}", if c.reminder_opt_out { "거부" } else { "수신" }));
+
+    doc.heading("2. 동의 현황");
+    doc.line(&format!("동의서 버전: {}", or_dash(&c.consent_version)));
+    doc.line(&format!("개인정보 수집·이용: {} ({})", yes_no(c.consent_service), or_dash(&c.consent_service_at)));
+    doc.line(&format!("마케팅 문자 수신: {} ({})", yes_no(c.consent_marketing), or_dash(&c.consent_marketing_at)));
+    doc.line(&format!("사진 촬영·활용: {} ({})", yes_no(c.consent_photo), or_dash(&c.consent_photo_at)));
+    if !dossier.consents.is_empty() {
+        doc.line("변경 이력:");
+        for record in &dossier.consents {
+            doc.indented(&format!(
+                "{} {} {} (버전 {})",
+                record.recorded_at,
+                match record.consent_type.as_str() {
+                    "service" => "개인정보 수집·이용",
+                    "marketing" => "마케팅 문자 수신",
+                    "photo" => "사진 촬영·활용",
+                    other => other,
+                },
+                yes_no(record.granted),
+                record.version
+            ));
+        }
+    }
+
+    doc.heading(&format!("3. 예약·시술 기록 ({}건)", dossier.reservations.len()));
+    if dossier.reservations.is_empty() {
+        doc.line("기록이 없습니다.");
+    }
+    for r in &dossier.reservations {
+        doc.line(&format!(
+            "{} {} · {} · {} · {}",
+            r.date,
+            r.time,
+            or_dash(&r.service_type),
+            or_dash(&r.designer_name),
+            status_label(&r.status)
+        ));
+        if let Some(notes) = r.notes.as_deref().filter(|n| !n.is_empty()) {
+            doc.indented(&format!("메모: {}", notes));
+        }
+    }
+
+    doc.heading(&format!("4. 보낸 메시지 ({}건)", dossier.messages.len()));
+    if dossier.messages.is_empty() {
+        doc.line("기록이 없습니다.");
+    }
+    for m in &dossier.messages {
+        doc.line(&format!(
+            "{} · {} · {} · {}",
+            m.sent_at.as_deref().unwrap_or(&m.created_at),
+            m.channel,
+            m.recipient,
+            status_label(&m.status)
+        ));
+        doc.indented(&m.body);
+    }
+
+    doc.finish()
+}
+
+pub fn write_pdf(dossier: &CustomerDossier, path: &Path) -> Result<PathBuf, String> {
+    std::fs::write(path, render_pdf(dossier)).map_err(|e| format!("파일을 저장할 수 없습니다: {}", e))?;
+    Ok(path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    #[test]
+    fn test_customer_dossier() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        conn.execute_batch(
+            "INSERT INTO customers (id, name, phone, allergies) VALUES ('c1', '김민지', '010-1111-1111', '염색약');
+             INSERT INTO designers (id, name) VALUES ('d1', '김지수');
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time, designer_id, service_type, customer_id)
+                 VALUES ('r1', '김민지', '010-1111-1111', '2025-03-01', '10:00', 'd1', '커트', 'c1');
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time, notes)
+                 VALUES ('r2', '김민지', '010-1111-1111', '2025-04-01', '11:00', '두피 예민');
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time)
+                 VALUES ('r3', '박서연', '010-2222-2222', '2025-04-01', '12:00');
+             INSERT INTO reservation_status_history (id, reservation_id, old_status, new_status)
+                 VALUES ('h1', 'r1', 'pending', 'completed');
+             INSERT INTO messages (id, customer_id, reservation_id, channel, template, recipient, body)
+                 VALUES ('m1', 'c1', 'r1', 'sms', 'reminder', '01011111111', '예약 안내');",
+        )
+        .unwrap();
+
+        let dossier = build_dossier(conn, "c1").unwrap();
+        assert_eq!(dossier.reservations.len(), 2);
+        assert_eq!(dossier.reservations[0].notes.as_deref(), Some("두피 예민"));
+        assert_eq!(dossier.reservations[1].status_history.len(), 1);
+        assert_eq!(dossier.reservations[1].designer_name.as_deref(), Some("김지수"));
+        assert_eq!(dossier.messages.len(), 1);
+
+        let pdf = render_pdf(&dossier);
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        // "김민지" 가 UTF-16 으로 기록됨
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("AE40BBFCC9C0"));
+    }
+
+    #[test]
+    fn test_pdf_pages_and_wrapping() {
+        let mut doc = pdf::PdfDocument::new();
+        for i in 0..120 {
+            doc.line(&format!("{}번째 줄 {}", i, "가".repeat(80)));
+        }
+        let pdf = doc.finish();
+        let text = String::from_utf8_lossy(&pdf);
+        // 한 줄이 두 줄로 접혀 240줄 → A4 여러 장
+        let pages = text.matches("/Type /Page ").count();
+        assert!(pages >= 4, "pages = {}", pages);
+        assert!(text.contains(&format!("/Count {}", pages)));
+    }
+}
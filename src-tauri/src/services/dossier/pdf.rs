@@ -0,0 +1,169 @@
+//! 의존성 없이 한글 텍스트 PDF 를 만드는 최소 구현
+//!
+//! 글꼴을 포함하지 않고 PDF 뷰어에 내장된 한글 CID 글꼴(HYGoThic-Medium, Adobe-Korea1)을
+//! 쓰므로 파일이 작다. 텍스트는 UTF-16BE 로 기록한다 (UniKS-UCS2-H).
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const BODY_SIZE: f32 = 10.0;
+const LINE_GAP: f32 = 1.45;
+
+pub struct PdfDocument {
+    pages: Vec<String>,
+    current: String,
+    y: f32,
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    pub fn title(&mut self, text: &str) {
+        self.write(text, 18.0, 0.0);
+        self.space(6.0);
+    }
+
+    pub fn heading(&mut self, text: &str) {
+        self.space(10.0);
+        self.write(text, 13.0, 0.0);
+        self.space(2.0);
+    }
+
+    pub fn line(&mut self, text: &str) {
+        self.write(text, BODY_SIZE, 0.0);
+    }
+
+    /// 들여쓴 본문
+    pub fn indented(&mut self, text: &str) {
+        self.write(text, BODY_SIZE, 14.0);
+    }
+
+    pub fn space(&mut self, points: f32) {
+        self.y -= points;
+    }
+
+    fn write(&mut self, text: &str, size: f32, indent: f32) {
+        let max_width = PAGE_WIDTH - MARGIN * 2.0 - indent;
+        for paragraph in text.split('\n') {
+            for line in wrap(paragraph, size, max_width) {
+                if self.y - size < MARGIN {
+                    self.new_page();
+                }
+                self.y -= size * LINE_GAP;
+                self.current.push_str(&format!(
+                    "BT /F1 {size} Tf {x:.1} {y:.1} Td <{hex}> Tj ET\n",
+                    x = MARGIN + indent,
+                    y = self.y,
+                    hex = utf16_hex(&line),
+                ));
+            }
+        }
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.current));
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        self.new_page();
+        let page_count = self.pages.len();
+
+        // 1: 카탈로그, 2: 페이지 목록, 3~5: 글꼴, 이후 페이지마다 (페이지, 내용) 2개
+        let page_ids: Vec<usize> = (0..page_count).map(|i| 6 + i * 2).collect();
+        let mut objects: Vec<String> = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Count {} /Kids [{}] >>",
+                page_count,
+                page_ids.iter().map(|id| format!("{} 0 R", id)).collect::<Vec<_>>().join(" ")
+            ),
+            "<< /Type /Font /Subtype /Type0 /BaseFont /HYGoThic-Medium /Encoding /UniKS-UCS2-H \
+             /DescendantFonts [4 0 R] >>"
+                .to_string(),
+            "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /HYGoThic-Medium \
+             /CIDSystemInfo << /Registry (Adobe) /Ordering (Korea1) /Supplement 2 >> \
+             /FontDescriptor 5 0 R /DW 1000 /W [1 95 500] >>"
+                .to_string(),
+            "<< /Type /FontDescriptor /FontName /HYGoThic-Medium /Flags 6 \
+             /FontBBox [-6 -145 1003 880] /ItalicAngle 0 /Ascent 880 /Descent -120 \
+             /CapHeight 880 /StemV 93 >>"
+                .to_string(),
+        ];
+        for (index, content) in self.pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                page_ids[index] + 1
+            ));
+            objects.push(format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content));
+        }
+
+        let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).as_bytes());
+        }
+        let xref_offset = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+        out
+    }
+}
+
+/// 영문·숫자는 반각, 그 밖의 글자는 전각으로 보고 너비를 추정한다
+fn char_width(c: char, size: f32) -> f32 {
+    if c.is_ascii() { size * 0.5 } else { size }
+}
+
+fn wrap(text: &str, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut width = 0.0;
+    for c in text.chars() {
+        let w = char_width(c, size);
+        if width + w > max_width && !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            width = 0.0;
+        }
+        line.push(c);
+        width += w;
+    }
+    lines.push(line);
+    lines
+}
+
+/// UniKS-UCS2-H 는 기본 다국어 평면만 표현할 수 있어 나머지 글자는 '?' 로 바꾼다
+fn utf16_hex(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let code = if (c as u32) <= 0xFFFF { c as u32 } else { '?' as u32 };
+            format!("{:04X}", code)
+        })
+        .collect()
+}
@@ -80,7 +80,7 @@ pub fn export_reservations(
             )
             .map_err(|e| e.to_string())?;
         worksheet
-            .write_string_with_format(row, 6, &status_to_korean(&reservation.status), &text_format)
+            .write_string_with_format(row, 6, status_to_korean(&reservation.status), &text_format)
             .map_err(|e| e.to_string())?;
         worksheet
             .write_string_with_format(
@@ -143,6 +143,41 @@ fn escape_csv(value: &str) -> String {
     }
 }
 
+/// CSV 를 줄(레코드)과 칸으로 나눈다. 큰따옴표로 감싼 칸 안의 쉼표·줄바꿈과 `""` 를 처리하고,
+/// 엑셀이 붙이는 BOM 과 빈 줄은 버린다.
+pub fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    records
+}
+
 fn get_reservations_for_period(
     conn: &Connection,
     period: &str,
@@ -164,7 +199,7 @@ fn get_reservations_for_period(
             let start = end - chrono::Duration::days(90);
             (start.to_string(), end.to_string())
         }
-        "all" | _ => ("1970-01-01".to_string(), "2099-12-31".to_string()),
+        _ => ("1970-01-01".to_string(), "2099-12-31".to_string()),
     };
 
     let mut stmt = conn
@@ -0,0 +1,382 @@
+use chrono::{Datelike, NaiveDate};
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::services::lunar;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarType {
+    Solar,
+    Lunar,
+}
+
+impl CalendarType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalendarType::Solar => "solar",
+            CalendarType::Lunar => "lunar",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "solar" => Ok(CalendarType::Solar),
+            "lunar" => Ok(CalendarType::Lunar),
+            _ => Err(format!("Invalid calendar type: {}", value)),
+        }
+    }
+}
+
+/// A row of the `holidays` table as a rule that can be expanded for any year
+#[derive(Debug, Clone)]
+pub struct HolidayRule {
+    pub id: String,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub description: Option<String>,
+    pub is_recurring: bool,
+    pub calendar_type: CalendarType,
+}
+
+/// A concrete solar date on which a holiday falls
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolidayOccurrence {
+    pub holiday_id: String,
+    pub date: String,
+    pub description: Option<String>,
+    pub calendar_type: CalendarType,
+    pub is_recurring: bool,
+}
+
+/// Korean public holidays bundled with the app: (calendar, month, day, name).
+/// Lunar 12/30 means the last day of the 12th month, i.e. the eve of Seollal.
+/// Substitute holidays (대체공휴일) and election days are not included.
+pub const KOREAN_PUBLIC_HOLIDAYS: &[(CalendarType, u32, u32, &str)] = &[
+    (CalendarType::Solar, 1, 1, "신정"),
+    (CalendarType::Lunar, 12, 30, "설날 연휴"),
+    (CalendarType::Lunar, 1, 1, "설날"),
+    (CalendarType::Lunar, 1, 2, "설날 연휴"),
+    (CalendarType::Solar, 3, 1, "삼일절"),
+    (CalendarType::Lunar, 4, 8, "부처님오신날"),
+    (CalendarType::Solar, 5, 5, "어린이날"),
+    (CalendarType::Solar, 6, 6, "현충일"),
+    (CalendarType::Solar, 8, 15, "광복절"),
+    (CalendarType::Lunar, 8, 14, "추석 연휴"),
+    (CalendarType::Lunar, 8, 15, "추석"),
+    (CalendarType::Lunar, 8, 16, "추석 연휴"),
+    (CalendarType::Solar, 10, 3, "개천절"),
+    (CalendarType::Solar, 10, 9, "한글날"),
+    (CalendarType::Solar, 12, 25, "성탄절"),
+];
+
+fn parse_rule_date(date: &str) -> Option<(i32, u32, u32)> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Some((year, month, day))
+}
+
+/// Solar dates must be real dates; lunar dates may use day 30 of any month
+pub fn validate_date(date: &str, calendar_type: CalendarType) -> Result<(), String> {
+    let valid = match calendar_type {
+        CalendarType::Solar => NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok(),
+        CalendarType::Lunar => date.len() == 10
+            && matches!(parse_rule_date(date), Some((_, m, d)) if (1..=12).contains(&m) && (1..=30).contains(&d)),
+    };
+    if !valid {
+        return Err(format!("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD): {}", date));
+    }
+    Ok(())
+}
+
+pub fn load_rules(conn: &Connection) -> Result<Vec<HolidayRule>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, date, description, is_recurring, calendar_type FROM holidays")
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            let id: String = row.get(0)?;
+            let date: String = row.get(1)?;
+            let description: Option<String> = row.get(2)?;
+            let is_recurring: i32 = row.get(3)?;
+            let calendar_type: String = row.get(4)?;
+            Ok((id, date, description, is_recurring == 1, calendar_type))
+        })
+        .map_err(|e| e.to_string())?;
+
+    let mut rules = Vec::new();
+    for (id, date, description, is_recurring, calendar_type) in rows.filter_map(|r| r.ok()) {
+        let Some((year, month, day)) = parse_rule_date(&date) else {
+            println!("[Holidays] Skipping holiday with invalid date: {} ({})", date, id);
+            continue;
+        };
+        rules.push(HolidayRule {
+            id,
+            year,
+            month,
+            day,
+            description,
+            is_recurring,
+            calendar_type: CalendarType::parse(&calendar_type).unwrap_or(CalendarType::Solar),
+        });
+    }
+    Ok(rules)
+}
+
+impl HolidayRule {
+    /// Solar dates in `year` on which this holiday falls
+    pub fn dates_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        match (self.calendar_type, self.is_recurring) {
+            (CalendarType::Solar, false) => NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+                .filter(|d| d.year() == year)
+                .into_iter()
+                .collect(),
+            // 2월 29일은 윤년에만 적용
+            (CalendarType::Solar, true) => NaiveDate::from_ymd_opt(year, self.month, self.day)
+                .into_iter()
+                .collect(),
+            (CalendarType::Lunar, false) => lunar::lunar_to_solar_clamped(self.year, self.month, self.day)
+                .ok()
+                .flatten()
+                .filter(|d| d.year() == year)
+                .into_iter()
+                .collect(),
+            // 음력 날짜는 양력으로 전년도 말 또는 해당 연도에 걸칠 수 있다
+            (CalendarType::Lunar, true) => [year - 1, year]
+                .into_iter()
+                .filter_map(|lunar_year| {
+                    lunar::lunar_to_solar_clamped(lunar_year, self.month, self.day).ok().flatten()
+                })
+                .filter(|d| d.year() == year)
+                .collect(),
+        }
+    }
+
+    /// Whether this holiday falls on `date`. `lunar_date` is `date` converted
+    /// to the lunar calendar (computed once by the caller).
+    fn matches(&self, date: NaiveDate, lunar_date: Option<&lunar::LunarDate>) -> bool {
+        match self.calendar_type {
+            CalendarType::Solar => {
+                date.month() == self.month
+                    && date.day() == self.day
+                    && (self.is_recurring || date.year() == self.year)
+            }
+            CalendarType::Lunar => {
+                let Some(lunar_date) = lunar_date else {
+                    return false;
+                };
+                if lunar_date.is_leap_month
+                    || lunar_date.month != self.month
+                    || (!self.is_recurring && lunar_date.year != self.year)
+                {
+                    return false;
+                }
+                if lunar_date.day == self.day {
+                    return true;
+                }
+                // 30일이 없는 작은달이면 그 달의 마지막 날로 본다
+                self.day > lunar_date.day
+                    && lunar::lunar_month_days(lunar_date.year, lunar_date.month, false)
+                        .ok()
+                        .flatten()
+                        == Some(lunar_date.day)
+            }
+        }
+    }
+}
+
+/// Description of the holiday falling on `date`, if any.
+/// Outer `None`: no holiday; inner `None`: holiday without description.
+pub fn holiday_on(conn: &Connection, date: NaiveDate) -> Result<Option<Option<String>>, String> {
+    let rules = load_rules(conn)?;
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    let lunar_date = if rules.iter().any(|r| r.calendar_type == CalendarType::Lunar) {
+        lunar::solar_to_lunar(date).ok()
+    } else {
+        None
+    };
+
+    Ok(rules
+        .into_iter()
+        .find(|r| r.matches(date, lunar_date.as_ref()))
+        .map(|r| r.description))
+}
+
+/// All holiday occurrences within a solar year, sorted by date
+pub fn occurrences_in_year(conn: &Connection, year: i32) -> Result<Vec<HolidayOccurrence>, String> {
+    let mut occurrences: Vec<HolidayOccurrence> = load_rules(conn)?
+        .into_iter()
+        .flat_map(|rule| {
+            rule.dates_in_year(year)
+                .into_iter()
+                .map(|date| HolidayOccurrence {
+                    holiday_id: rule.id.clone(),
+                    date: date.format("%Y-%m-%d").to_string(),
+                    description: rule.description.clone(),
+                    calendar_type: rule.calendar_type,
+                    is_recurring: rule.is_recurring,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    occurrences.sort_by(|a, b| a.date.cmp(&b.date));
+    Ok(occurrences)
+}
+
+/// Insert the bundled Korean public holidays as recurring rules.
+/// Existing rows on the same date are marked recurring instead of duplicated.
+/// Returns (added, updated).
+pub fn import_korean_public_holidays(conn: &Connection, anchor_year: i32) -> Result<(usize, usize), String> {
+    let mut added = 0;
+    let mut updated = 0;
+
+    for (calendar_type, month, day, name) in KOREAN_PUBLIC_HOLIDAYS {
+        let date = format!("{:04}-{:02}-{:02}", anchor_year, month, day);
+        let existing: Option<(String, i32)> = conn
+            .query_row(
+                "SELECT id, is_recurring FROM holidays WHERE date = ?1 AND calendar_type = ?2",
+                rusqlite::params![date, calendar_type.as_str()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?;
+
+        match existing {
+            Some((_, 1)) => {}
+            Some((id, _)) => {
+                conn.execute("UPDATE holidays SET is_recurring = 1 WHERE id = ?1", [&id])
+                    .map_err(|e| e.to_string())?;
+                updated += 1;
+            }
+            None => {
+                conn.execute(
+                    "INSERT INTO holidays (id, date, description, is_recurring, calendar_type) VALUES (?1, ?2, ?3, 1, ?4)",
+                    rusqlite::params![uuid::Uuid::new_v4().to_string(), date, name, calendar_type.as_str()],
+                )
+                .map_err(|e| e.to_string())?;
+                added += 1;
+            }
+        }
+    }
+
+    Ok((added, updated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(calendar_type: CalendarType, date: &str, is_recurring: bool) -> HolidayRule {
+        let (year, month, day) = parse_rule_date(date).unwrap();
+        HolidayRule { id: date.to_string(), year, month, day, description: None, is_recurring, calendar_type }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn dates(values: &[&str]) -> Vec<NaiveDate> {
+        values.iter().map(|v| date(v)).collect()
+    }
+
+    fn matches(rule: &HolidayRule, value: &str) -> bool {
+        let day = date(value);
+        rule.matches(day, lunar::solar_to_lunar(day).ok().as_ref())
+    }
+
+    #[test]
+    fn test_seollal_and_chuseok_across_years() {
+        let seollal = rule(CalendarType::Lunar, "2024-01-01", true);
+        let chuseok = rule(CalendarType::Lunar, "2024-08-15", true);
+
+        assert_eq!(seollal.dates_in_year(2024), dates(&["2024-02-10"]));
+        assert_eq!(seollal.dates_in_year(2025), dates(&["2025-01-29"]));
+        assert_eq!(seollal.dates_in_year(2026), dates(&["2026-02-17"]));
+        assert_eq!(chuseok.dates_in_year(2024), dates(&["2024-09-17"]));
+        assert_eq!(chuseok.dates_in_year(2025), dates(&["2025-10-06"]));
+
+        for day in ["2024-02-10", "2025-01-29", "2026-02-17"] {
+            assert!(matches(&seollal, day), "{}", day);
+        }
+        assert!(matches(&chuseok, "2025-10-06"));
+        assert!(!matches(&chuseok, "2025-10-05"));
+        assert!(!matches(&seollal, "2025-01-01"));
+
+        // 반복하지 않는 음력 휴일은 그 해에만
+        let once = rule(CalendarType::Lunar, "2024-08-15", false);
+        assert_eq!(once.dates_in_year(2024), dates(&["2024-09-17"]));
+        assert!(once.dates_in_year(2025).is_empty());
+        assert!(!matches(&once, "2025-10-06"));
+    }
+
+    #[test]
+    fn test_seollal_eve_falls_in_the_next_solar_year_and_clamps_to_day_29() {
+        let eve = rule(CalendarType::Lunar, "2024-12-30", true);
+
+        // 음력 2023년 12월은 30일까지 있어 2024-02-09
+        assert_eq!(eve.dates_in_year(2024), dates(&["2024-02-09"]));
+        assert!(matches(&eve, "2024-02-09"));
+        assert!(!matches(&eve, "2024-02-08"));
+
+        // 음력 2024년 12월은 29일까지라 마지막 날(2025-01-28)로 본다. 같은 해 음력 12월 30일(2026-02)은 넣지 않는다
+        assert_eq!(lunar::lunar_month_days(2024, 12, false).unwrap(), Some(29));
+        assert_eq!(eve.dates_in_year(2025), dates(&["2025-01-28"]));
+        assert!(matches(&eve, "2025-01-28"));
+        assert!(!matches(&eve, "2025-01-27"));
+
+        assert_eq!(eve.dates_in_year(2026), dates(&["2026-02-16"]));
+        assert!(matches(&eve, "2026-02-16"));
+
+        // 29일로 지정한 휴일은 30일이 있는 달에도 29일만
+        let day_29 = rule(CalendarType::Lunar, "2024-12-29", true);
+        assert_eq!(day_29.dates_in_year(2024), dates(&["2024-02-08"]));
+        assert!(!matches(&day_29, "2024-02-09"));
+    }
+
+    #[test]
+    fn test_solar_rules() {
+        let leap_day = rule(CalendarType::Solar, "2024-02-29", true);
+        assert_eq!(leap_day.dates_in_year(2028), dates(&["2028-02-29"]));
+        assert!(leap_day.dates_in_year(2025).is_empty());
+
+        let once = rule(CalendarType::Solar, "2025-05-02", false);
+        assert_eq!(once.dates_in_year(2025), dates(&["2025-05-02"]));
+        assert!(once.dates_in_year(2026).is_empty());
+        assert!(matches(&once, "2025-05-02"));
+        assert!(!matches(&once, "2026-05-02"));
+        assert!(matches(&rule(CalendarType::Solar, "2020-12-25", true), "2031-12-25"));
+    }
+
+    #[test]
+    fn test_holiday_on_with_bundled_holidays() {
+        let db = crate::db::Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        assert_eq!(holiday_on(conn, date("2025-01-28")).unwrap(), None);
+
+        assert_eq!(import_korean_public_holidays(conn, 2025).unwrap(), (KOREAN_PUBLIC_HOLIDAYS.len(), 0));
+        assert_eq!(import_korean_public_holidays(conn, 2025).unwrap(), (0, 0));
+
+        let name = |value: &str| holiday_on(conn, date(value)).unwrap().flatten();
+        assert_eq!(name("2025-01-28").as_deref(), Some("설날 연휴"));
+        assert_eq!(name("2025-01-29").as_deref(), Some("설날"));
+        assert_eq!(name("2025-01-30").as_deref(), Some("설날 연휴"));
+        assert_eq!(name("2024-02-09").as_deref(), Some("설날 연휴"));
+        assert_eq!(name("2024-09-17").as_deref(), Some("추석"));
+        assert_eq!(name("2025-10-07").as_deref(), Some("추석 연휴"));
+        assert_eq!(name("2026-03-01").as_deref(), Some("삼일절"));
+        assert_eq!(holiday_on(conn, date("2025-01-27")).unwrap(), None);
+
+        let in_2025: Vec<String> = occurrences_in_year(conn, 2025).unwrap().into_iter().map(|o| o.date).collect();
+        assert_eq!(in_2025.len(), KOREAN_PUBLIC_HOLIDAYS.len());
+        assert_eq!(in_2025[..4], ["2025-01-01", "2025-01-28", "2025-01-29", "2025-01-30"]);
+    }
+}
@@ -0,0 +1,389 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use rusqlite::Connection;
+use std::path::PathBuf;
+
+/// 기간을 정하지 않았을 때 내보내는 범위 (전체)
+pub const DEFAULT_DATE_FROM: &str = "1970-01-01";
+pub const DEFAULT_DATE_TO: &str = "2099-12-31";
+
+/// 모든 예약 시간은 한국 표준시로 저장된다
+pub const TZID: &str = "Asia/Seoul";
+
+/// 예약에는 종료 시간이 없으므로 캘린더에서는 1시간짜리 일정으로 표시한다
+pub const DEFAULT_DURATION_MINUTES: i64 = 60;
+
+const PRODID: &str = "-//Sisters Salon//Reservation App//KO";
+pub const UID_DOMAIN: &str = "sisters-salon";
+
+pub struct IcsReservation {
+    pub id: String,
+    pub date: String,
+    pub time: String,
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub designer_name: Option<String>,
+    pub service_type: Option<String>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    /// 예약 내용이 바뀔 때마다 1씩 커지는 SEQUENCE (ical_events)
+    pub sequence: i64,
+    /// 삭제된 예약. 고객 정보 없이 취소된 일정으로만 내보낸다.
+    pub deleted: bool,
+}
+
+/// 예약 id 로부터 만든 UID. 같은 예약을 다시 내보내도 UID 가 같아야
+/// 캘린더 앱이 기존 일정을 갱신한다.
+pub fn event_uid(reservation_id: &str) -> String {
+    format!("{}@{}", reservation_id, UID_DOMAIN)
+}
+
+pub fn status_to_ical(status: &str) -> &'static str {
+    match status {
+        "pending" => "TENTATIVE",
+        "cancelled" | "no_show" => "CANCELLED",
+        _ => "CONFIRMED",
+    }
+}
+
+/// RFC 5545 3.3.11 TEXT 값 이스케이프
+pub fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// RFC 5545 3.1 콘텐츠 라인을 75 옥텟 단위로 접는다 (UTF-8 문자는 쪼개지 않음)
+pub fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 70 * 3);
+    let mut octets = 0;
+    for c in line.chars() {
+        // 이어지는 줄은 앞의 공백 1 옥텟을 포함해 75 옥텟을 넘지 않아야 한다
+        if octets + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+fn parse_db_timestamp(value: Option<&str>) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value?, "%Y-%m-%d %H:%M:%S").ok()
+}
+
+fn format_utc(value: NaiveDateTime) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn event_start(reservation: &IcsReservation) -> Option<NaiveDateTime> {
+    let date = NaiveDate::parse_from_str(&reservation.date, "%Y-%m-%d").ok()?;
+    let time = NaiveTime::parse_from_str(&reservation.time, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(&reservation.time, "%H:%M:%S"))
+        .ok()?;
+    Some(date.and_time(time))
+}
+
+fn push_line(out: &mut String, line: &str) {
+    out.push_str(&fold_line(line));
+    out.push_str("\r\n");
+}
+
+fn push_event(out: &mut String, reservation: &IcsReservation, dtstamp: &str) {
+    let Some(start) = event_start(reservation) else {
+        println!("[iCal] Skipping reservation with invalid date/time: {}", reservation.id);
+        return;
+    };
+    let end = start + chrono::Duration::minutes(DEFAULT_DURATION_MINUTES);
+
+    let summary = match reservation.service_type.as_deref().filter(|s| !s.is_empty()) {
+        _ if reservation.deleted => "삭제된 예약".to_string(),
+        Some(service) => format!("{} - {}", reservation.customer_name, service),
+        None => reservation.customer_name.clone(),
+    };
+
+    let mut description = Vec::new();
+    if let Some(phone) = reservation.customer_phone.as_deref().filter(|s| !s.is_empty()) {
+        description.push(format!("연락처: {}", phone));
+    }
+    if let Some(designer) = reservation.designer_name.as_deref().filter(|s| !s.is_empty()) {
+        description.push(format!("디자이너: {}", designer));
+    }
+    if let Some(notes) = reservation.notes.as_deref().filter(|s| !s.is_empty()) {
+        description.push(format!("메모: {}", notes));
+    }
+
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", event_uid(&reservation.id)));
+    push_line(out, &format!("DTSTAMP:{}", dtstamp));
+    push_line(out, &format!("SEQUENCE:{}", reservation.sequence));
+    if let Some(created) = parse_db_timestamp(reservation.created_at.as_deref()) {
+        push_line(out, &format!("CREATED:{}", format_utc(created)));
+    }
+    if let Some(updated) = parse_db_timestamp(reservation.updated_at.as_deref()) {
+        push_line(out, &format!("LAST-MODIFIED:{}", format_utc(updated)));
+    }
+    push_line(out, &format!("DTSTART;TZID={}:{}", TZID, start.format("%Y%m%dT%H%M%S")));
+    push_line(out, &format!("DTEND;TZID={}:{}", TZID, end.format("%Y%m%dT%H%M%S")));
+    push_line(out, &format!("SUMMARY:{}", escape_text(&summary)));
+    if !description.is_empty() {
+        push_line(out, &format!("DESCRIPTION:{}", escape_text(&description.join("\n"))));
+    }
+    let status = if reservation.deleted { "CANCELLED" } else { status_to_ical(&reservation.status) };
+    push_line(out, &format!("STATUS:{}", status));
+    push_line(out, "END:VEVENT");
+}
+
+/// 예약 목록으로 VCALENDAR 문서를 만든다.
+/// 취소/노쇼 예약과 삭제된 예약도 STATUS:CANCELLED 로 포함해 캘린더 앱에서 일정이 취소되도록 한다.
+pub fn build_calendar(calendar_name: &str, reservations: &[IcsReservation]) -> String {
+    let dtstamp = format_utc(Utc::now().naive_utc());
+    let mut out = String::new();
+
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape_text(calendar_name)));
+    push_line(&mut out, &format!("X-WR-TIMEZONE:{}", TZID));
+
+    // 한국은 일광절약시간을 쓰지 않으므로 STANDARD 하나로 충분하다
+    push_line(&mut out, "BEGIN:VTIMEZONE");
+    push_line(&mut out, &format!("TZID:{}", TZID));
+    push_line(&mut out, "BEGIN:STANDARD");
+    push_line(&mut out, "DTSTART:19700101T000000");
+    push_line(&mut out, "TZOFFSETFROM:+0900");
+    push_line(&mut out, "TZOFFSETTO:+0900");
+    push_line(&mut out, "TZNAME:KST");
+    push_line(&mut out, "END:STANDARD");
+    push_line(&mut out, "END:VTIMEZONE");
+
+    for reservation in reservations {
+        push_event(&mut out, reservation, &dtstamp);
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+pub fn get_reservations_for_range(
+    conn: &Connection,
+    designer_id: Option<&str>,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<IcsReservation>, String> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT
+                r.id,
+                r.date,
+                r.time,
+                r.customer_name,
+                r.customer_phone,
+                d.name as designer_name,
+                r.service_type,
+                r.status,
+                r.notes,
+                r.created_at,
+                r.updated_at,
+                COALESCE(e.sequence, 0)
+            FROM reservations r
+            LEFT JOIN designers d ON r.designer_id = d.id
+            LEFT JOIN ical_events e ON e.reservation_id = r.id
+            WHERE r.date BETWEEN ?1 AND ?2
+              AND (?3 IS NULL OR r.designer_id = ?3)
+            ORDER BY r.date, r.time
+        "#,
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![date_from, date_to, designer_id], |row| {
+            Ok(IcsReservation {
+                id: row.get(0)?,
+                date: row.get(1)?,
+                time: row.get(2)?,
+                customer_name: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                customer_phone: row.get(4)?,
+                designer_name: row.get(5)?,
+                service_type: row.get(6)?,
+                status: row.get::<_, Option<String>>(7)?.unwrap_or_else(|| "pending".to_string()),
+                notes: row.get(8)?,
+                created_at: row.get(9)?,
+                updated_at: row.get(10)?,
+                sequence: row.get(11)?,
+                deleted: false,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let mut reservations: Vec<IcsReservation> = rows.filter_map(|r| r.ok()).collect();
+    reservations.extend(get_deleted_for_range(conn, designer_id, date_from, date_to)?);
+    Ok(reservations)
+}
+
+/// 기간 안에 있던 삭제된 예약 (ical_events 트리거가 기록)
+fn get_deleted_for_range(
+    conn: &Connection,
+    designer_id: Option<&str>,
+    date_from: &str,
+    date_to: &str,
+) -> Result<Vec<IcsReservation>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT reservation_id, date, time, sequence, deleted_at FROM ical_events
+             WHERE deleted_at IS NOT NULL
+               AND date BETWEEN ?1 AND ?2
+               AND (?3 IS NULL OR designer_id = ?3)
+             ORDER BY date, time",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![date_from, date_to, designer_id], |row| {
+            Ok(IcsReservation {
+                id: row.get(0)?,
+                date: row.get(1)?,
+                time: row.get(2)?,
+                customer_name: String::new(),
+                customer_phone: None,
+                designer_name: None,
+                service_type: None,
+                status: "cancelled".to_string(),
+                notes: None,
+                created_at: None,
+                updated_at: row.get(4)?,
+                sequence: row.get(3)?,
+                deleted: true,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+/// 디자이너 한 명(또는 살롱 전체)의 예약으로 .ics 내용을 만든다 (파일 내보내기와 API 구독 주소가 함께 쓴다)
+pub fn render_ics(conn: &Connection, designer_id: Option<&str>, date_from: &str, date_to: &str) -> Result<String, String> {
+    if date_from > date_to {
+        return Err("종료일은 시작일보다 빠를 수 없습니다".to_string());
+    }
+    let calendar_name = match designer_id {
+        Some(id) => {
+            let name: String = conn
+                .query_row("SELECT name FROM designers WHERE id = ?1", [id], |row| row.get(0))
+                .map_err(|_| "디자이너를 찾을 수 없습니다".to_string())?;
+            format!("Sisters Salon - {}", name)
+        }
+        None => "Sisters Salon".to_string(),
+    };
+
+    let reservations = get_reservations_for_range(conn, designer_id, date_from, date_to)?;
+    Ok(build_calendar(&calendar_name, &reservations))
+}
+
+/// 디자이너 한 명(또는 살롱 전체)의 예약을 .ics 파일로 저장
+pub fn export_ics(
+    conn: &Connection,
+    designer_id: Option<&str>,
+    date_from: &str,
+    date_to: &str,
+    output_path: PathBuf,
+) -> Result<PathBuf, String> {
+    let content = render_ics(conn, designer_id, date_from, date_to)?;
+    std::fs::write(&output_path, content.as_bytes()).map_err(|e| e.to_string())?;
+    Ok(output_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation(status: &str) -> IcsReservation {
+        IcsReservation {
+            id: "abc-123".to_string(),
+            date: "2025-03-14".to_string(),
+            time: "14:30".to_string(),
+            customer_name: "김민지".to_string(),
+            customer_phone: Some("010-1234-5678".to_string()),
+            designer_name: Some("수진".to_string()),
+            service_type: Some("커트, 염색".to_string()),
+            status: status.to_string(),
+            notes: Some("두피 민감; 저자극".to_string()),
+            created_at: Some("2025-03-01 01:00:00".to_string()),
+            updated_at: Some("2025-03-02 01:00:00".to_string()),
+            sequence: 3,
+            deleted: false,
+        }
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a,b;c\\d\r\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn test_fold_line_keeps_utf8_and_limit() {
+        let line = format!("DESCRIPTION:{}", "가".repeat(60));
+        let folded = fold_line(&line);
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= 75, "line too long: {}", part.len());
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn test_event_fields() {
+        let ics = build_calendar("Sisters Salon", &[reservation("cancelled")]);
+        let unfolded = ics.replace("\r\n ", "");
+        assert!(unfolded.contains("UID:abc-123@sisters-salon\r\n"));
+        assert!(unfolded.contains("DTSTART;TZID=Asia/Seoul:20250314T143000\r\n"));
+        assert!(unfolded.contains("DTEND;TZID=Asia/Seoul:20250314T153000\r\n"));
+        assert!(unfolded.contains("STATUS:CANCELLED\r\n"));
+        assert!(unfolded.contains("SEQUENCE:3\r\n"));
+        assert!(unfolded.contains("SUMMARY:김민지 - 커트\\, 염색\r\n"));
+        assert_eq!(status_to_ical("pending"), "TENTATIVE");
+        assert_eq!(status_to_ical("completed"), "CONFIRMED");
+    }
+
+    #[test]
+    fn test_sequence_counts_edits_and_deleted_reservations_are_cancelled() {
+        let db = crate::db::Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        conn.execute(
+            "INSERT INTO reservations (id, customer_name, date, time, status) VALUES ('r1', '김민지', '2025-03-14', '14:30', 'pending')",
+            [],
+        )
+        .unwrap();
+        let sequence = |conn: &Connection| get_reservations_for_range(conn, None, "2025-03-01", "2025-03-31").unwrap()[0].sequence;
+        assert_eq!(sequence(conn), 0);
+
+        conn.execute("UPDATE reservations SET time = '15:00' WHERE id = 'r1'", []).unwrap();
+        conn.execute("UPDATE reservations SET status = 'confirmed' WHERE id = 'r1'", []).unwrap();
+        assert_eq!(sequence(conn), 2);
+
+        conn.execute("DELETE FROM reservations WHERE id = 'r1'", []).unwrap();
+        let events = get_reservations_for_range(conn, None, "2025-03-01", "2025-03-31").unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].deleted);
+        assert_eq!(events[0].sequence, 3);
+
+        let unfolded = build_calendar("Sisters Salon", &events).replace("\r\n ", "");
+        assert!(unfolded.contains("UID:r1@sisters-salon\r\n"));
+        assert!(unfolded.contains("DTSTART;TZID=Asia/Seoul:20250314T150000\r\n"));
+        assert!(unfolded.contains("STATUS:CANCELLED\r\n"));
+        assert!(!unfolded.contains("김민지"));
+    }
+}
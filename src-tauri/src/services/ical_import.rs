@@ -0,0 +1,1293 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::services::{ical, schedule};
+
+/// 반복 일정 하나에서 만들어낼 수 있는 최대 예약 수
+const MAX_OCCURRENCES: usize = 2000;
+/// 날짜가 나오지 않는 규칙(예: 2월 31일)에서 무한히 돌지 않도록 하는 반복 주기 상한
+const MAX_PERIODS: u32 = 10_000;
+/// 종료 조건이 없는 반복 일정은 오늘부터 이 기간까지만 펼친다
+const OPEN_ENDED_DAYS: i64 = 365;
+
+const SEOUL: Tz = chrono_tz::Asia::Seoul;
+
+// 시술명에 흔히 쓰이는 단어. 이 단어가 들어간 토큰은 고객 이름으로 보지 않는다.
+const SERVICE_KEYWORDS: &[&str] = &[
+    "커트", "컷", "펌", "파마", "염색", "클리닉", "드라이", "매직", "셋팅", "세팅", "탈색", "두피", "헤드스파",
+    "스타일링", "뿌리", "다운", "트리트먼트", "예약",
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsFileInput {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsCalendarImport {
+    pub name: String,
+    pub content: String,
+    pub designer_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsImportInput {
+    pub calendars: Vec<IcsCalendarImport>,
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 매핑 단계에서 보여줄 캘린더 파일 요약
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsCalendarSummary {
+    pub file_name: String,
+    pub calendar_name: Option<String>,
+    pub event_count: usize,
+    pub recurring_count: usize,
+    pub first_date: Option<String>,
+    pub last_date: Option<String>,
+    pub suggested_designer_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+    Create,
+    Update,
+    Unchanged,
+    Skip,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsImportItem {
+    pub reservation_id: Option<String>,
+    pub source: String,
+    pub summary: Option<String>,
+    pub date: Option<String>,
+    pub time: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub service_type: Option<String>,
+    pub designer_id: Option<String>,
+    pub status: Option<String>,
+    pub action: ImportAction,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IcsImportResult {
+    pub dry_run: bool,
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub items: Vec<IcsImportItem>,
+}
+
+// ===== 파서 =====
+
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// RFC 5545 3.1 접힌 줄 펼치기
+fn unfold(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(rest) = raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        if !raw.is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+fn parse_property(line: &str) -> Option<Property> {
+    // 따옴표 안의 ':' 와 ';' 는 구분자가 아니다
+    let mut in_quotes = false;
+    let mut colon = None;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => {
+                colon = Some(i);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    in_quotes = false;
+    for c in head.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+
+    let mut parts = parts.into_iter();
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts
+        .filter_map(|p| {
+            let (k, v) = p.split_once('=')?;
+            Some((k.to_ascii_uppercase(), v.to_string()))
+        })
+        .collect();
+
+    Some(Property { name, params, value: value.to_string() })
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// TZID 를 IANA 시간대로 해석한다. Outlook 의 Windows 이름과
+/// "/mozilla.org/.../Asia/Seoul" 같은 접두어도 처리한다.
+fn resolve_tz(tzid: &str) -> Option<Tz> {
+    let tzid = tzid.trim_matches('"');
+    if let Ok(tz) = tzid.parse::<Tz>() {
+        return Some(tz);
+    }
+    let segments: Vec<&str> = tzid.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.len() >= 2 {
+        let suffix = segments[segments.len() - 2..].join("/");
+        if let Ok(tz) = suffix.parse::<Tz>() {
+            return Some(tz);
+        }
+    }
+    match tzid {
+        "Korea Standard Time" => Some(chrono_tz::Asia::Seoul),
+        "Tokyo Standard Time" => Some(chrono_tz::Asia::Tokyo),
+        "China Standard Time" => Some(chrono_tz::Asia::Shanghai),
+        "UTC" | "Coordinated Universal Time" => Some(chrono_tz::UTC),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Zone {
+    Utc,
+    Tz(Tz),
+}
+
+impl Zone {
+    /// 이 시간대의 현지 시각을 한국 시각으로 변환
+    fn to_kst(self, local: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            Zone::Utc => Utc.from_utc_datetime(&local).with_timezone(&SEOUL).naive_local(),
+            Zone::Tz(tz) => match tz.from_local_datetime(&local).earliest() {
+                Some(dt) => dt.with_timezone(&SEOUL).naive_local(),
+                // 일광절약시간 전환으로 존재하지 않는 시각이면 한 시간 뒤로 민다
+                None => tz
+                    .from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+                    .map(|dt| dt.with_timezone(&SEOUL).naive_local())
+                    .unwrap_or(local),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DateTimeValue {
+    local: NaiveDateTime,
+    zone: Zone,
+    all_day: bool,
+}
+
+impl DateTimeValue {
+    fn kst(&self) -> NaiveDateTime {
+        if self.all_day {
+            self.local
+        } else {
+            self.zone.to_kst(self.local)
+        }
+    }
+}
+
+fn parse_date_time(value: &str, tzid: Option<&str>, default_zone: Zone) -> Result<DateTimeValue, String> {
+    let value = value.trim();
+    if value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map_err(|_| format!("날짜를 해석할 수 없습니다: {}", value))?;
+        return Ok(DateTimeValue {
+            local: date.and_hms_opt(0, 0, 0).unwrap_or_default(),
+            zone: default_zone,
+            all_day: true,
+        });
+    }
+
+    let (raw, is_utc) = match value.strip_suffix('Z') {
+        Some(raw) => (raw, true),
+        None => (value, false),
+    };
+    let local = NaiveDateTime::parse_from_str(raw, "%Y%m%dT%H%M%S")
+        .map_err(|_| format!("날짜/시간을 해석할 수 없습니다: {}", value))?;
+
+    let zone = if is_utc {
+        Zone::Utc
+    } else {
+        match tzid {
+            Some(tzid) => resolve_tz(tzid).map(Zone::Tz).unwrap_or_else(|| {
+                println!("[ICS] Unknown TZID {}, using default zone", tzid);
+                default_zone
+            }),
+            None => default_zone,
+        }
+    };
+
+    Ok(DateTimeValue { local, zone, all_day: false })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone)]
+struct RecurrenceRule {
+    freq: Frequency,
+    interval: u32,
+    count: Option<usize>,
+    until: Option<NaiveDateTime>,
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// RRULE 중 미용실 일정에 쓰이는 부분(DAILY~YEARLY, INTERVAL, COUNT, UNTIL,
+/// BYDAY, BYMONTHDAY, BYMONTH)만 지원한다. UNTIL 은 한국 시각으로 변환해 둔다.
+fn parse_rrule(value: &str, zone: Zone) -> Result<RecurrenceRule, String> {
+    let mut freq = None;
+    let mut rule = RecurrenceRule {
+        freq: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: Vec::new(),
+        by_month_day: Vec::new(),
+        by_month: Vec::new(),
+    };
+    let unsupported = || format!("지원하지 않는 반복 규칙입니다: {}", value);
+
+    for part in value.split(';').filter(|p| !p.is_empty()) {
+        let (key, val) = part.split_once('=').ok_or_else(unsupported)?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match val {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    _ => return Err(unsupported()),
+                })
+            }
+            "INTERVAL" => rule.interval = val.parse().ok().filter(|i| *i > 0).ok_or_else(unsupported)?,
+            "COUNT" => rule.count = Some(val.parse().map_err(|_| unsupported())?),
+            "UNTIL" => {
+                let until = parse_date_time(val, None, zone)?;
+                rule.until = Some(if until.all_day {
+                    until.local.date().and_hms_opt(23, 59, 59).unwrap_or(until.local)
+                } else {
+                    until.kst()
+                });
+            }
+            "BYDAY" => {
+                for day in val.split(',') {
+                    let (ordinal, name) = day.split_at(day.len().saturating_sub(2));
+                    let weekday = parse_weekday(name).ok_or_else(unsupported)?;
+                    let ordinal = if ordinal.is_empty() {
+                        None
+                    } else {
+                        Some(ordinal.trim_start_matches('+').parse().map_err(|_| unsupported())?)
+                    };
+                    rule.by_day.push((ordinal, weekday));
+                }
+            }
+            "BYMONTHDAY" => {
+                for day in val.split(',') {
+                    rule.by_month_day.push(day.parse().map_err(|_| unsupported())?);
+                }
+            }
+            "BYMONTH" => {
+                for month in val.split(',') {
+                    rule.by_month.push(month.parse().map_err(|_| unsupported())?);
+                }
+            }
+            "WKST" => {}
+            _ => return Err(unsupported()),
+        }
+    }
+
+    rule.freq = freq.ok_or_else(unsupported)?;
+    Ok(rule)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .and_then(|d| d.pred_opt())
+        .map(|d| d.day())
+        .unwrap_or(28)
+}
+
+/// BYMONTHDAY / BYDAY 를 적용한 한 달 안의 날짜들 (둘 다 없으면 `default_day`)
+fn days_in_period_month(year: i32, month: u32, rule: &RecurrenceRule, default_day: u32) -> Vec<NaiveDate> {
+    let last = days_in_month(year, month);
+    let month_days: Vec<NaiveDate> = (1..=last)
+        .filter_map(|d| NaiveDate::from_ymd_opt(year, month, d))
+        .collect();
+
+    let mut days: Vec<NaiveDate> = if rule.by_day.is_empty() {
+        if rule.by_month_day.is_empty() {
+            month_days.iter().copied().filter(|d| d.day() == default_day).collect()
+        } else {
+            month_days.clone()
+        }
+    } else {
+        let mut matched = Vec::new();
+        for (ordinal, weekday) in &rule.by_day {
+            let same_weekday: Vec<NaiveDate> =
+                month_days.iter().copied().filter(|d| d.weekday() == *weekday).collect();
+            match ordinal {
+                None => matched.extend(same_weekday),
+                Some(n) if *n > 0 => matched.extend(same_weekday.get(*n as usize - 1).copied()),
+                Some(n) if *n < 0 => {
+                    let index = same_weekday.len() as i32 + n;
+                    if index >= 0 {
+                        matched.push(same_weekday[index as usize]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        matched
+    };
+
+    if !rule.by_month_day.is_empty() {
+        let wanted: Vec<u32> = rule
+            .by_month_day
+            .iter()
+            .filter_map(|d| {
+                let day = if *d > 0 { *d } else { last as i32 + 1 + d };
+                (1..=last as i32).contains(&day).then_some(day as u32)
+            })
+            .collect();
+        days.retain(|d| wanted.contains(&d.day()));
+    }
+
+    days.sort();
+    days.dedup();
+    days
+}
+
+fn add_months(date: NaiveDate, months: u32) -> Option<(i32, u32)> {
+    let total = (date.year() * 12 + date.month0() as i32).checked_add(i32::try_from(months).ok()?)?;
+    Some((total.div_euclid(12), total.rem_euclid(12) as u32 + 1))
+}
+
+/// 반복 일정을 펼쳐 한국 시각의 시작 시각 목록을 돌려준다 (`window_end` 이후는 버림)
+fn expand_rule(rule: &RecurrenceRule, start: &DateTimeValue, window_end: NaiveDate) -> Vec<NaiveDateTime> {
+    let first = start.local;
+    let time = first.time();
+    let mut occurrences = Vec::new();
+    let mut emitted = 0usize;
+
+    for period in 0..MAX_PERIODS {
+        let step = period.saturating_mul(rule.interval);
+        let (base, mut dates): (NaiveDate, Vec<NaiveDate>) = match rule.freq {
+            Frequency::Daily => {
+                let Some(day) = first.date().checked_add_signed(Duration::days(step as i64)) else { break };
+                let keep = rule.by_day.is_empty() || rule.by_day.iter().any(|(_, w)| *w == day.weekday());
+                (day, if keep { vec![day] } else { vec![] })
+            }
+            Frequency::Weekly => {
+                let Some(week_start) = first
+                    .date()
+                    .checked_sub_signed(Duration::days(first.weekday().num_days_from_monday() as i64))
+                    .and_then(|d| d.checked_add_signed(Duration::weeks(step as i64)))
+                else {
+                    break;
+                };
+                let mut weekdays: Vec<Weekday> = if rule.by_day.is_empty() {
+                    vec![first.weekday()]
+                } else {
+                    rule.by_day.iter().map(|(_, w)| *w).collect()
+                };
+                weekdays.sort_by_key(|w| w.num_days_from_monday());
+                weekdays.dedup();
+                let dates = weekdays
+                    .into_iter()
+                    .map(|w| week_start + Duration::days(w.num_days_from_monday() as i64))
+                    .collect();
+                (week_start, dates)
+            }
+            Frequency::Monthly => {
+                let Some((year, month)) = add_months(first.date(), step) else { break };
+                let Some(base) = NaiveDate::from_ymd_opt(year, month, 1) else { break };
+                (base, days_in_period_month(year, month, rule, first.day()))
+            }
+            Frequency::Yearly => {
+                let Some(year) = i32::try_from(step).ok().and_then(|s| first.year().checked_add(s)) else { break };
+                let Some(base) = NaiveDate::from_ymd_opt(year, 1, 1) else { break };
+                let months = if rule.by_month.is_empty() { vec![first.month()] } else { rule.by_month.clone() };
+                let dates = months
+                    .into_iter()
+                    .flat_map(|m| days_in_period_month(year, m, rule, first.day()))
+                    .collect();
+                (base, dates)
+            }
+        };
+
+        if base > window_end {
+            break;
+        }
+        if !rule.by_month.is_empty() && rule.freq != Frequency::Yearly {
+            dates.retain(|d| rule.by_month.contains(&d.month()));
+        }
+
+        for date in dates {
+            let local = date.and_time(time);
+            if local < first {
+                continue;
+            }
+            emitted += 1;
+            if rule.count.is_some_and(|count| emitted > count) {
+                return occurrences;
+            }
+            let kst = start.zone.to_kst(local);
+            if rule.until.is_some_and(|until| kst > until) || kst.date() > window_end {
+                return occurrences;
+            }
+            occurrences.push(kst);
+            if occurrences.len() >= MAX_OCCURRENCES {
+                return occurrences;
+            }
+        }
+    }
+
+    occurrences
+}
+
+#[derive(Debug, Default)]
+struct RawEvent {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    status: Option<String>,
+    start: Option<DateTimeValue>,
+    rrule: Option<String>,
+    exdates: Vec<NaiveDateTime>,
+    recurrence_id: Option<NaiveDateTime>,
+    error: Option<String>,
+}
+
+struct ParsedCalendar {
+    name: Option<String>,
+    events: Vec<RawEvent>,
+}
+
+fn apply_event_property(event: &mut RawEvent, prop: &Property, default_zone: Zone) -> Result<(), String> {
+    let value = prop.value.as_str();
+    let tzid = prop.param("TZID");
+    match prop.name.as_str() {
+        "UID" => event.uid = Some(value.to_string()),
+        "SUMMARY" => event.summary = Some(unescape_text(value)),
+        "DESCRIPTION" => event.description = Some(unescape_text(value)),
+        "STATUS" => event.status = Some(value.to_ascii_uppercase()),
+        "RRULE" => event.rrule = Some(value.to_string()),
+        "DTSTART" => event.start = Some(parse_date_time(value, tzid, default_zone)?),
+        "RECURRENCE-ID" => event.recurrence_id = Some(parse_date_time(value, tzid, default_zone)?.kst()),
+        "EXDATE" => {
+            for v in value.split(',') {
+                event.exdates.push(parse_date_time(v, tzid, default_zone)?.kst());
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn parse_calendar(content: &str) -> Result<ParsedCalendar, String> {
+    let lines = unfold(content);
+    if !lines.iter().any(|l| l.eq_ignore_ascii_case("BEGIN:VCALENDAR")) {
+        return Err("iCalendar(.ics) 파일이 아닙니다".to_string());
+    }
+
+    // 시간대 없는 시각은 X-WR-TIMEZONE, 없으면 한국 시간으로 본다
+    let default_zone = lines
+        .iter()
+        .filter_map(|l| parse_property(l))
+        .find(|p| p.name == "X-WR-TIMEZONE")
+        .and_then(|p| resolve_tz(&p.value))
+        .map(Zone::Tz)
+        .unwrap_or(Zone::Tz(SEOUL));
+
+    let mut calendar = ParsedCalendar { name: None, events: Vec::new() };
+    let mut current: Option<RawEvent> = None;
+    // VEVENT 안의 VALARM 등 하위 컴포넌트 깊이
+    let mut nested = 0;
+
+    for line in &lines {
+        let Some(prop) = parse_property(line) else { continue };
+        let value = prop.value.as_str();
+
+        match (prop.name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => current = Some(RawEvent::default()),
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                calendar.events.extend(current.take());
+            }
+            ("X-WR-CALNAME", None) => calendar.name = Some(unescape_text(value)),
+            (_, Some(_)) if nested > 0 => {}
+            (_, Some(event)) => {
+                if let Err(e) = apply_event_property(event, &prop, default_zone) {
+                    event.error.get_or_insert(e);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(calendar)
+}
+
+// ===== 고객 정보 추출 =====
+
+fn format_phone(digits: &str) -> Option<String> {
+    let digits = match digits.strip_prefix("82") {
+        Some(rest) if digits.len() >= 11 => format!("0{}", rest.trim_start_matches('0')),
+        _ => digits.to_string(),
+    };
+    if !digits.starts_with('0') {
+        return None;
+    }
+    let len = digits.len();
+    let formatted = if digits.starts_with("02") {
+        match len {
+            9 => format!("{}-{}-{}", &digits[..2], &digits[2..5], &digits[5..]),
+            10 => format!("{}-{}-{}", &digits[..2], &digits[2..6], &digits[6..]),
+            _ => return None,
+        }
+    } else {
+        match len {
+            10 => format!("{}-{}-{}", &digits[..3], &digits[3..6], &digits[6..]),
+            11 => format!("{}-{}-{}", &digits[..3], &digits[3..7], &digits[7..]),
+            _ => return None,
+        }
+    };
+    Some(formatted)
+}
+
+/// 텍스트에서 한국 전화번호를 찾아 (정규화된 번호, 원문 범위) 로 돌려준다
+fn find_phone(text: &str) -> Option<(String, std::ops::Range<usize>)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        if !(c.is_ascii_digit() || c == '+') {
+            i += 1;
+            continue;
+        }
+        let mut digits = String::new();
+        let mut end = start;
+        let mut j = i;
+        while j < chars.len() {
+            let (pos, ch) = chars[j];
+            if ch.is_ascii_digit() {
+                digits.push(ch);
+                end = pos + 1;
+            } else if !(matches!(ch, '-' | '.' | ' ' | '(' | ')') || (ch == '+' && j == i)) {
+                break;
+            }
+            j += 1;
+        }
+        if let Some(phone) = format_phone(&digits) {
+            return Some((phone, start..end));
+        }
+        i = j.max(i + 1);
+    }
+    None
+}
+
+fn is_hangul_name(token: &str) -> bool {
+    let count = token.chars().count();
+    (2..=4).contains(&count)
+        && token.chars().all(|c| ('가'..='힣').contains(&c))
+        && !SERVICE_KEYWORDS.iter().any(|k| token.contains(k))
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CustomerInfo {
+    name: Option<String>,
+    phone: Option<String>,
+    service: Option<String>,
+    designer_name: Option<String>,
+    notes: Option<String>,
+}
+
+fn labeled_value<'a>(line: &'a str, labels: &[&str]) -> Option<&'a str> {
+    let (label, value) = line.split_once(':').or_else(|| line.split_once('：'))?;
+    let label = label.trim().to_lowercase();
+    labels.contains(&label.as_str()).then(|| value.trim()).filter(|v| !v.is_empty())
+}
+
+/// SUMMARY/DESCRIPTION 에서 고객 이름, 연락처, 시술명을 추정한다.
+/// "이름: ..." 처럼 레이블이 붙은 줄이 있으면 그것을 우선한다.
+fn extract_customer_info(summary: &str, description: &str) -> CustomerInfo {
+    let mut info = CustomerInfo::default();
+    let mut note_lines = Vec::new();
+
+    for line in description.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(v) = labeled_value(line, &["이름", "고객", "고객명", "성함", "name"]) {
+            info.name = Some(v.to_string());
+        } else if let Some(v) = labeled_value(line, &["연락처", "전화", "전화번호", "휴대폰", "핸드폰", "phone", "tel"]) {
+            info.phone = find_phone(v).map(|(p, _)| p);
+        } else if let Some(v) = labeled_value(line, &["시술", "서비스", "service"]) {
+            info.service = Some(v.to_string());
+        } else if let Some(v) = labeled_value(line, &["디자이너", "담당", "designer"]) {
+            info.designer_name = Some(v.to_string());
+        } else if let Some(v) = labeled_value(line, &["메모", "memo", "note", "notes"]) {
+            note_lines.push(v.to_string());
+        } else {
+            note_lines.push(line.to_string());
+        }
+    }
+
+    let mut summary = summary.to_string();
+    if let Some((phone, range)) = find_phone(&summary) {
+        info.phone.get_or_insert(phone);
+        summary.replace_range(range, " ");
+    }
+    if info.phone.is_none() {
+        if let Some((phone, _)) = find_phone(description) {
+            info.phone = Some(phone);
+        }
+    }
+
+    let tokens: Vec<String> = summary
+        .split(['-', '/', '|', '(', ')', '[', ']', ',', ':', '·', '_'])
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let name_index = if info.name.is_some() {
+        None
+    } else {
+        tokens
+            .iter()
+            .position(|t| is_hangul_name(t))
+            .or_else(|| {
+                // "김민지 커트" 처럼 공백으로만 구분된 경우
+                tokens.first().and_then(|t| {
+                    t.split_whitespace().next().filter(|w| is_hangul_name(w)).map(|_| 0)
+                })
+            })
+    };
+
+    let mut service_tokens = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if Some(i) == name_index {
+            let mut words = token.split_whitespace();
+            if let Some(name) = words.next() {
+                info.name = Some(name.to_string());
+            }
+            let rest: Vec<&str> = words.collect();
+            if !rest.is_empty() {
+                service_tokens.push(rest.join(" "));
+            }
+        } else {
+            service_tokens.push(token.clone());
+        }
+    }
+    if info.service.is_none() && !service_tokens.is_empty() {
+        info.service = Some(service_tokens.join(", "));
+    }
+    if info.name.is_none() {
+        // 이름을 찾지 못하면 SUMMARY 전체를 이름으로 쓴다
+        info.name = Some(summary.trim().to_string()).filter(|s| !s.is_empty());
+        info.service = None;
+    }
+    if !note_lines.is_empty() {
+        info.notes = Some(note_lines.join("\n"));
+    }
+    info
+}
+
+// ===== 가져오기 =====
+
+struct Occurrence {
+    key: String,
+    uid: String,
+    start: NaiveDateTime,
+    summary: Option<String>,
+    description: Option<String>,
+    status: Option<String>,
+}
+
+struct ExpandedCalendar {
+    occurrences: Vec<Occurrence>,
+    skipped: Vec<IcsImportItem>,
+    recurring_count: usize,
+}
+
+fn skip_item(source: &str, summary: Option<String>, message: String) -> IcsImportItem {
+    IcsImportItem {
+        reservation_id: None,
+        source: source.to_string(),
+        summary,
+        date: None,
+        time: None,
+        customer_name: None,
+        customer_phone: None,
+        service_type: None,
+        designer_id: None,
+        status: None,
+        action: ImportAction::Skip,
+        message: Some(message),
+    }
+}
+
+fn expand_calendar(
+    source: &str,
+    calendar: ParsedCalendar,
+    window_start: NaiveDate,
+    window_end: Option<NaiveDate>,
+) -> ExpandedCalendar {
+    let open_ended_end = window_end
+        .unwrap_or_else(|| chrono::Local::now().date_naive() + Duration::days(OPEN_ENDED_DAYS));
+    let mut expanded = ExpandedCalendar { occurrences: Vec::new(), skipped: Vec::new(), recurring_count: 0 };
+
+    // RECURRENCE-ID 가 있는 이벤트는 반복 일정의 특정 회차를 수정한 것이다
+    let overridden: HashSet<(String, NaiveDateTime)> = calendar
+        .events
+        .iter()
+        .filter_map(|e| Some((e.uid.clone()?, e.recurrence_id?)))
+        .collect();
+
+    for event in calendar.events {
+        let summary = event.summary.clone();
+        if let Some(error) = event.error {
+            expanded.skipped.push(skip_item(source, summary, error));
+            continue;
+        }
+        let (Some(uid), Some(start)) = (event.uid.clone(), event.start) else {
+            expanded.skipped.push(skip_item(source, summary, "UID 또는 시작 시각이 없습니다".to_string()));
+            continue;
+        };
+        if start.all_day {
+            expanded.skipped.push(skip_item(source, summary, "종일 일정은 예약으로 가져오지 않습니다".to_string()));
+            continue;
+        }
+
+        let starts: Vec<(String, NaiveDateTime)> = match (&event.rrule, event.recurrence_id) {
+            (_, Some(recurrence_id)) => vec![(recurrence_id.format("%Y%m%dT%H%M%S").to_string(), start.kst())],
+            (Some(rrule), None) => {
+                expanded.recurring_count += 1;
+                let rule = match parse_rrule(rrule, start.zone) {
+                    Ok(rule) => rule,
+                    Err(e) => {
+                        expanded.skipped.push(skip_item(source, summary, e));
+                        continue;
+                    }
+                };
+                expand_rule(&rule, &start, open_ended_end)
+                    .into_iter()
+                    .filter(|kst| !event.exdates.contains(kst) && !overridden.contains(&(uid.clone(), *kst)))
+                    .map(|kst| (kst.format("%Y%m%dT%H%M%S").to_string(), kst))
+                    .collect()
+            }
+            (None, None) => vec![(String::new(), start.kst())],
+        };
+
+        for (key, kst) in starts {
+            if kst.date() < window_start || window_end.is_some_and(|end| kst.date() > end) {
+                continue;
+            }
+            expanded.occurrences.push(Occurrence {
+                key,
+                uid: uid.clone(),
+                start: kst,
+                summary: event.summary.clone(),
+                description: event.description.clone(),
+                status: event.status.clone(),
+            });
+        }
+    }
+
+    expanded.occurrences.sort_by_key(|o| o.start);
+    expanded
+}
+
+/// 이 앱에서 내보낸 일정이면 원래 예약 id
+fn exported_reservation_id<'a>(uid: &'a str, key: &str) -> Option<&'a str> {
+    if !key.is_empty() {
+        return None;
+    }
+    uid.strip_suffix(&format!("@{}", ical::UID_DOMAIN))
+}
+
+/// 같은 일정을 다시 가져오면 같은 예약 id 가 나오도록 UID(와 반복 회차)에서 id 를 만든다.
+/// 이 앱에서 내보낸 일정은 원래 예약 id 를 그대로 쓴다.
+fn reservation_id_for(uid: &str, key: &str) -> String {
+    if let Some(id) = exported_reservation_id(uid, key) {
+        return id.to_string();
+    }
+    Uuid::new_v5(&Uuid::NAMESPACE_URL, format!("ics:{}#{}", uid, key).as_bytes()).to_string()
+}
+
+fn status_from_ical(status: Option<&str>, start: NaiveDateTime, now: NaiveDateTime) -> &'static str {
+    match status {
+        Some("CANCELLED") => "cancelled",
+        Some("TENTATIVE") => "pending",
+        // 지난 일정은 시술이 끝난 것으로 본다
+        _ if start < now => "completed",
+        _ => "confirmed",
+    }
+}
+
+fn parse_window(date_from: Option<&str>, date_to: Option<&str>) -> Result<(NaiveDate, Option<NaiveDate>), String> {
+    let start = match date_from {
+        Some(d) => schedule::parse_date(d)?,
+        None => NaiveDate::MIN,
+    };
+    let end = date_to.map(schedule::parse_date).transpose()?;
+    if end.is_some_and(|end| end < start) {
+        return Err("종료일은 시작일보다 빠를 수 없습니다".to_string());
+    }
+    Ok((start, end))
+}
+
+fn designer_names(conn: &Connection) -> Result<Vec<(String, String)>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, name FROM designers ORDER BY LENGTH(name) DESC")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| e.to_string())?;
+    let designers: Vec<(String, String)> = rows.filter_map(|r| r.ok()).collect();
+    Ok(designers)
+}
+
+/// 매핑 단계: 각 파일의 캘린더 이름과 일정 수를 보여주고, 이름이 겹치는 디자이너를 추천한다
+pub fn inspect_files(conn: &Connection, files: &[IcsFileInput]) -> Result<Vec<IcsCalendarSummary>, String> {
+    let designers = designer_names(conn)?;
+    let mut summaries = Vec::new();
+
+    for file in files {
+        let calendar = parse_calendar(&file.content).map_err(|e| format!("{}: {}", file.name, e))?;
+        let calendar_name = calendar.name.clone();
+        let expanded = expand_calendar(&file.name, calendar, NaiveDate::MIN, None);
+
+        let label = format!("{} {}", calendar_name.as_deref().unwrap_or(""), file.name);
+        let suggested_designer_id = designers
+            .iter()
+            .find(|(_, name)| !name.is_empty() && label.contains(name.as_str()))
+            .map(|(id, _)| id.clone());
+
+        summaries.push(IcsCalendarSummary {
+            file_name: file.name.clone(),
+            calendar_name,
+            event_count: expanded.occurrences.len(),
+            recurring_count: expanded.recurring_count,
+            first_date: expanded.occurrences.first().map(|o| o.start.format("%Y-%m-%d").to_string()),
+            last_date: expanded.occurrences.last().map(|o| o.start.format("%Y-%m-%d").to_string()),
+            suggested_designer_id,
+        });
+    }
+
+    Ok(summaries)
+}
+
+type ExistingReservation = (
+    Option<String>,
+    Option<String>,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+/// .ics 일정을 예약으로 가져온다. `dry_run` 이면 아무것도 저장하지 않고 결과만 돌려준다.
+/// 과거 일정을 옮기는 용도이므로 영업시간/차단 시간 검사는 하지 않는다.
+pub fn import_calendars(conn: &Connection, input: &IcsImportInput) -> Result<IcsImportResult, String> {
+    let (window_start, window_end) = parse_window(input.date_from.as_deref(), input.date_to.as_deref())?;
+    let designers = designer_names(conn)?;
+    let designer_ids: HashMap<&str, &str> = designers.iter().map(|(id, name)| (id.as_str(), name.as_str())).collect();
+    let now = chrono::Local::now().naive_local();
+
+    let mut result = IcsImportResult {
+        dry_run: input.dry_run,
+        created: 0,
+        updated: 0,
+        unchanged: 0,
+        skipped: 0,
+        items: Vec::new(),
+    };
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut seen = HashSet::new();
+
+    for source in &input.calendars {
+        if let Some(id) = source.designer_id.as_deref() {
+            if !designer_ids.contains_key(id) {
+                return Err(format!("{}: 디자이너를 찾을 수 없습니다", source.name));
+            }
+        }
+        let calendar = parse_calendar(&source.content).map_err(|e| format!("{}: {}", source.name, e))?;
+        let expanded = expand_calendar(&source.name, calendar, window_start, window_end);
+        result.items.extend(expanded.skipped);
+
+        for occurrence in expanded.occurrences {
+            let reservation_id = reservation_id_for(&occurrence.uid, &occurrence.key);
+            if !seen.insert(reservation_id.clone()) {
+                continue;
+            }
+
+            let info = extract_customer_info(
+                occurrence.summary.as_deref().unwrap_or(""),
+                occurrence.description.as_deref().unwrap_or(""),
+            );
+            let designer_id = source.designer_id.clone().or_else(|| {
+                let name = info.designer_name.as_deref()?;
+                designers.iter().find(|(_, n)| n == name).map(|(id, _)| id.clone())
+            });
+            let date = occurrence.start.format("%Y-%m-%d").to_string();
+            let time = occurrence.start.format("%H:%M").to_string();
+            let status = status_from_ical(occurrence.status.as_deref(), occurrence.start, now).to_string();
+
+            let mut item = IcsImportItem {
+                reservation_id: Some(reservation_id.clone()),
+                source: source.name.clone(),
+                summary: occurrence.summary.clone(),
+                date: Some(date.clone()),
+                time: Some(time.clone()),
+                customer_name: info.name.clone(),
+                customer_phone: info.phone.clone(),
+                service_type: info.service.clone(),
+                designer_id: designer_id.clone(),
+                status: Some(status.clone()),
+                action: ImportAction::Create,
+                message: None,
+            };
+
+            if info.name.is_none() && info.phone.is_none() {
+                item.action = ImportAction::Skip;
+                item.message = Some("고객 정보를 찾을 수 없습니다".to_string());
+                result.items.push(item);
+                continue;
+            }
+
+            let existing: Option<ExistingReservation> = tx
+                .query_row(
+                    "SELECT customer_name, customer_phone, date, time, designer_id, service_type, status, notes
+                     FROM reservations WHERE id = ?1",
+                    [&reservation_id],
+                    |row| {
+                        Ok((
+                            row.get(0)?,
+                            row.get(1)?,
+                            row.get(2)?,
+                            row.get(3)?,
+                            row.get(4)?,
+                            row.get(5)?,
+                            row.get(6)?,
+                            row.get(7)?,
+                        ))
+                    },
+                )
+                .optional()
+                .map_err(|e| e.to_string())?;
+
+            let incoming: ExistingReservation = (
+                info.name.clone(),
+                info.phone.clone(),
+                date.clone(),
+                time.clone(),
+                designer_id.clone(),
+                info.service.clone(),
+                Some(status.clone()),
+                info.notes.clone(),
+            );
+            item.action = match existing {
+                None => ImportAction::Create,
+                // 이 앱에서 내보낸 예약은 앱의 기록(노쇼·취소 상태, 메모 등)이 원본이므로 덮어쓰지 않는다
+                Some(_) if exported_reservation_id(&occurrence.uid, &occurrence.key).is_some() => {
+                    item.message = Some("이 앱의 예약이 이미 있어 덮어쓰지 않았습니다".to_string());
+                    ImportAction::Skip
+                }
+                Some(current) if current == incoming => ImportAction::Unchanged,
+                Some(_) => ImportAction::Update,
+            };
+
+            let customer_id: Option<String> = match info.phone.as_deref() {
+                Some(phone) => tx
+                    .query_row("SELECT id FROM customers WHERE phone = ?1", [phone], |row| row.get(0))
+                    .optional()
+                    .map_err(|e| e.to_string())?,
+                None => None,
+            };
+            let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+            match item.action {
+                ImportAction::Create => {
+                    tx.execute(
+                        "INSERT INTO reservations (id, customer_name, customer_phone, date, time, designer_id,
+                                                   service_type, status, notes, customer_id, created_at, updated_at)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?11)",
+                        rusqlite::params![
+                            reservation_id,
+                            info.name.as_deref().unwrap_or(""),
+                            info.phone,
+                            date,
+                            time,
+                            designer_id,
+                            info.service,
+                            status,
+                            info.notes,
+                            customer_id,
+                            timestamp,
+                        ],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    result.created += 1;
+                }
+                ImportAction::Update => {
+                    tx.execute(
+                        "UPDATE reservations SET customer_name = ?2, customer_phone = ?3, date = ?4, time = ?5,
+                                designer_id = ?6, service_type = ?7, status = ?8, notes = ?9,
+                                customer_id = COALESCE(customer_id, ?10), updated_at = ?11
+                         WHERE id = ?1",
+                        rusqlite::params![
+                            reservation_id,
+                            info.name.as_deref().unwrap_or(""),
+                            info.phone,
+                            date,
+                            time,
+                            designer_id,
+                            info.service,
+                            status,
+                            info.notes,
+                            customer_id,
+                            timestamp,
+                        ],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    result.updated += 1;
+                }
+                ImportAction::Unchanged => result.unchanged += 1,
+                ImportAction::Skip => {}
+            }
+            result.items.push(item);
+        }
+    }
+
+    result.skipped = result.items.iter().filter(|i| i.action == ImportAction::Skip).count();
+
+    if input.dry_run {
+        tx.rollback().map_err(|e| e.to_string())?;
+    } else {
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd_hm(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, min, 0).unwrap()
+    }
+
+    const SAMPLE: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+X-WR-CALNAME:수진 예약\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly-1\r\n\
+DTSTART;TZID=America/New_York:20250303T200000\r\n\
+RRULE:FREQ=WEEKLY;COUNT=4\r\n\
+EXDATE;TZID=America/New_York:20250317T200000\r\n\
+SUMMARY:김민지 010-1234-5678 커트\r\n\
+BEGIN:VALARM\r\n\
+DESCRIPTION:알림\r\n\
+END:VALARM\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:weekly-1\r\n\
+RECURRENCE-ID;TZID=America/New_York:20250324T200000\r\n\
+DTSTART;TZID=America/New_York:20250324T210000\r\n\
+SUMMARY:김민지 010-1234-5678 커트\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:abc@sisters-salon\r\n\
+DTSTART:20250401T010000Z\r\n\
+SUMMARY:이서연 - 염색\\, 클리닉\r\n\
+DESCRIPTION:연락처: 010-9876-5432\\n디자이너: 수진\\n메모: 두피 민감\r\n\
+STATUS:CANCELLED\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_and_expand_with_time_zones() {
+        let calendar = parse_calendar(SAMPLE).unwrap();
+        assert_eq!(calendar.name.as_deref(), Some("수진 예약"));
+        let expanded = expand_calendar("sample.ics", calendar, NaiveDate::MIN, None);
+        let starts: Vec<NaiveDateTime> = expanded.occurrences.iter().map(|o| o.start).collect();
+        // 뉴욕 20:00 은 3/9 일광절약시간 시작 전 KST 10:00, 이후 09:00
+        assert_eq!(
+            starts,
+            vec![
+                ymd_hm(2025, 3, 4, 10, 0),
+                ymd_hm(2025, 3, 11, 9, 0),
+                ymd_hm(2025, 3, 25, 10, 0),
+                ymd_hm(2025, 4, 1, 10, 0),
+            ]
+        );
+        assert_eq!(reservation_id_for("abc@sisters-salon", ""), "abc");
+        assert_eq!(
+            reservation_id_for("weekly-1", &expanded.occurrences[0].key),
+            reservation_id_for("weekly-1", "20250304T100000")
+        );
+    }
+
+    #[test]
+    fn test_monthly_rules() {
+        let start = DateTimeValue { local: ymd_hm(2025, 1, 14, 11, 0), zone: Zone::Tz(SEOUL), all_day: false };
+        let second_tuesday = parse_rrule("FREQ=MONTHLY;BYDAY=2TU;UNTIL=20250430T000000Z", start.zone).unwrap();
+        let dates: Vec<NaiveDate> = expand_rule(&second_tuesday, &start, NaiveDate::MAX)
+            .into_iter()
+            .map(|d| d.date())
+            .collect();
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2025, 1, 14).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 2, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 3, 11).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, 8).unwrap(),
+            ]
+        );
+
+        let start = DateTimeValue { local: ymd_hm(2025, 1, 31, 11, 0), zone: Zone::Tz(SEOUL), all_day: false };
+        let month_end = parse_rrule("FREQ=MONTHLY;COUNT=3", start.zone).unwrap();
+        let dates: Vec<u32> = expand_rule(&month_end, &start, NaiveDate::MAX).iter().map(|d| d.month()).collect();
+        // 31일이 없는 달은 건너뛴다 (RFC 5545)
+        assert_eq!(dates, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn test_extract_customer_info() {
+        let info = extract_customer_info("김민지 010-1234-5678 커트", "");
+        assert_eq!(info.name.as_deref(), Some("김민지"));
+        assert_eq!(info.phone.as_deref(), Some("010-1234-5678"));
+        assert_eq!(info.service.as_deref(), Some("커트"));
+
+        let info = extract_customer_info("펌 / 박지현 (01098765432)", "주차 필요");
+        assert_eq!(info.name.as_deref(), Some("박지현"));
+        assert_eq!(info.phone.as_deref(), Some("010-9876-5432"));
+        assert_eq!(info.service.as_deref(), Some("펌"));
+        assert_eq!(info.notes.as_deref(), Some("주차 필요"));
+
+        let info = extract_customer_info("Jane Doe", "이름: 제인\nTel: +82 10-2222-3333\n디자이너: 수진");
+        assert_eq!(info.name.as_deref(), Some("제인"));
+        assert_eq!(info.phone.as_deref(), Some("010-2222-3333"));
+        assert_eq!(info.designer_name.as_deref(), Some("수진"));
+    }
+
+    #[test]
+    fn test_reimporting_exported_reservation_never_overwrites() {
+        let db = crate::db::Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        conn.execute(
+            "INSERT INTO reservations (id, customer_name, customer_phone, date, time, status, notes)
+             VALUES ('abc', '이서연', '010-9876-5432', '2025-04-01', '10:00', 'no_show', '연락 안 됨')",
+            [],
+        )
+        .unwrap();
+        let input = IcsImportInput {
+            calendars: vec![IcsCalendarImport { name: "sample.ics".to_string(), content: SAMPLE.to_string(), designer_id: None }],
+            date_from: None,
+            date_to: None,
+            dry_run: false,
+        };
+
+        let result = import_calendars(conn, &input).unwrap();
+        assert_eq!(result.updated, 0);
+        let item = result.items.iter().find(|i| i.reservation_id.as_deref() == Some("abc")).unwrap();
+        assert_eq!(item.action, ImportAction::Skip);
+        let (status, notes): (String, String) = conn
+            .query_row("SELECT status, notes FROM reservations WHERE id = 'abc'", [], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!((status.as_str(), notes.as_str()), ("no_show", "연락 안 됨"));
+    }
+}
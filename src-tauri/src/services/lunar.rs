@@ -0,0 +1,396 @@
+// Korean lunisolar calendar (음력) conversion
+//
+// Computed offline from astronomical formulas instead of a lookup table:
+// - new moons: Meeus, "Astronomical Algorithms" ch. 49
+// - principal solar terms (중기): Meeus ch. 25 apparent solar longitude
+// Month boundaries are taken in Korea Standard Time (UTC+9), which is what
+// makes e.g. Seollal 2027 fall one day later than the Chinese new year.
+// Accuracy is within minutes, good for the supported range below.
+
+use chrono::{DateTime, Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+pub const MIN_YEAR: i32 = 1900;
+pub const MAX_YEAR: i32 = 2100;
+
+const KST_OFFSET_DAYS: f64 = 9.0 / 24.0;
+const SYNODIC_MONTH: f64 = 29.530588861;
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub is_leap_month: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LunarMonth {
+    year: i32,
+    month: u32,
+    is_leap: bool,
+    start: NaiveDate,
+    days: u32,
+}
+
+fn sin_deg(x: f64) -> f64 {
+    x.to_radians().sin()
+}
+
+fn normalize_deg(x: f64) -> f64 {
+    x.rem_euclid(360.0)
+}
+
+/// TT - UT in days (Espenak & Meeus polynomial approximations)
+fn delta_t_days(year: f64) -> f64 {
+    let seconds = if year < 1920.0 {
+        let t = year - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if year < 1941.0 {
+        let t = year - 1920.0;
+        21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if year < 1961.0 {
+        let t = year - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if year < 1986.0 {
+        let t = year - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if year < 2005.0 {
+        let t = year - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if year < 2050.0 {
+        let t = year - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - year)
+    };
+    seconds / 86400.0
+}
+
+fn jde_to_ut(jde: f64) -> f64 {
+    let year = 2000.0 + (jde - 2451545.0) / 365.25;
+    jde - delta_t_days(year)
+}
+
+/// Calendar date in KST for a Julian Day (UT)
+fn jd_to_kst_date(jd: f64) -> NaiveDate {
+    let seconds = ((jd + KST_OFFSET_DAYS - UNIX_EPOCH_JD) * 86400.0).floor() as i64;
+    DateTime::from_timestamp(seconds, 0)
+        .map(|dt| dt.date_naive())
+        .unwrap_or(NaiveDate::MIN)
+}
+
+fn date_to_jd(date: NaiveDate) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or(NaiveDate::MIN);
+    UNIX_EPOCH_JD + (date - epoch).num_days() as f64
+}
+
+/// JDE (TT) of the k-th new moon counted from January 2000 (Meeus ch. 49)
+fn new_moon_jde(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let jde = 2451550.09766 + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.000000150 * t3
+        + 0.00000000073 * t4;
+
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let m = 2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3;
+    let mp = 201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4;
+    let f = 160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4;
+    let omega = 124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3;
+
+    let correction = -0.40720 * sin_deg(mp)
+        + 0.17241 * e * sin_deg(m)
+        + 0.01608 * sin_deg(2.0 * mp)
+        + 0.01039 * sin_deg(2.0 * f)
+        + 0.00739 * e * sin_deg(mp - m)
+        - 0.00514 * e * sin_deg(mp + m)
+        + 0.00208 * e * e * sin_deg(2.0 * m)
+        - 0.00111 * sin_deg(mp - 2.0 * f)
+        - 0.00057 * sin_deg(mp + 2.0 * f)
+        + 0.00056 * e * sin_deg(2.0 * mp + m)
+        - 0.00042 * sin_deg(3.0 * mp)
+        + 0.00042 * e * sin_deg(m + 2.0 * f)
+        + 0.00038 * e * sin_deg(m - 2.0 * f)
+        - 0.00024 * e * sin_deg(2.0 * mp - m)
+        - 0.00017 * sin_deg(omega)
+        - 0.00007 * sin_deg(mp + 2.0 * m)
+        + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+        + 0.00004 * sin_deg(3.0 * m)
+        + 0.00003 * sin_deg(mp + m - 2.0 * f)
+        + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+        - 0.00003 * sin_deg(mp + m + 2.0 * f)
+        + 0.00003 * sin_deg(mp - m + 2.0 * f)
+        - 0.00002 * sin_deg(mp - m - 2.0 * f)
+        - 0.00002 * sin_deg(3.0 * mp + m)
+        + 0.00002 * sin_deg(4.0 * mp);
+
+    let planetary = [
+        (0.000325, 299.77 + 0.107408 * k - 0.009173 * t2),
+        (0.000165, 251.88 + 0.016321 * k),
+        (0.000164, 251.83 + 26.651886 * k),
+        (0.000126, 349.42 + 36.412478 * k),
+        (0.000110, 84.66 + 18.206239 * k),
+        (0.000062, 141.74 + 53.303771 * k),
+        (0.000060, 207.14 + 2.453732 * k),
+        (0.000056, 154.84 + 7.306860 * k),
+        (0.000047, 34.52 + 27.261239 * k),
+        (0.000042, 207.19 + 0.121824 * k),
+        (0.000040, 291.34 + 1.844379 * k),
+        (0.000037, 161.72 + 24.198154 * k),
+        (0.000035, 239.56 + 25.513099 * k),
+        (0.000023, 331.55 + 3.592518 * k),
+    ]
+    .iter()
+    .map(|(coef, arg)| coef * sin_deg(*arg))
+    .sum::<f64>();
+
+    jde + correction + planetary
+}
+
+/// Apparent geocentric solar longitude in degrees (Meeus ch. 25, ~0.01°)
+fn solar_longitude(jde: f64) -> f64 {
+    let t = (jde - 2451545.0) / 36525.0;
+    let l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m = 357.52911 + 35999.05029 * t - 0.0001537 * t * t;
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * sin_deg(m)
+        + (0.019993 - 0.000101 * t) * sin_deg(2.0 * m)
+        + 0.000289 * sin_deg(3.0 * m);
+    let omega = 125.04 - 1934.136 * t;
+    normalize_deg(l0 + c - 0.00569 - 0.00478 * sin_deg(omega))
+}
+
+/// JDE at which the sun reaches `target` longitude, searching near `jde_guess`
+fn solar_term_jde(target: f64, jde_guess: f64) -> f64 {
+    let mut jde = jde_guess;
+    for _ in 0..50 {
+        let diff = (target - solar_longitude(jde) + 540.0).rem_euclid(360.0) - 180.0;
+        jde += diff * 365.2422 / 360.0;
+        if diff.abs() < 1e-7 {
+            break;
+        }
+    }
+    jde
+}
+
+/// KST date of the winter solstice in December of `year`
+fn winter_solstice(year: i32) -> NaiveDate {
+    let guess = date_to_jd(NaiveDate::from_ymd_opt(year, 12, 21).unwrap_or(NaiveDate::MIN));
+    jd_to_kst_date(jde_to_ut(solar_term_jde(270.0, guess)))
+}
+
+/// KST dates of the new moons from the one on or before `from` through the first after `to`
+fn new_moons_between(from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+    let mut k = ((date_to_jd(from) - 2451550.09766) / SYNODIC_MONTH).floor() - 1.0;
+    let mut moons = Vec::new();
+    loop {
+        let date = jd_to_kst_date(jde_to_ut(new_moon_jde(k)));
+        if date > to {
+            moons.push(date);
+            break;
+        }
+        if date <= from {
+            moons.clear();
+        }
+        moons.push(date);
+        k += 1.0;
+    }
+    moons
+}
+
+/// KST dates of the principal terms (중기, multiples of 30°) within a date range
+fn principal_terms_between(from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+    let start_jd = date_to_jd(from) - 31.0;
+    let mut terms = Vec::new();
+    let mut longitude = (solar_longitude(start_jd) / 30.0).ceil() * 30.0;
+    let mut guess = start_jd;
+    loop {
+        let jde = solar_term_jde(normalize_deg(longitude), guess);
+        let date = jd_to_kst_date(jde_to_ut(jde));
+        if date > to {
+            break;
+        }
+        if date >= from {
+            terms.push(date);
+        }
+        longitude += 30.0;
+        guess = jde + 30.4;
+    }
+    terms
+}
+
+/// Lunar months from the 11th month containing the winter solstice of
+/// `year - 1` up to (but excluding) the 11th month of `year`.
+fn months_in_solstice_window(year: i32) -> Vec<LunarMonth> {
+    let solstice_start = winter_solstice(year - 1);
+    let solstice_end = winter_solstice(year);
+    let moons = new_moons_between(solstice_start, solstice_end);
+
+    // moons[0] starts month 11; the last entry starts the next month 11
+    let month_count = moons.len() - 2;
+    let terms = principal_terms_between(moons[0], moons[moons.len() - 1]);
+
+    let mut leap_index = None;
+    if month_count == 13 {
+        leap_index = (1..month_count).find(|&i| {
+            !terms.iter().any(|t| *t >= moons[i] && *t < moons[i + 1])
+        });
+    }
+
+    let mut months = Vec::with_capacity(month_count);
+    let mut number = 11u32;
+    for i in 0..month_count {
+        let is_leap = Some(i) == leap_index;
+        if i > 0 && !is_leap {
+            number = number % 12 + 1;
+        }
+        let lunar_year = if number >= 11 && i < 3 { year - 1 } else { year };
+        months.push(LunarMonth {
+            year: lunar_year,
+            month: number,
+            is_leap,
+            start: moons[i],
+            days: (moons[i + 1] - moons[i]).num_days() as u32,
+        });
+    }
+    months
+}
+
+fn check_year(year: i32) -> Result<(), String> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(format!("음력 변환은 {}~{}년만 지원합니다", MIN_YEAR, MAX_YEAR));
+    }
+    Ok(())
+}
+
+fn find_month(year: i32, month: u32, is_leap: bool) -> Result<Option<LunarMonth>, String> {
+    check_year(year)?;
+    let found = months_in_solstice_window(year)
+        .into_iter()
+        .chain(months_in_solstice_window(year + 1))
+        .find(|m| m.year == year && m.month == month && m.is_leap == is_leap);
+    Ok(found)
+}
+
+/// Convert a lunar date to the solar (Gregorian) calendar.
+/// Returns `None` if the month/day does not exist in that year.
+pub fn lunar_to_solar(year: i32, month: u32, day: u32, is_leap_month: bool) -> Result<Option<NaiveDate>, String> {
+    if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+        return Err(format!("잘못된 음력 날짜입니다: {}-{}-{}", year, month, day));
+    }
+    Ok(find_month(year, month, is_leap_month)?
+        .filter(|m| day <= m.days)
+        .and_then(|m| m.start.checked_add_days(chrono::Days::new((day - 1) as u64))))
+}
+
+/// Like `lunar_to_solar` for a regular month, but day 30 in a 29-day month
+/// falls on the last day of the month. Used for recurring dates such as
+/// Seollal's eve (12/30), which is always the day before 1/1.
+pub fn lunar_to_solar_clamped(year: i32, month: u32, day: u32) -> Result<Option<NaiveDate>, String> {
+    if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+        return Err(format!("잘못된 음력 날짜입니다: {}-{}-{}", year, month, day));
+    }
+    Ok(find_month(year, month, false)?
+        .and_then(|m| m.start.checked_add_days(chrono::Days::new((day.min(m.days) - 1) as u64))))
+}
+
+/// Convert a solar (Gregorian) date to the lunar calendar
+pub fn solar_to_lunar(date: NaiveDate) -> Result<LunarDate, String> {
+    check_year(date.year())?;
+    let month = months_in_solstice_window(date.year())
+        .into_iter()
+        .chain(months_in_solstice_window(date.year() + 1))
+        .find(|m| date >= m.start && (date - m.start).num_days() < m.days as i64)
+        .ok_or_else(|| format!("음력 변환에 실패했습니다: {}", date))?;
+
+    Ok(LunarDate {
+        year: month.year,
+        month: month.month,
+        day: (date - month.start).num_days() as u32 + 1,
+        is_leap_month: month.is_leap,
+    })
+}
+
+/// Number of days in a lunar month, used to treat day 30 as "last day"
+pub fn lunar_month_days(year: i32, month: u32, is_leap_month: bool) -> Result<Option<u32>, String> {
+    Ok(find_month(year, month, is_leap_month)?.map(|m| m.days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_new_moon_matches_meeus_example() {
+        // Meeus example 49.a: 1977 February new moon, JDE 2443192.65118
+        assert!((new_moon_jde(-283.0) - 2443192.65118).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_seollal_and_chuseok() {
+        let seollal = [
+            (2020, ymd(2020, 1, 25)),
+            (2023, ymd(2023, 1, 22)),
+            (2024, ymd(2024, 2, 10)),
+            (2025, ymd(2025, 1, 29)),
+            (2026, ymd(2026, 2, 17)),
+            // KST differs from China (Feb 6) this year
+            (2027, ymd(2027, 2, 7)),
+        ];
+        for (year, expected) in seollal {
+            assert_eq!(lunar_to_solar(year, 1, 1, false).unwrap(), Some(expected), "seollal {}", year);
+        }
+
+        let chuseok = [
+            (2023, ymd(2023, 9, 29)),
+            (2024, ymd(2024, 9, 17)),
+            (2025, ymd(2025, 10, 6)),
+            (2026, ymd(2026, 9, 25)),
+        ];
+        for (year, expected) in chuseok {
+            assert_eq!(lunar_to_solar(year, 8, 15, false).unwrap(), Some(expected), "chuseok {}", year);
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        // 2023 윤2월, 2025 윤6월
+        assert_eq!(lunar_to_solar(2023, 2, 1, true).unwrap(), Some(ymd(2023, 3, 22)));
+        assert_eq!(lunar_to_solar(2025, 6, 1, true).unwrap(), Some(ymd(2025, 7, 25)));
+        assert_eq!(lunar_to_solar(2024, 6, 1, true).unwrap(), None);
+        assert_eq!(
+            solar_to_lunar(ymd(2025, 8, 1)).unwrap(),
+            LunarDate { year: 2025, month: 6, day: 8, is_leap_month: true }
+        );
+    }
+
+    #[test]
+    fn test_round_trip_and_clamping() {
+        let mut date = ymd(2024, 1, 1);
+        while date < ymd(2026, 1, 1) {
+            let lunar = solar_to_lunar(date).unwrap();
+            let back = lunar_to_solar(lunar.year, lunar.month, lunar.day, lunar.is_leap_month).unwrap();
+            assert_eq!(back, Some(date));
+            date = date.succ_opt().unwrap();
+        }
+
+        // Seollal's eve is always the day before lunar 1/1, even when 12th month has 29 days
+        for year in 2020..2030 {
+            let eve = lunar_to_solar_clamped(year - 1, 12, 30).unwrap().unwrap();
+            let seollal = lunar_to_solar(year, 1, 1, false).unwrap().unwrap();
+            assert_eq!(eve.succ_opt().unwrap(), seollal);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! 고객 커뮤니케이션 기록
+//!
+//! 예약 알림, 확인 문자 등 고객에게 보낸 메시지를 발송 결과와 함께 `messages` 에 남겨
+//! 고객별로 무엇을 언제 받았는지 확인할 수 있게 한다.
+
+use rusqlite::Connection;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// 메시지 종류
+pub const TEMPLATE_REMINDER: &str = "reminder";
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub id: String,
+    pub customer_id: Option<String>,
+    pub reservation_id: Option<String>,
+    pub reservation_date: Option<String>,
+    pub reservation_time: Option<String>,
+    pub channel: String,
+    pub template: String,
+    pub recipient: String,
+    pub body: String,
+    pub status: String,
+    pub provider_message_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub sent_at: Option<String>,
+}
+
+/// 기록할 메시지
+#[derive(Debug, Default)]
+pub struct NewMessage<'a> {
+    pub customer_id: Option<&'a str>,
+    pub reservation_id: Option<&'a str>,
+    pub channel: &'a str,
+    pub template: &'a str,
+    pub recipient: &'a str,
+    pub body: &'a str,
+    pub status: &'a str,
+    pub provider_message_id: Option<&'a str>,
+    pub error: Option<&'a str>,
+    pub sent_at: Option<&'a str>,
+    /// 지정하지 않으면 현재 시각 (UTC)
+    pub created_at: Option<&'a str>,
+}
+
+pub fn record_message(conn: &Connection, message: &NewMessage) -> Result<String, String> {
+    let id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO messages (id, customer_id, reservation_id, channel, template, recipient, body, status,
+                               provider_message_id, error, sent_at, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, COALESCE(?12, datetime('now')))",
+        rusqlite::params![
+            id,
+            message.customer_id,
+            message.reservation_id,
+            message.channel,
+            message.template,
+            message.recipient,
+            message.body,
+            message.status,
+            message.provider_message_id,
+            message.error,
+            message.sent_at,
+            message.created_at,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+/// 고객이 받은 메시지를 최근 순으로 조회
+///
+/// 고객이 직접 연결된 메시지와, 고객의 예약에 대해 보낸 메시지를 함께 보여준다.
+pub fn get_customer_messages(
+    conn: &Connection,
+    customer_id: &str,
+    limit: Option<u32>,
+) -> Result<Vec<Message>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.id, m.customer_id, m.reservation_id, r.date, r.time, m.channel, m.template, m.recipient,
+                    m.body, m.status, m.provider_message_id, m.error, m.created_at, m.sent_at
+             FROM messages m
+             LEFT JOIN reservations r ON r.id = m.reservation_id
+             WHERE m.customer_id = ?1 OR (m.customer_id IS NULL AND r.customer_id = ?1)
+             ORDER BY m.created_at DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![customer_id, limit.unwrap_or(100)], |row| {
+            Ok(Message {
+                id: row.get(0)?,
+                customer_id: row.get(1)?,
+                reservation_id: row.get(2)?,
+                reservation_date: row.get(3)?,
+                reservation_time: row.get(4)?,
+                channel: row.get(5)?,
+                template: row.get(6)?,
+                recipient: row.get(7)?,
+                body: row.get(8)?,
+                status: row.get(9)?,
+                provider_message_id: row.get(10)?,
+                error: row.get(11)?,
+                created_at: row.get(12)?,
+                sent_at: row.get(13)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let messages: Vec<Message> = rows.filter_map(|r| r.ok()).collect();
+    Ok(messages)
+}
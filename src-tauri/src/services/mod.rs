@@ -2,3 +2,20 @@ pub mod excel;
 pub mod backup;
 pub mod auth;
 pub mod cloudkit;
+pub mod schedule;
+pub mod time_blocks;
+pub mod lunar;
+pub mod holidays;
+pub mod ical;
+pub mod ical_import;
+pub mod reservations;
+pub mod designers;
+pub mod customers;
+pub mod statistics;
+pub mod api;
+pub mod webhooks;
+pub mod reminders;
+pub mod messages;
+pub mod privacy;
+pub mod dossier;
+pub mod sync;
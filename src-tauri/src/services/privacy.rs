@@ -0,0 +1,763 @@
+//! 개인정보 보호 (개인정보 보호법)
+//!
+//! - 고객별 동의 항목(개인정보 수집·이용, 마케팅 문자, 사진)을 시각·약관 버전과 함께 기록하고,
+//!   바뀔 때마다 `customer_consent_log` 에 이력을 남긴다.
+//! - 보관 기간(N년) 동안 방문·예약이 없는 고객은 익명 처리한다. 예약은 통계를 위해 남기되
+//!   이름·연락처·메모를 지우고, 메시지와 알림 기록은 삭제한다.
+//! - 파기할 때마다 무엇을 지웠는지 `privacy_audit_log` 에 남긴다 (개인정보 자체는 남기지 않음).
+
+use chrono::{Months, NaiveDate};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::sync::changes;
+use crate::services::webhooks;
+
+const SETTINGS_KEY: &str = "privacy_settings";
+const ANONYMIZED_NAME: &str = "익명 고객";
+/// 익명 처리할 때 비우는 고객 항목
+const PURGED_FIELDS: [&str; 8] = [
+    "name",
+    "phone",
+    "email",
+    "birthdate",
+    "gender",
+    "preferredService",
+    "allergies",
+    "notes",
+];
+
+pub const CONSENT_TYPES: [&str; 3] = ["service", "marketing", "photo"];
+
+pub const TRIGGER_MANUAL: &str = "manual";
+pub const TRIGGER_AUTO: &str = "auto";
+/// 고객의 삭제 요청
+pub const TRIGGER_REQUEST: &str = "request";
+/// 다른 기기에서 익명 처리되어 동기화로 들어온 고객
+pub const TRIGGER_SYNC: &str = "sync";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacySettings {
+    /// 마지막 방문·예약 후 이 기간이 지나면 익명 처리
+    pub retention_years: u32,
+    /// 앱 시작 시 자동으로 익명 처리
+    pub auto_purge: bool,
+    /// 현재 사용하는 동의서 버전 (동의를 받을 때 함께 기록)
+    pub consent_version: String,
+}
+
+impl Default for PrivacySettings {
+    fn default() -> Self {
+        Self {
+            retention_years: 3,
+            auto_purge: false,
+            consent_version: "v1".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentRecord {
+    pub id: String,
+    pub consent_type: String,
+    pub granted: bool,
+    pub version: String,
+    pub recorded_at: String,
+}
+
+/// 익명 처리 대상 고객 (미리보기용, 이름은 가려서 보여준다)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionCandidate {
+    pub customer_id: String,
+    pub masked_name: String,
+    pub last_activity: String,
+    pub reservation_count: i64,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymizeResult {
+    pub reservations: usize,
+    pub messages: usize,
+    pub reminders: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeReport {
+    pub run_id: String,
+    pub cutoff_date: String,
+    pub customers: usize,
+    pub reservations: usize,
+    pub messages: usize,
+    pub reminders: usize,
+    pub performed_at: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub id: String,
+    pub run_id: String,
+    pub action: String,
+    pub customer_id: Option<String>,
+    pub trigger: String,
+    pub details: serde_json::Value,
+    pub performed_at: String,
+}
+
+/// 기간 내 파기 기록과 합계
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub date_from: Option<String>,
+    pub date_to: Option<String>,
+    pub runs: usize,
+    pub customers: usize,
+    pub reservations: usize,
+    pub messages: usize,
+    pub reminders: usize,
+    pub entries: Vec<AuditEntry>,
+}
+
+fn now_text() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Get privacy settings from app_settings table
+pub fn get_settings(conn: &Connection) -> Result<PrivacySettings, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(PrivacySettings::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Save privacy settings to app_settings table
+pub fn save_settings(conn: &Connection, settings: &PrivacySettings) -> Result<(), String> {
+    if !(1..=10).contains(&settings.retention_years) {
+        return Err("보관 기간은 1~10년 사이로 설정해주세요".to_string());
+    }
+    if settings.consent_version.trim().is_empty() {
+        return Err("동의서 버전을 입력해주세요".to_string());
+    }
+
+    let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, datetime('now'))",
+        rusqlite::params![SETTINGS_KEY, json],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 바뀐 동의 항목만 현재 동의서 버전으로 기록한다
+///
+/// `requested`, `current` 는 `CONSENT_TYPES` 순서 (개인정보, 마케팅, 사진)
+pub fn apply_consents(
+    conn: &Connection,
+    customer_id: &str,
+    requested: [Option<bool>; 3],
+    current: [bool; 3],
+) -> Result<(), String> {
+    let changes: Vec<(&str, bool)> = CONSENT_TYPES
+        .iter()
+        .zip(requested.iter().zip(current.iter()))
+        .filter_map(|(kind, (requested, current))| match requested {
+            Some(granted) if granted != current => Some((*kind, *granted)),
+            _ => None,
+        })
+        .collect();
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let version = get_settings(conn)?.consent_version;
+    let now = now_text();
+    for (kind, granted) in changes {
+        // 컬럼 이름은 CONSENT_TYPES 에서만 온다
+        conn.execute(
+            &format!(
+                "UPDATE customers SET consent_{kind} = ?2, consent_{kind}_at = ?3, consent_version = ?4 WHERE id = ?1"
+            ),
+            rusqlite::params![customer_id, granted as i32, now, version],
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO customer_consent_log (id, customer_id, consent_type, granted, version, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![Uuid::new_v4().to_string(), customer_id, kind, granted as i32, version, now],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+pub fn get_consent_history(conn: &Connection, customer_id: &str) -> Result<Vec<ConsentRecord>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, consent_type, granted, version, recorded_at
+             FROM customer_consent_log
+             WHERE customer_id = ?1
+             ORDER BY recorded_at DESC, rowid DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([customer_id], |row| {
+            Ok(ConsentRecord {
+                id: row.get(0)?,
+                consent_type: row.get(1)?,
+                granted: row.get::<_, i32>(2)? == 1,
+                version: row.get(3)?,
+                recorded_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let records: Vec<ConsentRecord> = rows.filter_map(|r| r.ok()).collect();
+    Ok(records)
+}
+
+fn cutoff_date(today: NaiveDate, years: u32) -> Result<NaiveDate, String> {
+    today
+        .checked_sub_months(Months::new(years * 12))
+        .ok_or_else(|| "보관 기간 계산에 실패했습니다".to_string())
+}
+
+/// "김민지" -> "김**"
+fn mask_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => std::iter::once(first).chain(chars.map(|_| '*')).collect(),
+        None => String::new(),
+    }
+}
+
+/// 마지막 방문·예약·등록일이 기준일보다 이전인 고객
+pub fn find_retention_candidates(
+    conn: &Connection,
+    retention_years: u32,
+    today: NaiveDate,
+) -> Result<Vec<RetentionCandidate>, String> {
+    let cutoff = cutoff_date(today, retention_years)?.format("%Y-%m-%d").to_string();
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, name, last_activity, reservation_count FROM (
+                 SELECT c.id, c.name,
+                        MAX(COALESCE(c.last_visit_date, ''),
+                            substr(COALESCE(c.created_at, ''), 1, 10),
+                            COALESCE((SELECT MAX(r.date) FROM reservations r
+                                      WHERE r.customer_id = c.id
+                                         OR (r.customer_id IS NULL AND r.customer_phone = c.phone)), '')) AS last_activity,
+                        (SELECT COUNT(*) FROM reservations r
+                         WHERE r.customer_id = c.id
+                            OR (r.customer_id IS NULL AND r.customer_phone = c.phone)) AS reservation_count
+                 FROM customers c
+                 WHERE c.anonymized_at IS NULL
+             )
+             WHERE last_activity < ?1
+             ORDER BY last_activity",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([&cutoff], |row| {
+            let name: String = row.get(1)?;
+            Ok(RetentionCandidate {
+                customer_id: row.get(0)?,
+                masked_name: mask_name(&name),
+                last_activity: row.get(2)?,
+                reservation_count: row.get(3)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let candidates: Vec<RetentionCandidate> = rows.filter_map(|r| r.ok()).collect();
+    Ok(candidates)
+}
+
+/// 고객 한 명을 익명 처리하고 파기 기록을 남긴다
+///
+/// 예약은 날짜·시술·디자이너만 남겨 통계에 계속 쓰고, 메시지·알림 기록은 삭제한다.
+pub fn anonymize_customer(
+    conn: &Connection,
+    customer_id: &str,
+    run_id: &str,
+    trigger: &str,
+    reason: &str,
+) -> Result<AnonymizeResult, String> {
+    let phone: Option<String> = conn
+        .query_row("SELECT phone FROM customers WHERE id = ?1", [customer_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    let now = now_text();
+
+    // 전화번호로만 연결된 예약도 이 고객에 묶은 뒤 개인정보를 지운다
+    if let Some(phone) = phone.as_deref().filter(|p| !p.is_empty()) {
+        conn.execute(
+            "UPDATE reservations SET customer_id = ?1 WHERE customer_id IS NULL AND customer_phone = ?2",
+            rusqlite::params![customer_id, phone],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    let (reminders, messages) = delete_local_messages(conn, customer_id)?;
+    let reservations = conn
+        .execute(
+            "UPDATE reservations SET customer_name = ?2, customer_phone = NULL, notes = NULL, updated_at = ?3
+             WHERE customer_id = ?1",
+            rusqlite::params![customer_id, ANONYMIZED_NAME, now],
+        )
+        .map_err(|e| e.to_string())?;
+    // 동기화 기록에 남은 이전 값도 지운다 (다음 동기화에서 익명 값으로 모든 필드에 새 버전이 매겨진다)
+    let reservation_ids = query_strings(conn, "SELECT id FROM reservations WHERE customer_id = ?1", customer_id)?;
+    for id in &reservation_ids {
+        changes::forget_values(conn, "reservations", id)?;
+    }
+    changes::forget_values(conn, "customers", customer_id)?;
+
+    // 웹훅 대기열·전송 기록에 담긴 고객·예약 정보도 지운다
+    let mut subject_ids = reservation_ids;
+    subject_ids.push(customer_id.to_string());
+    webhooks::purge_events_about(conn, &subject_ids, phone.as_deref().filter(|p| !p.is_empty()))?;
+    conn.execute(
+        "UPDATE customers SET name = ?2, phone = NULL, email = NULL, birthdate = NULL, gender = NULL,
+                preferred_designer_id = NULL, preferred_service = NULL, allergies = NULL, notes = NULL,
+                reminder_opt_out = 1, consent_service = 0, consent_marketing = 0, consent_photo = 0,
+                anonymized_at = ?3, updated_at = ?3
+         WHERE id = ?1",
+        rusqlite::params![customer_id, ANONYMIZED_NAME, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    let result = AnonymizeResult { reservations, messages, reminders };
+    let details = serde_json::json!({
+        "reason": reason,
+        "fields": PURGED_FIELDS,
+        "reservations": result.reservations,
+        "messages": result.messages,
+        "reminders": result.reminders,
+    });
+    conn.execute(
+        "INSERT INTO privacy_audit_log (id, run_id, action, customer_id, trigger, details, performed_at)
+         VALUES (?1, ?2, 'anonymize', ?3, ?4, ?5, ?6)",
+        rusqlite::params![Uuid::new_v4().to_string(), run_id, customer_id, trigger, details.to_string(), now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(result)
+}
+
+/// 고객과 그 고객 예약에 딸린 알림·메시지 기록을 지운다. (알림 수, 메시지 수)를 돌려준다.
+fn delete_local_messages(conn: &Connection, customer_id: &str) -> Result<(usize, usize), String> {
+    let reminders = conn
+        .execute(
+            "DELETE FROM reminders WHERE reservation_id IN (SELECT id FROM reservations WHERE customer_id = ?1)",
+            [customer_id],
+        )
+        .map_err(|e| e.to_string())?;
+    let messages = conn
+        .execute(
+            "DELETE FROM messages
+             WHERE customer_id = ?1
+                OR reservation_id IN (SELECT id FROM reservations WHERE customer_id = ?1)",
+            [customer_id],
+        )
+        .map_err(|e| e.to_string())?;
+    Ok((reminders, messages))
+}
+
+fn query_strings(conn: &Connection, sql: &str, param: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+    let values = stmt
+        .query_map([param], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(values)
+}
+
+/// 다른 기기에서 익명 처리한 고객이 동기화로 들어오면, 이 기기에만 있는 알림·메시지 기록과
+/// 웹훅 대기열에 남은 그 고객의 정보도 지운다. 처리한 고객 수를 돌려준다.
+///
+/// 이 기기의 파기 기록에 없는 익명 고객만 처리하고, 처리하면 `TRIGGER_SYNC` 로 기록을 남긴다.
+pub fn erase_synced_anonymizations(conn: &Connection) -> Result<usize, String> {
+    let customer_ids: Vec<String> = {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id FROM customers c
+                 WHERE anonymized_at IS NOT NULL
+                   AND NOT EXISTS (SELECT 1 FROM privacy_audit_log l WHERE l.customer_id = c.id AND l.action = 'anonymize')",
+            )
+            .map_err(|e| e.to_string())?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        ids
+    };
+    if customer_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let run_id = Uuid::new_v4().to_string();
+    let now = now_text();
+    for customer_id in &customer_ids {
+        // 동기화된 고객 행에는 전화번호가 없으므로 이 기기의 발송 기록에서 찾는다
+        let phones = query_strings(
+            conn,
+            "SELECT recipient FROM messages
+             WHERE customer_id = ?1 OR reservation_id IN (SELECT id FROM reservations WHERE customer_id = ?1)
+             UNION
+             SELECT recipient FROM reminders WHERE reservation_id IN (SELECT id FROM reservations WHERE customer_id = ?1)",
+            customer_id,
+        )?;
+        let (reminders, messages) = delete_local_messages(conn, customer_id)?;
+
+        let mut subject_ids = query_strings(conn, "SELECT id FROM reservations WHERE customer_id = ?1", customer_id)?;
+        subject_ids.push(customer_id.clone());
+        webhooks::purge_events_about(conn, &subject_ids, None)?;
+        for phone in phones.iter().filter(|p| !p.is_empty()) {
+            webhooks::purge_events_about(conn, &subject_ids, Some(phone))?;
+        }
+
+        let details = serde_json::json!({
+            "reason": "다른 기기에서 익명 처리",
+            "messages": messages,
+            "reminders": reminders,
+        });
+        conn.execute(
+            "INSERT INTO privacy_audit_log (id, run_id, action, customer_id, trigger, details, performed_at)
+             VALUES (?1, ?2, 'anonymize', ?3, ?4, ?5, ?6)",
+            rusqlite::params![Uuid::new_v4().to_string(), run_id, customer_id, TRIGGER_SYNC, details.to_string(), now],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(customer_ids.len())
+}
+
+/// 보관 기간이 지난 고객을 모두 익명 처리
+pub fn purge_inactive_customers(
+    conn: &Connection,
+    settings: &PrivacySettings,
+    today: NaiveDate,
+    trigger: &str,
+) -> Result<PurgeReport, String> {
+    let cutoff = cutoff_date(today, settings.retention_years)?;
+    let candidates = find_retention_candidates(conn, settings.retention_years, today)?;
+    let run_id = Uuid::new_v4().to_string();
+    let reason = format!("{}년 보관 기간 경과 (기준일 {})", settings.retention_years, cutoff);
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut report = PurgeReport {
+        run_id: run_id.clone(),
+        cutoff_date: cutoff.format("%Y-%m-%d").to_string(),
+        customers: 0,
+        reservations: 0,
+        messages: 0,
+        reminders: 0,
+        performed_at: now_text(),
+    };
+    for candidate in &candidates {
+        let result = anonymize_customer(&tx, &candidate.customer_id, &run_id, trigger, &reason)?;
+        report.customers += 1;
+        report.reservations += result.reservations;
+        report.messages += result.messages;
+        report.reminders += result.reminders;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(report)
+}
+
+/// 고객의 삭제 요청 처리
+///
+/// 개인정보는 모두 지우고 예약 행은 익명으로 남겨 매출·방문 통계가 바뀌지 않게 한다.
+pub fn erase_customer(conn: &Connection, customer_id: &str) -> Result<AnonymizeResult, String> {
+    let anonymized_at: Option<String> = conn
+        .query_row("SELECT anonymized_at FROM customers WHERE id = ?1", [customer_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    if anonymized_at.is_some() {
+        return Err("이미 개인정보가 삭제된 고객입니다".to_string());
+    }
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let result = anonymize_customer(
+        &tx,
+        customer_id,
+        &Uuid::new_v4().to_string(),
+        TRIGGER_REQUEST,
+        "고객 삭제 요청",
+    )?;
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(result)
+}
+
+/// 자동 파기가 켜져 있으면 실행 (앱 시작 시)
+pub fn run_scheduled_purge(conn: &Connection, today: NaiveDate) -> Result<Option<PurgeReport>, String> {
+    let settings = get_settings(conn)?;
+    if !settings.auto_purge {
+        return Ok(None);
+    }
+    purge_inactive_customers(conn, &settings, today, TRIGGER_AUTO).map(Some)
+}
+
+/// 파기 기록 조회 (date_from, date_to: YYYY-MM-DD)
+pub fn get_audit_report(
+    conn: &Connection,
+    date_from: Option<&str>,
+    date_to: Option<&str>,
+) -> Result<AuditReport, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, run_id, action, customer_id, trigger, details, performed_at
+             FROM privacy_audit_log
+             WHERE (?1 IS NULL OR substr(performed_at, 1, 10) >= ?1)
+               AND (?2 IS NULL OR substr(performed_at, 1, 10) <= ?2)
+             ORDER BY performed_at DESC, rowid DESC",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![date_from, date_to], |row| {
+            let details: String = row.get(5)?;
+            Ok(AuditEntry {
+                id: row.get(0)?,
+                run_id: row.get(1)?,
+                action: row.get(2)?,
+                customer_id: row.get(3)?,
+                trigger: row.get(4)?,
+                details: serde_json::from_str(&details).unwrap_or(serde_json::Value::Null),
+                performed_at: row.get(6)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+    let entries: Vec<AuditEntry> = rows.filter_map(|r| r.ok()).collect();
+
+    let count = |key: &str| -> usize {
+        entries
+            .iter()
+            .filter_map(|e| e.details.get(key).and_then(|v| v.as_u64()))
+            .sum::<u64>() as usize
+    };
+    let runs: std::collections::HashSet<&str> = entries.iter().map(|e| e.run_id.as_str()).collect();
+
+    Ok(AuditReport {
+        date_from: date_from.map(str::to_string),
+        date_to: date_to.map(str::to_string),
+        runs: runs.len(),
+        customers: entries.iter().filter(|e| e.action == "anonymize").count(),
+        reservations: count("reservations"),
+        messages: count("messages"),
+        reminders: count("reminders"),
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use crate::services::customers::{self, CreateCustomerInput, UpdateCustomerInput};
+
+    fn customer_input(name: &str, phone: &str) -> CreateCustomerInput {
+        CreateCustomerInput {
+            name: name.to_string(),
+            phone: Some(phone.to_string()),
+            email: None,
+            birthdate: Some("1990-01-01".to_string()),
+            gender: None,
+            preferred_designer_id: None,
+            preferred_service: None,
+            allergies: Some("염색약".to_string()),
+            notes: None,
+            reminder_opt_out: None,
+            consent_service: Some(true),
+            consent_marketing: Some(true),
+            consent_photo: None,
+        }
+    }
+
+    #[test]
+    fn test_consent_changes_are_versioned() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        let customer = customers::create_customer(conn, customer_input("김민지", "010-1111-1111")).unwrap();
+        assert!(customer.consent_service && customer.consent_marketing && !customer.consent_photo);
+        assert_eq!(customer.consent_version.as_deref(), Some("v1"));
+        assert!(customer.consent_marketing_at.is_some());
+
+        save_settings(conn, &PrivacySettings { consent_version: "2025-03".to_string(), ..Default::default() }).unwrap();
+        let updated = customers::update_customer(
+            conn,
+            &customer.id,
+            UpdateCustomerInput {
+                name: None,
+                phone: None,
+                email: None,
+                birthdate: None,
+                gender: None,
+                preferred_designer_id: None,
+                preferred_service: None,
+                allergies: None,
+                notes: None,
+                reminder_opt_out: None,
+                consent_service: Some(true),
+                consent_marketing: Some(false),
+                consent_photo: None,
+            },
+        )
+        .unwrap();
+        assert!(updated.consent_service && !updated.consent_marketing);
+        assert_eq!(updated.consent_version.as_deref(), Some("2025-03"));
+
+        // 바뀌지 않은 항목은 다시 기록하지 않음
+        let history = get_consent_history(conn, &customer.id).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].consent_type, "marketing");
+        assert!(!history[0].granted);
+        assert_eq!(history[0].version, "2025-03");
+    }
+
+    #[test]
+    fn test_purge_inactive_customers() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        let old = customers::create_customer(conn, customer_input("김민지", "010-1111-1111")).unwrap();
+        let recent = customers::create_customer(conn, customer_input("박서연", "010-2222-2222")).unwrap();
+        conn.execute_batch(
+            "UPDATE customers SET created_at = '2019-01-01 00:00:00';
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time, notes)
+                 VALUES ('r1', '김민지', '010-1111-1111', '2020-05-01', '10:00', '두피 예민');
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time)
+                 VALUES ('r2', '박서연', '010-2222-2222', '2024-12-01', '10:00');
+             INSERT INTO messages (id, reservation_id, channel, template, recipient, body)
+                 VALUES ('m1', 'r1', 'sms', 'reminder', '01011111111', '김민지님 예약 안내');",
+        )
+        .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let candidates = find_retention_candidates(conn, 3, today).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].masked_name, "김**");
+        assert_eq!(candidates[0].last_activity, "2020-05-01");
+
+        let report = purge_inactive_customers(conn, &PrivacySettings::default(), today, TRIGGER_MANUAL).unwrap();
+        assert_eq!((report.customers, report.reservations, report.messages), (1, 1, 1));
+        assert_eq!(report.cutoff_date, "2022-03-01");
+
+        let purged = customers::get_customer(conn, &old.id).unwrap();
+        assert_eq!(purged.name, ANONYMIZED_NAME);
+        assert!(purged.phone.is_none() && purged.birthdate.is_none() && purged.allergies.is_none());
+        assert!(purged.anonymized_at.is_some());
+        assert!(customers::get_customer(conn, &recent.id).unwrap().anonymized_at.is_none());
+
+        // 예약은 통계용으로 남지만 개인정보는 지워짐
+        let (name, phone, notes, customer_id): (String, Option<String>, Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT customer_name, customer_phone, notes, customer_id FROM reservations WHERE id = 'r1'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!((name.as_str(), phone, notes, customer_id), (ANONYMIZED_NAME, None, None, Some(old.id.clone())));
+
+        // 이미 익명 처리된 고객은 다시 대상이 되지 않음
+        assert!(find_retention_candidates(conn, 3, today).unwrap().is_empty());
+
+        let audit = get_audit_report(conn, None, None).unwrap();
+        assert_eq!((audit.runs, audit.customers, audit.reservations, audit.messages), (1, 1, 1, 1));
+        assert_eq!(audit.entries[0].customer_id.as_deref(), Some(old.id.as_str()));
+        assert_eq!(audit.entries[0].trigger, TRIGGER_MANUAL);
+    }
+
+    #[test]
+    fn test_erase_customer_keeps_reservation_rows() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        let customer = customers::create_customer(conn, customer_input("김민지", "010-1111-1111")).unwrap();
+        conn.execute_batch(&format!(
+            "INSERT INTO reservations (id, customer_name, customer_phone, date, time, service_type, status, customer_id)
+                 VALUES ('r1', '김민지', '010-1111-1111', '2025-03-01', '10:00', '커트', 'completed', '{id}');
+             INSERT INTO reservations (id, customer_name, customer_phone, date, time, service_type, status)
+                 VALUES ('r2', '김민지', '010-1111-1111', '2025-04-01', '10:00', '펌', 'completed');",
+            id = customer.id
+        ))
+        .unwrap();
+
+        let result = erase_customer(conn, &customer.id).unwrap();
+        assert_eq!(result.reservations, 2);
+        assert!(erase_customer(conn, &customer.id).is_err());
+
+        let (count, with_pii): (i64, i64) = conn
+            .query_row(
+                "SELECT COUNT(*), SUM(customer_phone IS NOT NULL OR customer_name = '김민지') FROM reservations",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((count, with_pii), (2, 0));
+        let erased = customers::get_customer(conn, &customer.id).unwrap();
+        assert!(!erased.consent_marketing && erased.phone.is_none());
+        assert_eq!(get_audit_report(conn, None, None).unwrap().entries[0].trigger, TRIGGER_REQUEST);
+    }
+
+    #[test]
+    fn test_erase_customer_leaves_no_copy_in_webhooks_or_sync_log() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        webhooks::create_endpoint(
+            conn,
+            webhooks::CreateWebhookEndpointInput {
+                name: "단체방".to_string(),
+                url: "http://127.0.0.1:9/hook".to_string(),
+                events: vec!["*".to_string()],
+                is_active: None,
+            },
+        )
+        .unwrap();
+        let customer = customers::create_customer(conn, customer_input("김민지", "010-1111-1111")).unwrap();
+        conn.execute(
+            "INSERT INTO reservations (id, customer_name, customer_phone, date, time, customer_id)
+             VALUES ('r1', '김민지', '010-1111-1111', '2025-03-01', '10:00', ?1)",
+            [&customer.id],
+        )
+        .unwrap();
+        let other = customers::create_customer(conn, customer_input("박서연", "010-2222-2222")).unwrap();
+        conn.execute(
+            "INSERT INTO webhook_deliveries (id, outbox_id, endpoint_id, event_type, attempt, success)
+             SELECT 'd-' || id, id, endpoint_id, event_type, 1, 0 FROM webhook_outbox",
+            [],
+        )
+        .unwrap();
+        changes::collect(conn).unwrap();
+
+        erase_customer(conn, &customer.id).unwrap();
+
+        let copies = |sql: &str| -> i64 {
+            conn.query_row(sql, ["%김민지%", "%010-1111-1111%"], |row| row.get(0)).unwrap()
+        };
+        assert_eq!(copies("SELECT COUNT(*) FROM webhook_outbox WHERE payload LIKE ?1 OR payload LIKE ?2"), 0);
+        assert_eq!(copies("SELECT COUNT(*) FROM sync_fields WHERE value LIKE ?1 OR value LIKE ?2"), 0);
+        changes::collect(conn).unwrap();
+        assert_eq!(copies("SELECT COUNT(*) FROM sync_fields WHERE value LIKE ?1 OR value LIKE ?2"), 0);
+        let deliveries: i64 = conn
+            .query_row("SELECT COUNT(*) FROM webhook_deliveries WHERE outbox_id NOT IN (SELECT id FROM webhook_outbox)", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(deliveries, 0);
+
+        // 다른 고객의 기록은 그대로
+        let kept: i64 = conn
+            .query_row("SELECT COUNT(*) FROM webhook_outbox WHERE json_extract(payload, '$.data.id') = ?1", [&other.id], |row| row.get(0))
+            .unwrap();
+        assert_eq!(kept, 1);
+    }
+}
@@ -0,0 +1,745 @@
+//! 예약 알림 (노쇼 방지)
+//!
+//! 예약 시각 N시간 전에 보낼 알림을 `reminders` 에 예약해 두고, 백그라운드 작업이
+//! 때가 된 알림을 설정된 발송 수단(`ReminderProvider`)으로 보낸다.
+//! 예약이 바뀌면 알림 시각도 다시 계산하고, 취소되거나 고객이 수신을 거부하면 보내지 않는다.
+//! 시각은 모두 현지 시각(`YYYY-MM-DD HH:MM`)으로 저장한다.
+//! 알림과 발송 기록은 기기마다 따로 두므로, 동기화하는 기기 중 알림을 보내는 기기로 정해진
+//! 한 곳(`shared_settings` 의 `reminder_sender`)에서만 보낸다.
+
+pub mod providers;
+
+use chrono::{Datelike, Duration, NaiveDateTime};
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::db::Database;
+use crate::services::messages::{self, NewMessage};
+use crate::services::sync;
+use providers::{normalize_phone, OutgoingMessage, ReminderProvider, ReminderProviderConfig};
+
+const SETTINGS_KEY: &str = "reminder_settings";
+/// 알림을 보내는 기기의 동기화 기기 id (모든 기기가 함께 쓰는 설정)
+const SENDER_KEY: &str = "reminder_sender";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+/// 예약까지 이보다 적게 남았으면 새로 알림을 만들지 않는다
+const MIN_LEAD_MINUTES: i64 = 60;
+const MAX_ATTEMPTS: i32 = 3;
+const RETRY_MINUTES: i64 = 10;
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+pub const DEFAULT_TEMPLATE: &str =
+    "[#{살롱명}] #{고객명}님, #{날짜} #{시간}에 #{디자이너} 디자이너 예약이 있습니다. 변경이나 취소는 매장으로 연락 부탁드립니다.";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderSettings {
+    pub enabled: bool,
+    pub hours_before: u32,
+    pub salon_name: String,
+    pub template: String,
+    pub provider: ReminderProviderConfig,
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hours_before: 24,
+            salon_name: "Sisters Salon".to_string(),
+            template: DEFAULT_TEMPLATE.to_string(),
+            provider: ReminderProviderConfig::File,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reminder {
+    pub id: String,
+    pub reservation_id: String,
+    pub customer_name: Option<String>,
+    pub recipient: String,
+    pub reservation_at: String,
+    pub scheduled_at: String,
+    pub status: String,
+    pub provider: Option<String>,
+    pub message: Option<String>,
+    pub attempts: i32,
+    pub provider_message_id: Option<String>,
+    pub error: Option<String>,
+    pub sent_at: Option<String>,
+}
+
+/// Get reminder settings from app_settings table
+pub fn get_settings(conn: &Connection) -> Result<ReminderSettings, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(ReminderSettings::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Save reminder settings to app_settings table
+pub fn save_settings(conn: &Connection, settings: &ReminderSettings) -> Result<(), String> {
+    if settings.hours_before == 0 || settings.hours_before > 24 * 7 {
+        return Err("알림 시점은 1시간에서 168시간(7일) 전 사이로 설정해주세요".to_string());
+    }
+    if settings.template.trim().is_empty() {
+        return Err("알림 문구를 입력해주세요".to_string());
+    }
+
+    // 알림을 새로 켠 기기가 보내는 기기를 맡는다
+    if settings.enabled && !get_settings(conn)?.enabled {
+        claim_sender(conn)?;
+    }
+
+    let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![SETTINGS_KEY, json, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// 이 기기가 알림을 보내는 기기인지. 아직 정해진 기기가 없으면 이 기기로 본다.
+pub fn is_sender(conn: &Connection) -> Result<bool, String> {
+    match sync::get_shared_value(conn, SENDER_KEY)? {
+        Some(sender) => Ok(sender == sync::changes::node_id(conn)?),
+        None => Ok(true),
+    }
+}
+
+/// 이 기기가 알림을 보내도록 한다. 동기화되면 다른 기기는 보내지 않는다.
+pub fn claim_sender(conn: &Connection) -> Result<(), String> {
+    sync::set_shared_value(conn, SENDER_KEY, &sync::changes::node_id(conn)?)
+}
+
+/// `#{변수}` 를 값으로 바꾼다. 모르는 변수는 그대로 둔다.
+pub fn render_template(template: &str, variables: &BTreeMap<String, String>) -> String {
+    let mut text = template.to_string();
+    for (key, value) in variables {
+        text = text.replace(&format!("#{{{}}}", key), value);
+    }
+    text
+}
+
+const WEEKDAYS: [&str; 7] = ["일", "월", "화", "수", "목", "금", "토"];
+
+fn template_variables(
+    salon_name: &str,
+    customer_name: Option<&str>,
+    designer_name: Option<&str>,
+    service_type: Option<&str>,
+    starts_at: NaiveDateTime,
+) -> BTreeMap<String, String> {
+    let date = starts_at.date();
+    let weekday = WEEKDAYS[date.weekday().num_days_from_sunday() as usize];
+    let non_empty = |v: Option<&str>| v.filter(|s| !s.trim().is_empty()).map(str::to_string);
+
+    BTreeMap::from([
+        ("고객명".to_string(), non_empty(customer_name).unwrap_or_else(|| "고객".to_string())),
+        ("날짜".to_string(), format!("{}월 {}일({})", date.month(), date.day(), weekday)),
+        ("시간".to_string(), starts_at.format("%H:%M").to_string()),
+        ("디자이너".to_string(), non_empty(designer_name).unwrap_or_else(|| "담당".to_string())),
+        ("시술".to_string(), non_empty(service_type).unwrap_or_default()),
+        ("살롱명".to_string(), salon_name.to_string()),
+    ])
+}
+
+/// 설정 화면 미리보기용 예시 문구
+pub fn preview(settings: &ReminderSettings) -> String {
+    let starts_at = chrono::Local::now().naive_local().date().and_hms_opt(14, 30, 0).unwrap() + Duration::days(1);
+    let variables = template_variables(&settings.salon_name, Some("홍길동"), Some("김지수"), Some("커트"), starts_at);
+    render_template(&settings.template, &variables)
+}
+
+fn parse_datetime(date: &str, time: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(&format!("{} {}", date, time), DATETIME_FORMAT).ok()
+}
+
+fn format_datetime(value: NaiveDateTime) -> String {
+    value.format(DATETIME_FORMAT).to_string()
+}
+
+struct UpcomingReservation {
+    id: String,
+    starts_at: NaiveDateTime,
+    status: String,
+    phone: Option<String>,
+    opt_out: bool,
+}
+
+impl UpcomingReservation {
+    /// 알림을 보내야 하는 예약인지 (보내지 않는 이유)
+    fn skip_reason(&self, now: NaiveDateTime) -> Option<&'static str> {
+        if self.status != "pending" && self.status != "confirmed" {
+            Some("예약이 취소되었거나 완료되었습니다")
+        } else if self.opt_out {
+            Some("고객이 알림 수신을 거부했습니다")
+        } else if self.phone.as_deref().map(normalize_phone).unwrap_or_default().is_empty() {
+            Some("전화번호가 없습니다")
+        } else if self.starts_at <= now {
+            Some("예약 시간이 지났습니다")
+        } else {
+            None
+        }
+    }
+}
+
+struct ExistingReminder {
+    id: String,
+    status: String,
+    reservation_at: String,
+}
+
+/// 앞으로의 예약에 맞춰 알림을 만들거나 다시 잡거나 취소한다. 바뀐 알림 수를 돌려준다.
+pub fn schedule_reminders(conn: &Connection, settings: &ReminderSettings, now: NaiveDateTime) -> Result<usize, String> {
+    let lead = Duration::hours(settings.hours_before as i64);
+    let from = now.date().format("%Y-%m-%d").to_string();
+    let to = (now + lead + Duration::days(1)).date().format("%Y-%m-%d").to_string();
+
+    let upcoming: Vec<UpcomingReservation> = {
+        let mut stmt = conn
+            .prepare(
+                "SELECT r.id, r.date, r.time, r.status, COALESCE(NULLIF(r.customer_phone, ''), c.phone),
+                        COALESCE(c.reminder_opt_out, 0)
+                 FROM reservations r
+                 LEFT JOIN customers c
+                   ON c.id = r.customer_id OR (r.customer_id IS NULL AND c.phone = r.customer_phone)
+                 WHERE r.date >= ?1 AND r.date <= ?2",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([&from, &to], |row| {
+                let date: String = row.get(1)?;
+                let time: String = row.get(2)?;
+                let opt_out: i32 = row.get(5)?;
+                Ok((row.get::<_, String>(0)?, date, time, row.get::<_, String>(3)?, row.get(4)?, opt_out == 1))
+            })
+            .map_err(|e| e.to_string())?;
+        rows.filter_map(|r| r.ok())
+            .filter_map(|(id, date, time, status, phone, opt_out)| {
+                Some(UpcomingReservation { id, starts_at: parse_datetime(&date, &time)?, status, phone, opt_out })
+            })
+            .collect()
+    };
+
+    let mut changed = 0;
+    for reservation in &upcoming {
+        let existing: Option<ExistingReminder> = conn
+            .query_row(
+                "SELECT id, status, reservation_at FROM reminders WHERE reservation_id = ?1",
+                [&reservation.id],
+                |row| Ok(ExistingReminder { id: row.get(0)?, status: row.get(1)?, reservation_at: row.get(2)? }),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?;
+
+        let reservation_at = format_datetime(reservation.starts_at);
+        let scheduled_at = format_datetime((reservation.starts_at - lead).max(now));
+        let recipient = reservation.phone.as_deref().map(normalize_phone).unwrap_or_default();
+        let skip = reservation.skip_reason(now);
+        let too_late = reservation.starts_at - now < Duration::minutes(MIN_LEAD_MINUTES);
+
+        match (existing, skip) {
+            (None, None) if !too_late => {
+                conn.execute(
+                    "INSERT INTO reminders (id, reservation_id, recipient, reservation_at, scheduled_at, status, attempts, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 'pending', 0, ?6, ?6)",
+                    rusqlite::params![Uuid::new_v4().to_string(), reservation.id, recipient, reservation_at, scheduled_at, format_datetime(now)],
+                )
+                .map_err(|e| e.to_string())?;
+                changed += 1;
+            }
+            (Some(existing), Some(reason)) if existing.status == "pending" => {
+                conn.execute(
+                    "UPDATE reminders SET status = 'cancelled', error = ?2, updated_at = ?3 WHERE id = ?1",
+                    rusqlite::params![existing.id, reason, format_datetime(now)],
+                )
+                .map_err(|e| e.to_string())?;
+                changed += 1;
+            }
+            (Some(existing), None) => {
+                let rescheduled = existing.reservation_at != reservation_at;
+                let reactivate = existing.status == "cancelled" || (existing.status != "pending" && rescheduled);
+                if existing.status == "pending" && rescheduled {
+                    conn.execute(
+                        "UPDATE reminders SET recipient = ?2, reservation_at = ?3, scheduled_at = ?4, updated_at = ?5 WHERE id = ?1",
+                        rusqlite::params![existing.id, recipient, reservation_at, scheduled_at, format_datetime(now)],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    changed += 1;
+                } else if reactivate && !too_late {
+                    // 취소됐던 예약이 되살아났거나, 보낸 뒤 예약 시각이 바뀌었으면 새로 알림
+                    conn.execute(
+                        "UPDATE reminders SET status = 'pending', recipient = ?2, reservation_at = ?3, scheduled_at = ?4,
+                                attempts = 0, error = NULL, message = NULL, sent_at = NULL, updated_at = ?5
+                         WHERE id = ?1",
+                        rusqlite::params![existing.id, recipient, reservation_at, scheduled_at, format_datetime(now)],
+                    )
+                    .map_err(|e| e.to_string())?;
+                    changed += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(changed)
+}
+
+struct DueReminder {
+    id: String,
+    reservation_id: String,
+    customer_id: Option<String>,
+    recipient: String,
+    attempts: i32,
+    starts_at: Option<NaiveDateTime>,
+    customer_name: Option<String>,
+    designer_name: Option<String>,
+    service_type: Option<String>,
+    status: String,
+}
+
+fn due_reminders(conn: &Connection, now: NaiveDateTime) -> Result<Vec<DueReminder>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.id, m.recipient, m.attempts, r.date, r.time, r.customer_name, d.name, r.service_type, r.status,
+                    m.reservation_id,
+                    COALESCE(r.customer_id, (SELECT c.id FROM customers c WHERE c.phone = r.customer_phone LIMIT 1))
+             FROM reminders m
+             JOIN reservations r ON r.id = m.reservation_id
+             LEFT JOIN designers d ON d.id = r.designer_id
+             WHERE m.status = 'pending' AND m.scheduled_at <= ?1
+             ORDER BY m.scheduled_at",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([format_datetime(now)], |row| {
+            let date: String = row.get(3)?;
+            let time: String = row.get(4)?;
+            Ok(DueReminder {
+                id: row.get(0)?,
+                recipient: row.get(1)?,
+                attempts: row.get(2)?,
+                starts_at: parse_datetime(&date, &time),
+                customer_name: row.get(5)?,
+                designer_name: row.get(6)?,
+                service_type: row.get(7)?,
+                status: row.get(8)?,
+                reservation_id: row.get(9)?,
+                customer_id: row.get(10)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let reminders: Vec<DueReminder> = rows.filter_map(|r| r.ok()).collect();
+    Ok(reminders)
+}
+
+/// 알림 예약을 갱신한 뒤 때가 된 알림을 보낸다. 발송을 시도한 수를 돌려준다.
+/// 다른 기기가 알림을 보내는 기기로 정해져 있으면 아무것도 하지 않는다.
+/// 발송 중에는 DB 잠금을 잡지 않는다.
+pub fn process_due(
+    db: &Mutex<Database>,
+    settings: &ReminderSettings,
+    provider: &dyn ReminderProvider,
+    now: NaiveDateTime,
+) -> Result<usize, String> {
+    let due = {
+        let db = db.lock().map_err(|e| e.to_string())?;
+        if sync::get_shared_value(db.conn(), SENDER_KEY)?.is_none() {
+            claim_sender(db.conn())?;
+        } else if !is_sender(db.conn())? {
+            return Ok(0);
+        }
+        schedule_reminders(db.conn(), settings, now)?;
+        due_reminders(db.conn(), now)?
+    };
+
+    let mut attempted = 0;
+    for reminder in due {
+        let Some(starts_at) = reminder.starts_at.filter(|s| *s > now) else {
+            let db = db.lock().map_err(|e| e.to_string())?;
+            mark(db.conn(), &reminder.id, "skipped", Some("예약 시간이 지났습니다"), now)?;
+            continue;
+        };
+        if reminder.status != "pending" && reminder.status != "confirmed" {
+            let db = db.lock().map_err(|e| e.to_string())?;
+            mark(db.conn(), &reminder.id, "cancelled", Some("예약이 취소되었거나 완료되었습니다"), now)?;
+            continue;
+        }
+
+        let variables = template_variables(
+            &settings.salon_name,
+            reminder.customer_name.as_deref(),
+            reminder.designer_name.as_deref(),
+            reminder.service_type.as_deref(),
+            starts_at,
+        );
+        let message = OutgoingMessage {
+            reminder_id: reminder.id.clone(),
+            to: reminder.recipient.clone(),
+            text: render_template(&settings.template, &variables),
+            variables,
+        };
+
+        let result = provider.send(&message);
+        attempted += 1;
+
+        let db = db.lock().map_err(|e| e.to_string())?;
+        let conn = db.conn();
+        let attempts = reminder.attempts + 1;
+        let now_text = format_datetime(now);
+        match &result {
+            Ok(provider_message_id) => conn.execute(
+                "UPDATE reminders SET status = 'sent', provider = ?2, message = ?3, attempts = ?4,
+                        provider_message_id = ?5, error = NULL, sent_at = ?6, updated_at = ?6
+                 WHERE id = ?1",
+                rusqlite::params![reminder.id, provider.name(), message.text, attempts, provider_message_id, now_text],
+            ),
+            Err(e) => conn.execute(
+                "UPDATE reminders SET status = ?2, provider = ?3, message = ?4, attempts = ?5, error = ?6,
+                        scheduled_at = ?7, updated_at = ?8
+                 WHERE id = ?1",
+                rusqlite::params![
+                    reminder.id,
+                    if attempts >= MAX_ATTEMPTS { "failed" } else { "pending" },
+                    provider.name(),
+                    message.text,
+                    attempts,
+                    e,
+                    format_datetime(now + Duration::minutes(RETRY_MINUTES)),
+                    now_text,
+                ],
+            ),
+        }
+        .map_err(|e| e.to_string())?;
+
+        // 고객 커뮤니케이션 기록: 보냈거나 최종 실패한 경우만 남긴다
+        if result.is_ok() || attempts >= MAX_ATTEMPTS {
+            messages::record_message(
+                conn,
+                &NewMessage {
+                    customer_id: reminder.customer_id.as_deref(),
+                    reservation_id: Some(&reminder.reservation_id),
+                    channel: provider.name(),
+                    template: messages::TEMPLATE_REMINDER,
+                    recipient: &reminder.recipient,
+                    body: &message.text,
+                    status: if result.is_ok() { "sent" } else { "failed" },
+                    provider_message_id: result.as_ref().ok().and_then(|id| id.as_deref()),
+                    error: result.as_ref().err().map(String::as_str),
+                    sent_at: result.is_ok().then_some(now_text.as_str()),
+                    created_at: Some(&now_text),
+                },
+            )?;
+        }
+    }
+
+    Ok(attempted)
+}
+
+fn mark(conn: &Connection, id: &str, status: &str, error: Option<&str>, now: NaiveDateTime) -> Result<(), String> {
+    conn.execute(
+        "UPDATE reminders SET status = ?2, error = ?3, updated_at = ?4 WHERE id = ?1",
+        rusqlite::params![id, status, error, format_datetime(now)],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn get_reminders(
+    conn: &Connection,
+    date_from: Option<&str>,
+    date_to: Option<&str>,
+    status: Option<&str>,
+) -> Result<Vec<Reminder>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.id, m.reservation_id, r.customer_name, m.recipient, m.reservation_at, m.scheduled_at, m.status,
+                    m.provider, m.message, m.attempts, m.provider_message_id, m.error, m.sent_at
+             FROM reminders m
+             LEFT JOIN reservations r ON r.id = m.reservation_id
+             WHERE (?1 IS NULL OR substr(m.reservation_at, 1, 10) >= ?1)
+               AND (?2 IS NULL OR substr(m.reservation_at, 1, 10) <= ?2)
+               AND (?3 IS NULL OR m.status = ?3)
+             ORDER BY m.scheduled_at DESC
+             LIMIT 500",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![date_from, date_to, status], |row| {
+            Ok(Reminder {
+                id: row.get(0)?,
+                reservation_id: row.get(1)?,
+                customer_name: row.get(2)?,
+                recipient: row.get(3)?,
+                reservation_at: row.get(4)?,
+                scheduled_at: row.get(5)?,
+                status: row.get(6)?,
+                provider: row.get(7)?,
+                message: row.get(8)?,
+                attempts: row.get(9)?,
+                provider_message_id: row.get(10)?,
+                error: row.get(11)?,
+                sent_at: row.get(12)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let reminders: Vec<Reminder> = rows.filter_map(|r| r.ok()).collect();
+    Ok(reminders)
+}
+
+/// 보내기 전 알림을 직접 취소 (예약이 바뀌어도 다시 잡히지 않도록 skipped 로 둔다)
+pub fn cancel_reminder(conn: &Connection, id: &str) -> Result<(), String> {
+    let updated = conn
+        .execute(
+            "UPDATE reminders SET status = 'skipped', error = '직접 취소', updated_at = ?2 WHERE id = ?1 AND status = 'pending'",
+            rusqlite::params![id, format_datetime(chrono::Local::now().naive_local())],
+        )
+        .map_err(|e| e.to_string())?;
+    if updated == 0 {
+        return Err("대기 중인 알림만 취소할 수 있습니다".to_string());
+    }
+    Ok(())
+}
+
+/// 실패한 알림을 바로 다시 보내도록 대기열에 올림
+pub fn retry_reminder(conn: &Connection, id: &str) -> Result<(), String> {
+    let now = format_datetime(chrono::Local::now().naive_local());
+    let updated = conn
+        .execute(
+            "UPDATE reminders SET status = 'pending', attempts = 0, scheduled_at = ?2, updated_at = ?2
+             WHERE id = ?1 AND status = 'failed'",
+            rusqlite::params![id, now],
+        )
+        .map_err(|e| e.to_string())?;
+    if updated == 0 {
+        return Err("실패한 알림만 다시 보낼 수 있습니다".to_string());
+    }
+    Ok(())
+}
+
+/// 설정한 발송 수단으로 시험 메시지를 바로 보낸다
+pub fn send_test(settings: &ReminderSettings, data_dir: &std::path::Path, phone: &str) -> Result<Option<String>, String> {
+    let to = normalize_phone(phone);
+    if to.is_empty() {
+        return Err("받을 전화번호를 입력해주세요".to_string());
+    }
+    let provider = settings.provider.build(data_dir)?;
+    let starts_at = chrono::Local::now().naive_local() + Duration::hours(settings.hours_before as i64);
+    let variables = template_variables(&settings.salon_name, Some("테스트"), None, None, starts_at);
+    provider.send(&OutgoingMessage {
+        reminder_id: "test".to_string(),
+        to,
+        text: render_template(&settings.template, &variables),
+        variables,
+    })
+}
+
+/// 앱 실행 동안 1분마다 알림을 예약하고 보내는 백그라운드 스레드
+pub fn start_worker(db: Arc<Mutex<Database>>, data_dir: PathBuf) {
+    let spawned = std::thread::Builder::new()
+        .name("reminder-worker".to_string())
+        .spawn(move || loop {
+            let settings = db.lock().map_err(|e| e.to_string()).and_then(|guard| get_settings(guard.conn()));
+            match settings {
+                Ok(settings) if settings.enabled => {
+                    let result = settings
+                        .provider
+                        .build(&data_dir)
+                        .and_then(|provider| process_due(&db, &settings, provider.as_ref(), chrono::Local::now().naive_local()));
+                    if let Err(e) = result {
+                        eprintln!("Reminder processing failed: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to load reminder settings: {}", e),
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("Failed to start reminder worker: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use providers::MockProvider;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, DATETIME_FORMAT).unwrap()
+    }
+
+    fn insert_reservation(conn: &Connection, id: &str, phone: &str, date: &str, time: &str, customer_id: Option<&str>) {
+        conn.execute(
+            "INSERT INTO reservations (id, customer_name, customer_phone, date, time, designer_id, status, customer_id)
+             VALUES (?1, '김민지', ?2, ?3, ?4, 'd1', 'confirmed', ?5)",
+            rusqlite::params![id, phone, date, time, customer_id],
+        )
+        .unwrap();
+    }
+
+    fn setup() -> (Mutex<Database>, ReminderSettings) {
+        let db = Database::open_in_memory().unwrap();
+        db.conn()
+            .execute("INSERT INTO designers (id, name) VALUES ('d1', '김지수')", [])
+            .unwrap();
+        let settings = ReminderSettings { enabled: true, ..Default::default() };
+        (Mutex::new(db), settings)
+    }
+
+    fn status_of(db: &Mutex<Database>, reservation_id: &str) -> Option<String> {
+        db.lock()
+            .unwrap()
+            .conn()
+            .query_row("SELECT status FROM reminders WHERE reservation_id = ?1", [reservation_id], |row| row.get(0))
+            .optional()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_render_template() {
+        let variables = template_variables("Sisters Salon", Some("김민지"), Some("김지수"), None, at("2025-03-05 14:30"));
+        assert_eq!(
+            render_template(DEFAULT_TEMPLATE, &variables),
+            "[Sisters Salon] 김민지님, 3월 5일(수) 14:30에 김지수 디자이너 예약이 있습니다. 변경이나 취소는 매장으로 연락 부탁드립니다."
+        );
+        assert_eq!(render_template("#{없는변수}", &variables), "#{없는변수}");
+    }
+
+    #[test]
+    fn test_reminder_lifecycle() {
+        let (db, settings) = setup();
+        {
+            let guard = db.lock().unwrap();
+            let conn = guard.conn();
+            conn.execute(
+                "INSERT INTO customers (id, name, phone, reminder_opt_out) VALUES ('c2', '박서연', '010-2222-2222', 1)",
+                [],
+            )
+            .unwrap();
+            conn.execute("INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-1111')", [])
+                .unwrap();
+            insert_reservation(conn, "r1", "010-1111-1111", "2025-03-06", "14:00", None);
+            insert_reservation(conn, "r2", "010-2222-2222", "2025-03-06", "15:00", Some("c2"));
+        }
+        let provider = MockProvider::default();
+
+        // 하루 전 알림은 아직 때가 아님
+        assert_eq!(process_due(&db, &settings, &provider, at("2025-03-05 10:00")).unwrap(), 0);
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("pending"));
+        // 수신 거부 고객은 알림을 만들지 않음
+        assert_eq!(status_of(&db, "r2"), None);
+
+        assert_eq!(process_due(&db, &settings, &provider, at("2025-03-05 14:00")).unwrap(), 1);
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("sent"));
+        let sent = provider.sent.lock().unwrap();
+        assert_eq!(sent[0].to, "01011111111");
+        assert!(sent[0].text.contains("김민지님, 3월 6일(목) 14:00에 김지수 디자이너"));
+        drop(sent);
+
+        // 전화번호로 찾은 고객의 커뮤니케이션 기록에 남음
+        {
+            let guard = db.lock().unwrap();
+            let history = messages::get_customer_messages(guard.conn(), "c1", None).unwrap();
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].status, "sent");
+            assert_eq!(history[0].template, messages::TEMPLATE_REMINDER);
+            assert_eq!(history[0].reservation_id.as_deref(), Some("r1"));
+            assert_eq!(history[0].provider_message_id.as_deref(), Some("mock-1"));
+        }
+
+        // 보낸 뒤 예약 시각이 바뀌면 새 시각 기준으로 다시 알림
+        {
+            let guard = db.lock().unwrap();
+            guard.conn().execute("UPDATE reservations SET time = '17:00' WHERE id = 'r1'", []).unwrap();
+        }
+        process_due(&db, &settings, &provider, at("2025-03-05 15:00")).unwrap();
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("pending"));
+
+        // 취소된 예약의 알림은 보내지 않음
+        {
+            let guard = db.lock().unwrap();
+            guard.conn().execute("UPDATE reservations SET status = 'cancelled' WHERE id = 'r1'", []).unwrap();
+        }
+        assert_eq!(process_due(&db, &settings, &provider, at("2025-03-05 17:00")).unwrap(), 0);
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("cancelled"));
+        assert_eq!(provider.sent.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_send_is_retried() {
+        let (db, settings) = setup();
+        {
+            let guard = db.lock().unwrap();
+            insert_reservation(guard.conn(), "r1", "010-1111-1111", "2025-03-06", "14:00", None);
+        }
+        let failing = MockProvider { fail: true, ..Default::default() };
+
+        let now = at("2025-03-05 14:00");
+        for attempt in 0..MAX_ATTEMPTS {
+            let when = now + Duration::minutes(RETRY_MINUTES * attempt as i64);
+            assert_eq!(process_due(&db, &settings, &failing, when).unwrap(), 1);
+        }
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("failed"));
+
+        let guard = db.lock().unwrap();
+        let reminders = get_reminders(guard.conn(), None, None, Some("failed")).unwrap();
+        assert_eq!(reminders[0].attempts, MAX_ATTEMPTS);
+        assert_eq!(reminders[0].error.as_deref(), Some("mock failure"));
+
+        // 재시도 중간 실패는 기록하지 않고 최종 실패만 남김
+        let failed: i32 = guard
+            .conn()
+            .query_row("SELECT COUNT(*) FROM messages WHERE status = 'failed'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(failed, 1);
+    }
+
+    #[test]
+    fn test_only_the_sender_device_sends() {
+        let (db, settings) = setup();
+        {
+            let guard = db.lock().unwrap();
+            insert_reservation(guard.conn(), "r1", "010-1111-1111", "2025-03-06", "14:00", None);
+            // 다른 기기가 알림을 켜서 보내는 기기를 맡았고 동기화로 전해졌다
+            sync::set_shared_value(guard.conn(), SENDER_KEY, "other-device").unwrap();
+            assert!(!is_sender(guard.conn()).unwrap());
+        }
+        let provider = MockProvider::default();
+
+        assert_eq!(process_due(&db, &settings, &provider, at("2025-03-05 14:00")).unwrap(), 0);
+        assert_eq!(status_of(&db, "r1"), None);
+        assert!(provider.sent.lock().unwrap().is_empty());
+
+        // 이 기기에서 알림을 다시 켜면 이 기기가 맡는다
+        {
+            let guard = db.lock().unwrap();
+            save_settings(guard.conn(), &ReminderSettings::default()).unwrap();
+            save_settings(guard.conn(), &settings).unwrap();
+            assert!(is_sender(guard.conn()).unwrap());
+        }
+        assert_eq!(process_due(&db, &settings, &provider, at("2025-03-05 14:00")).unwrap(), 1);
+        assert_eq!(status_of(&db, "r1").as_deref(), Some("sent"));
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 보낼 메시지 한 건
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingMessage {
+    pub reminder_id: String,
+    /// 숫자만 남긴 수신 번호
+    pub to: String,
+    pub text: String,
+    /// 템플릿 변수 (알림톡처럼 변수를 따로 받는 API 용)
+    pub variables: BTreeMap<String, String>,
+}
+
+/// 알림 발송 수단
+pub trait ReminderProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// 성공하면 발송사 메시지 ID(있으면)를 돌려준다
+    fn send(&self, message: &OutgoingMessage) -> Result<Option<String>, String>;
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReminderProviderConfig {
+    /// 실제로 보내지 않고 앱 데이터 폴더의 reminders.log 에 기록 (테스트용)
+    File,
+    /// 문자 발송 HTTP API
+    #[serde(rename_all = "camelCase")]
+    Sms {
+        api_url: String,
+        api_key: String,
+        sender: String,
+    },
+    /// 카카오 알림톡 HTTP API
+    #[serde(rename_all = "camelCase")]
+    Alimtalk {
+        api_url: String,
+        api_key: String,
+        sender_key: String,
+        template_code: String,
+    },
+}
+
+impl ReminderProviderConfig {
+    pub fn build(&self, data_dir: &std::path::Path) -> Result<Box<dyn ReminderProvider>, String> {
+        Ok(match self {
+            ReminderProviderConfig::File => Box::new(FileProvider {
+                path: data_dir.join("reminders.log"),
+            }),
+            ReminderProviderConfig::Sms { api_url, api_key, sender } => {
+                require("API 주소", api_url)?;
+                require("발신번호", sender)?;
+                Box::new(SmsProvider {
+                    api_url: api_url.clone(),
+                    api_key: api_key.clone(),
+                    sender: normalize_phone(sender),
+                })
+            }
+            ReminderProviderConfig::Alimtalk { api_url, api_key, sender_key, template_code } => {
+                require("API 주소", api_url)?;
+                require("발신 프로필 키", sender_key)?;
+                require("템플릿 코드", template_code)?;
+                Box::new(AlimtalkProvider {
+                    api_url: api_url.clone(),
+                    api_key: api_key.clone(),
+                    sender_key: sender_key.clone(),
+                    template_code: template_code.clone(),
+                })
+            }
+        })
+    }
+}
+
+fn require(label: &str, value: &str) -> Result<(), String> {
+    if value.trim().is_empty() {
+        Err(format!("{}를 입력해주세요", label))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn normalize_phone(phone: &str) -> String {
+    phone.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// 한 줄에 JSON 하나씩 파일에 기록
+pub struct FileProvider {
+    pub path: PathBuf,
+}
+
+impl ReminderProvider for FileProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn send(&self, message: &OutgoingMessage) -> Result<Option<String>, String> {
+        let line = serde_json::json!({
+            "sentAt": chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            "message": message,
+        });
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("알림 기록 파일을 열 수 없습니다: {}", e))?;
+        writeln!(file, "{}", line).map_err(|e| e.to_string())?;
+        Ok(None)
+    }
+}
+
+/// JSON 을 POST 하고 응답의 메시지 ID 를 찾는다
+fn post_json(url: &str, api_key: &str, body: serde_json::Value) -> Result<Option<String>, String> {
+    let agent = ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build();
+    let mut request = agent.post(url).set("Content-Type", "application/json");
+    if !api_key.is_empty() {
+        request = request.set("Authorization", &format!("Bearer {}", api_key));
+    }
+
+    match request.send_string(&body.to_string()) {
+        Ok(response) => {
+            let value: serde_json::Value = response
+                .into_string()
+                .ok()
+                .and_then(|body| serde_json::from_str(&body).ok())
+                .unwrap_or(serde_json::Value::Null);
+            let id = ["messageId", "message_id", "id", "groupId"]
+                .iter()
+                .find_map(|key| value.get(*key))
+                .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()));
+            Ok(id)
+        }
+        Err(ureq::Error::Status(code, response)) => {
+            let detail = response.into_string().unwrap_or_default();
+            Err(format!("HTTP {} {}", code, detail.chars().take(200).collect::<String>()).trim().to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 90바이트(EUC-KR 기준 한글 45자)를 넘으면 장문(LMS)
+fn sms_type(text: &str) -> &'static str {
+    let bytes: usize = text.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum();
+    if bytes > 90 { "LMS" } else { "SMS" }
+}
+
+pub struct SmsProvider {
+    api_url: String,
+    api_key: String,
+    sender: String,
+}
+
+impl ReminderProvider for SmsProvider {
+    fn name(&self) -> &'static str {
+        "sms"
+    }
+
+    fn send(&self, message: &OutgoingMessage) -> Result<Option<String>, String> {
+        post_json(
+            &self.api_url,
+            &self.api_key,
+            serde_json::json!({
+                "type": sms_type(&message.text),
+                "from": self.sender,
+                "to": message.to,
+                "text": message.text,
+            }),
+        )
+    }
+}
+
+pub struct AlimtalkProvider {
+    api_url: String,
+    api_key: String,
+    sender_key: String,
+    template_code: String,
+}
+
+impl ReminderProvider for AlimtalkProvider {
+    fn name(&self) -> &'static str {
+        "alimtalk"
+    }
+
+    fn send(&self, message: &OutgoingMessage) -> Result<Option<String>, String> {
+        // 알림톡은 사전 승인된 템플릿 코드로 보내며, 변수 값도 함께 전달한다
+        let variables: BTreeMap<String, String> = message
+            .variables
+            .iter()
+            .map(|(k, v)| (format!("#{{{}}}", k), v.clone()))
+            .collect();
+        post_json(
+            &self.api_url,
+            &self.api_key,
+            serde_json::json!({
+                "senderKey": self.sender_key,
+                "templateCode": self.template_code,
+                "to": message.to,
+                "text": message.text,
+                "variables": variables,
+            }),
+        )
+    }
+}
+
+/// 보낸 메시지를 메모리에 모아 두는 테스트용 발송 수단
+#[cfg(test)]
+#[derive(Default)]
+pub struct MockProvider {
+    pub sent: std::sync::Mutex<Vec<OutgoingMessage>>,
+    pub fail: bool,
+}
+
+#[cfg(test)]
+impl ReminderProvider for MockProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn send(&self, message: &OutgoingMessage) -> Result<Option<String>, String> {
+        if self.fail {
+            return Err("mock failure".to_string());
+        }
+        let mut sent = self.sent.lock().unwrap();
+        sent.push(message.clone());
+        Ok(Some(format!("mock-{}", sent.len())))
+    }
+}
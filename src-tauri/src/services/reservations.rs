@@ -0,0 +1,258 @@
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::{schedule, time_blocks, webhooks};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reservation {
+    pub id: String,
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub date: String,
+    pub time: String,
+    pub designer_id: Option<String>,
+    pub service_type: Option<String>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReservationInput {
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub date: String,
+    pub time: String,
+    pub designer_id: Option<String>,
+    pub service_type: Option<String>,
+    pub notes: Option<String>,
+}
+
+pub const RESERVATION_STATUSES: &[&str] = &["pending", "confirmed", "completed", "cancelled", "no_show"];
+
+fn row_to_reservation(row: &rusqlite::Row) -> rusqlite::Result<Reservation> {
+    Ok(Reservation {
+        id: row.get(0)?,
+        customer_name: row.get(1)?,
+        customer_phone: row.get(2)?,
+        date: row.get(3)?,
+        time: row.get(4)?,
+        designer_id: row.get(5)?,
+        service_type: row.get(6)?,
+        status: row.get(7)?,
+        notes: row.get(8)?,
+        created_at: row.get(9)?,
+        updated_at: row.get(10)?,
+    })
+}
+
+/// 영업시간(예외, 휴무 기간, 휴일 포함)과 디자이너 차단 시간을 기준으로 예약 가능 여부 확인
+fn ensure_schedulable(conn: &Connection, data: &CreateReservationInput) -> Result<(), String> {
+    schedule::ensure_open_at(conn, &data.date, &data.time)?;
+    time_blocks::ensure_no_block_conflict(conn, data.designer_id.as_deref(), &data.date, &data.time)
+}
+
+pub fn get_reservations(
+    conn: &Connection,
+    date: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+) -> Result<Vec<Reservation>, String> {
+    let reservations = if let Some(d) = date {
+        // Single date filter (for calendar view)
+        let mut stmt = conn
+            .prepare("SELECT * FROM reservations WHERE date = ?1 ORDER BY time")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([d], row_to_reservation)
+            .map_err(|e| e.to_string())?;
+        rows.filter_map(|r| r.ok()).collect()
+    } else if date_from.is_some() || date_to.is_some() {
+        // Date range filter
+        match (date_from, date_to) {
+            (Some(from), Some(to)) => {
+                let mut stmt = conn
+                    .prepare("SELECT * FROM reservations WHERE date >= ?1 AND date <= ?2 ORDER BY date DESC, time")
+                    .map_err(|e| e.to_string())?;
+                let rows = stmt
+                    .query_map([from, to], row_to_reservation)
+                    .map_err(|e| e.to_string())?;
+                rows.filter_map(|r| r.ok()).collect()
+            }
+            (Some(from), None) => {
+                let mut stmt = conn
+                    .prepare("SELECT * FROM reservations WHERE date >= ?1 ORDER BY date DESC, time")
+                    .map_err(|e| e.to_string())?;
+                let rows = stmt
+                    .query_map([from], row_to_reservation)
+                    .map_err(|e| e.to_string())?;
+                rows.filter_map(|r| r.ok()).collect()
+            }
+            (None, Some(to)) => {
+                let mut stmt = conn
+                    .prepare("SELECT * FROM reservations WHERE date <= ?1 ORDER BY date DESC, time")
+                    .map_err(|e| e.to_string())?;
+                let rows = stmt
+                    .query_map([to], row_to_reservation)
+                    .map_err(|e| e.to_string())?;
+                rows.filter_map(|r| r.ok()).collect()
+            }
+            _ => vec![]
+        }
+    } else {
+        // No filter - return all reservations
+        let mut stmt = conn
+            .prepare("SELECT * FROM reservations ORDER BY date DESC, time")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], row_to_reservation)
+            .map_err(|e| e.to_string())?;
+        rows.filter_map(|r| r.ok()).collect()
+    };
+
+    Ok(reservations)
+}
+
+pub fn get_reservation(conn: &Connection, id: &str) -> Result<Reservation, String> {
+    conn.query_row(
+        "SELECT * FROM reservations WHERE id = ?1",
+        [id],
+        row_to_reservation,
+    )
+    .map_err(|e| e.to_string())
+}
+
+pub fn create_reservation(conn: &Connection, data: CreateReservationInput) -> Result<Reservation, String> {
+    ensure_schedulable(conn, &data)?;
+
+    let id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    // 웹훅 이벤트가 예약과 함께 저장되거나 함께 취소되도록 한 트랜잭션으로 묶는다
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "INSERT INTO reservations (id, customer_name, customer_phone, date, time, designer_id, service_type, status, notes, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 'pending', ?8, ?9, ?9)",
+        rusqlite::params![
+            id,
+            data.customer_name,
+            data.customer_phone,
+            data.date,
+            data.time,
+            data.designer_id,
+            data.service_type,
+            data.notes,
+            now,
+        ],
+    ).map_err(|e| e.to_string())?;
+
+    let reservation = Reservation {
+        id,
+        customer_name: data.customer_name,
+        customer_phone: data.customer_phone,
+        date: data.date,
+        time: data.time,
+        designer_id: data.designer_id,
+        service_type: data.service_type,
+        status: "pending".to_string(),
+        notes: data.notes,
+        created_at: now.clone(),
+        updated_at: now,
+    };
+    webhooks::emit(&tx, webhooks::EVENT_RESERVATION_CREATED, &reservation)?;
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(reservation)
+}
+
+pub fn update_reservation(conn: &Connection, id: &str, data: CreateReservationInput) -> Result<Reservation, String> {
+    // 일정이 바뀌는 경우에만 예약 가능 여부 확인 (메모 수정 등은 그대로 허용)
+    let (current_date, current_time, current_designer): (String, String, Option<String>) = conn
+        .query_row(
+            "SELECT date, time, designer_id FROM reservations WHERE id = ?1",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|e| e.to_string())?;
+    if current_date != data.date || current_time != data.time || current_designer != data.designer_id {
+        ensure_schedulable(conn, &data)?;
+    }
+
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE reservations SET customer_name = ?2, customer_phone = ?3, date = ?4, time = ?5, designer_id = ?6, service_type = ?7, notes = ?8, updated_at = ?9 WHERE id = ?1",
+        rusqlite::params![
+            id,
+            data.customer_name,
+            data.customer_phone,
+            data.date,
+            data.time,
+            data.designer_id,
+            data.service_type,
+            data.notes,
+            now,
+        ],
+    ).map_err(|e| e.to_string())?;
+
+    // 업데이트된 데이터 반환
+    let reservation = get_reservation(&tx, id)?;
+    webhooks::emit(&tx, webhooks::EVENT_RESERVATION_UPDATED, &reservation)?;
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(reservation)
+}
+
+pub fn delete_reservation(conn: &Connection, id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM reservations WHERE id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+pub fn update_reservation_status(conn: &Connection, id: &str, status: &str) -> Result<Reservation, String> {
+    if !RESERVATION_STATUSES.contains(&status) {
+        return Err(format!("Invalid status: {}", status));
+    }
+
+    // 이전 상태 조회
+    let old_status: String = conn.query_row(
+        "SELECT status FROM reservations WHERE id = ?1",
+        [id],
+        |row| row.get(0),
+    ).map_err(|e| e.to_string())?;
+
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    // 상태 업데이트
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "UPDATE reservations SET status = ?2, updated_at = ?3 WHERE id = ?1",
+        rusqlite::params![id, status, now],
+    ).map_err(|e| e.to_string())?;
+
+    // 이력 기록
+    let history_id = Uuid::new_v4().to_string();
+    tx.execute(
+        "INSERT INTO reservation_status_history (id, reservation_id, old_status, new_status, changed_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+        rusqlite::params![history_id, id, old_status, status, now],
+    ).map_err(|e| e.to_string())?;
+
+    // 업데이트된 데이터 반환
+    let reservation = get_reservation(&tx, id)?;
+    if old_status != status {
+        webhooks::emit(
+            &tx,
+            webhooks::EVENT_RESERVATION_STATUS_CHANGED,
+            &serde_json::json!({ "previousStatus": old_status, "reservation": reservation }),
+        )?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    Ok(reservation)
+}
@@ -0,0 +1,466 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use rusqlite::{Connection, OptionalExtension};
+use serde::Serialize;
+
+use crate::services::time_blocks;
+use crate::services::holidays;
+
+/// 빈 시간 계산에 쓰는 예약 단위 (분)
+pub const SLOT_MINUTES: i64 = 30;
+
+/// Where the effective hours for a date came from, in order of precedence
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HoursSource {
+    Override,
+    Closure,
+    Holiday,
+    Weekly,
+}
+
+/// Opening hours that actually apply on a specific date
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveHours {
+    pub date: String,
+    pub day_of_week: i32,
+    pub open_time: Option<String>,
+    pub close_time: Option<String>,
+    pub break_start: Option<String>,
+    pub break_end: Option<String>,
+    pub is_closed: bool,
+    pub source: HoursSource,
+    pub description: Option<String>,
+}
+
+impl EffectiveHours {
+    fn closed(date: NaiveDate, source: HoursSource, description: Option<String>) -> Self {
+        Self {
+            date: date.format("%Y-%m-%d").to_string(),
+            day_of_week: date.weekday().num_days_from_sunday() as i32,
+            open_time: None,
+            close_time: None,
+            break_start: None,
+            break_end: None,
+            is_closed: true,
+            source,
+            description,
+        }
+    }
+
+    /// Whether a reservation can start at `time` (HH:MM).
+    /// Open interval is [open_time, close_time), break is [break_start, break_end).
+    pub fn is_open_at(&self, time: &str) -> bool {
+        if self.is_closed {
+            return false;
+        }
+        let (Some(open), Some(close)) = (&self.open_time, &self.close_time) else {
+            return false;
+        };
+        if time < open.as_str() || time >= close.as_str() {
+            return false;
+        }
+        if let (Some(start), Some(end)) = (&self.break_start, &self.break_end) {
+            if time >= start.as_str() && time < end.as_str() {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// 입력받은 하루 영업시간 (요일별 영업시간, 특정일 예외에 같이 쓴다)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayHours {
+    pub open_time: Option<String>,
+    pub close_time: Option<String>,
+    pub break_start: Option<String>,
+    pub break_end: Option<String>,
+}
+
+impl DayHours {
+    /// 시간을 검증하고 "9:00" 같은 입력을 "09:00" 으로 맞춘다. 빈 문자열은 값이 없는 것으로 본다.
+    /// 영업하는 날은 시작·종료 시간이 모두 있어야 하고, 휴게시간은 영업시간 안에 있어야 한다.
+    pub fn validate(self, is_closed: bool) -> Result<Self, String> {
+        let normalize = |t: Option<String>| -> Result<Option<String>, String> {
+            match t.as_deref().map(str::trim).filter(|t| !t.is_empty()) {
+                Some(t) => NaiveTime::parse_from_str(t, "%H:%M")
+                    .map(|t| Some(t.format("%H:%M").to_string()))
+                    .map_err(|_| format!("시간 형식이 올바르지 않습니다 (HH:MM): {}", t)),
+                None => Ok(None),
+            }
+        };
+        let hours = Self {
+            open_time: normalize(self.open_time)?,
+            close_time: normalize(self.close_time)?,
+            break_start: normalize(self.break_start)?,
+            break_end: normalize(self.break_end)?,
+        };
+        if is_closed {
+            return Ok(hours);
+        }
+
+        let (Some(open), Some(close)) = (&hours.open_time, &hours.close_time) else {
+            return Err("영업 시작/종료 시간을 입력해주세요".to_string());
+        };
+        if open >= close {
+            return Err("영업 종료 시간은 시작 시간보다 늦어야 합니다".to_string());
+        }
+        match (&hours.break_start, &hours.break_end) {
+            (None, None) => {}
+            (Some(start), Some(end)) => {
+                if start >= end {
+                    return Err("휴게 종료 시간은 시작 시간보다 늦어야 합니다".to_string());
+                }
+                if start < open || end > close {
+                    return Err(format!("휴게시간은 영업시간({}~{}) 안이어야 합니다", open, close));
+                }
+            }
+            _ => return Err("휴게시간은 시작과 종료를 모두 입력해주세요".to_string()),
+        }
+        Ok(hours)
+    }
+}
+
+pub fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| format!("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD): {}", date))
+}
+
+/// Resolve the hours for a date.
+///
+/// Precedence: per-date override > closure period > holiday > weekly business hours.
+/// Every scheduling feature should go through this instead of reading
+/// `business_hours` directly.
+pub fn effective_hours_for_date(conn: &Connection, date: NaiveDate) -> Result<EffectiveHours, String> {
+    let date_str = date.format("%Y-%m-%d").to_string();
+    let day_of_week = date.weekday().num_days_from_sunday() as i32;
+
+    // 1. 특정일 예외
+    let override_hours = conn
+        .query_row(
+            "SELECT open_time, close_time, break_start, break_end, is_closed, description
+             FROM business_hour_overrides WHERE date = ?1",
+            [&date_str],
+            |row| {
+                let is_closed: i32 = row.get(4)?;
+                Ok(EffectiveHours {
+                    date: date_str.clone(),
+                    day_of_week,
+                    open_time: row.get(0)?,
+                    close_time: row.get(1)?,
+                    break_start: row.get(2)?,
+                    break_end: row.get(3)?,
+                    is_closed: is_closed == 1,
+                    source: HoursSource::Override,
+                    description: row.get(5)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    if let Some(hours) = override_hours {
+        return Ok(hours);
+    }
+
+    // 2. 휴무 기간
+    let closure: Option<Option<String>> = conn
+        .query_row(
+            "SELECT description FROM closure_periods
+             WHERE start_date <= ?1 AND end_date >= ?1
+             ORDER BY start_date LIMIT 1",
+            [&date_str],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    if let Some(description) = closure {
+        return Ok(EffectiveHours::closed(date, HoursSource::Closure, description));
+    }
+
+    // 3. 휴일 (매년 반복, 음력 휴일 포함)
+    if let Some(description) = holidays::holiday_on(conn, date)? {
+        return Ok(EffectiveHours::closed(date, HoursSource::Holiday, description));
+    }
+
+    // 4. 요일별 기본 영업시간
+    let weekly = conn
+        .query_row(
+            "SELECT open_time, close_time, break_start, break_end, is_closed
+             FROM business_hours WHERE day_of_week = ?1",
+            [day_of_week],
+            |row| {
+                let is_closed: i32 = row.get(4)?;
+                Ok(EffectiveHours {
+                    date: date_str.clone(),
+                    day_of_week,
+                    open_time: row.get(0)?,
+                    close_time: row.get(1)?,
+                    break_start: row.get(2)?,
+                    break_end: row.get(3)?,
+                    is_closed: is_closed == 1,
+                    source: HoursSource::Weekly,
+                    description: None,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+
+    Ok(weekly.unwrap_or_else(|| EffectiveHours::closed(date, HoursSource::Weekly, None)))
+}
+
+/// Resolve hours for every date in `[start, end]`
+pub fn effective_hours_for_range(
+    conn: &Connection,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<EffectiveHours>, String> {
+    if end < start {
+        return Err("종료일은 시작일보다 빠를 수 없습니다".to_string());
+    }
+    if (end - start).num_days() > 366 {
+        return Err("조회 기간은 1년을 넘을 수 없습니다".to_string());
+    }
+
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|d| effective_hours_for_date(conn, d))
+        .collect()
+}
+
+/// Reject a reservation time that falls outside the effective hours of its date
+pub fn ensure_open_at(conn: &Connection, date: &str, time: &str) -> Result<(), String> {
+    let hours = effective_hours_for_date(conn, parse_date(date)?)?;
+
+    if hours.is_closed {
+        let reason = match hours.source {
+            HoursSource::Override | HoursSource::Weekly => "휴무일",
+            HoursSource::Closure => "휴무 기간",
+            HoursSource::Holiday => "휴일",
+        };
+        return Err(match hours.description.as_deref().filter(|d| !d.is_empty()) {
+            Some(desc) => format!("{}은(는) {}입니다 ({})", date, reason, desc),
+            None => format!("{}은(는) {}입니다", date, reason),
+        });
+    }
+
+    if !hours.is_open_at(time) {
+        if let (Some(start), Some(end)) = (&hours.break_start, &hours.break_end) {
+            if time >= start.as_str() && time < end.as_str() {
+                return Err(format!("{} 휴게시간({}~{})입니다", date, start, end));
+            }
+        }
+        return Err(format!(
+            "{} 영업시간({}~{}) 외의 시간입니다",
+            date,
+            hours.open_time.as_deref().unwrap_or("-"),
+            hours.close_time.as_deref().unwrap_or("-"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// 특정 날짜(와 디자이너)의 예약 가능 시간
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Availability {
+    pub date: String,
+    pub designer_id: Option<String>,
+    pub hours: EffectiveHours,
+    pub booked_times: Vec<String>,
+    pub available_times: Vec<String>,
+}
+
+/// 영업시간을 `SLOT_MINUTES` 단위로 나눠 비어 있는 시작 시각을 구한다.
+/// 디자이너를 지정하면 그 디자이너의 예약과 차단 시간도 제외하고,
+/// 지정하지 않으면 영업시간만 기준으로 한다 (booked_times 는 살롱 전체 예약).
+pub fn availability(conn: &Connection, date: &str, designer_id: Option<&str>) -> Result<Availability, String> {
+    let hours = effective_hours_for_date(conn, parse_date(date)?)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT time FROM reservations
+             WHERE date = ?1 AND (?2 IS NULL OR designer_id = ?2)
+               AND status NOT IN ('cancelled', 'no_show')
+             ORDER BY time",
+        )
+        .map_err(|e| e.to_string())?;
+    let booked_times: Vec<String> = stmt
+        .query_map(rusqlite::params![date, designer_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut available_times = Vec::new();
+    if let (false, Some(open), Some(close)) = (hours.is_closed, &hours.open_time, &hours.close_time) {
+        let parse = |t: &str| NaiveTime::parse_from_str(t, "%H:%M").map_err(|_| format!("Invalid time: {}", t));
+        let (open, close) = (parse(open)?, parse(close)?);
+        let step = chrono::Duration::minutes(SLOT_MINUTES);
+
+        let mut slot = open;
+        while slot < close {
+            let start = slot.format("%H:%M").to_string();
+            let end = (slot + step).format("%H:%M").to_string();
+            let booked = designer_id.is_some()
+                && booked_times.iter().any(|t| t.as_str() >= start.as_str() && (t.as_str() < end.as_str() || end < start));
+            let blocked = match designer_id {
+                Some(id) => time_blocks::find_conflicting_block(conn, id, date, &start)?.is_some(),
+                None => false,
+            };
+            if hours.is_open_at(&start) && !booked && !blocked {
+                available_times.push(start);
+            }
+            let next = slot + step;
+            // 자정을 넘기면 멈춘다
+            if next <= slot {
+                break;
+            }
+            slot = next;
+        }
+    }
+
+    Ok(Availability {
+        date: date.to_string(),
+        designer_id: designer_id.map(str::to_string),
+        hours,
+        booked_times,
+        available_times,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(open: &str, close: &str, break_start: &str, break_end: &str) -> DayHours {
+        let value = |t: &str| Some(t.to_string());
+        DayHours { open_time: value(open), close_time: value(close), break_start: value(break_start), break_end: value(break_end) }
+    }
+
+    #[test]
+    fn test_day_hours_validation() {
+        let normalized = hours("9:00", "18:00", "", "").validate(false).unwrap();
+        assert_eq!(normalized.open_time.as_deref(), Some("09:00"));
+        assert_eq!(normalized.break_start, None);
+
+        assert!(hours("10:00", "25:00", "", "").validate(false).is_err());
+        assert!(hours("18:00", "10:00", "", "").validate(false).is_err());
+        assert!(hours("10:00", "18:00", "13:00", "").validate(false).is_err());
+        assert!(hours("10:00", "18:00", "09:00", "10:30").validate(false).is_err());
+        assert!(hours("10:00", "18:00", "13:00", "14:00").validate(false).is_ok());
+        assert!(DayHours::default().validate(true).is_ok());
+        assert!(DayHours::default().validate(false).is_err());
+    }
+
+    fn setup() -> crate::db::Database {
+        crate::db::Database::open_in_memory().unwrap()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        parse_date(value).unwrap()
+    }
+
+    #[test]
+    fn test_effective_hours_precedence() {
+        let db = setup();
+        let conn = db.conn();
+        // 2025-03-12 (수): 요일별 영업시간 10:00~20:00
+        let day = date("2025-03-12");
+        let resolved = effective_hours_for_date(conn, day).unwrap();
+        assert_eq!(resolved.source, HoursSource::Weekly);
+        assert_eq!((resolved.open_time.as_deref(), resolved.close_time.as_deref()), (Some("10:00"), Some("20:00")));
+
+        // 같은 날짜에 휴일, 휴무 기간, 예외를 차례로 겹쳐 넣는다
+        conn.execute("INSERT INTO holidays (id, date, description) VALUES ('h1', '2025-03-12', '창립기념일')", [])
+            .unwrap();
+        let resolved = effective_hours_for_date(conn, day).unwrap();
+        assert_eq!((resolved.source, resolved.is_closed), (HoursSource::Holiday, true));
+        assert_eq!(resolved.description.as_deref(), Some("창립기념일"));
+
+        conn.execute(
+            "INSERT INTO closure_periods (id, start_date, end_date, description) VALUES ('p1', '2025-03-10', '2025-03-14', '내부 공사')",
+            [],
+        )
+        .unwrap();
+        let resolved = effective_hours_for_date(conn, day).unwrap();
+        assert_eq!((resolved.source, resolved.is_closed), (HoursSource::Closure, true));
+        assert_eq!(resolved.description.as_deref(), Some("내부 공사"));
+
+        conn.execute(
+            "INSERT INTO business_hour_overrides (id, date, open_time, close_time, is_closed, description)
+             VALUES ('o1', '2025-03-12', '12:00', '16:00', 0, '단축 영업')",
+            [],
+        )
+        .unwrap();
+        let resolved = effective_hours_for_date(conn, day).unwrap();
+        assert_eq!((resolved.source, resolved.is_closed), (HoursSource::Override, false));
+        assert_eq!((resolved.open_time.as_deref(), resolved.close_time.as_deref()), (Some("12:00"), Some("16:00")));
+
+        // 예외가 없는 다음 날은 휴무 기간, 기간이 끝난 뒤는 요일별 영업시간
+        assert_eq!(effective_hours_for_date(conn, date("2025-03-13")).unwrap().source, HoursSource::Closure);
+        assert_eq!(effective_hours_for_date(conn, date("2025-03-15")).unwrap().source, HoursSource::Weekly);
+        // 일요일은 요일별 휴무
+        let sunday = effective_hours_for_date(conn, date("2025-03-16")).unwrap();
+        assert_eq!((sunday.source, sunday.is_closed), (HoursSource::Weekly, true));
+    }
+
+    #[test]
+    fn test_ensure_open_at() {
+        let db = setup();
+        let conn = db.conn();
+        conn.execute("UPDATE business_hours SET break_start = '13:00', break_end = '14:00' WHERE day_of_week = 3", [])
+            .unwrap();
+
+        assert!(ensure_open_at(conn, "2025-03-12", "10:00").is_ok());
+        assert!(ensure_open_at(conn, "2025-03-12", "19:30").is_ok());
+        assert_eq!(ensure_open_at(conn, "2025-03-12", "09:30").unwrap_err(), "2025-03-12 영업시간(10:00~20:00) 외의 시간입니다");
+        assert!(ensure_open_at(conn, "2025-03-12", "20:00").is_err());
+        assert_eq!(ensure_open_at(conn, "2025-03-12", "13:30").unwrap_err(), "2025-03-12 휴게시간(13:00~14:00)입니다");
+        assert!(ensure_open_at(conn, "2025-03-12", "14:00").is_ok());
+        assert_eq!(ensure_open_at(conn, "2025-03-16", "11:00").unwrap_err(), "2025-03-16은(는) 휴무일입니다");
+
+        conn.execute(
+            "INSERT INTO closure_periods (id, start_date, end_date, description) VALUES ('p1', '2025-03-17', '2025-03-18', '여름 휴가')",
+            [],
+        )
+        .unwrap();
+        assert_eq!(ensure_open_at(conn, "2025-03-18", "11:00").unwrap_err(), "2025-03-18은(는) 휴무 기간입니다 (여름 휴가)");
+        conn.execute("INSERT INTO holidays (id, date, description) VALUES ('h1', '2025-03-19', '')", []).unwrap();
+        assert_eq!(ensure_open_at(conn, "2025-03-19", "11:00").unwrap_err(), "2025-03-19은(는) 휴일입니다");
+
+        assert!(ensure_open_at(conn, "2025/03/12", "11:00").unwrap_err().contains("날짜 형식"));
+    }
+
+    #[test]
+    fn test_effective_hours_for_range() {
+        let db = setup();
+        let conn = db.conn();
+        conn.execute(
+            "INSERT INTO closure_periods (id, start_date, end_date) VALUES ('p1', '2025-03-13', '2025-03-14')",
+            [],
+        )
+        .unwrap();
+
+        let days = effective_hours_for_range(conn, date("2025-03-12"), date("2025-03-16")).unwrap();
+        let sources: Vec<HoursSource> = days.iter().map(|d| d.source).collect();
+        assert_eq!(
+            sources,
+            vec![HoursSource::Weekly, HoursSource::Closure, HoursSource::Closure, HoursSource::Weekly, HoursSource::Weekly]
+        );
+        assert_eq!(days[0].date, "2025-03-12");
+        assert_eq!(days[4].date, "2025-03-16");
+        assert!(days[4].is_closed);
+
+        assert_eq!(effective_hours_for_range(conn, date("2025-03-12"), date("2025-03-12")).unwrap().len(), 1);
+        assert!(effective_hours_for_range(conn, date("2025-03-12"), date("2025-03-11")).is_err());
+        // 시작일과 종료일 사이가 366일까지 허용 (윤년의 1년)
+        assert_eq!(effective_hours_for_range(conn, date("2024-01-01"), date("2025-01-01")).unwrap().len(), 367);
+        assert_eq!(
+            effective_hours_for_range(conn, date("2024-01-01"), date("2025-01-02")).unwrap_err(),
+            "조회 기간은 1년을 넘을 수 없습니다"
+        );
+    }
+}
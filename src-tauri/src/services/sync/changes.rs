@@ -0,0 +1,643 @@
+//! 행 단위 변경 기록과 병합
+//!
+//! 트리거가 sync_dirty 에 남긴 행을 `collect` 가 sync_fields 와 비교해 바뀐 필드에만 새 HLC 를
+//! 붙이고, 사라진 행은 삭제 표시(tombstone)로 남긴다. 다른 기기의 변경은 필드마다 HLC 가 큰 쪽이
+//! 이기고(last-writer-wins), 필드 중 가장 큰 HLC 가 삭제 표시보다 크면 행이 살아 있다.
+//! 병합 규칙이 HLC 만 보므로 어느 기기에서 어떤 순서로 합쳐도 결과가 같다.
+
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use super::hlc::{self, Hlc};
+use super::{SyncConflict, SyncConflictKind, SyncReport};
+use crate::db::schema::SYNC_TABLES;
+use crate::services::privacy;
+
+pub const FORMAT_VERSION: u32 = 1;
+
+const NODE_ID_KEY: &str = "node_id";
+const CLOCK_KEY: &str = "clock";
+const SEQ_KEY: &str = "seq";
+const INITIALIZED_KEY: &str = "initialized";
+/// `seen:{기기 id}` = 그 기기가 만든 변경 중 받은 가장 큰 HLC
+const SEEN_PREFIX: &str = "seen:";
+
+/// 필드 하나의 버전
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub table: String,
+    pub row_id: String,
+    pub column: String,
+    pub value: Value,
+    pub hlc: String,
+}
+
+/// 행 삭제 표시
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tombstone {
+    pub table: String,
+    pub row_id: String,
+    pub hlc: String,
+}
+
+/// 한 기기가 다른 기기로 보내는 변경 묶음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSet {
+    pub format_version: u32,
+    pub node_id: String,
+    pub device_name: String,
+    /// 이 묶음에 담긴 가장 큰 기록 순서. 다음에는 이 값 이후만 요청하면 된다
+    pub seq: i64,
+    pub generated_at: String,
+    /// 보낸 기기가 기기별로 받은 가장 큰 HLC (자기 자신 포함). 동시에 고친 값인지 판단할 때 쓴다
+    #[serde(default)]
+    pub seen: BTreeMap<String, String>,
+    pub fields: Vec<FieldChange>,
+    pub tombstones: Vec<Tombstone>,
+}
+
+/// 이 기기의 id 와 시계. 불러온 뒤 `save` 해야 반영된다
+struct Clock {
+    node: String,
+    last: Option<Hlc>,
+    seq: i64,
+}
+
+impl Clock {
+    fn load(conn: &Connection) -> Result<Self, String> {
+        let node = match get_state(conn, NODE_ID_KEY)? {
+            Some(node) => node,
+            None => {
+                let node = uuid::Uuid::new_v4().simple().to_string()[..12].to_string();
+                set_state(conn, NODE_ID_KEY, &node)?;
+                node
+            }
+        };
+        let last = get_state(conn, CLOCK_KEY)?.map(|value| Hlc::parse(&value)).transpose()?;
+        let seq = get_state(conn, SEQ_KEY)?.and_then(|value| value.parse().ok()).unwrap_or(0);
+        Ok(Self { node, last, seq })
+    }
+
+    fn tick(&mut self, at_ms: i64) -> String {
+        let next = Hlc::tick(self.last.as_ref(), at_ms, &self.node);
+        self.last = Some(next.clone());
+        next.to_string()
+    }
+
+    fn receive(&mut self, remote: &Hlc) {
+        self.last = Some(Hlc::receive(self.last.as_ref(), remote, hlc::now_ms(), &self.node));
+    }
+
+    fn next_seq(&mut self) -> i64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    fn save(&self, conn: &Connection) -> Result<(), String> {
+        if let Some(last) = &self.last {
+            set_state(conn, CLOCK_KEY, &last.to_string())?;
+        }
+        set_state(conn, SEQ_KEY, &self.seq.to_string())
+    }
+}
+
+pub(super) fn get_state(conn: &Connection, key: &str) -> Result<Option<String>, String> {
+    conn.query_row("SELECT value FROM sync_state WHERE key = ?1", [key], |row| row.get(0))
+        .optional()
+        .map_err(|e| e.to_string())
+}
+
+pub(super) fn set_state(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    conn.execute("INSERT OR REPLACE INTO sync_state (key, value) VALUES (?1, ?2)", params![key, value])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 이 기기의 동기화 id
+pub fn node_id(conn: &Connection) -> Result<String, String> {
+    Ok(Clock::load(conn)?.node)
+}
+
+/// 기기 id 와 시계를 새로 만든다. 다른 기기의 데이터를 통째로 가져온 뒤 두 기기가
+/// 같은 id 를 쓰지 않도록 할 때 쓴다. 필드 버전은 그대로 두므로 기존 기록은 유지된다.
+pub fn reset_node(conn: &Connection) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM sync_state WHERE key IN (?1, ?2, ?3)",
+        params![NODE_ID_KEY, CLOCK_KEY, super::lan::IDENTITY_KEY],
+    )
+    .map_err(|e| e.to_string())?;
+    // 페어링은 원래 기기의 키로 맺은 것이므로 새 id 로는 다시 페어링해야 한다
+    conn.execute("DELETE FROM sync_peers", []).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn to_json(value: ValueRef) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(i) => Value::from(i),
+        ValueRef::Real(f) => serde_json::Number::from_f64(f).map(Value::Number).unwrap_or(Value::Null),
+        ValueRef::Text(text) => Value::String(String::from_utf8_lossy(text).to_string()),
+        // 동기화 대상 테이블에는 BLOB 컬럼이 없다
+        ValueRef::Blob(_) => Value::Null,
+    }
+}
+
+fn to_sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(*b as i64),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or(0.0)),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
+
+fn table_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table)).map_err(|e| e.to_string())?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(columns)
+}
+
+/// 현재 행의 (컬럼, JSON 값). id 컬럼은 빼고, 행이 없으면 None
+fn read_row(conn: &Connection, table: &str, row_id: &str) -> Result<Option<Vec<(String, String)>>, String> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT * FROM {} WHERE id = ?1", table))
+        .map_err(|e| e.to_string())?;
+    let names: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+    let mut rows = stmt.query([row_id]).map_err(|e| e.to_string())?;
+    let Some(row) = rows.next().map_err(|e| e.to_string())? else {
+        return Ok(None);
+    };
+
+    let mut columns = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        if name == "id" {
+            continue;
+        }
+        let value = to_json(row.get_ref(index).map_err(|e| e.to_string())?);
+        columns.push((name.clone(), value.to_string()));
+    }
+    Ok(Some(columns))
+}
+
+/// 기록된 필드: 컬럼 → (JSON 값, HLC)
+fn known_fields(conn: &Connection, table: &str, row_id: &str) -> Result<HashMap<String, (String, String)>, String> {
+    let mut stmt = conn
+        .prepare("SELECT column_name, value, hlc FROM sync_fields WHERE table_name = ?1 AND row_id = ?2")
+        .map_err(|e| e.to_string())?;
+    let fields = stmt
+        .query_map([table, row_id], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<HashMap<_, _>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(fields)
+}
+
+fn tombstone_hlc(conn: &Connection, table: &str, row_id: &str) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT hlc FROM sync_tombstones WHERE table_name = ?1 AND row_id = ?2",
+        [table, row_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// 필드 중 가장 큰 버전이 삭제 표시보다 크면 살아 있는 행
+fn is_alive(fields: &HashMap<String, (String, String)>, tombstone: Option<&String>) -> bool {
+    match fields.values().map(|(_, hlc)| hlc).max() {
+        Some(latest) => tombstone.is_none_or(|deleted| latest > deleted),
+        None => false,
+    }
+}
+
+fn upsert_field(conn: &Connection, table: &str, row_id: &str, column: &str, value: &str, hlc: &str, seq: i64) -> Result<(), String> {
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_fields (table_name, row_id, column_name, value, hlc, seq) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![table, row_id, column, value, hlc, seq],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn upsert_tombstone(conn: &Connection, table: &str, row_id: &str, hlc: &str, seq: i64) -> Result<(), String> {
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_tombstones (table_name, row_id, hlc, seq) VALUES (?1, ?2, ?3, ?4)",
+        params![table, row_id, hlc, seq],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 행의 기록된 필드 값을 null 로 지운다. HLC 와 seq 는 그대로 두어 병합 결과는 바뀌지 않고,
+/// 삭제했거나 개인정보를 지운 행의 이전 값이 전체 상태 내보내기로 다른 기기에 다시 가지 않는다.
+pub fn scrub_values(conn: &Connection, table: &str, row_id: &str) -> Result<(), String> {
+    conn.execute(
+        "UPDATE sync_fields SET value = 'null' WHERE table_name = ?1 AND row_id = ?2",
+        [table, row_id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 살아 있는 행의 기록된 필드를 모두 지운다. 다음 `collect` 가 모든 필드에 새 버전을 매기므로,
+/// null 로 비운 필드도 다른 기기에 전해진다 (개인정보를 지운 행에 쓴다).
+pub fn forget_values(conn: &Connection, table: &str, row_id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM sync_fields WHERE table_name = ?1 AND row_id = ?2", [table, row_id])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR IGNORE INTO sync_dirty (table_name, row_id, changed_ms) VALUES (?1, ?2, ?3)",
+        params![table, row_id, hlc::now_ms()],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 트리거가 기록한 변경 행에 버전을 매긴다. 처음 실행하면 모든 행을 변경으로 본다.
+/// 버전을 새로 매긴 행 수를 돌려준다.
+pub fn collect(conn: &Connection) -> Result<usize, String> {
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut clock = Clock::load(&tx)?;
+
+    if get_state(&tx, INITIALIZED_KEY)?.is_none() {
+        let now = hlc::now_ms();
+        for table in SYNC_TABLES {
+            tx.execute(
+                &format!(
+                    "INSERT OR IGNORE INTO sync_dirty (table_name, row_id, changed_ms) SELECT '{}', CAST(id AS TEXT), ?1 FROM {}",
+                    table, table
+                ),
+                [now],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        set_state(&tx, INITIALIZED_KEY, "1")?;
+    }
+
+    let dirty: Vec<(String, String, i64)> = {
+        let mut stmt = tx
+            .prepare("SELECT table_name, row_id, changed_ms FROM sync_dirty ORDER BY changed_ms, table_name, row_id")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+
+    let mut versioned = 0;
+    for (table, row_id, changed_ms) in dirty {
+        if SYNC_TABLES.contains(&table.as_str()) {
+            let known = known_fields(&tx, &table, &row_id)?;
+            let tombstone = tombstone_hlc(&tx, &table, &row_id)?;
+            let alive = is_alive(&known, tombstone.as_ref());
+
+            match read_row(&tx, &table, &row_id)? {
+                Some(columns) => {
+                    // 삭제 표시된 행이 다시 생겼으면 모든 필드에 새 버전을 붙인다
+                    let changed: Vec<(String, String)> = columns
+                        .into_iter()
+                        .filter(|(column, value)| !alive || known.get(column).is_none_or(|(known_value, _)| known_value != value))
+                        .collect();
+                    if !changed.is_empty() {
+                        let version = clock.tick(changed_ms);
+                        for (column, value) in changed {
+                            let seq = clock.next_seq();
+                            upsert_field(&tx, &table, &row_id, &column, &value, &version, seq)?;
+                        }
+                        versioned += 1;
+                    }
+                }
+                None if alive => {
+                    let version = clock.tick(changed_ms);
+                    let seq = clock.next_seq();
+                    upsert_tombstone(&tx, &table, &row_id, &version, seq)?;
+                    scrub_values(&tx, &table, &row_id)?;
+                    versioned += 1;
+                }
+                None => {}
+            }
+        }
+        tx.execute("DELETE FROM sync_dirty WHERE table_name = ?1 AND row_id = ?2", [&table, &row_id])
+            .map_err(|e| e.to_string())?;
+    }
+
+    clock.save(&tx)?;
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(versioned)
+}
+
+/// `since_seq` 이후에 기록한 변경 (0 이면 전체 상태)
+pub fn export_changes(conn: &Connection, device_name: &str, since_seq: i64) -> Result<ChangeSet, String> {
+    collect(conn)?;
+    let clock = Clock::load(conn)?;
+
+    let mut stmt = conn
+        .prepare("SELECT table_name, row_id, column_name, value, hlc FROM sync_fields WHERE seq > ?1 ORDER BY seq")
+        .map_err(|e| e.to_string())?;
+    let fields = stmt
+        .query_map([since_seq], |row| {
+            let value: String = row.get(3)?;
+            Ok(FieldChange {
+                table: row.get(0)?,
+                row_id: row.get(1)?,
+                column: row.get(2)?,
+                value: serde_json::from_str(&value).unwrap_or(Value::Null),
+                hlc: row.get(4)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut stmt = conn
+        .prepare("SELECT table_name, row_id, hlc FROM sync_tombstones WHERE seq > ?1 ORDER BY seq")
+        .map_err(|e| e.to_string())?;
+    let tombstones = stmt
+        .query_map([since_seq], |row| Ok(Tombstone { table: row.get(0)?, row_id: row.get(1)?, hlc: row.get(2)? }))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut seen: BTreeMap<String, String> = {
+        let mut stmt = conn
+            .prepare("SELECT substr(key, ?2), value FROM sync_state WHERE key LIKE ?1")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map(params![format!("{}%", SEEN_PREFIX), SEEN_PREFIX.len() as i64 + 1], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+    if let Some(last) = &clock.last {
+        seen.insert(clock.node.clone(), last.to_string());
+    }
+
+    Ok(ChangeSet {
+        format_version: FORMAT_VERSION,
+        seen,
+        node_id: clock.node,
+        device_name: device_name.to_string(),
+        seq: clock.seq,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        fields,
+        tombstones,
+    })
+}
+
+/// 다른 기기의 변경을 합친다. 제약 조건 때문에 반영하지 못한 행과 오프라인에서 생긴
+/// 중복 예약은 충돌로 보고하고 나머지는 계속 반영한다.
+pub fn apply_changes(conn: &Connection, changes: &ChangeSet) -> Result<SyncReport, String> {
+    if changes.format_version > FORMAT_VERSION {
+        return Err(format!("더 새로운 동기화 형식(v{})입니다. 앱을 업데이트해주세요", changes.format_version));
+    }
+    // 아직 버전을 매기지 않은 이 기기의 변경을 먼저 기록해야 덮어쓰지 않는다
+    collect(conn)?;
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut clock = Clock::load(&tx)?;
+    if changes.node_id == clock.node {
+        return Err("같은 동기화 id 를 쓰는 기기의 변경입니다. 한 기기의 데이터를 복사한 기기라면 동기화를 다시 설정해주세요".to_string());
+    }
+
+    // 받은 변경을 만든 기기별로 가장 큰 HLC 를 기억한다
+    let mut latest_by_node: BTreeMap<String, Hlc> = BTreeMap::new();
+    for version in changes.fields.iter().map(|f| &f.hlc).chain(changes.tombstones.iter().map(|t| &t.hlc)) {
+        let version = Hlc::parse(version)?;
+        if latest_by_node.get(&version.node).is_none_or(|latest| version > *latest) {
+            latest_by_node.insert(version.node.clone(), version);
+        }
+    }
+    for (node, latest) in &latest_by_node {
+        if *node == clock.node {
+            continue;
+        }
+        let key = format!("{}{}", SEEN_PREFIX, node);
+        if get_state(&tx, &key)?.is_none_or(|seen| latest.to_string() > seen) {
+            set_state(&tx, &key, &latest.to_string())?;
+        }
+    }
+    if let Some(latest) = latest_by_node.values().max() {
+        clock.receive(latest);
+    }
+    // 보낸 기기가 이 기기의 변경을 어디까지 받았는지
+    let seen_here = changes.seen.get(&clock.node);
+
+    let mut report = SyncReport::default();
+    // (테이블 순서, 행 id)
+    let mut touched: BTreeSet<(usize, String)> = BTreeSet::new();
+
+    for field in &changes.fields {
+        let Some(table_index) = SYNC_TABLES.iter().position(|t| *t == field.table) else {
+            continue;
+        };
+        if field.column == "id" {
+            continue;
+        }
+        let value = field.value.to_string();
+        let local: Option<(String, String)> = tx
+            .query_row(
+                "SELECT value, hlc FROM sync_fields WHERE table_name = ?1 AND row_id = ?2 AND column_name = ?3",
+                [&field.table, &field.row_id, &field.column],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?;
+
+        if local.as_ref().is_some_and(|(_, local_hlc)| *local_hlc >= field.hlc) {
+            continue;
+        }
+        if let Some((local_value, local_hlc)) = &local {
+            // 이 기기에서 고친 값을, 그 값을 받기 전의 다른 기기가 고친 값으로 바꾸는 경우만 알린다
+            let authored_here = Hlc::parse(local_hlc).map(|h| h.node == clock.node).unwrap_or(false);
+            let concurrent = seen_here.is_none_or(|seen| seen < local_hlc);
+            if authored_here && concurrent && *local_value != value {
+                report.conflicts.push(SyncConflict {
+                    kind: SyncConflictKind::FieldOverwritten,
+                    table: field.table.clone(),
+                    row_id: field.row_id.clone(),
+                    other_row_id: None,
+                    column: Some(field.column.clone()),
+                    detail: format!("{} → {} ({} 기기의 나중 변경)", local_value, value, changes.device_name),
+                });
+            }
+        }
+        let seq = clock.next_seq();
+        upsert_field(&tx, &field.table, &field.row_id, &field.column, &value, &field.hlc, seq)?;
+        report.applied += 1;
+        touched.insert((table_index, field.row_id.clone()));
+    }
+
+    for tombstone in &changes.tombstones {
+        let Some(table_index) = SYNC_TABLES.iter().position(|t| *t == tombstone.table) else {
+            continue;
+        };
+        let local = tombstone_hlc(&tx, &tombstone.table, &tombstone.row_id)?;
+        if local.as_ref().is_some_and(|local| *local >= tombstone.hlc) {
+            continue;
+        }
+        let seq = clock.next_seq();
+        upsert_tombstone(&tx, &tombstone.table, &tombstone.row_id, &tombstone.hlc, seq)?;
+        report.applied += 1;
+        touched.insert((table_index, tombstone.row_id.clone()));
+    }
+
+    let mut columns_cache: HashMap<usize, Vec<String>> = HashMap::new();
+    let mut deletes = Vec::new();
+    for (table_index, row_id) in &touched {
+        let table = SYNC_TABLES[*table_index];
+        let known = known_fields(&tx, table, row_id)?;
+        let tombstone = tombstone_hlc(&tx, table, row_id)?;
+        if !is_alive(&known, tombstone.as_ref()) {
+            scrub_values(&tx, table, row_id)?;
+            deletes.push((table, row_id));
+            continue;
+        }
+
+        if !columns_cache.contains_key(table_index) {
+            columns_cache.insert(*table_index, table_columns(&tx, table)?);
+        }
+        let columns = &columns_cache[table_index];
+        match write_row(&tx, table, row_id, &known, columns) {
+            Ok(true) => report.inserted += 1,
+            Ok(false) => report.updated += 1,
+            Err(e) => {
+                report.conflicts.push(constraint_conflict(table, row_id, &e));
+                continue;
+            }
+        }
+        if table == "reservations" {
+            detect_double_booking(&tx, row_id, &mut report)?;
+        }
+    }
+
+    for (table, row_id) in deletes.into_iter().rev() {
+        match tx.execute(&format!("DELETE FROM {} WHERE id = ?1", table), [row_id]) {
+            Ok(deleted) => report.deleted += deleted,
+            Err(e) => report.conflicts.push(constraint_conflict(table, row_id, &e)),
+        }
+    }
+
+    // 다른 기기에서 익명 처리한 고객은 동기화하지 않는 이 기기의 기록에서도 지운다
+    privacy::erase_synced_anonymizations(&tx)?;
+
+    clock.save(&tx)?;
+    tx.commit().map_err(|e| e.to_string())?;
+
+    // 반영하면서 트리거가 남긴 기록을 정리한다 (값이 같으므로 새 버전은 생기지 않는다)
+    collect(conn)?;
+    Ok(report)
+}
+
+/// 기록된 필드로 행을 만들거나 고친다. 새로 만들었으면 true
+fn write_row(
+    conn: &Connection,
+    table: &str,
+    row_id: &str,
+    known: &HashMap<String, (String, String)>,
+    columns: &[String],
+) -> Result<bool, rusqlite::Error> {
+    let mut names = vec!["id".to_string()];
+    let mut values = vec![SqlValue::Text(row_id.to_string())];
+    for column in columns.iter().filter(|c| c.as_str() != "id") {
+        if let Some((value, _)) = known.get(column) {
+            names.push(column.clone());
+            values.push(to_sql(&serde_json::from_str(value).unwrap_or(Value::Null)));
+        }
+    }
+
+    let exists: bool = conn.query_row(&format!("SELECT EXISTS(SELECT 1 FROM {} WHERE id = ?1)", table), [row_id], |row| row.get(0))?;
+    if names.len() == 1 {
+        conn.execute(&format!("INSERT OR IGNORE INTO {} (id) VALUES (?1)", table), [row_id])?;
+        return Ok(!exists);
+    }
+
+    let placeholders: Vec<String> = (1..=names.len()).map(|i| format!("?{}", i)).collect();
+    let updates: Vec<String> = names.iter().skip(1).map(|name| format!("{} = excluded.{}", name, name)).collect();
+    conn.execute(
+        &format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT(id) DO UPDATE SET {}",
+            table,
+            names.join(", "),
+            placeholders.join(", "),
+            updates.join(", ")
+        ),
+        rusqlite::params_from_iter(values),
+    )?;
+    Ok(!exists)
+}
+
+fn constraint_conflict(table: &str, row_id: &str, error: &rusqlite::Error) -> SyncConflict {
+    let unique = matches!(
+        error,
+        rusqlite::Error::SqliteFailure(e, _)
+            if e.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE
+                || e.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+    );
+    SyncConflict {
+        kind: if unique { SyncConflictKind::UniqueViolation } else { SyncConflictKind::ApplyFailed },
+        table: table.to_string(),
+        row_id: row_id.to_string(),
+        other_row_id: None,
+        column: None,
+        detail: error.to_string(),
+    }
+}
+
+/// 같은 디자이너, 같은 날짜·시간에 취소되지 않은 다른 예약이 있으면 충돌로 보고한다
+fn detect_double_booking(conn: &Connection, reservation_id: &str, report: &mut SyncReport) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT other.id, me.date, me.time, COALESCE(d.name, me.designer_id)
+             FROM reservations me
+             JOIN reservations other
+               ON other.designer_id = me.designer_id AND other.date = me.date AND other.time = me.time AND other.id != me.id
+             LEFT JOIN designers d ON d.id = me.designer_id
+             WHERE me.id = ?1
+               AND COALESCE(me.status, 'pending') NOT IN ('cancelled', 'no_show')
+               AND COALESCE(other.status, 'pending') NOT IN ('cancelled', 'no_show')",
+        )
+        .map_err(|e| e.to_string())?;
+    let others = stmt
+        .query_map([reservation_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, String>(3)?))
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    for (other_id, date, time, designer) in others {
+        let duplicate = report.conflicts.iter().any(|c| {
+            c.kind == SyncConflictKind::DoubleBooking
+                && c.row_id == other_id
+                && c.other_row_id.as_deref() == Some(reservation_id)
+        });
+        if duplicate {
+            continue;
+        }
+        report.conflicts.push(SyncConflict {
+            kind: SyncConflictKind::DoubleBooking,
+            table: "reservations".to_string(),
+            row_id: reservation_id.to_string(),
+            other_row_id: Some(other_id),
+            column: None,
+            detail: format!("{} {} {} 디자이너에게 예약이 두 건 있습니다", date, time, designer),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! 공유 폴더 동기화
+//!
+//! 기기마다 `{기기 id}.salonsync` 파일 하나에 자기 전체 상태를 써 두고, 다른 기기의 파일을 읽어 합친다.
+//! 파일은 백업 아카이브와 같은 방식(Argon2id + XChaCha20-Poly1305)으로 동기화 비밀번호로 암호화한다.
+//! 각 기기는 자기 파일만 쓰므로 Dropbox, NAS 같은 폴더 동기화 도구가 충돌 사본을 만들지 않는다.
+
+use rusqlite::Connection;
+use std::path::Path;
+
+use super::changes::{self, ChangeSet};
+use super::{SyncPeer, SyncReport};
+use crate::services::backup::archive::{self, KdfParams};
+
+pub const FILE_EXTENSION: &str = "salonsync";
+
+/// 다른 기기의 파일을 모두 합친 뒤 이 기기의 상태를 폴더에 쓴다.
+/// 읽지 못한 파일은 건너뛰고 보고서의 오류로 남긴다.
+pub fn sync_folder(
+    conn: &Connection,
+    folder: &Path,
+    device_name: &str,
+    passphrase: &str,
+    params: KdfParams,
+) -> Result<SyncReport, String> {
+    if !folder.is_dir() {
+        return Err(format!("동기화 폴더를 찾을 수 없습니다: {}", folder.display()));
+    }
+    let own = changes::node_id(conn)?;
+
+    let mut peer_files: Vec<_> = std::fs::read_dir(folder)
+        .map_err(|e| format!("동기화 폴더를 읽을 수 없습니다: {}", e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == FILE_EXTENSION))
+        .filter(|path| path.file_stem().is_some_and(|stem| stem.to_string_lossy() != own))
+        .collect();
+    peer_files.sort();
+
+    let mut report = SyncReport::default();
+    for path in peer_files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let applied = read_changes(&path, passphrase).and_then(|changes| {
+            let result = changes::apply_changes(conn, &changes)?;
+            Ok((changes, result))
+        });
+        match applied {
+            Ok((changes, result)) => {
+                println!("[Sync] Merged {} ({}): {} changes", name, changes.device_name, result.applied);
+                report.merge(result);
+                report.peers.push(SyncPeer {
+                    node_id: changes.node_id,
+                    device_name: changes.device_name,
+                    generated_at: changes.generated_at,
+                });
+            }
+            Err(e) => {
+                println!("[Sync] Skipped {}: {}", name, e);
+                report.errors.push(format!("{}: {}", name, e));
+            }
+        }
+    }
+
+    let state = changes::export_changes(conn, device_name, 0)?;
+    write_changes(&folder.join(format!("{}.{}", own, FILE_EXTENSION)), &state, passphrase, params)?;
+    Ok(report)
+}
+
+fn read_changes(path: &Path, passphrase: &str) -> Result<ChangeSet, String> {
+    let data = std::fs::read(path).map_err(|e| format!("파일을 읽을 수 없습니다: {}", e))?;
+    let json = archive::open(&data, passphrase)
+        .map_err(|_| "동기화 비밀번호가 다르거나 파일이 손상되었습니다".to_string())?;
+    serde_json::from_slice(&json).map_err(|e| format!("동기화 파일 형식이 올바르지 않습니다: {}", e))
+}
+
+/// 다른 기기가 쓰는 도중의 파일을 읽지 않도록 임시 파일에 쓴 뒤 이름을 바꾼다
+fn write_changes(path: &Path, changes: &ChangeSet, passphrase: &str, params: KdfParams) -> Result<(), String> {
+    let json = serde_json::to_vec(changes).map_err(|e| e.to_string())?;
+    let sealed = archive::seal(&json, passphrase, params)?;
+    let temp = path.with_extension(format!("{}.tmp", FILE_EXTENSION));
+    std::fs::write(&temp, sealed).map_err(|e| format!("동기화 파일을 쓸 수 없습니다: {}", e))?;
+    std::fs::rename(&temp, path).map_err(|e| {
+        let _ = std::fs::remove_file(&temp);
+        format!("동기화 파일을 쓸 수 없습니다: {}", e)
+    })
+}
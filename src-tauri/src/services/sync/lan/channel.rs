@@ -0,0 +1,308 @@
+//! 기기 간 암호화 채널
+//!
+//! TCP 위에서 길이(4바이트, big endian)를 앞에 붙인 프레임을 주고받는다. 첫 인사만 평문이고
+//! 이후 프레임은 방향마다 다른 키로 ChaCha20-Poly1305 암호화한다.
+//!
+//! 키는 양쪽의 임시 X25519 키 교환에 다음 중 하나를 섞어 HKDF 로 만든다.
+//! - 페어링: 받는 쪽 화면에 뜬 6자리 코드를 Argon2id 로 늘린 값. 코드를 모르면 같은 키가 나오지 않아
+//!   첫 프레임부터 복호화에 실패한다.
+//! - 동기화: 페어링 때 교환한 고정 키로 한 교환 두 개(es, se). 페어링한 그 기기만 같은 키를 만든다.
+
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::services::backup::archive::{self, KdfParams};
+
+pub const PROTOCOL_VERSION: u8 = 1;
+/// 전체 동기화 내용이 한 프레임에 담기므로 넉넉하게 잡는다
+const MAX_FRAME: usize = 64 * 1024 * 1024;
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    Pair,
+    Sync,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hello {
+    pub version: u8,
+    pub mode: Mode,
+    pub node_id: String,
+    /// 이번 연결에만 쓰는 X25519 공개 키 (base64)
+    pub ephemeral: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HelloReply {
+    Accept(Hello),
+    Refuse { message: String },
+}
+
+/// 채널 키를 만들 때 섞을 비밀
+pub enum Auth<'a> {
+    /// 받는 쪽 화면의 페어링 코드
+    Pairing { code: &'a str, kdf: KdfParams },
+    /// 이 기기의 고정 키와 페어링한 상대의 공개 키
+    Paired { identity: &'a StaticSecret, peer: PublicKey },
+}
+
+impl Auth<'_> {
+    fn mode(&self) -> Mode {
+        match self {
+            Auth::Pairing { .. } => Mode::Pair,
+            Auth::Paired { .. } => Mode::Sync,
+        }
+    }
+}
+
+pub fn encode_key(key: &PublicKey) -> String {
+    base64::engine::general_purpose::STANDARD.encode(key.as_bytes())
+}
+
+pub fn decode_key(value: &str) -> Result<PublicKey, String> {
+    let bytes: [u8; 32] = base64::engine::general_purpose::STANDARD
+        .decode(value)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| "상대 기기의 키가 올바르지 않습니다".to_string())?;
+    Ok(PublicKey::from(bytes))
+}
+
+pub fn generate_secret() -> Result<StaticSecret, String> {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(StaticSecret::from(bytes))
+}
+
+fn write_frame(stream: &mut TcpStream, payload: &[u8]) -> Result<(), String> {
+    let len = u32::try_from(payload.len()).map_err(|_| "보낼 데이터가 너무 큽니다".to_string())?;
+    stream.write_all(&len.to_be_bytes()).map_err(|e| format!("전송 실패: {}", e))?;
+    stream.write_all(payload).map_err(|e| format!("전송 실패: {}", e))?;
+    stream.flush().map_err(|e| format!("전송 실패: {}", e))
+}
+
+fn read_frame(stream: &mut TcpStream) -> Result<Vec<u8>, String> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len).map_err(|e| format!("수신 실패: {}", e))?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME {
+        return Err("받은 데이터가 너무 큽니다".to_string());
+    }
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload).map_err(|e| format!("수신 실패: {}", e))?;
+    Ok(payload)
+}
+
+fn configure(stream: &TcpStream) -> Result<(), String> {
+    stream.set_nonblocking(false).map_err(|e| e.to_string())?;
+    stream.set_read_timeout(Some(IO_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_write_timeout(Some(IO_TIMEOUT)).map_err(|e| e.to_string())?;
+    stream.set_nodelay(true).map_err(|e| e.to_string())
+}
+
+pub struct Channel {
+    stream: TcpStream,
+    send_cipher: ChaCha20Poly1305,
+    recv_cipher: ChaCha20Poly1305,
+    send_counter: u64,
+    recv_counter: u64,
+}
+
+fn nonce(counter: u64) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[4..].copy_from_slice(&counter.to_le_bytes());
+    *Nonce::from_slice(&nonce)
+}
+
+impl Channel {
+    fn establish(
+        stream: TcpStream,
+        initiator: bool,
+        transcript: &[u8],
+        ephemeral: &StaticSecret,
+        peer_ephemeral: &PublicKey,
+        auth: &Auth,
+    ) -> Result<Self, String> {
+        let mut ikm = ephemeral.diffie_hellman(peer_ephemeral).as_bytes().to_vec();
+        match auth {
+            Auth::Pairing { code, kdf } => {
+                ikm.extend_from_slice(&archive::derive_key(code, &transcript[..16], *kdf)?);
+            }
+            Auth::Paired { identity, peer } => {
+                // 순서를 양쪽이 같게: 보내는 쪽 임시 키 × 받는 쪽 고정 키, 보내는 쪽 고정 키 × 받는 쪽 임시 키
+                let to_static = ephemeral.diffie_hellman(peer);
+                let from_static = identity.diffie_hellman(peer_ephemeral);
+                let (es, se) = if initiator { (to_static, from_static) } else { (from_static, to_static) };
+                ikm.extend_from_slice(es.as_bytes());
+                ikm.extend_from_slice(se.as_bytes());
+            }
+        }
+
+        let hkdf = Hkdf::<Sha256>::new(Some(transcript), &ikm);
+        let mut initiator_key = [0u8; 32];
+        let mut responder_key = [0u8; 32];
+        hkdf.expand(b"salon-sync initiator", &mut initiator_key).map_err(|e| e.to_string())?;
+        hkdf.expand(b"salon-sync responder", &mut responder_key).map_err(|e| e.to_string())?;
+        let (send_key, recv_key) = if initiator { (initiator_key, responder_key) } else { (responder_key, initiator_key) };
+
+        Ok(Self {
+            stream,
+            send_cipher: ChaCha20Poly1305::new(Key::from_slice(&send_key)),
+            recv_cipher: ChaCha20Poly1305::new(Key::from_slice(&recv_key)),
+            send_counter: 0,
+            recv_counter: 0,
+        })
+    }
+
+    pub fn send<T: Serialize>(&mut self, message: &T) -> Result<(), String> {
+        let plaintext = serde_json::to_vec(message).map_err(|e| e.to_string())?;
+        let ciphertext = self
+            .send_cipher
+            .encrypt(&nonce(self.send_counter), Payload { msg: &plaintext, aad: b"" })
+            .map_err(|_| "암호화 실패".to_string())?;
+        self.send_counter += 1;
+        write_frame(&mut self.stream, &ciphertext)
+    }
+
+    pub fn recv<T: DeserializeOwned>(&mut self) -> Result<T, String> {
+        let ciphertext = read_frame(&mut self.stream)?;
+        let plaintext = self
+            .recv_cipher
+            .decrypt(&nonce(self.recv_counter), Payload { msg: &ciphertext, aad: b"" })
+            .map_err(|_| "상대 기기를 인증하지 못했습니다 (코드가 틀렸거나 페어링하지 않은 기기입니다)".to_string())?;
+        self.recv_counter += 1;
+        serde_json::from_slice(&plaintext).map_err(|e| format!("잘못된 메시지: {}", e))
+    }
+}
+
+fn transcript(hello: &[u8], reply: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update((hello.len() as u32).to_be_bytes());
+    hasher.update(hello);
+    hasher.update(reply);
+    hasher.finalize().into()
+}
+
+/// 상대 기기에 연결을 열고 상대의 인사를 함께 돌려준다
+pub fn connect(mut stream: TcpStream, node_id: &str, auth: &Auth) -> Result<(Channel, Hello), String> {
+    configure(&stream)?;
+    let ephemeral = generate_secret()?;
+    let hello = Hello {
+        version: PROTOCOL_VERSION,
+        mode: auth.mode(),
+        node_id: node_id.to_string(),
+        ephemeral: encode_key(&PublicKey::from(&ephemeral)),
+    };
+    let hello_bytes = serde_json::to_vec(&hello).map_err(|e| e.to_string())?;
+    write_frame(&mut stream, &hello_bytes)?;
+
+    let reply_bytes = read_frame(&mut stream)?;
+    let peer = match serde_json::from_slice(&reply_bytes).map_err(|e| format!("잘못된 응답: {}", e))? {
+        HelloReply::Accept(peer) => peer,
+        HelloReply::Refuse { message } => return Err(message),
+    };
+    if peer.version != PROTOCOL_VERSION || peer.mode != hello.mode {
+        return Err("상대 기기의 동기화 버전이 다릅니다. 두 기기 모두 앱을 업데이트해주세요".to_string());
+    }
+
+    let transcript = transcript(&hello_bytes, &reply_bytes);
+    let channel = Channel::establish(stream, true, &transcript, &ephemeral, &decode_key(&peer.ephemeral)?, auth)?;
+    Ok((channel, peer))
+}
+
+/// 받은 연결의 첫 인사. 상대를 확인한 뒤 `accept` 나 `refuse` 로 답한다.
+pub struct Incoming {
+    stream: TcpStream,
+    pub hello: Hello,
+    hello_bytes: Vec<u8>,
+}
+
+impl Incoming {
+    pub fn read(mut stream: TcpStream) -> Result<Self, String> {
+        configure(&stream)?;
+        let hello_bytes = read_frame(&mut stream)?;
+        let hello: Hello = serde_json::from_slice(&hello_bytes).map_err(|e| format!("잘못된 인사: {}", e))?;
+        Ok(Self { stream, hello, hello_bytes })
+    }
+
+    pub fn accept(mut self, node_id: &str, auth: &Auth) -> Result<Channel, String> {
+        if self.hello.version != PROTOCOL_VERSION || self.hello.mode != auth.mode() {
+            let _ = self.refuse("동기화 버전이 다릅니다. 두 기기 모두 앱을 업데이트해주세요");
+            return Err("지원하지 않는 동기화 버전입니다".to_string());
+        }
+        let peer_ephemeral = decode_key(&self.hello.ephemeral)?;
+        let ephemeral = generate_secret()?;
+        let reply = HelloReply::Accept(Hello {
+            version: PROTOCOL_VERSION,
+            mode: auth.mode(),
+            node_id: node_id.to_string(),
+            ephemeral: encode_key(&PublicKey::from(&ephemeral)),
+        });
+        let reply_bytes = serde_json::to_vec(&reply).map_err(|e| e.to_string())?;
+        write_frame(&mut self.stream, &reply_bytes)?;
+
+        let transcript = transcript(&self.hello_bytes, &reply_bytes);
+        Channel::establish(self.stream, false, &transcript, &ephemeral, &peer_ephemeral, auth)
+    }
+
+    pub fn refuse(mut self, message: &str) -> Result<(), String> {
+        let reply = HelloReply::Refuse { message: message.to_string() };
+        write_frame(&mut self.stream, &serde_json::to_vec(&reply).map_err(|e| e.to_string())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn exchange(client_auth: Auth<'static>, server_auth: Auth<'static>) -> Result<String, String> {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || -> Result<(), String> {
+            let (stream, _) = listener.accept().unwrap();
+            let incoming = Incoming::read(stream)?;
+            assert_eq!(incoming.hello.node_id, "client");
+            let mut channel = incoming.accept("server", &server_auth)?;
+            let message: String = channel.recv()?;
+            channel.send(&format!("{} 받음", message))
+        });
+
+        let result = connect(TcpStream::connect(addr).unwrap(), "client", &client_auth).and_then(|(mut channel, peer)| {
+            assert_eq!(peer.node_id, "server");
+            channel.send(&"안녕".to_string())?;
+            channel.recv::<String>()
+        });
+        let _ = server.join().unwrap();
+        result
+    }
+
+    #[test]
+    fn test_channel_requires_matching_secrets() {
+        let code = |code: &'static str| Auth::Pairing { code, kdf: archive::TEST_KDF };
+        assert_eq!(exchange(code("123456"), code("123456")).unwrap(), "안녕 받음");
+        assert!(exchange(code("123456"), code("654321")).is_err());
+
+        let leak = |secret: StaticSecret| -> &'static StaticSecret { Box::leak(Box::new(secret)) };
+        let client = leak(generate_secret().unwrap());
+        let server = leak(generate_secret().unwrap());
+        let stranger = leak(generate_secret().unwrap());
+        let paired = |identity: &'static StaticSecret, peer: &StaticSecret| Auth::Paired { identity, peer: PublicKey::from(peer) };
+        assert_eq!(exchange(paired(client, server), paired(server, client)).unwrap(), "안녕 받음");
+        // 페어링하지 않은 기기는 받는 쪽이 알고 있는 공개 키와 맞지 않는다
+        assert!(exchange(paired(stranger, server), paired(server, client)).is_err());
+    }
+}
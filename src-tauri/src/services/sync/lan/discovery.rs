@@ -0,0 +1,91 @@
+//! 같은 네트워크의 기기 찾기 (mDNS / DNS-SD)
+//!
+//! 동기화를 켠 기기는 `_salonsync._tcp` 서비스로 자신을 알린다. 인스턴스 이름이 기기 id 라서
+//! 주소가 바뀌어도 페어링한 기기를 다시 찾을 수 있다.
+
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use serde::Serialize;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+
+pub const SERVICE_TYPE: &str = "_salonsync._tcp.local.";
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveredDevice {
+    pub node_id: String,
+    pub device_name: String,
+    /// `ip:port`
+    pub address: String,
+    /// 이미 페어링한 기기인지
+    pub paired: bool,
+}
+
+/// 이 기기를 네트워크에 알리는 동안 살아 있는 핸들. 버리면 알림을 거둔다.
+pub struct Advertisement {
+    daemon: ServiceDaemon,
+    fullname: String,
+}
+
+impl Advertisement {
+    pub fn start(node_id: &str, device_name: &str, port: u16) -> Result<Self, String> {
+        let daemon = ServiceDaemon::new().map_err(|e| format!("mDNS 시작 실패: {}", e))?;
+        let host_name = format!("{}.local.", node_id);
+        let properties = [("node", node_id), ("name", device_name)];
+        let info = ServiceInfo::new(SERVICE_TYPE, node_id, &host_name, "", port, &properties[..])
+            .map_err(|e| format!("mDNS 서비스 정보 오류: {}", e))?
+            .enable_addr_auto();
+        let fullname = info.get_fullname().to_string();
+        daemon.register(info).map_err(|e| format!("mDNS 등록 실패: {}", e))?;
+        Ok(Self { daemon, fullname })
+    }
+}
+
+impl Drop for Advertisement {
+    fn drop(&mut self) {
+        let _ = self.daemon.unregister(&self.fullname);
+        let _ = self.daemon.shutdown();
+    }
+}
+
+/// `timeout` 동안 네트워크의 다른 기기를 찾는다. 자기 자신(`own_node`)은 뺀다.
+pub fn browse(timeout: Duration, own_node: &str) -> Result<Vec<DiscoveredDevice>, String> {
+    let daemon = ServiceDaemon::new().map_err(|e| format!("mDNS 시작 실패: {}", e))?;
+    let receiver = daemon.browse(SERVICE_TYPE).map_err(|e| format!("기기 찾기 실패: {}", e))?;
+
+    let deadline = Instant::now() + timeout;
+    let mut found: Vec<DiscoveredDevice> = Vec::new();
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        let Ok(event) = receiver.recv_timeout(remaining) else {
+            break;
+        };
+        let ServiceEvent::ServiceResolved(info) = event else {
+            continue;
+        };
+        let Some(node_id) = info.get_property_val_str("node").map(str::to_string) else {
+            continue;
+        };
+        // IPv4, 루프백이 아닌 주소를 먼저 쓴다
+        let Some(ip) = info
+            .get_addresses()
+            .iter()
+            .copied()
+            .min_by_key(|ip: &IpAddr| (!ip.is_ipv4(), ip.is_loopback()))
+        else {
+            continue;
+        };
+        if node_id == own_node || found.iter().any(|d| d.node_id == node_id) {
+            continue;
+        }
+        found.push(DiscoveredDevice {
+            device_name: info.get_property_val_str("name").unwrap_or_default().to_string(),
+            address: SocketAddr::new(ip, info.get_port()).to_string(),
+            paired: false,
+            node_id,
+        });
+    }
+
+    let _ = daemon.stop_browse(SERVICE_TYPE);
+    let _ = daemon.shutdown();
+    Ok(found)
+}
@@ -0,0 +1,680 @@
+//! 같은 네트워크의 기기와 직접 동기화
+//!
+//! 공유 폴더 없이 매장 와이파이 안에서 기기끼리 변경을 주고받는다.
+//! - 찾기: mDNS 로 `_salonsync._tcp` 서비스를 알리고 찾는다 (`discovery`).
+//! - 페어링: 받는 쪽이 띄운 6자리 코드를 보내는 쪽에 입력하면 서로의 고정 공개 키를 저장한다.
+//! - 동기화: 저장한 키로 인증한 암호화 채널(`channel`)에서 변경을 받고(Pull) 보낸다(Push).
+//!
+//! 병합은 공유 폴더 동기화와 같은 `changes` 규칙을 쓰므로 두 방식을 함께 써도 된다.
+
+pub mod channel;
+pub mod discovery;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use base64::Engine;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use self::channel::{Auth, Channel, Incoming, Mode};
+use self::discovery::{Advertisement, DiscoveredDevice};
+use super::changes::{self, ChangeSet};
+use super::{SyncPeer, SyncReport, SyncSettings, DATETIME_FORMAT};
+use crate::db::Database;
+use crate::services::backup::archive::KdfParams;
+
+/// sync_state 에 두는 이 기기의 고정 X25519 비밀 키 (base64)
+pub const IDENTITY_KEY: &str = "lan_identity";
+const PAIRING_CODE_TTL_MINUTES: i64 = 5;
+/// 코드 하나로 허용하는 연결 시도 수
+const MAX_PAIRING_ATTEMPTS: u32 = 5;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const ACCEPT_POLL: Duration = Duration::from_millis(100);
+/// 자동 동기화 때 페어링한 기기의 현재 주소를 찾는 시간
+const BROWSE_TIMEOUT: Duration = Duration::from_secs(2);
+/// 화면에서 기기 찾기를 눌렀을 때 기다리는 시간
+pub const DISCOVER_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message {
+    /// 페어링 요청과 응답. 서로의 고정 공개 키를 알린다
+    #[serde(rename_all = "camelCase")]
+    Pair { node_id: String, device_name: String, public_key: String, port: u16 },
+    /// `since_seq` 이후의 변경 요청
+    #[serde(rename_all = "camelCase")]
+    Pull { since_seq: i64 },
+    Changes { changes: Box<ChangeSet> },
+    Push { changes: Box<ChangeSet> },
+    Applied { report: SyncReport },
+    Error { message: String },
+    Done,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairedDevice {
+    pub node_id: String,
+    pub device_name: String,
+    /// 마지막으로 연결한 `ip:port`
+    pub address: Option<String>,
+    pub paired_at: String,
+    pub last_synced_at: Option<String>,
+    #[serde(skip)]
+    public_key: String,
+    #[serde(skip)]
+    pulled_seq: i64,
+    #[serde(skip)]
+    pushed_seq: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PairingCode {
+    pub code: String,
+    pub expires_at: String,
+    #[serde(skip)]
+    attempts: u32,
+}
+
+impl PairingCode {
+    fn is_active(&self) -> bool {
+        self.attempts < MAX_PAIRING_ATTEMPTS
+            && chrono::DateTime::parse_from_rfc3339(&self.expires_at).is_ok_and(|at| at > chrono::Utc::now())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanStatus {
+    pub running: bool,
+    pub port: Option<u16>,
+    /// 다른 기기가 찾을 수 있게 알리고 있는지
+    pub advertised: bool,
+    /// 화면에 띄운 페어링 코드
+    pub pairing: Option<PairingCode>,
+}
+
+fn now() -> String {
+    chrono::Local::now().format(DATETIME_FORMAT).to_string()
+}
+
+/// 이 기기의 고정 키. 처음 쓸 때 만든다
+fn identity(conn: &Connection) -> Result<StaticSecret, String> {
+    let engine = base64::engine::general_purpose::STANDARD;
+    if let Some(value) = changes::get_state(conn, IDENTITY_KEY)? {
+        let bytes: [u8; 32] = engine
+            .decode(value)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| "저장된 기기 키가 손상되었습니다".to_string())?;
+        return Ok(StaticSecret::from(bytes));
+    }
+    let secret = channel::generate_secret()?;
+    changes::set_state(conn, IDENTITY_KEY, &engine.encode(secret.to_bytes()))?;
+    Ok(secret)
+}
+
+fn map_peer(row: &rusqlite::Row) -> rusqlite::Result<PairedDevice> {
+    Ok(PairedDevice {
+        node_id: row.get(0)?,
+        device_name: row.get(1)?,
+        public_key: row.get(2)?,
+        address: row.get(3)?,
+        pulled_seq: row.get(4)?,
+        pushed_seq: row.get(5)?,
+        paired_at: row.get(6)?,
+        last_synced_at: row.get(7)?,
+    })
+}
+
+const PEER_COLUMNS: &str = "node_id, device_name, public_key, address, pulled_seq, pushed_seq, paired_at, last_synced_at";
+
+pub fn paired_devices(conn: &Connection) -> Result<Vec<PairedDevice>, String> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT {} FROM sync_peers ORDER BY paired_at", PEER_COLUMNS))
+        .map_err(|e| e.to_string())?;
+    let peers = stmt
+        .query_map([], map_peer)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(peers)
+}
+
+fn find_peer(conn: &Connection, node_id: &str) -> Result<Option<PairedDevice>, String> {
+    conn.query_row(&format!("SELECT {} FROM sync_peers WHERE node_id = ?1", PEER_COLUMNS), [node_id], map_peer)
+        .optional()
+        .map_err(|e| e.to_string())
+}
+
+/// 다시 페어링하면 주고받은 위치를 처음부터 다시 센다
+fn save_peer(conn: &Connection, node_id: &str, device_name: &str, public_key: &str, address: Option<&str>) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO sync_peers (node_id, device_name, public_key, address, paired_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(node_id) DO UPDATE SET
+            device_name = excluded.device_name, public_key = excluded.public_key, address = excluded.address,
+            pulled_seq = 0, pushed_seq = 0, paired_at = excluded.paired_at, last_synced_at = NULL",
+        params![node_id, device_name, public_key, address, now()],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub fn unpair(conn: &Connection, node_id: &str) -> Result<(), String> {
+    let deleted = conn.execute("DELETE FROM sync_peers WHERE node_id = ?1", [node_id]).map_err(|e| e.to_string())?;
+    if deleted == 0 {
+        return Err("페어링한 기기를 찾을 수 없습니다".to_string());
+    }
+    Ok(())
+}
+
+fn lock(db: &Mutex<Database>) -> Result<std::sync::MutexGuard<'_, Database>, String> {
+    db.lock().map_err(|e| e.to_string())
+}
+
+fn connect_to(address: &str) -> Result<TcpStream, String> {
+    let addr = address
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .ok_or_else(|| format!("잘못된 주소입니다: {}", address))?;
+    TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).map_err(|e| format!("{} 에 연결할 수 없습니다: {}", address, e))
+}
+
+/// 이 기기에서 동기화 요청을 받는 서버
+pub struct LanServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    pairing: Arc<Mutex<Option<PairingCode>>>,
+    advert: Option<Advertisement>,
+}
+
+impl LanServer {
+    pub fn start(db: Arc<Mutex<Database>>, addr: SocketAddr, kdf: KdfParams) -> Result<Self, String> {
+        let listener = TcpListener::bind(addr).map_err(|e| format!("동기화 포트를 열 수 없습니다 ({}): {}", addr, e))?;
+        listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+        let addr = listener.local_addr().map_err(|e| e.to_string())?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let pairing = Arc::new(Mutex::new(None));
+        let thread = {
+            let stop = stop.clone();
+            let pairing = pairing.clone();
+            std::thread::Builder::new()
+                .name("lan-sync".to_string())
+                .spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        match listener.accept() {
+                            Ok((stream, peer_addr)) => {
+                                let db = db.clone();
+                                let pairing = pairing.clone();
+                                let spawned = std::thread::Builder::new().name("lan-sync-peer".to_string()).spawn(move || {
+                                    if let Err(e) = serve(&db, &pairing, kdf, addr.port(), stream, peer_addr.ip()) {
+                                        println!("[Sync] LAN connection from {} failed: {}", peer_addr, e);
+                                    }
+                                });
+                                if let Err(e) = spawned {
+                                    eprintln!("Failed to handle LAN sync connection: {}", e);
+                                }
+                            }
+                            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => std::thread::sleep(ACCEPT_POLL),
+                            Err(e) => {
+                                eprintln!("LAN sync accept failed: {}", e);
+                                std::thread::sleep(ACCEPT_POLL);
+                            }
+                        }
+                    }
+                })
+                .map_err(|e| e.to_string())?
+        };
+
+        Ok(Self { addr, stop, thread: Some(thread), pairing, advert: None })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 새 페어링 코드를 띄운다. 이전 코드는 더 이상 쓸 수 없다
+    pub fn start_pairing(&self) -> Result<PairingCode, String> {
+        let mut bytes = [0u8; 4];
+        getrandom::getrandom(&mut bytes).map_err(|e| e.to_string())?;
+        let code = PairingCode {
+            code: format!("{:06}", u32::from_le_bytes(bytes) % 1_000_000),
+            expires_at: (chrono::Utc::now() + chrono::Duration::minutes(PAIRING_CODE_TTL_MINUTES)).to_rfc3339(),
+            attempts: 0,
+        };
+        *self.pairing.lock().map_err(|e| e.to_string())? = Some(code.clone());
+        Ok(code)
+    }
+
+    pub fn cancel_pairing(&self) -> Result<(), String> {
+        *self.pairing.lock().map_err(|e| e.to_string())? = None;
+        Ok(())
+    }
+
+    fn status(&self) -> LanStatus {
+        LanStatus {
+            running: true,
+            port: Some(self.addr.port()),
+            advertised: self.advert.is_some(),
+            pairing: self.pairing.lock().ok().and_then(|p| p.clone()).filter(PairingCode::is_active),
+        }
+    }
+
+    fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.advert = None;
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for LanServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// 받은 연결 하나를 처리한다
+fn serve(
+    db: &Mutex<Database>,
+    pairing: &Mutex<Option<PairingCode>>,
+    kdf: KdfParams,
+    port: u16,
+    stream: TcpStream,
+    peer_ip: IpAddr,
+) -> Result<(), String> {
+    let incoming = Incoming::read(stream)?;
+    let peer_node = incoming.hello.node_id.clone();
+    let (node_id, device_name, identity, peer) = {
+        let db = lock(db)?;
+        let conn = db.conn();
+        (changes::node_id(conn)?, super::get_settings(conn)?.device_name, identity(conn)?, find_peer(conn, &peer_node)?)
+    };
+
+    match incoming.hello.mode {
+        Mode::Pair => {
+            let code = {
+                let mut pairing = pairing.lock().map_err(|e| e.to_string())?;
+                match pairing.as_mut().filter(|p| p.is_active()) {
+                    Some(active) => {
+                        active.attempts += 1;
+                        Some(active.code.clone())
+                    }
+                    None => None,
+                }
+            };
+            let Some(code) = code else {
+                return incoming.refuse("상대 기기에서 페어링 코드를 띄운 뒤 다시 시도해주세요");
+            };
+
+            let mut channel = incoming.accept(&node_id, &Auth::Pairing { code: &code, kdf })?;
+            let Message::Pair { node_id: paired_node, device_name: peer_name, public_key, port: peer_port } = channel.recv()? else {
+                return Err("잘못된 페어링 요청입니다".to_string());
+            };
+            if paired_node != peer_node {
+                return Err("페어링 요청의 기기 id 가 다릅니다".to_string());
+            }
+            channel::decode_key(&public_key)?;
+            *pairing.lock().map_err(|e| e.to_string())? = None;
+
+            let address = (peer_port != 0).then(|| SocketAddr::new(peer_ip, peer_port).to_string());
+            save_peer(lock(db)?.conn(), &paired_node, &peer_name, &public_key, address.as_deref())?;
+            println!("[Sync] Paired with {} ({})", peer_name, paired_node);
+            channel.send(&Message::Pair {
+                node_id,
+                device_name,
+                public_key: channel::encode_key(&PublicKey::from(&identity)),
+                port,
+            })
+        }
+        Mode::Sync => {
+            let Some(peer) = peer else {
+                return incoming.refuse("페어링하지 않은 기기입니다. 먼저 두 기기를 페어링해주세요");
+            };
+            let auth = Auth::Paired { identity: &identity, peer: channel::decode_key(&peer.public_key)? };
+            let mut channel = incoming.accept(&node_id, &auth)?;
+            loop {
+                let reply = match channel.recv()? {
+                    Message::Pull { since_seq } => export_for(db, &device_name, since_seq).map(|changes| Message::Changes { changes: Box::new(changes) }),
+                    Message::Push { changes } if changes.node_id == peer.node_id => {
+                        apply_from(db, &peer, &changes).map(|report| Message::Applied { report })
+                    }
+                    Message::Done => return Ok(()),
+                    _ => Err("잘못된 동기화 요청입니다".to_string()),
+                };
+                match reply {
+                    Ok(reply) => channel.send(&reply)?,
+                    Err(message) => return channel.send(&Message::Error { message }),
+                }
+            }
+        }
+    }
+}
+
+/// 요청한 위치가 이 기기의 기록보다 앞서면(이 기기를 초기화한 경우) 전체 상태를 보낸다
+fn export_for(db: &Mutex<Database>, device_name: &str, since_seq: i64) -> Result<ChangeSet, String> {
+    let db = lock(db)?;
+    let changes = changes::export_changes(db.conn(), device_name, since_seq)?;
+    if since_seq > changes.seq {
+        return changes::export_changes(db.conn(), device_name, 0);
+    }
+    Ok(changes)
+}
+
+/// 상대가 보낸 변경을 반영하고 이 기기의 동기화 상태로도 남긴다
+fn apply_from(db: &Mutex<Database>, peer: &PairedDevice, changes: &ChangeSet) -> Result<SyncReport, String> {
+    let db = lock(db)?;
+    let conn = db.conn();
+    let result = changes::apply_changes(conn, changes).map(|mut report| {
+        report.peers.push(SyncPeer {
+            node_id: changes.node_id.clone(),
+            device_name: changes.device_name.clone(),
+            generated_at: changes.generated_at.clone(),
+        });
+        report
+    });
+    if result.is_ok() {
+        conn.execute("UPDATE sync_peers SET last_synced_at = ?1 WHERE node_id = ?2", params![now(), peer.node_id])
+            .map_err(|e| e.to_string())?;
+    }
+    if let Err(e) = super::record_status(conn, &result) {
+        eprintln!("Failed to record sync status: {}", e);
+    }
+    result
+}
+
+fn unexpected(message: Message) -> String {
+    match message {
+        Message::Error { message } => message,
+        _ => "상대 기기가 예상하지 못한 응답을 보냈습니다".to_string(),
+    }
+}
+
+/// 상대 기기 화면의 코드로 페어링한다
+pub fn pair(db: &Mutex<Database>, address: &str, code: &str) -> Result<PairedDevice, String> {
+    let own_port = status()?.port.unwrap_or(0);
+    pair_with(db, address, code, own_port, KdfParams::default())
+}
+
+fn pair_with(db: &Mutex<Database>, address: &str, code: &str, own_port: u16, kdf: KdfParams) -> Result<PairedDevice, String> {
+    let code = code.trim();
+    if code.len() != 6 || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Err("페어링 코드 6자리를 입력해주세요".to_string());
+    }
+    let (node_id, device_name, identity) = {
+        let db = lock(db)?;
+        let conn = db.conn();
+        (changes::node_id(conn)?, super::get_settings(conn)?.device_name, identity(conn)?)
+    };
+
+    let (mut channel, hello) = channel::connect(connect_to(address)?, &node_id, &Auth::Pairing { code, kdf })?;
+    channel.send(&Message::Pair {
+        node_id,
+        device_name,
+        public_key: channel::encode_key(&PublicKey::from(&identity)),
+        port: own_port,
+    })?;
+    // 코드가 틀리면 상대가 복호화에 실패하고 연결을 끊는다
+    let reply = channel
+        .recv()
+        .map_err(|_| "페어링 코드가 틀렸거나 만료되었습니다. 상대 기기의 코드를 확인해주세요".to_string())?;
+    let Message::Pair { node_id: peer_node, device_name: peer_name, public_key, .. } = reply else {
+        return Err(unexpected(reply));
+    };
+    if peer_node != hello.node_id {
+        return Err("페어링 응답의 기기 id 가 다릅니다".to_string());
+    }
+    channel::decode_key(&public_key)?;
+
+    let db = lock(db)?;
+    save_peer(db.conn(), &peer_node, &peer_name, &public_key, Some(address))?;
+    println!("[Sync] Paired with {} ({})", peer_name, peer_node);
+    find_peer(db.conn(), &peer_node)?.ok_or_else(|| "페어링한 기기를 저장하지 못했습니다".to_string())
+}
+
+fn request(channel: &mut Channel, message: &Message) -> Result<Message, String> {
+    channel.send(message)?;
+    channel.recv()
+}
+
+/// 페어링한 기기 하나와 변경을 주고받는다. 네트워크로 주고받는 동안에는 DB 를 잠그지 않는다
+pub fn sync_peer(db: &Mutex<Database>, peer: &PairedDevice, address: &str) -> Result<SyncReport, String> {
+    let (node_id, identity, outgoing) = {
+        let db = lock(db)?;
+        let conn = db.conn();
+        let device_name = super::get_settings(conn)?.device_name;
+        (changes::node_id(conn)?, identity(conn)?, changes::export_changes(conn, &device_name, peer.pushed_seq)?)
+    };
+    let pushed_seq = outgoing.seq;
+
+    let auth = Auth::Paired { identity: &identity, peer: channel::decode_key(&peer.public_key)? };
+    let (mut channel, hello) = channel::connect(connect_to(address)?, &node_id, &auth)?;
+    if hello.node_id != peer.node_id {
+        return Err("이 주소에서 다른 기기가 응답했습니다".to_string());
+    }
+    let incoming = match request(&mut channel, &Message::Pull { since_seq: peer.pulled_seq })? {
+        Message::Changes { changes } if changes.node_id == peer.node_id => changes,
+        other => return Err(unexpected(other)),
+    };
+    match request(&mut channel, &Message::Push { changes: Box::new(outgoing) })? {
+        Message::Applied { report } => {
+            println!("[Sync] {} applied {} changes", peer.device_name, report.applied);
+        }
+        other => return Err(unexpected(other)),
+    }
+    channel.send(&Message::Done)?;
+
+    let db = lock(db)?;
+    let conn = db.conn();
+    let mut report = changes::apply_changes(conn, &incoming)?;
+    report.peers.push(SyncPeer {
+        node_id: incoming.node_id.clone(),
+        device_name: incoming.device_name.clone(),
+        generated_at: incoming.generated_at.clone(),
+    });
+    conn.execute(
+        "UPDATE sync_peers SET device_name = ?1, address = ?2, pulled_seq = ?3, pushed_seq = ?4, last_synced_at = ?5 WHERE node_id = ?6",
+        params![incoming.device_name, address, incoming.seq, pushed_seq, now(), peer.node_id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(report)
+}
+
+/// 페어링한 모든 기기와 동기화한다. 찾지 못했거나 실패한 기기는 보고서의 오류로 남긴다
+pub fn sync_paired(db: &Mutex<Database>) -> Result<SyncReport, String> {
+    let (own, peers) = {
+        let db = lock(db)?;
+        (changes::node_id(db.conn())?, paired_devices(db.conn())?)
+    };
+    let mut report = SyncReport::default();
+    if peers.is_empty() {
+        return Ok(report);
+    }
+
+    // 주소가 바뀌었을 수 있으니 먼저 찾아보고, 없으면 마지막 주소로 시도한다
+    let found = discovery::browse(BROWSE_TIMEOUT, &own).unwrap_or_else(|e| {
+        report.errors.push(e);
+        Vec::new()
+    });
+    for peer in &peers {
+        let address = found
+            .iter()
+            .find(|device| device.node_id == peer.node_id)
+            .map(|device| device.address.clone())
+            .or_else(|| peer.address.clone());
+        let Some(address) = address else {
+            report.errors.push(format!("{}: 같은 네트워크에서 기기를 찾지 못했습니다", peer.device_name));
+            continue;
+        };
+        match sync_peer(db, peer, &address) {
+            Ok(result) => report.merge(result),
+            Err(e) => {
+                println!("[Sync] LAN sync with {} failed: {}", peer.device_name, e);
+                report.errors.push(format!("{}: {}", peer.device_name, e));
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// 네트워크의 동기화 기기를 찾아 페어링 여부를 표시한다
+pub fn discover(db: &Mutex<Database>) -> Result<Vec<DiscoveredDevice>, String> {
+    let own = changes::node_id(lock(db)?.conn())?;
+    let mut devices = discovery::browse(DISCOVER_TIMEOUT, &own)?;
+    let paired = paired_devices(lock(db)?.conn())?;
+    for device in &mut devices {
+        device.paired = paired.iter().any(|p| p.node_id == device.node_id);
+    }
+    Ok(devices)
+}
+
+// 앱 전역에서 하나만 실행
+static RUNNING: Mutex<Option<LanServer>> = Mutex::new(None);
+
+/// 설정에 맞춰 동기화 서버를 (재)시작하거나 중지
+pub fn apply_settings(db: Arc<Mutex<Database>>, settings: &SyncSettings) -> Result<LanStatus, String> {
+    let mut running = RUNNING.lock().map_err(|e| e.to_string())?;
+    if let Some(server) = running.take() {
+        server.stop();
+    }
+
+    if settings.lan_enabled {
+        let node_id = changes::node_id(lock(&db)?.conn())?;
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), settings.lan_port);
+        let mut server = LanServer::start(db, addr, KdfParams::default())?;
+        // 알리지 못해도 주소를 직접 입력해 페어링할 수 있으므로 서버는 계속 띄운다
+        match Advertisement::start(&node_id, &settings.device_name, server.addr().port()) {
+            Ok(advert) => server.advert = Some(advert),
+            Err(e) => eprintln!("Failed to advertise LAN sync: {}", e),
+        }
+        println!("[Sync] LAN sync listening on port {}", server.addr().port());
+        *running = Some(server);
+    }
+
+    Ok(status_of(running.as_ref()))
+}
+
+/// 앱 시작 시 설정이 켜져 있으면 서버 실행
+pub fn start_if_enabled(db: Arc<Mutex<Database>>) -> Result<(), String> {
+    let settings = super::get_settings(lock(&db)?.conn())?;
+    if settings.lan_enabled {
+        apply_settings(db, &settings)?;
+    }
+    Ok(())
+}
+
+pub fn status() -> Result<LanStatus, String> {
+    let running = RUNNING.lock().map_err(|e| e.to_string())?;
+    Ok(status_of(running.as_ref()))
+}
+
+fn status_of(server: Option<&LanServer>) -> LanStatus {
+    match server {
+        Some(server) => server.status(),
+        None => LanStatus { running: false, port: None, advertised: false, pairing: None },
+    }
+}
+
+pub fn start_pairing() -> Result<PairingCode, String> {
+    let running = RUNNING.lock().map_err(|e| e.to_string())?;
+    running
+        .as_ref()
+        .ok_or_else(|| "같은 네트워크 동기화를 먼저 켜주세요".to_string())?
+        .start_pairing()
+}
+
+pub fn cancel_pairing() -> Result<(), String> {
+    let running = RUNNING.lock().map_err(|e| e.to_string())?;
+    match running.as_ref() {
+        Some(server) => server.cancel_pairing(),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::backup::archive;
+    use crate::services::sync::SyncConflictKind;
+
+    fn device(name: &str) -> Arc<Mutex<Database>> {
+        let db = Database::open_in_memory().unwrap();
+        let settings = SyncSettings { device_name: name.to_string(), lan_enabled: true, ..SyncSettings::default() };
+        super::super::save_settings(db.conn(), &settings).unwrap();
+        Arc::new(Mutex::new(db))
+    }
+
+    fn exec(db: &Mutex<Database>, sql: &str) {
+        db.lock().unwrap().conn().execute_batch(sql).unwrap();
+    }
+
+    fn value(db: &Mutex<Database>, sql: &str) -> Option<String> {
+        db.lock().unwrap().conn().query_row(sql, [], |row| row.get(0)).ok()
+    }
+
+    #[test]
+    fn test_pair_with_code_then_sync_directly() {
+        let front = device("프런트");
+        let tablet = device("디자이너 태블릿");
+        let server = LanServer::start(front.clone(), "127.0.0.1:0".parse().unwrap(), archive::TEST_KDF).unwrap();
+        let address = server.addr().to_string();
+
+        // 코드를 띄우지 않았으면 거절
+        let error = pair_with(&tablet, &address, "000000", 0, archive::TEST_KDF).unwrap_err();
+        assert!(error.contains("페어링 코드를 띄운"), "{}", error);
+
+        let code = server.start_pairing().unwrap().code;
+        let wrong = if code == "000000" { "111111" } else { "000000" };
+        assert!(pair_with(&tablet, &address, wrong, 0, archive::TEST_KDF).is_err());
+        assert!(server.status().pairing.is_some());
+
+        let peer = pair_with(&tablet, &address, &code, 0, archive::TEST_KDF).unwrap();
+        assert_eq!(peer.device_name, "프런트");
+        let front_peers = paired_devices(front.lock().unwrap().conn()).unwrap();
+        assert_eq!(front_peers.len(), 1);
+        assert_eq!(front_peers[0].device_name, "디자이너 태블릿");
+        // 코드는 한 번만 쓸 수 있다
+        assert!(server.status().pairing.is_none());
+        assert!(pair_with(&tablet, &address, &code, 0, archive::TEST_KDF).is_err());
+
+        exec(&front, "INSERT INTO designers (id, name) VALUES ('d1', '수진')");
+        exec(&tablet, "INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-2222')");
+        sync_peer(&tablet, &peer, &address).unwrap();
+        assert_eq!(value(&tablet, "SELECT name FROM designers WHERE id = 'd1'").as_deref(), Some("수진"));
+        assert_eq!(value(&front, "SELECT name FROM customers WHERE id = 'c1'").as_deref(), Some("김민지"));
+
+        // 두 기기가 오프라인으로 같은 시간에 예약을 받으면 양쪽 모두에 충돌로 남는다
+        exec(&front, "INSERT INTO reservations (id, customer_name, date, time, designer_id) VALUES ('r-front', '김민지', '2026-03-02', '14:00', 'd1')");
+        exec(&tablet, "INSERT INTO reservations (id, customer_name, date, time, designer_id) VALUES ('r-tablet', '이서연', '2026-03-02', '14:00', 'd1')");
+        let peer = find_peer(tablet.lock().unwrap().conn(), &peer.node_id).unwrap().unwrap();
+        let report = sync_peer(&tablet, &peer, &address).unwrap();
+        assert_eq!(report.peers[0].device_name, "프런트");
+        assert!(report.conflicts.iter().any(|c| c.kind == SyncConflictKind::DoubleBooking));
+        let front_status = super::super::get_status(front.lock().unwrap().conn()).unwrap().unwrap();
+        assert!(front_status.report.unwrap().conflicts.iter().any(|c| c.kind == SyncConflictKind::DoubleBooking));
+        for db in [&front, &tablet] {
+            assert_eq!(value(db, "SELECT group_concat(id) FROM (SELECT id FROM reservations ORDER BY id)").as_deref(), Some("r-front,r-tablet"));
+        }
+
+        // 상대가 페어링을 지우면 더 이상 동기화할 수 없다
+        let tablet_node = changes::node_id(tablet.lock().unwrap().conn()).unwrap();
+        unpair(front.lock().unwrap().conn(), &tablet_node).unwrap();
+        let error = sync_peer(&tablet, &peer, &address).unwrap_err();
+        assert!(error.contains("페어링하지 않은 기기"), "{}", error);
+    }
+}
@@ -0,0 +1,516 @@
+//! 기기 간 동기화
+//!
+//! 카운터(프런트)와 디자이너 기기가 각자 오프라인으로 고친 데이터를 행·필드 단위로 합친다.
+//! 변경 기록과 병합 규칙은 `changes`, 공유 폴더(Dropbox, NAS 등)를 통한 교환은 `folder`,
+//! 같은 네트워크의 기기와 직접 주고받는 교환은 `lan` 에 있다.
+//! 병합 중 반영하지 못한 행, 덮어쓴 값, 오프라인에서 생긴 중복 예약은 충돌 보고서로 남긴다.
+
+pub mod changes;
+pub mod folder;
+pub mod hlc;
+pub mod lan;
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::db::Database;
+use crate::services::backup::archive::{self, KdfParams};
+
+const SETTINGS_KEY: &str = "sync_settings";
+const STATUS_KEY: &str = "sync_status";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+/// 백그라운드 동기화가 설정을 확인하는 간격
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSettings {
+    pub enabled: bool,
+    /// 충돌 보고서와 다른 기기 목록에 보일 이 기기의 이름
+    pub device_name: String,
+    /// 모든 기기가 함께 쓰는 폴더
+    pub folder: Option<String>,
+    /// 공유 폴더에 두는 동기화 파일을 암호화하는 비밀번호 (모든 기기가 같아야 한다)
+    pub passphrase: String,
+    pub interval_minutes: u32,
+    /// 같은 네트워크에서 페어링한 기기와 직접 동기화
+    #[serde(default)]
+    pub lan_enabled: bool,
+    /// 다른 기기의 연결을 받을 포트 (0 이면 자동)
+    #[serde(default)]
+    pub lan_port: u16,
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            device_name: String::new(),
+            folder: None,
+            passphrase: String::new(),
+            interval_minutes: 5,
+            lan_enabled: false,
+            lan_port: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictKind {
+    /// 두 기기에서 같은 디자이너·시간에 예약을 받음
+    DoubleBooking,
+    /// 전화번호, 날짜 등 중복될 수 없는 값이 겹쳐 반영하지 못함
+    UniqueViolation,
+    /// 이 기기에서 고친 값을 다른 기기의 나중 변경으로 바꿈
+    FieldOverwritten,
+    /// 그 밖의 이유(삭제된 디자이너를 참조하는 예약 등)로 반영하지 못함
+    ApplyFailed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflict {
+    pub kind: SyncConflictKind,
+    pub table: String,
+    pub row_id: String,
+    /// 중복 예약의 상대 예약
+    pub other_row_id: Option<String>,
+    pub column: Option<String>,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPeer {
+    pub node_id: String,
+    pub device_name: String,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    /// 받아들인 필드·삭제 표시 수
+    pub applied: usize,
+    pub inserted: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub conflicts: Vec<SyncConflict>,
+    pub peers: Vec<SyncPeer>,
+    /// 읽지 못한 다른 기기 파일 등
+    pub errors: Vec<String>,
+}
+
+impl SyncReport {
+    pub fn merge(&mut self, other: SyncReport) {
+        self.applied += other.applied;
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.deleted += other.deleted;
+        self.conflicts.extend(other.conflicts);
+        self.peers.extend(other.peers);
+        self.errors.extend(other.errors);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStatus {
+    pub attempted_at: String,
+    pub success: bool,
+    pub error: Option<String>,
+    pub last_success_at: Option<String>,
+    pub report: Option<SyncReport>,
+}
+
+pub fn get_settings(conn: &Connection) -> Result<SyncSettings, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [SETTINGS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(SyncSettings::default()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn save_settings(conn: &Connection, settings: &SyncSettings) -> Result<(), String> {
+    let folder = settings.folder.as_deref().map(str::trim).filter(|f| !f.is_empty());
+    if (settings.enabled || settings.lan_enabled) && settings.device_name.trim().is_empty() {
+        return Err("이 기기의 이름을 입력해주세요".to_string());
+    }
+    if settings.enabled && folder.is_none() && !settings.lan_enabled {
+        return Err("동기화할 공유 폴더를 선택하거나 같은 네트워크 동기화를 켜주세요".to_string());
+    }
+    if let Some(folder) = folder.filter(|_| settings.enabled) {
+        if !Path::new(folder).is_dir() {
+            return Err(format!("동기화 폴더를 찾을 수 없습니다: {}", folder));
+        }
+        archive::validate_passphrase(&settings.passphrase)?;
+    }
+    if settings.lan_port != 0 && settings.lan_port < 1024 {
+        return Err("동기화 포트는 1024 이상으로 설정해주세요 (0 이면 자동)".to_string());
+    }
+    if settings.interval_minutes == 0 || settings.interval_minutes > 24 * 60 {
+        return Err("동기화 간격은 1분~24시간으로 설정해주세요".to_string());
+    }
+
+    save_value(conn, SETTINGS_KEY, &serde_json::to_string(settings).map_err(|e| e.to_string())?)
+}
+
+pub fn get_status(conn: &Connection) -> Result<Option<SyncStatus>, String> {
+    let result: Result<String, _> = conn.query_row(
+        "SELECT value FROM app_settings WHERE key = ?1",
+        [STATUS_KEY],
+        |row| row.get(0),
+    );
+
+    match result {
+        Ok(json) => serde_json::from_str(&json).map(Some).map_err(|e| e.to_string()),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn record_status(conn: &Connection, result: &Result<SyncReport, String>) -> Result<(), String> {
+    let attempted_at = chrono::Local::now().format(DATETIME_FORMAT).to_string();
+    let previous = get_status(conn)?;
+    let status = SyncStatus {
+        success: result.is_ok(),
+        error: result.as_ref().err().cloned(),
+        last_success_at: match result {
+            Ok(_) => Some(attempted_at.clone()),
+            Err(_) => previous.and_then(|p| p.last_success_at),
+        },
+        report: result.as_ref().ok().cloned(),
+        attempted_at,
+    };
+    save_value(conn, STATUS_KEY, &serde_json::to_string(&status).map_err(|e| e.to_string())?)
+}
+
+fn save_value(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    let now = chrono::Utc::now().format(DATETIME_FORMAT).to_string();
+    conn.execute(
+        "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![key, value, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 모든 기기가 함께 쓰는 설정 값 (`shared_settings` 는 동기화되므로 마지막으로 바꾼 값이 남는다)
+pub fn get_shared_value(conn: &Connection, key: &str) -> Result<Option<String>, String> {
+    conn.query_row("SELECT value FROM shared_settings WHERE id = ?1", [key], |row| row.get(0))
+        .optional()
+        .map_err(|e| e.to_string())
+}
+
+pub fn set_shared_value(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    let now = chrono::Utc::now().format(DATETIME_FORMAT).to_string();
+    conn.execute(
+        "INSERT INTO shared_settings (id, value, updated_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(id) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        rusqlite::params![key, value, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 설정된 공유 폴더와 페어링한 기기로 지금 동기화하고 결과를 기록한다.
+/// 네트워크로 주고받는 동안에는 DB 잠금을 풀어 두므로 잠그지 않은 채로 부른다.
+pub fn run_sync(db: &Mutex<Database>) -> Result<SyncReport, String> {
+    run_sync_with(db, KdfParams::default())
+}
+
+fn run_sync_with(db: &Mutex<Database>, params: KdfParams) -> Result<SyncReport, String> {
+    let settings = get_settings(db.lock().map_err(|e| e.to_string())?.conn())?;
+    let result = sync_all(db, &settings, params);
+    let db = db.lock().map_err(|e| e.to_string())?;
+    if let Err(e) = record_status(db.conn(), &result) {
+        eprintln!("Failed to record sync status: {}", e);
+    }
+    result
+}
+
+fn sync_all(db: &Mutex<Database>, settings: &SyncSettings, params: KdfParams) -> Result<SyncReport, String> {
+    let dir = settings.folder.as_deref().filter(|f| !f.trim().is_empty());
+    if dir.is_none() && !settings.lan_enabled {
+        return Err("동기화할 공유 폴더를 선택하거나 같은 네트워크 동기화를 켜주세요".to_string());
+    }
+
+    let mut report = SyncReport::default();
+    if let Some(dir) = dir {
+        let result = {
+            let db = db.lock().map_err(|e| e.to_string())?;
+            folder::sync_folder(db.conn(), Path::new(dir), &settings.device_name, &settings.passphrase, params)
+        };
+        match result {
+            Ok(result) => report.merge(result),
+            // 네트워크 동기화는 폴더와 상관없이 계속한다
+            Err(e) if settings.lan_enabled => report.errors.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+    if settings.lan_enabled {
+        report.merge(lan::sync_paired(db)?);
+    }
+    Ok(report)
+}
+
+fn is_due(status: Option<&SyncStatus>, interval_minutes: u32, now: chrono::NaiveDateTime) -> bool {
+    let Some(attempted_at) = status.and_then(|s| chrono::NaiveDateTime::parse_from_str(&s.attempted_at, DATETIME_FORMAT).ok())
+    else {
+        return true;
+    };
+    now - attempted_at >= chrono::Duration::minutes(interval_minutes as i64)
+}
+
+fn run_due(db: &Arc<Mutex<Database>>) -> Result<(), String> {
+    {
+        let db = db.lock().map_err(|e| e.to_string())?;
+        let settings = get_settings(db.conn())?;
+        if !settings.enabled || !is_due(get_status(db.conn())?.as_ref(), settings.interval_minutes, chrono::Local::now().naive_local()) {
+            return Ok(());
+        }
+    }
+    let report = run_sync(db)?;
+    if !report.conflicts.is_empty() {
+        println!("[Sync] {} conflicts need attention", report.conflicts.len());
+    }
+    Ok(())
+}
+
+/// 설정한 간격마다 공유 폴더·페어링한 기기와 동기화하는 백그라운드 작업
+pub fn start_worker(db: Arc<Mutex<Database>>) {
+    let spawned = std::thread::Builder::new()
+        .name("sync-worker".to_string())
+        .spawn(move || loop {
+            if let Err(e) = run_due(&db) {
+                eprintln!("Sync failed: {}", e);
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("Failed to start sync worker: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(name: &str, dir: &Path) -> Mutex<Database> {
+        let db = Database::open_in_memory().unwrap();
+        let settings = SyncSettings {
+            enabled: true,
+            device_name: name.to_string(),
+            folder: Some(dir.to_string_lossy().to_string()),
+            passphrase: "shared secret".to_string(),
+            ..SyncSettings::default()
+        };
+        save_settings(db.conn(), &settings).unwrap();
+        Mutex::new(db)
+    }
+
+    fn sync(db: &Mutex<Database>) -> SyncReport {
+        run_sync_with(db, archive::TEST_KDF).unwrap()
+    }
+
+    fn exec(db: &Mutex<Database>, sql: &str) {
+        db.lock().unwrap().conn().execute_batch(sql).unwrap();
+    }
+
+    fn value(db: &Mutex<Database>, sql: &str) -> Option<String> {
+        db.lock().unwrap().conn().query_row(sql, [], |row| row.get(0)).ok()
+    }
+
+    #[test]
+    fn test_field_level_merge_and_deletes_through_shared_folder() {
+        let dir = std::env::temp_dir().join(format!("salon-sync-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let front = device("프런트", &dir);
+        let tablet = device("디자이너 태블릿", &dir);
+
+        exec(&front, "INSERT INTO designers (id, name) VALUES ('d1', '수진')");
+        exec(&front, "INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-2222')");
+        sync(&front);
+        let report = sync(&tablet);
+        assert_eq!(report.peers.len(), 1);
+        assert_eq!(report.peers[0].device_name, "프런트");
+        assert_eq!(value(&tablet, "SELECT name FROM customers WHERE id = 'c1'").as_deref(), Some("김민지"));
+
+        // 오프라인에서 같은 고객의 서로 다른 필드를 고치면 둘 다 남는다
+        exec(&front, "UPDATE customers SET email = 'minji@example.com' WHERE id = 'c1'");
+        exec(&tablet, "UPDATE customers SET allergies = '염색약' WHERE id = 'c1'");
+        exec(&tablet, "DELETE FROM designers WHERE id = 'd1'");
+        sync(&front);
+        sync(&tablet);
+        let report = sync(&front);
+        assert!(report.conflicts.is_empty(), "{:?}", report.conflicts);
+
+        for db in [&front, &tablet] {
+            assert_eq!(value(db, "SELECT email FROM customers WHERE id = 'c1'").as_deref(), Some("minji@example.com"));
+            assert_eq!(value(db, "SELECT allergies FROM customers WHERE id = 'c1'").as_deref(), Some("염색약"));
+            assert_eq!(value(db, "SELECT name FROM designers WHERE id = 'd1'"), None);
+            // 삭제된 행의 이전 값은 남기지 않는다 (버전만 유지)
+            assert_eq!(
+                value(db, "SELECT group_concat(DISTINCT value) FROM sync_fields WHERE table_name = 'designers' AND row_id = 'd1'").as_deref(),
+                Some("null")
+            );
+        }
+
+        // 같은 필드는 나중 변경이 이기고, 덮어쓴 쪽에 보고된다
+        exec(&front, "UPDATE customers SET notes = '창가 자리' WHERE id = 'c1'");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        exec(&tablet, "UPDATE customers SET notes = '조용한 자리' WHERE id = 'c1'");
+        sync(&tablet);
+        let report = sync(&front);
+        assert_eq!(value(&front, "SELECT notes FROM customers WHERE id = 'c1'").as_deref(), Some("조용한 자리"));
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].kind, SyncConflictKind::FieldOverwritten);
+
+        // 파일 비밀번호가 다르면 그 기기는 건너뛰고 오류로 알린다
+        {
+            let db = tablet.lock().unwrap();
+            let mut settings = get_settings(db.conn()).unwrap();
+            settings.passphrase = "different secret".to_string();
+            save_settings(db.conn(), &settings).unwrap();
+        }
+        let report = sync(&tablet);
+        assert_eq!(report.errors.len(), 1);
+        assert!(get_status(tablet.lock().unwrap().conn()).unwrap().unwrap().success);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_erasure_and_reminder_sender_reach_other_devices() {
+        use crate::services::{privacy, reminders};
+
+        let dir = std::env::temp_dir().join(format!("salon-sync-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let front = device("프런트", &dir);
+        let tablet = device("디자이너 태블릿", &dir);
+
+        exec(&front, "INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-2222')");
+        exec(
+            &front,
+            "INSERT INTO reservations (id, customer_name, customer_phone, date, time, status, customer_id)
+             VALUES ('r1', '김민지', '010-1111-2222', '2025-03-06', '14:00', 'confirmed', 'c1')",
+        );
+        reminders::claim_sender(front.lock().unwrap().conn()).unwrap();
+        sync(&front);
+        sync(&tablet);
+        assert!(!reminders::is_sender(tablet.lock().unwrap().conn()).unwrap());
+
+        // 알림·메시지 기록은 동기화하지 않으므로 태블릿에만 있다
+        exec(
+            &tablet,
+            "INSERT INTO reminders (id, reservation_id, recipient, reservation_at, scheduled_at)
+                 VALUES ('m1', 'r1', '01011112222', '2025-03-06 14:00', '2025-03-05 14:00');
+             INSERT INTO messages (id, customer_id, reservation_id, channel, template, recipient, body)
+                 VALUES ('msg1', 'c1', 'r1', 'sms', 'reminder', '01011112222', '김민지님, 내일 14:00 예약');",
+        );
+
+        privacy::erase_customer(front.lock().unwrap().conn(), "c1").unwrap();
+        sync(&front);
+        sync(&tablet);
+        assert_eq!(value(&tablet, "SELECT customer_phone FROM reservations WHERE id = 'r1'"), None);
+        assert_eq!(value(&tablet, "SELECT id FROM reminders"), None);
+        assert_eq!(value(&tablet, "SELECT body FROM messages"), None);
+
+        // 한 번만 처리하고 파기 기록에 남긴다
+        sync(&tablet);
+        let audit = privacy::get_audit_report(tablet.lock().unwrap().conn(), None, None).unwrap();
+        assert_eq!(audit.entries.len(), 1);
+        assert_eq!(audit.entries[0].trigger, privacy::TRIGGER_SYNC);
+
+        // 태블릿이 알림을 맡으면 프런트는 보내지 않는다
+        reminders::claim_sender(tablet.lock().unwrap().conn()).unwrap();
+        sync(&tablet);
+        sync(&front);
+        assert!(!reminders::is_sender(front.lock().unwrap().conn()).unwrap());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_offline_double_booking_is_reported() {
+        let dir = std::env::temp_dir().join(format!("salon-sync-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let front = device("프런트", &dir);
+        let tablet = device("디자이너 태블릿", &dir);
+
+        exec(&front, "INSERT INTO designers (id, name) VALUES ('d1', '수진')");
+        sync(&front);
+        sync(&tablet);
+
+        let book = |db: &Mutex<Database>, id: &str, name: &str| {
+            db.lock()
+                .unwrap()
+                .conn()
+                .execute(
+                    "INSERT INTO reservations (id, customer_name, date, time, designer_id) VALUES (?1, ?2, '2026-03-02', '14:00', 'd1')",
+                    [id, name],
+                )
+                .unwrap();
+        };
+        book(&front, "r-front", "김민지");
+        book(&tablet, "r-tablet", "이서연");
+
+        sync(&front);
+        let report = sync(&tablet);
+        let double: Vec<_> = report.conflicts.iter().filter(|c| c.kind == SyncConflictKind::DoubleBooking).collect();
+        assert_eq!(double.len(), 1);
+        assert_eq!(double[0].row_id, "r-front");
+        assert_eq!(double[0].other_row_id.as_deref(), Some("r-tablet"));
+        assert!(double[0].detail.contains("수진"));
+
+        // 두 예약 모두 지우지 않고 남겨 두어 직접 정리하게 한다
+        let report = sync(&front);
+        assert_eq!(report.conflicts.iter().filter(|c| c.kind == SyncConflictKind::DoubleBooking).count(), 1);
+        let count: i64 = front.lock().unwrap().conn().query_row("SELECT COUNT(*) FROM reservations", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 2);
+
+        // 한쪽을 취소하면 더 이상 충돌이 아니다
+        exec(&front, "UPDATE reservations SET status = 'cancelled' WHERE id = 'r-tablet'");
+        sync(&front);
+        let report = sync(&tablet);
+        assert!(report.conflicts.is_empty(), "{:?}", report.conflicts);
+        assert_eq!(value(&tablet, "SELECT status FROM reservations WHERE id = 'r-tablet'").as_deref(), Some("cancelled"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_unique_violation_is_reported_not_fatal() {
+        let dir = std::env::temp_dir().join(format!("salon-sync-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let front = device("프런트", &dir);
+        let tablet = device("디자이너 태블릿", &dir);
+
+        exec(&front, "INSERT INTO customers (id, name, phone) VALUES ('c1', '김민지', '010-1111-2222')");
+        exec(&front, "INSERT INTO holidays (id, date, description) VALUES ('h1', '2026-05-05', '어린이날')");
+        exec(&tablet, "INSERT INTO customers (id, name, phone) VALUES ('c2', '김민지', '010-1111-2222')");
+
+        sync(&front);
+        let report = sync(&tablet);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].kind, SyncConflictKind::UniqueViolation);
+        assert_eq!(report.conflicts[0].row_id, "c1");
+        // 나머지 변경은 반영된다
+        assert_eq!(value(&tablet, "SELECT description FROM holidays WHERE id = 'h1'").as_deref(), Some("어린이날"));
+        // 영업시간 기본값은 두 기기가 같으므로 충돌이 아니다
+        assert_eq!(value(&tablet, "SELECT close_time FROM business_hours WHERE id = 6").as_deref(), Some("18:00"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,341 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 디자이너의 예약 불가 시간 (교육, 개인 용무, 시술 대기 등)
+/// 고객 예약이 아니므로 통계와 내보내기에는 포함되지 않는다.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBlock {
+    pub id: String,
+    pub designer_id: String,
+    pub date: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub reason: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTimeBlockInput {
+    pub designer_id: String,
+    pub date: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub reason: Option<String>,
+}
+
+const SELECT_TIME_BLOCK: &str =
+    "SELECT id, designer_id, date, start_time, end_time, reason, created_at, updated_at FROM time_blocks";
+
+fn row_to_time_block(row: &rusqlite::Row) -> rusqlite::Result<TimeBlock> {
+    Ok(TimeBlock {
+        id: row.get(0)?,
+        designer_id: row.get(1)?,
+        date: row.get(2)?,
+        start_time: row.get(3)?,
+        end_time: row.get(4)?,
+        reason: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+/// 예약 시간이 해당 디자이너의 차단 시간에 걸리는지 확인
+/// 차단 구간은 [start_time, end_time) 으로 취급한다.
+pub fn find_conflicting_block(
+    conn: &Connection,
+    designer_id: &str,
+    date: &str,
+    time: &str,
+) -> Result<Option<TimeBlock>, String> {
+    let sql = format!(
+        "{} WHERE designer_id = ?1 AND date = ?2 AND start_time <= ?3 AND end_time > ?3 ORDER BY start_time LIMIT 1",
+        SELECT_TIME_BLOCK
+    );
+    match conn.query_row(&sql, rusqlite::params![designer_id, date, time], row_to_time_block) {
+        Ok(block) => Ok(Some(block)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 예약과 겹치는 차단 시간이면 사용자에게 보여줄 오류 메시지를 만든다
+pub fn ensure_no_block_conflict(
+    conn: &Connection,
+    designer_id: Option<&str>,
+    date: &str,
+    time: &str,
+) -> Result<(), String> {
+    let Some(designer_id) = designer_id.filter(|d| !d.is_empty()) else {
+        return Ok(());
+    };
+
+    if let Some(block) = find_conflicting_block(conn, designer_id, date, time)? {
+        return Err(format!(
+            "해당 시간은 예약이 차단되어 있습니다 ({}~{}{})",
+            block.start_time,
+            block.end_time,
+            block
+                .reason
+                .as_deref()
+                .filter(|r| !r.is_empty())
+                .map(|r| format!(", {}", r))
+                .unwrap_or_default()
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_input(conn: &Connection, data: &CreateTimeBlockInput, exclude_id: Option<&str>) -> Result<(), String> {
+    if chrono::NaiveDate::parse_from_str(&data.date, "%Y-%m-%d").is_err() {
+        return Err("날짜 형식이 올바르지 않습니다 (YYYY-MM-DD)".to_string());
+    }
+    for t in [&data.start_time, &data.end_time] {
+        if chrono::NaiveTime::parse_from_str(t, "%H:%M").is_err() {
+            return Err("시간 형식이 올바르지 않습니다 (HH:MM)".to_string());
+        }
+    }
+    if data.start_time >= data.end_time {
+        return Err("종료 시간은 시작 시간보다 늦어야 합니다".to_string());
+    }
+
+    // 같은 디자이너의 다른 차단 시간과 겹치는지 확인
+    let overlapping_block: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM time_blocks
+                           WHERE designer_id = ?1 AND date = ?2 AND start_time < ?4 AND end_time > ?3
+                             AND id != COALESCE(?5, ''))",
+            rusqlite::params![data.designer_id, data.date, data.start_time, data.end_time, exclude_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+    if overlapping_block {
+        return Err("이미 차단된 시간과 겹칩니다".to_string());
+    }
+
+    // 이미 잡혀 있는 고객 예약과 겹치는지 확인
+    let overlapping_reservation: Option<String> = conn
+        .query_row(
+            "SELECT time FROM reservations
+             WHERE designer_id = ?1 AND date = ?2 AND time >= ?3 AND time < ?4
+               AND status NOT IN ('cancelled', 'no_show')
+             ORDER BY time LIMIT 1",
+            rusqlite::params![data.designer_id, data.date, data.start_time, data.end_time],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    if let Some(time) = overlapping_reservation {
+        return Err(format!("{} 예약과 겹쳐 시간을 차단할 수 없습니다", time));
+    }
+
+    Ok(())
+}
+
+pub fn get_time_blocks(
+    conn: &Connection,
+    date: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+    designer_id: Option<String>,
+) -> Result<Vec<TimeBlock>, String> {
+    let (from, to) = match date {
+        Some(d) => (d.clone(), d),
+        None => (
+            date_from.unwrap_or_else(|| "0000-01-01".to_string()),
+            date_to.unwrap_or_else(|| "9999-12-31".to_string()),
+        ),
+    };
+
+    let sql = format!(
+        "{} WHERE date >= ?1 AND date <= ?2 AND (?3 IS NULL OR designer_id = ?3) ORDER BY date, start_time",
+        SELECT_TIME_BLOCK
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![from, to, designer_id], row_to_time_block)
+        .map_err(|e| e.to_string())?;
+
+    let blocks: Vec<TimeBlock> = rows.filter_map(|r| r.ok()).collect();
+    Ok(blocks)
+}
+
+pub fn create_time_block(conn: &Connection, data: CreateTimeBlockInput) -> Result<TimeBlock, String> {
+    validate_input(conn, &data, None)?;
+
+    let id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO time_blocks (id, designer_id, date, start_time, end_time, reason, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+        rusqlite::params![
+            id,
+            data.designer_id,
+            data.date,
+            data.start_time,
+            data.end_time,
+            data.reason,
+            now,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(TimeBlock {
+        id,
+        designer_id: data.designer_id,
+        date: data.date,
+        start_time: data.start_time,
+        end_time: data.end_time,
+        reason: data.reason,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+}
+
+pub fn update_time_block(conn: &Connection, id: &str, data: CreateTimeBlockInput) -> Result<TimeBlock, String> {
+    validate_input(conn, &data, Some(id))?;
+
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "UPDATE time_blocks SET designer_id = ?2, date = ?3, start_time = ?4, end_time = ?5, reason = ?6, updated_at = ?7 WHERE id = ?1",
+        rusqlite::params![
+            id,
+            data.designer_id,
+            data.date,
+            data.start_time,
+            data.end_time,
+            data.reason,
+            now,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    conn.query_row(
+        &format!("{} WHERE id = ?1", SELECT_TIME_BLOCK),
+        [id],
+        row_to_time_block,
+    )
+    .map_err(|e| e.to_string())
+}
+
+pub fn delete_time_block(conn: &Connection, id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM time_blocks WHERE id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use crate::services::{reservations, statistics};
+
+    // 2025-03-12 는 수요일 (기본 영업시간 10:00~20:00)
+    const DATE: &str = "2025-03-12";
+
+    fn setup() -> Database {
+        let db = Database::open_in_memory().unwrap();
+        db.conn()
+            .execute("INSERT INTO designers (id, name) VALUES ('d1', '김지수'), ('d2', '이하나')", [])
+            .unwrap();
+        db
+    }
+
+    fn block(designer_id: &str, start_time: &str, end_time: &str) -> CreateTimeBlockInput {
+        CreateTimeBlockInput {
+            designer_id: designer_id.to_string(),
+            date: DATE.to_string(),
+            start_time: start_time.to_string(),
+            end_time: end_time.to_string(),
+            reason: Some("교육".to_string()),
+        }
+    }
+
+    fn reservation(designer_id: &str, time: &str) -> reservations::CreateReservationInput {
+        reservations::CreateReservationInput {
+            customer_name: Some("김민지".to_string()),
+            customer_phone: Some("010-1111-2222".to_string()),
+            date: DATE.to_string(),
+            time: time.to_string(),
+            designer_id: Some(designer_id.to_string()),
+            service_type: Some("커트".to_string()),
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_input_rejects_overlaps() {
+        let db = setup();
+        let conn = db.conn();
+        let existing = create_time_block(conn, block("d1", "13:00", "15:00")).unwrap();
+
+        assert!(create_time_block(conn, block("d1", "14:00", "16:00")).unwrap_err().contains("겹칩니다"));
+        assert!(create_time_block(conn, block("d1", "12:00", "13:30")).is_err());
+        // 끝난 시각에 바로 이어지는 차단과 다른 디자이너의 같은 시간은 허용
+        create_time_block(conn, block("d1", "15:00", "16:00")).unwrap();
+        create_time_block(conn, block("d2", "13:00", "15:00")).unwrap();
+        // 자기 자신과는 겹쳐도 수정할 수 있다
+        update_time_block(conn, &existing.id, block("d1", "13:30", "15:00")).unwrap();
+
+        assert!(create_time_block(conn, block("d1", "18:00", "17:00")).is_err());
+        assert!(create_time_block(conn, block("d1", "9시", "10:00")).is_err());
+
+        // 이미 잡힌 예약이 있는 시간은 막을 수 없고, 취소된 예약은 무시한다
+        reservations::create_reservation(conn, reservation("d1", "17:00")).unwrap();
+        let error = create_time_block(conn, block("d1", "16:30", "18:00")).unwrap_err();
+        assert_eq!(error, "17:00 예약과 겹쳐 시간을 차단할 수 없습니다");
+        conn.execute("UPDATE reservations SET status = 'cancelled' WHERE time = '17:00'", []).unwrap();
+        create_time_block(conn, block("d1", "16:30", "18:00")).unwrap();
+    }
+
+    #[test]
+    fn test_block_is_half_open() {
+        let db = setup();
+        let conn = db.conn();
+        create_time_block(conn, block("d1", "13:00", "14:00")).unwrap();
+
+        assert!(find_conflicting_block(conn, "d1", DATE, "12:30").unwrap().is_none());
+        assert!(find_conflicting_block(conn, "d1", DATE, "13:00").unwrap().is_some());
+        assert!(find_conflicting_block(conn, "d1", DATE, "13:30").unwrap().is_some());
+        assert!(find_conflicting_block(conn, "d1", DATE, "14:00").unwrap().is_none());
+        assert!(find_conflicting_block(conn, "d2", DATE, "13:30").unwrap().is_none());
+        assert!(find_conflicting_block(conn, "d1", "2025-03-13", "13:30").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reservation_inside_block_is_rejected() {
+        let db = setup();
+        let conn = db.conn();
+        create_time_block(conn, block("d1", "13:00", "14:00")).unwrap();
+
+        let error = reservations::create_reservation(conn, reservation("d1", "13:30")).unwrap_err();
+        assert_eq!(error, "해당 시간은 예약이 차단되어 있습니다 (13:00~14:00, 교육)");
+        reservations::create_reservation(conn, reservation("d1", "14:00")).unwrap();
+        reservations::create_reservation(conn, reservation("d2", "13:30")).unwrap();
+    }
+
+    #[test]
+    fn test_blocks_are_not_counted_in_statistics() {
+        let db = setup();
+        let conn = db.conn();
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        conn.execute(
+            "INSERT INTO reservations (id, customer_name, date, time, designer_id, status)
+             VALUES ('r1', '김민지', ?1, '11:00', 'd1', 'completed')",
+            [&today],
+        )
+        .unwrap();
+        create_time_block(conn, CreateTimeBlockInput { date: today, ..block("d1", "13:00", "15:00") }).unwrap();
+
+        let summary = statistics::get_statistics_summary(conn, "7d".to_string()).unwrap();
+        assert_eq!(summary.total_reservations, 1);
+        assert_eq!(summary.completed, 1);
+    }
+}
@@ -0,0 +1,758 @@
+//! 외부로 보내는 웹훅
+//!
+//! 예약/고객 변경 시 `emit` 이 구독 중인 주소마다 `webhook_outbox` 에 한 줄씩 기록하고,
+//! 백그라운드 작업(`start_worker`)이 이를 전송한다. 실패하면 지수 백오프로 재시도하며
+//! 모든 시도는 `webhook_deliveries` 에 남는다.
+//!
+//! 요청에는 다음 헤더가 붙는다.
+//! - `X-Salon-Event`: 이벤트 이름
+//! - `X-Salon-Delivery`: 대기열 항목 ID (재시도해도 같음)
+//! - `X-Salon-Timestamp`: 전송 시각 (유닉스 초)
+//! - `X-Salon-Signature`: `sha256=` + HMAC-SHA256(secret, "{timestamp}.{body}") 의 hex
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::db::Database;
+
+pub const EVENT_RESERVATION_CREATED: &str = "reservation.created";
+pub const EVENT_RESERVATION_UPDATED: &str = "reservation.updated";
+pub const EVENT_RESERVATION_STATUS_CHANGED: &str = "reservation.status_changed";
+pub const EVENT_CUSTOMER_CREATED: &str = "customer.created";
+/// 설정 화면의 "테스트 전송" 용
+pub const EVENT_PING: &str = "ping";
+
+pub const EVENT_TYPES: &[&str] = &[
+    EVENT_RESERVATION_CREATED,
+    EVENT_RESERVATION_UPDATED,
+    EVENT_RESERVATION_STATUS_CHANGED,
+    EVENT_CUSTOMER_CREATED,
+];
+
+/// 이 횟수만큼 실패하면 더 이상 재시도하지 않는다
+pub const MAX_ATTEMPTS: i32 = 8;
+const BASE_BACKOFF_SECS: i64 = 30;
+const MAX_BACKOFF_SECS: i64 = 6 * 60 * 60;
+const BATCH_SIZE: usize = 20;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// 전송 완료된 대기열과 기록을 보관하는 기간
+const RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl WebhookEndpoint {
+    fn subscribes_to(&self, event_type: &str) -> bool {
+        event_type == EVENT_PING || self.events.iter().any(|e| e == "*" || e == event_type)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookEndpointInput {
+    pub name: String,
+    pub url: String,
+    /// 비어 있으면 모든 이벤트
+    #[serde(default)]
+    pub events: Vec<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhookEndpointInput {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub outbox_id: String,
+    pub endpoint_id: String,
+    pub endpoint_name: Option<String>,
+    pub event_type: String,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration_ms: Option<i64>,
+    pub attempted_at: String,
+    /// 해당 대기열 항목의 현재 상태 (pending / delivered / failed)
+    pub outbox_status: Option<String>,
+}
+
+fn now_string(now: DateTime<Utc>) -> String {
+    now.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn parse_events(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn normalize_events(events: &[String]) -> Result<String, String> {
+    if events.is_empty() || events.iter().any(|e| e == "*") {
+        return Ok("*".to_string());
+    }
+    for event in events {
+        if !EVENT_TYPES.contains(&event.as_str()) {
+            return Err(format!("알 수 없는 이벤트입니다: {}", event));
+        }
+    }
+    let mut events: Vec<&str> = events.iter().map(|e| e.as_str()).collect();
+    events.sort();
+    events.dedup();
+    Ok(events.join(","))
+}
+
+fn validate_url(url: &str) -> Result<(), String> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(())
+    } else {
+        Err("웹훅 주소는 http:// 또는 https:// 로 시작해야 합니다".to_string())
+    }
+}
+
+fn row_to_endpoint(row: &rusqlite::Row) -> rusqlite::Result<WebhookEndpoint> {
+    let events: String = row.get(4)?;
+    let is_active: i32 = row.get(5)?;
+    Ok(WebhookEndpoint {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        url: row.get(2)?,
+        secret: row.get(3)?,
+        events: parse_events(&events),
+        is_active: is_active == 1,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+const SELECT_ENDPOINT: &str =
+    "SELECT id, name, url, secret, events, is_active, created_at, updated_at FROM webhook_endpoints";
+
+pub fn get_endpoints(conn: &Connection) -> Result<Vec<WebhookEndpoint>, String> {
+    let mut stmt = conn
+        .prepare(&format!("{} ORDER BY created_at", SELECT_ENDPOINT))
+        .map_err(|e| e.to_string())?;
+    let rows = stmt.query_map([], row_to_endpoint).map_err(|e| e.to_string())?;
+
+    let endpoints: Vec<WebhookEndpoint> = rows.filter_map(|r| r.ok()).collect();
+    Ok(endpoints)
+}
+
+pub fn get_endpoint(conn: &Connection, id: &str) -> Result<WebhookEndpoint, String> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT_ENDPOINT), [id], row_to_endpoint)
+        .map_err(|e| e.to_string())
+}
+
+pub fn create_endpoint(conn: &Connection, data: CreateWebhookEndpointInput) -> Result<WebhookEndpoint, String> {
+    let name = data.name.trim();
+    if name.is_empty() {
+        return Err("이름을 입력해주세요".to_string());
+    }
+    let url = data.url.trim();
+    validate_url(url)?;
+    let events = normalize_events(&data.events)?;
+
+    let id = Uuid::new_v4().to_string();
+    let secret = format!("whsec_{}", Uuid::new_v4().simple());
+    let now = now_string(Utc::now());
+
+    conn.execute(
+        "INSERT INTO webhook_endpoints (id, name, url, secret, events, is_active, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+        rusqlite::params![id, name, url, secret, events, data.is_active.unwrap_or(true) as i32, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    get_endpoint(conn, &id)
+}
+
+pub fn update_endpoint(
+    conn: &Connection,
+    id: &str,
+    data: UpdateWebhookEndpointInput,
+) -> Result<WebhookEndpoint, String> {
+    let current = get_endpoint(conn, id)?;
+
+    let name = data.name.map(|n| n.trim().to_string()).unwrap_or(current.name);
+    if name.is_empty() {
+        return Err("이름을 입력해주세요".to_string());
+    }
+    let url = data.url.map(|u| u.trim().to_string()).unwrap_or(current.url);
+    validate_url(&url)?;
+    let events = normalize_events(&data.events.unwrap_or(current.events))?;
+    let is_active = data.is_active.unwrap_or(current.is_active);
+    let now = now_string(Utc::now());
+
+    conn.execute(
+        "UPDATE webhook_endpoints SET name = ?2, url = ?3, events = ?4, is_active = ?5, updated_at = ?6 WHERE id = ?1",
+        rusqlite::params![id, name, url, events, is_active as i32, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    if is_active {
+        notify_worker();
+    }
+    get_endpoint(conn, id)
+}
+
+pub fn delete_endpoint(conn: &Connection, id: &str) -> Result<(), String> {
+    conn.execute("DELETE FROM webhook_deliveries WHERE endpoint_id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM webhook_outbox WHERE endpoint_id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+    conn.execute("DELETE FROM webhook_endpoints WHERE id = ?1", [id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 이벤트를 구독 중인 활성 주소마다 대기열에 기록하고 전송 작업을 깨운다
+pub fn emit<T: Serialize>(conn: &Connection, event_type: &str, data: &T) -> Result<(), String> {
+    let endpoints: Vec<WebhookEndpoint> = get_endpoints(conn)?
+        .into_iter()
+        .filter(|e| e.is_active && e.subscribes_to(event_type))
+        .collect();
+    if endpoints.is_empty() {
+        return Ok(());
+    }
+
+    enqueue(conn, &endpoints, event_type, data)?;
+    notify_worker();
+    Ok(())
+}
+
+/// 특정 주소로 ping 이벤트를 보낸다
+pub fn send_test(conn: &Connection, endpoint_id: &str) -> Result<(), String> {
+    let endpoint = get_endpoint(conn, endpoint_id)?;
+    let data = serde_json::json!({ "message": "웹훅 연결 테스트", "endpointId": endpoint.id });
+    enqueue(conn, &[endpoint], EVENT_PING, &data)?;
+    notify_worker();
+    Ok(())
+}
+
+fn enqueue<T: Serialize>(
+    conn: &Connection,
+    endpoints: &[WebhookEndpoint],
+    event_type: &str,
+    data: &T,
+) -> Result<(), String> {
+    let now = Utc::now();
+    let payload = serde_json::json!({
+        "id": Uuid::new_v4().to_string(),
+        "type": event_type,
+        "createdAt": now.to_rfc3339(),
+        "data": data,
+    })
+    .to_string();
+
+    for endpoint in endpoints {
+        conn.execute(
+            "INSERT INTO webhook_outbox (id, endpoint_id, event_type, payload, status, attempts, next_attempt_at, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'pending', 0, ?5, ?5)",
+            rusqlite::params![Uuid::new_v4().to_string(), endpoint.id, event_type, payload, now_string(now)],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 고객·예약(`data.id` 또는 `data.reservation.id`)이나 전화번호가 담긴 이벤트를 대기열과 전송 기록에서
+/// 지운다. 개인정보를 파기하면 아직 보내지 않은 이벤트도 보내지 않는다. 지운 이벤트 수를 돌려준다.
+pub fn purge_events_about(conn: &Connection, subject_ids: &[String], phone: Option<&str>) -> Result<usize, String> {
+    let matches = "json_extract(payload, '$.data.id') = ?1
+                   OR json_extract(payload, '$.data.reservation.id') = ?1
+                   OR (?2 IS NOT NULL AND instr(payload, ?2) > 0)";
+    let mut purged = 0;
+    for id in subject_ids {
+        conn.execute(
+            &format!("DELETE FROM webhook_deliveries WHERE outbox_id IN (SELECT id FROM webhook_outbox WHERE {})", matches),
+            rusqlite::params![id, phone],
+        )
+        .map_err(|e| e.to_string())?;
+        purged += conn
+            .execute(&format!("DELETE FROM webhook_outbox WHERE {}", matches), rusqlite::params![id, phone])
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(purged)
+}
+
+/// 실패로 끝난 항목을 다시 대기열에 올린다
+pub fn retry(conn: &Connection, outbox_id: &str) -> Result<(), String> {
+    let updated = conn
+        .execute(
+            "UPDATE webhook_outbox SET status = 'pending', attempts = 0, next_attempt_at = ?2
+             WHERE id = ?1 AND status = 'failed'",
+            rusqlite::params![outbox_id, now_string(Utc::now())],
+        )
+        .map_err(|e| e.to_string())?;
+    if updated == 0 {
+        return Err("재시도할 수 있는 항목이 아닙니다".to_string());
+    }
+    notify_worker();
+    Ok(())
+}
+
+pub fn get_deliveries(
+    conn: &Connection,
+    endpoint_id: Option<&str>,
+    limit: u32,
+) -> Result<Vec<WebhookDelivery>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT d.id, d.outbox_id, d.endpoint_id, e.name, d.event_type, d.attempt, d.status_code,
+                    d.success, d.error, d.duration_ms, d.attempted_at, o.status
+             FROM webhook_deliveries d
+             LEFT JOIN webhook_endpoints e ON e.id = d.endpoint_id
+             LEFT JOIN webhook_outbox o ON o.id = d.outbox_id
+             WHERE ?1 IS NULL OR d.endpoint_id = ?1
+             ORDER BY d.attempted_at DESC, d.attempt DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![endpoint_id, limit], |row| {
+            let success: i32 = row.get(7)?;
+            Ok(WebhookDelivery {
+                id: row.get(0)?,
+                outbox_id: row.get(1)?,
+                endpoint_id: row.get(2)?,
+                endpoint_name: row.get(3)?,
+                event_type: row.get(4)?,
+                attempt: row.get(5)?,
+                status_code: row.get(6)?,
+                success: success == 1,
+                error: row.get(8)?,
+                duration_ms: row.get(9)?,
+                attempted_at: row.get(10)?,
+                outbox_status: row.get(11)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let deliveries: Vec<WebhookDelivery> = rows.filter_map(|r| r.ok()).collect();
+    Ok(deliveries)
+}
+
+/// HMAC-SHA256(secret, "{timestamp}.{body}") 의 hex
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// n 번째 실패 후 다음 시도까지의 대기 시간 (30초, 1분, 2분, ... 최대 6시간)
+fn backoff(attempt: i32) -> chrono::Duration {
+    let exponent = (attempt - 1).clamp(0, 20) as u32;
+    let secs = BASE_BACKOFF_SECS.saturating_mul(1 << exponent).min(MAX_BACKOFF_SECS);
+    chrono::Duration::seconds(secs)
+}
+
+struct DueItem {
+    id: String,
+    endpoint_id: String,
+    url: String,
+    secret: String,
+    event_type: String,
+    payload: String,
+    attempts: i32,
+}
+
+struct Attempt {
+    status_code: Option<u16>,
+    result: Result<(), String>,
+    duration_ms: i64,
+}
+
+fn post(item: &DueItem) -> Attempt {
+    let started = Instant::now();
+    let timestamp = Utc::now().timestamp();
+    let agent = ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build();
+
+    let response = agent
+        .post(&item.url)
+        .set("Content-Type", "application/json")
+        .set("User-Agent", concat!("SistersSalon-Webhook/", env!("CARGO_PKG_VERSION")))
+        .set("X-Salon-Event", &item.event_type)
+        .set("X-Salon-Delivery", &item.id)
+        .set("X-Salon-Timestamp", &timestamp.to_string())
+        .set("X-Salon-Signature", &format!("sha256={}", sign(&item.secret, timestamp, &item.payload)))
+        .send_string(&item.payload);
+
+    let (status_code, result) = match response {
+        Ok(resp) if (200..300).contains(&resp.status()) => (Some(resp.status()), Ok(())),
+        Ok(resp) => (Some(resp.status()), Err(format!("HTTP {}", resp.status()))),
+        Err(ureq::Error::Status(code, _)) => (Some(code), Err(format!("HTTP {}", code))),
+        Err(e) => (None, Err(e.to_string())),
+    };
+
+    Attempt {
+        status_code,
+        result,
+        duration_ms: started.elapsed().as_millis() as i64,
+    }
+}
+
+fn due_items(conn: &Connection, now: DateTime<Utc>) -> Result<Vec<DueItem>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT o.id, o.endpoint_id, e.url, e.secret, o.event_type, o.payload, o.attempts
+             FROM webhook_outbox o
+             JOIN webhook_endpoints e ON e.id = o.endpoint_id
+             WHERE o.status = 'pending' AND o.next_attempt_at <= ?1 AND e.is_active = 1
+             ORDER BY o.next_attempt_at, o.created_at
+             LIMIT ?2",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params![now_string(now), BATCH_SIZE as i64], |row| {
+            Ok(DueItem {
+                id: row.get(0)?,
+                endpoint_id: row.get(1)?,
+                url: row.get(2)?,
+                secret: row.get(3)?,
+                event_type: row.get(4)?,
+                payload: row.get(5)?,
+                attempts: row.get(6)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    let items: Vec<DueItem> = rows.filter_map(|r| r.ok()).collect();
+    Ok(items)
+}
+
+fn record_attempt(conn: &Connection, item: &DueItem, attempt: &Attempt, now: DateTime<Utc>) -> Result<(), String> {
+    let number = item.attempts + 1;
+    let now_str = now_string(now);
+
+    conn.execute(
+        "INSERT INTO webhook_deliveries (id, outbox_id, endpoint_id, event_type, attempt, status_code, success, error, duration_ms, attempted_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        rusqlite::params![
+            Uuid::new_v4().to_string(),
+            item.id,
+            item.endpoint_id,
+            item.event_type,
+            number,
+            attempt.status_code,
+            attempt.result.is_ok() as i32,
+            attempt.result.as_ref().err(),
+            attempt.duration_ms,
+            now_str,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    match &attempt.result {
+        Ok(()) => conn.execute(
+            "UPDATE webhook_outbox SET status = 'delivered', attempts = ?2, last_error = NULL, delivered_at = ?3 WHERE id = ?1",
+            rusqlite::params![item.id, number, now_str],
+        ),
+        Err(e) if number >= MAX_ATTEMPTS => conn.execute(
+            "UPDATE webhook_outbox SET status = 'failed', attempts = ?2, last_error = ?3 WHERE id = ?1",
+            rusqlite::params![item.id, number, e],
+        ),
+        Err(e) => conn.execute(
+            "UPDATE webhook_outbox SET attempts = ?2, last_error = ?3, next_attempt_at = ?4 WHERE id = ?1",
+            rusqlite::params![item.id, number, e, now_string(now + backoff(number))],
+        ),
+    }
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// 시각 `now` 기준으로 전송할 차례인 항목을 한 묶음 보낸다. 보낸 개수를 돌려준다.
+/// HTTP 요청 중에는 DB 잠금을 잡지 않는다.
+pub fn deliver_due(db: &Mutex<Database>, now: DateTime<Utc>) -> Result<usize, String> {
+    let items = {
+        let db = db.lock().map_err(|e| e.to_string())?;
+        due_items(db.conn(), now)?
+    };
+
+    for item in &items {
+        let attempt = post(item);
+        let db = db.lock().map_err(|e| e.to_string())?;
+        record_attempt(db.conn(), item, &attempt, Utc::now().max(now))?;
+    }
+
+    Ok(items.len())
+}
+
+/// 오래된 전송 완료 항목과 기록 정리
+pub fn prune(conn: &Connection, now: DateTime<Utc>) -> Result<(), String> {
+    let cutoff = now_string(now - chrono::Duration::days(RETENTION_DAYS));
+    conn.execute("DELETE FROM webhook_deliveries WHERE attempted_at < ?1", [&cutoff])
+        .map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM webhook_outbox WHERE status = 'delivered' AND delivered_at < ?1",
+        [&cutoff],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// 새 항목이 생기면 전송 작업을 바로 깨운다
+static WAKE_PENDING: Mutex<bool> = Mutex::new(false);
+static WAKE_SIGNAL: Condvar = Condvar::new();
+
+fn notify_worker() {
+    if let Ok(mut pending) = WAKE_PENDING.lock() {
+        *pending = true;
+        WAKE_SIGNAL.notify_all();
+    }
+}
+
+fn wait_for_work(timeout: Duration) {
+    let Ok(guard) = WAKE_PENDING.lock() else {
+        std::thread::sleep(timeout);
+        return;
+    };
+    if let Ok((mut pending, _)) = WAKE_SIGNAL.wait_timeout_while(guard, timeout, |pending| !*pending) {
+        *pending = false;
+    }
+}
+
+/// 앱 실행 동안 대기열을 처리하는 백그라운드 스레드
+pub fn start_worker(db: Arc<Mutex<Database>>) {
+    let spawned = std::thread::Builder::new()
+        .name("webhook-worker".to_string())
+        .spawn(move || {
+            if let Ok(guard) = db.lock() {
+                if let Err(e) = prune(guard.conn(), Utc::now()) {
+                    eprintln!("Webhook prune failed: {}", e);
+                }
+            }
+
+            loop {
+                match deliver_due(&db, Utc::now()) {
+                    // 한 묶음이 가득 찼으면 남은 항목이 있을 수 있으므로 바로 이어서 처리
+                    Ok(sent) if sent == BATCH_SIZE => continue,
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Webhook delivery failed: {}", e),
+                }
+                wait_for_work(POLL_INTERVAL);
+            }
+        });
+
+    if let Err(e) = spawned {
+        eprintln!("Failed to start webhook worker: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    struct Received {
+        head: String,
+        body: String,
+    }
+
+    impl Received {
+        fn header(&self, name: &str) -> Option<String> {
+            self.head.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim().to_string())
+            })
+        }
+    }
+
+    /// 요청마다 `statuses` 의 상태 코드를 차례로 응답하는 로컬 수신 서버
+    fn receiver(statuses: Vec<u16>) -> (String, mpsc::Receiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let (head, body) = loop {
+                    let n = stream.read(&mut chunk).unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&buf).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse().unwrap()))
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break (head.to_string(), body.to_string());
+                        }
+                    }
+                };
+                stream
+                    .write_all(format!("HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status).as_bytes())
+                    .unwrap();
+                tx.send(Received { head, body }).unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    fn outbox_state(conn: &Connection) -> (String, i32) {
+        conn.query_row("SELECT status, attempts FROM webhook_outbox", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_signed_delivery() {
+        let db = Mutex::new(Database::open_in_memory().unwrap());
+        let (url, rx) = receiver(vec![200]);
+
+        let endpoint = {
+            let guard = db.lock().unwrap();
+            let conn = guard.conn();
+            let endpoint = create_endpoint(
+                conn,
+                CreateWebhookEndpointInput {
+                    name: "단체방".to_string(),
+                    url,
+                    events: vec![EVENT_RESERVATION_CREATED.to_string()],
+                    is_active: None,
+                },
+            )
+            .unwrap();
+            // 구독하지 않은 이벤트는 기록하지 않음
+            emit(conn, EVENT_CUSTOMER_CREATED, &serde_json::json!({ "id": "c1" })).unwrap();
+            emit(conn, EVENT_RESERVATION_CREATED, &serde_json::json!({ "id": "r1" })).unwrap();
+            endpoint
+        };
+
+        assert_eq!(deliver_due(&db, Utc::now()).unwrap(), 1);
+
+        let received = rx.recv().unwrap();
+        assert_eq!(received.header("x-salon-event").as_deref(), Some(EVENT_RESERVATION_CREATED));
+        let timestamp: i64 = received.header("x-salon-timestamp").unwrap().parse().unwrap();
+        let expected = format!("sha256={}", sign(&endpoint.secret, timestamp, &received.body));
+        assert_eq!(received.header("x-salon-signature"), Some(expected));
+        let payload: serde_json::Value = serde_json::from_str(&received.body).unwrap();
+        assert_eq!(payload["type"], EVENT_RESERVATION_CREATED);
+        assert_eq!(payload["data"]["id"], "r1");
+
+        let guard = db.lock().unwrap();
+        assert_eq!(outbox_state(guard.conn()), ("delivered".to_string(), 1));
+        let log = get_deliveries(guard.conn(), None, 10).unwrap();
+        assert_eq!(log.len(), 1);
+        assert!(log[0].success);
+        assert_eq!(log[0].status_code, Some(200));
+    }
+
+    #[test]
+    fn test_retry_with_backoff() {
+        let db = Mutex::new(Database::open_in_memory().unwrap());
+        let (url, rx) = receiver(vec![500, 200]);
+
+        {
+            let guard = db.lock().unwrap();
+            let conn = guard.conn();
+            create_endpoint(
+                conn,
+                CreateWebhookEndpointInput { name: "test".to_string(), url, events: vec![], is_active: None },
+            )
+            .unwrap();
+            emit(conn, EVENT_CUSTOMER_CREATED, &serde_json::json!({ "id": "c1" })).unwrap();
+        }
+
+        let now = Utc::now();
+        assert_eq!(deliver_due(&db, now).unwrap(), 1);
+        let first = rx.recv().unwrap();
+        {
+            let guard = db.lock().unwrap();
+            assert_eq!(outbox_state(guard.conn()), ("pending".to_string(), 1));
+        }
+
+        // 백오프 시간 전에는 다시 보내지 않음
+        assert_eq!(deliver_due(&db, now).unwrap(), 0);
+
+        let later = now + backoff(1) + chrono::Duration::seconds(1);
+        assert_eq!(deliver_due(&db, later).unwrap(), 1);
+        let second = rx.recv().unwrap();
+        assert_eq!(first.header("x-salon-delivery"), second.header("x-salon-delivery"));
+
+        let guard = db.lock().unwrap();
+        assert_eq!(outbox_state(guard.conn()), ("delivered".to_string(), 2));
+        let log = get_deliveries(guard.conn(), None, 10).unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.iter().filter(|d| d.success).count(), 1);
+        assert_eq!(log.iter().find(|d| !d.success).unwrap().status_code, Some(500));
+    }
+
+    #[test]
+    fn test_failed_emit_rolls_back_the_change() {
+        let db = Database::open_in_memory().unwrap();
+        let conn = db.conn();
+        create_endpoint(
+            conn,
+            CreateWebhookEndpointInput {
+                name: "단체방".to_string(),
+                url: "http://127.0.0.1:9/hook".to_string(),
+                events: vec![EVENT_CUSTOMER_CREATED.to_string()],
+                is_active: None,
+            },
+        )
+        .unwrap();
+        conn.execute_batch(
+            "CREATE TRIGGER fail_outbox BEFORE INSERT ON webhook_outbox BEGIN SELECT RAISE(ABORT, 'outbox full'); END;",
+        )
+        .unwrap();
+
+        // 이벤트를 기록하지 못하면 고객도 저장하지 않아, 다시 시도해도 중복되지 않는다
+        let input = crate::services::customers::CreateCustomerInput {
+            name: "김민지".to_string(),
+            phone: Some("010-1111-2222".to_string()),
+            email: None,
+            birthdate: None,
+            gender: None,
+            preferred_designer_id: None,
+            preferred_service: None,
+            allergies: None,
+            notes: None,
+            reminder_opt_out: None,
+            consent_service: None,
+            consent_marketing: None,
+            consent_photo: None,
+        };
+        assert!(crate::services::customers::create_customer(conn, input).is_err());
+        let customers: i64 = conn.query_row("SELECT COUNT(*) FROM customers", [], |row| row.get(0)).unwrap();
+        assert_eq!(customers, 0);
+    }
+}
@@ -0,0 +1,177 @@
+//! Tauri 없이 core(`db`, `services`)만으로 돌리는 통합 테스트
+
+use chrono::{Datelike, Duration, Local, Weekday};
+use std::sync::Mutex;
+
+use sisters_salon_reservation_app_lib::db::encryption::DatabaseKey;
+use sisters_salon_reservation_app_lib::db::{migrations, Database};
+use sisters_salon_reservation_app_lib::services::backup::manager::BackupManager;
+use sisters_salon_reservation_app_lib::services::backup::preview::{self, BackupSource};
+use sisters_salon_reservation_app_lib::services::backup::{self, CloudService};
+use sisters_salon_reservation_app_lib::services::cloudkit;
+use sisters_salon_reservation_app_lib::services::customers::{self, CreateCustomerInput};
+use sisters_salon_reservation_app_lib::services::designers::{self, CreateDesignerInput};
+use sisters_salon_reservation_app_lib::services::reservations::{self, CreateReservationInput};
+use sisters_salon_reservation_app_lib::services::statistics;
+
+/// 기본 영업시간에서 일요일은 휴무라서 최근 영업일을 쓴다
+fn recent_open_day() -> String {
+    let today = Local::now().date_naive();
+    let day = if today.weekday() == Weekday::Sun { today - Duration::days(1) } else { today };
+    day.format("%Y-%m-%d").to_string()
+}
+
+fn customer(name: &str, phone: &str) -> CreateCustomerInput {
+    CreateCustomerInput {
+        name: name.to_string(),
+        phone: Some(phone.to_string()),
+        email: None,
+        birthdate: None,
+        gender: None,
+        preferred_designer_id: None,
+        preferred_service: None,
+        allergies: None,
+        notes: None,
+        reminder_opt_out: None,
+        consent_service: None,
+        consent_marketing: None,
+        consent_photo: None,
+    }
+}
+
+fn reservation(date: &str, time: &str, designer_id: &str, phone: &str) -> CreateReservationInput {
+    CreateReservationInput {
+        customer_name: Some("김민지".to_string()),
+        customer_phone: Some(phone.to_string()),
+        date: date.to_string(),
+        time: time.to_string(),
+        designer_id: Some(designer_id.to_string()),
+        service_type: Some("커트".to_string()),
+        notes: None,
+    }
+}
+
+#[test]
+fn test_reservation_flow_on_in_memory_database() {
+    let db = Database::open_in_memory().unwrap();
+    let conn = db.conn();
+    let date = recent_open_day();
+
+    let designer = designers::create_designer(
+        conn,
+        CreateDesignerInput { name: "수진".to_string(), specialty: None, is_active: Some(true) },
+    )
+    .unwrap();
+    customers::create_customer(conn, customer("김민지", "010-1234-5678")).unwrap();
+    assert!(customers::create_customer(conn, customer("중복", "010-1234-5678")).is_err());
+
+    let first = reservations::create_reservation(conn, reservation(&date, "11:00", &designer.id, "010-1234-5678")).unwrap();
+    reservations::create_reservation(conn, reservation(&date, "14:00", &designer.id, "010-1234-5678")).unwrap();
+    // 영업시간 밖은 예약할 수 없다
+    assert!(reservations::create_reservation(conn, reservation(&date, "23:00", &designer.id, "010-1234-5678")).is_err());
+
+    reservations::update_reservation_status(conn, &first.id, "completed").unwrap();
+    assert_eq!(reservations::get_reservations(conn, Some(date.clone()), None, None).unwrap().len(), 2);
+
+    let summary = statistics::get_statistics_summary(conn, "7d".to_string()).unwrap();
+    assert_eq!(summary.total_reservations, 2);
+    assert_eq!(summary.completed, 1);
+    assert_eq!(summary.top_designer.map(|d| d.name).as_deref(), Some("수진"));
+}
+
+#[test]
+fn test_backup_round_trip_without_tauri() {
+    let dir = std::env::temp_dir().join(format!("salon-core-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let db = Database::open_encrypted(&dir.join("database.db"), &DatabaseKey::generate().unwrap()).unwrap();
+    designers::create_designer(
+        db.conn(),
+        CreateDesignerInput { name: "수진".to_string(), specialty: None, is_active: Some(true) },
+    )
+    .unwrap();
+
+    let db = Mutex::new(db);
+    let manager = BackupManager { db: &db, data_dir: &dir, icloud: cloudkit::store() };
+
+    let created = manager.create(&CloudService::Local, None).unwrap();
+    assert_eq!(manager.list(&CloudService::Local).unwrap().len(), 1);
+    let preview = manager.preview(&CloudService::Local, &created.filename, None).unwrap();
+    assert!(preview.tables.iter().any(|t| t.table == "designers" && t.rows == 1));
+
+    // 기기 키 없이는 열 수 없고, 키가 있으면 무결성 검사를 통과한다
+    let backup_path = dir.join("backups").join(&created.filename);
+    assert!(BackupSource::open(&backup_path, None, None).is_err());
+    let key = db.lock().unwrap().key().cloned();
+    let source = BackupSource::open(&backup_path, key.as_ref(), None).unwrap();
+    assert_eq!(preview::verify_backup(&source).unwrap().tables.len(), preview.tables.len());
+
+    db.lock().unwrap().conn().execute("DELETE FROM designers", []).unwrap();
+    manager.restore(&CloudService::Local, &created.filename, None).unwrap();
+    let db = db.lock().unwrap();
+    assert_eq!(designers::get_designers(db.conn()).unwrap().len(), 1);
+    backup::check_integrity(db.conn()).unwrap();
+
+    drop(db);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_import_customers_from_csv() {
+    let db = Database::open_in_memory().unwrap();
+    let conn = db.conn();
+    customers::create_customer(conn, customer("기존 고객", "010-0000-0000")).unwrap();
+
+    let csv = "\u{feff}이름,연락처,메모\r\n\
+               김민지,010-1111-2222,\"염색, 커트\"\r\n\
+               ,010-3333-4444,\r\n\
+               중복,010-0000-0000,\r\n\
+               \r\n\
+               \"이 \"\"수진\"\"\",,\"두 줄\n메모\"\r\n";
+    let result = customers::import_customers_csv(conn, csv).unwrap();
+    assert_eq!(result.imported, 2);
+    assert_eq!(result.skipped.iter().map(|r| r.line).collect::<Vec<_>>(), vec![3, 4]);
+
+    let imported = customers::get_customer_by_phone(conn, "010-1111-2222").unwrap().unwrap();
+    assert_eq!(imported.notes.as_deref(), Some("염색, 커트"));
+    let quoted = customers::search_customers(conn, "수진").unwrap();
+    assert_eq!(quoted[0].name, "이 \"수진\"");
+    assert_eq!(quoted[0].notes.as_deref(), Some("두 줄\n메모"));
+
+    assert!(customers::import_customers_csv(conn, "연락처\n010-5555-6666\n").is_err());
+}
+
+#[test]
+fn test_open_existing_reads_without_migrating() {
+    let dir = std::env::temp_dir().join(format!("salon-core-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("database.db");
+    let key = DatabaseKey::generate().unwrap();
+
+    // 이전 버전 앱이 쓰던 데이터베이스
+    let db = Database::open_encrypted(&path, &key).unwrap();
+    let older = migrations::latest_version() - 1;
+    db.conn().pragma_update(None, "user_version", older).unwrap();
+    drop(db);
+
+    assert!(Database::open_existing(&dir.join("missing.db"), Some(&key), true).is_err());
+    assert!(Database::open_existing(&path, None, true).is_err());
+
+    let db = Database::open_existing(&path, Some(&key), true).unwrap();
+    assert_eq!(migrations::current_version(db.conn()).unwrap(), older);
+    assert!(db.conn().execute("DELETE FROM designers", []).is_err());
+
+    // 읽기 전용으로도 기기 키 백업을 만들 수 있다
+    let db = Mutex::new(db);
+    BackupManager { db: &db, data_dir: &dir, icloud: cloudkit::store() }.create(&CloudService::Local, None).unwrap();
+    drop(db);
+
+    // 아카이브는 메모리 데이터베이스를 붙여 내보내므로 쓰기로 열지만, 마이그레이션은 하지 않는다
+    let db = Mutex::new(Database::open_existing(&path, Some(&key), false).unwrap());
+    BackupManager { db: &db, data_dir: &dir, icloud: cloudkit::store() }
+        .create(&CloudService::Local, Some("passphrase!"))
+        .unwrap();
+    assert_eq!(migrations::current_version(db.lock().unwrap().conn()).unwrap(), older);
+
+    drop(db);
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -1,9 +1,10 @@
 import { useState, useEffect, useCallback } from 'react';
 import { Plus } from 'lucide-react';
-import { reservationApi } from './lib/tauri';
+import { reservationApi, utilApi } from './lib/tauri';
 import { ResponsiveContainer } from './components/layout/ResponsiveContainer';
 import { AppointmentForm } from './components/reservation/AppointmentForm';
 import { ReservationTable } from './components/reservation/ReservationTable';
+import { TimeBlockPanel } from './components/reservation/TimeBlockPanel';
 import { DateRangeFilter, type DateRange, type DateRangePreset } from './components/reservation/DateRangeFilter';
 import { CustomerManagement } from './components/customer/CustomerManagement';
 import { DesignerManagement } from './components/designer/DesignerManagement';
@@ -16,9 +17,10 @@ import { DisplaySettingsProvider } from './contexts/DisplaySettingsContext';
 import { UnsavedChangesProvider, useUnsavedChanges } from './contexts/UnsavedChangesContext';
 import { ModalProvider, useModal } from './contexts/ModalContext';
 import { UnsavedChangesDialog } from './components/common/UnsavedChangesDialog';
+import { StartupErrorScreen } from './components/common/StartupErrorScreen';
 import { useAppLock } from './hooks/useAppLock';
 import { useOnboarding } from './hooks/useOnboarding';
-import type { Reservation } from './types';
+import type { Reservation, StartupError } from './types';
 
 type Page = 'reservations' | 'customers' | 'designers' | 'business-hours' | 'statistics' | 'settings';
 
@@ -237,6 +239,9 @@ function AppContent() {
               </div>
             )}
 
+            {/* 디자이너 예약 불가 시간 (하루 보기) */}
+            {!dateRange && <TimeBlockPanel date={selectedDate} />}
+
             {/* Table */}
             <ReservationTable
               reservations={reservations}
@@ -281,6 +286,16 @@ function AppContent() {
 }
 
 function App() {
+  // undefined: 확인 중, null: 정상 시작
+  const [startupError, setStartupError] = useState<StartupError | null | undefined>(undefined);
+
+  useEffect(() => {
+    utilApi.getStartupError().then(setStartupError).catch(() => setStartupError(null));
+  }, []);
+
+  if (startupError === undefined) return null;
+  if (startupError) return <StartupErrorScreen error={startupError} />;
+
   return (
     <DisplaySettingsProvider>
       <ModalProvider>
@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import { Clock, Coffee, Save, Loader2, Info } from 'lucide-react';
 import { businessHoursApi } from '../../lib/tauri';
 import type { BusinessHours as BusinessHoursType } from '../../types';
+import { ScheduleExceptions } from './ScheduleExceptions';
 
 const dayNames = ['일요일', '월요일', '화요일', '수요일', '목요일', '금요일', '토요일'];
 const shortDayNames = ['일', '월', '화', '수', '목', '금', '토'];
@@ -41,7 +42,7 @@ export function BusinessHours() {
       alert('영업시간이 저장되었습니다.');
     } catch (error) {
       console.error('Failed to save business hours:', error);
-      alert('저장에 실패했습니다.');
+      alert(`저장에 실패했습니다.\n${error}`);
     } finally {
       setSaving(false);
     }
@@ -187,6 +188,7 @@ export function BusinessHours() {
         </div>
       </div>
 
+      <ScheduleExceptions />
     </div>
   );
 }
@@ -0,0 +1,266 @@
+import { useState, useEffect, useCallback } from 'react';
+import { CalendarClock, CalendarOff, Plus, Trash2, Loader2, X } from 'lucide-react';
+import { businessHoursApi } from '../../lib/tauri';
+import type { BusinessHourOverride, ClosurePeriod } from '../../types';
+
+const today = () => new Date().toISOString().split('T')[0];
+
+const emptyOverride = {
+  date: '',
+  isClosed: false,
+  openTime: '',
+  closeTime: '',
+  breakStart: '',
+  breakEnd: '',
+  description: '',
+};
+
+const emptyClosure = { startDate: '', endDate: '', description: '' };
+
+const optional = (value: string) => value.trim() || undefined;
+
+/**
+ * 요일별 영업시간보다 우선하는 특정일 예외와 휴무 기간 (오늘 이후만 보여준다)
+ */
+export function ScheduleExceptions() {
+  const [overrides, setOverrides] = useState<BusinessHourOverride[]>([]);
+  const [closures, setClosures] = useState<ClosurePeriod[]>([]);
+  const [overrideForm, setOverrideForm] = useState<typeof emptyOverride | null>(null);
+  const [closureForm, setClosureForm] = useState<typeof emptyClosure | null>(null);
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const load = useCallback(async () => {
+    try {
+      const [overrideList, closureList] = await Promise.all([
+        businessHoursApi.getOverrides(today()),
+        businessHoursApi.getClosurePeriods(),
+      ]);
+      setOverrides(overrideList);
+      setClosures(closureList.filter((c) => c.endDate >= today()));
+    } catch (e) {
+      console.error('Failed to load schedule exceptions:', e);
+    }
+  }, []);
+
+  useEffect(() => {
+    load();
+  }, [load]);
+
+  const save = async (action: () => Promise<unknown>, done: () => void) => {
+    setSaving(true);
+    setError(null);
+    try {
+      await action();
+      done();
+      load();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleSaveOverride = () => {
+    if (!overrideForm) return;
+    if (!overrideForm.date) {
+      setError('날짜를 선택해주세요');
+      return;
+    }
+    save(
+      () => businessHoursApi.setOverride({
+        date: overrideForm.date,
+        isClosed: overrideForm.isClosed,
+        openTime: overrideForm.isClosed ? undefined : optional(overrideForm.openTime),
+        closeTime: overrideForm.isClosed ? undefined : optional(overrideForm.closeTime),
+        breakStart: overrideForm.isClosed ? undefined : optional(overrideForm.breakStart),
+        breakEnd: overrideForm.isClosed ? undefined : optional(overrideForm.breakEnd),
+        description: optional(overrideForm.description),
+      }),
+      () => setOverrideForm(null),
+    );
+  };
+
+  const handleSaveClosure = () => {
+    if (!closureForm) return;
+    if (!closureForm.startDate || !closureForm.endDate) {
+      setError('시작일과 종료일을 선택해주세요');
+      return;
+    }
+    save(
+      () => businessHoursApi.addClosurePeriod({
+        startDate: closureForm.startDate,
+        endDate: closureForm.endDate,
+        description: optional(closureForm.description),
+      }),
+      () => setClosureForm(null),
+    );
+  };
+
+  const describeOverride = (o: BusinessHourOverride) => {
+    if (o.isClosed) return '휴무';
+    const hours = `${o.openTime ?? '-'}~${o.closeTime ?? '-'}`;
+    return o.breakStart && o.breakEnd ? `${hours} (휴식 ${o.breakStart}~${o.breakEnd})` : hours;
+  };
+
+  return (
+    <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
+      {/* 특정일 예외 */}
+      <div className="glass-card p-4 space-y-3">
+        <div className="flex items-center justify-between">
+          <h3 className="heading-3 flex items-center gap-2">
+            <CalendarClock className="w-5 h-5 text-primary-500" />
+            특정일 영업시간
+          </h3>
+          {!overrideForm && (
+            <button onClick={() => { setOverrideForm(emptyOverride); setError(null); }} className="btn btn-ghost btn-sm">
+              <Plus className="w-4 h-4" />
+              추가
+            </button>
+          )}
+        </div>
+
+        {overrideForm && (
+          <div className="space-y-2 p-3 rounded-lg bg-white/50 dark:bg-white/5">
+            <div className="flex items-center gap-3">
+              <input
+                type="date"
+                value={overrideForm.date}
+                onChange={(e) => setOverrideForm({ ...overrideForm, date: e.target.value })}
+                className="input py-2 px-3 flex-1"
+              />
+              <label className="flex items-center gap-2 cursor-pointer select-none">
+                <input
+                  type="checkbox"
+                  checked={overrideForm.isClosed}
+                  onChange={(e) => setOverrideForm({ ...overrideForm, isClosed: e.target.checked })}
+                  className="checkbox"
+                />
+                <span className="text-sm text-gray-600 dark:text-gray-400">휴무</span>
+              </label>
+            </div>
+            {!overrideForm.isClosed && (
+              <div className="grid grid-cols-2 gap-2">
+                <div className="flex items-center gap-2">
+                  <span className="text-sm text-gray-600 dark:text-gray-400 w-10">영업</span>
+                  <input type="time" value={overrideForm.openTime} onChange={(e) => setOverrideForm({ ...overrideForm, openTime: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+                  <input type="time" value={overrideForm.closeTime} onChange={(e) => setOverrideForm({ ...overrideForm, closeTime: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+                </div>
+                <div className="flex items-center gap-2">
+                  <span className="text-sm text-gray-600 dark:text-gray-400 w-10">휴식</span>
+                  <input type="time" value={overrideForm.breakStart} onChange={(e) => setOverrideForm({ ...overrideForm, breakStart: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+                  <input type="time" value={overrideForm.breakEnd} onChange={(e) => setOverrideForm({ ...overrideForm, breakEnd: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+                </div>
+              </div>
+            )}
+            <input
+              type="text"
+              value={overrideForm.description}
+              onChange={(e) => setOverrideForm({ ...overrideForm, description: e.target.value })}
+              placeholder="메모 (예: 단축 영업)"
+              className="input py-2 px-3"
+            />
+            <div className="flex justify-end gap-2">
+              <button onClick={() => setOverrideForm(null)} className="btn btn-ghost btn-sm">
+                <X className="w-4 h-4" />
+                취소
+              </button>
+              <button onClick={handleSaveOverride} disabled={saving} className="btn btn-primary btn-sm">
+                {saving ? <Loader2 className="w-4 h-4 animate-spin" /> : '저장'}
+              </button>
+            </div>
+          </div>
+        )}
+
+        {overrides.length === 0 ? (
+          <p className="text-caption text-sm">예정된 예외가 없습니다</p>
+        ) : (
+          <ul className="divide-y divide-black/5 dark:divide-white/5">
+            {overrides.map((o) => (
+              <li key={o.id} className="flex items-center justify-between gap-3 py-2 text-sm">
+                <div className="min-w-0">
+                  <span className="font-medium mr-2">{o.date}</span>
+                  <span className="text-gray-600 dark:text-gray-400">{describeOverride(o)}</span>
+                  {o.description && <p className="text-caption truncate">{o.description}</p>}
+                </div>
+                <button
+                  onClick={() => save(() => businessHoursApi.deleteOverride(o.id), () => {})}
+                  className="btn btn-ghost btn-sm btn-icon text-red-500 hover:bg-red-50 dark:hover:bg-red-950"
+                  title="삭제"
+                >
+                  <Trash2 className="w-4 h-4" />
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </div>
+
+      {/* 휴무 기간 */}
+      <div className="glass-card p-4 space-y-3">
+        <div className="flex items-center justify-between">
+          <h3 className="heading-3 flex items-center gap-2">
+            <CalendarOff className="w-5 h-5 text-red-500" />
+            휴무 기간
+          </h3>
+          {!closureForm && (
+            <button onClick={() => { setClosureForm(emptyClosure); setError(null); }} className="btn btn-ghost btn-sm">
+              <Plus className="w-4 h-4" />
+              추가
+            </button>
+          )}
+        </div>
+
+        {closureForm && (
+          <div className="space-y-2 p-3 rounded-lg bg-white/50 dark:bg-white/5">
+            <div className="flex items-center gap-2">
+              <input type="date" value={closureForm.startDate} onChange={(e) => setClosureForm({ ...closureForm, startDate: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+              <span className="text-gray-400">~</span>
+              <input type="date" value={closureForm.endDate} onChange={(e) => setClosureForm({ ...closureForm, endDate: e.target.value })} className="input py-2 px-3 flex-1 min-w-0" />
+            </div>
+            <input
+              type="text"
+              value={closureForm.description}
+              onChange={(e) => setClosureForm({ ...closureForm, description: e.target.value })}
+              placeholder="메모 (예: 여름 휴가)"
+              className="input py-2 px-3"
+            />
+            <div className="flex justify-end gap-2">
+              <button onClick={() => setClosureForm(null)} className="btn btn-ghost btn-sm">
+                <X className="w-4 h-4" />
+                취소
+              </button>
+              <button onClick={handleSaveClosure} disabled={saving} className="btn btn-primary btn-sm">
+                {saving ? <Loader2 className="w-4 h-4 animate-spin" /> : '저장'}
+              </button>
+            </div>
+          </div>
+        )}
+
+        {closures.length === 0 ? (
+          <p className="text-caption text-sm">예정된 휴무 기간이 없습니다</p>
+        ) : (
+          <ul className="divide-y divide-black/5 dark:divide-white/5">
+            {closures.map((c) => (
+              <li key={c.id} className="flex items-center justify-between gap-3 py-2 text-sm">
+                <div className="min-w-0">
+                  <span className="font-medium">{c.startDate} ~ {c.endDate}</span>
+                  {c.description && <p className="text-caption truncate">{c.description}</p>}
+                </div>
+                <button
+                  onClick={() => save(() => businessHoursApi.deleteClosurePeriod(c.id), () => {})}
+                  className="btn btn-ghost btn-sm btn-icon text-red-500 hover:bg-red-50 dark:hover:bg-red-950"
+                  title="삭제"
+                >
+                  <Trash2 className="w-4 h-4" />
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </div>
+
+      {error && <p className="lg:col-span-2 text-sm text-red-500">{error}</p>}
+    </div>
+  );
+}
@@ -0,0 +1,34 @@
+import { AlertTriangle } from 'lucide-react';
+import type { StartupError } from '../../types';
+
+interface StartupErrorScreenProps {
+  error: StartupError;
+}
+
+// 데이터베이스를 열지 못했을 때 앱 대신 보여주는 화면
+export function StartupErrorScreen({ error }: StartupErrorScreenProps) {
+  return (
+    <div className="min-h-screen flex items-center justify-center p-4 bg-gray-50 dark:bg-gray-900">
+      <div className="bg-white dark:bg-gray-800 rounded-2xl shadow-xl max-w-lg w-full p-6 space-y-4">
+        <div className="flex items-center gap-3">
+          <div className="p-2 rounded-full bg-red-100 dark:bg-red-900/50">
+            <AlertTriangle className="w-5 h-5 text-red-600 dark:text-red-400" />
+          </div>
+          <h1 className="text-lg font-semibold text-gray-900 dark:text-white">데이터를 열 수 없습니다</h1>
+        </div>
+        <p className="text-sm text-gray-600 dark:text-gray-400">
+          예약 데이터베이스를 여는 중 문제가 생겨 앱을 시작하지 못했습니다. 데이터는 변경하지 않았습니다.
+        </p>
+        <pre className="p-3 rounded-lg bg-gray-100 dark:bg-gray-700 text-xs text-gray-800 dark:text-gray-200 whitespace-pre-wrap break-all">
+          {error.message}
+        </pre>
+        {error.dataDir && (
+          <p className="text-xs text-gray-500 dark:text-gray-400 break-all">데이터 폴더: {error.dataDir}</p>
+        )}
+        <p className="text-sm text-gray-600 dark:text-gray-400">
+          앱을 업데이트하거나, 데이터 폴더를 그대로 둔 채 위 내용과 함께 문의해주세요. 백업 파일이 있다면 백업에서 복원할 수 있습니다.
+        </p>
+      </div>
+    </div>
+  );
+}
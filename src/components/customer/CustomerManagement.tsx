@@ -14,10 +14,13 @@ import {
   X,
   Clock,
   ChevronRight,
+  MessageSquare,
+  FileDown,
+  Eraser,
 } from 'lucide-react';
-import { customerApi, designerApi } from '../../lib/tauri';
+import { customerApi, designerApi, privacyApi } from '../../lib/tauri';
 import { useModal } from '../../contexts/ModalContext';
-import type { Customer, CreateCustomerInput, CustomerReservation, Designer } from '../../types';
+import type { Customer, CreateCustomerInput, CustomerMessage, CustomerReservation, Designer } from '../../types';
 
 interface CustomerFormData {
   name: string;
@@ -29,8 +32,32 @@ interface CustomerFormData {
   preferredService: string;
   allergies: string;
   notes: string;
+  reminderOptOut: boolean;
+  consentService: boolean;
+  consentMarketing: boolean;
+  consentPhoto: boolean;
 }
 
+const CONSENT_OPTIONS: {
+  key: 'consentService' | 'consentMarketing' | 'consentPhoto';
+  atKey: 'consentServiceAt' | 'consentMarketingAt' | 'consentPhotoAt';
+  label: string;
+}[] = [
+  { key: 'consentService', atKey: 'consentServiceAt', label: '개인정보 수집·이용 동의 (필수)' },
+  { key: 'consentMarketing', atKey: 'consentMarketingAt', label: '마케팅 문자 수신 동의' },
+  { key: 'consentPhoto', atKey: 'consentPhotoAt', label: '시술 사진 촬영·활용 동의' },
+];
+
+const MESSAGE_TEMPLATE_LABELS: Record<string, string> = {
+  reminder: '예약 알림',
+};
+
+const MESSAGE_CHANNEL_LABELS: Record<string, string> = {
+  sms: '문자',
+  alimtalk: '알림톡',
+  file: '파일 기록',
+};
+
 const initialFormData: CustomerFormData = {
   name: '',
   phone: '',
@@ -41,6 +68,10 @@ const initialFormData: CustomerFormData = {
   preferredService: '',
   allergies: '',
   notes: '',
+  reminderOptOut: false,
+  consentService: false,
+  consentMarketing: false,
+  consentPhoto: false,
 };
 
 export function CustomerManagement() {
@@ -54,6 +85,7 @@ export function CustomerManagement() {
   const [error, setError] = useState<string | null>(null);
   const [selectedCustomer, setSelectedCustomer] = useState<Customer | null>(null);
   const [reservationHistory, setReservationHistory] = useState<CustomerReservation[]>([]);
+  const [messageHistory, setMessageHistory] = useState<CustomerMessage[]>([]);
   const [loadingHistory, setLoadingHistory] = useState(false);
 
   const { setModalOpen } = useModal();
@@ -122,6 +154,10 @@ export function CustomerManagement() {
         preferredService: formData.preferredService || undefined,
         allergies: formData.allergies || undefined,
         notes: formData.notes || undefined,
+        reminderOptOut: formData.reminderOptOut,
+        consentService: formData.consentService,
+        consentMarketing: formData.consentMarketing,
+        consentPhoto: formData.consentPhoto,
       };
 
       if (editingId) {
@@ -152,6 +188,10 @@ export function CustomerManagement() {
       preferredService: customer.preferredService || '',
       allergies: customer.allergies || '',
       notes: customer.notes || '',
+      reminderOptOut: customer.reminderOptOut,
+      consentService: customer.consentService,
+      consentMarketing: customer.consentMarketing,
+      consentPhoto: customer.consentPhoto,
     });
     setShowForm(true);
     setError(null);
@@ -181,16 +221,47 @@ export function CustomerManagement() {
     setSelectedCustomer(customer);
     setLoadingHistory(true);
     try {
-      const history = await customerApi.getReservations(customer.id);
+      const [history, messages] = await Promise.all([
+        customerApi.getReservations(customer.id),
+        customerApi.getMessages(customer.id, 20),
+      ]);
       setReservationHistory(history);
+      setMessageHistory(messages);
     } catch (error) {
       console.error('Failed to load reservation history:', error);
       setReservationHistory([]);
+      setMessageHistory([]);
     } finally {
       setLoadingHistory(false);
     }
   };
 
+  const handleExportDossier = async (customer: Customer, format: 'json' | 'pdf') => {
+    try {
+      const path = await privacyApi.exportDossier(customer.id, format);
+      alert(`열람 자료를 저장했습니다.\n${path}`);
+    } catch (err) {
+      alert(`열람 자료를 만들지 못했습니다: ${err}`);
+    }
+  };
+
+  const handleErase = async (customer: Customer) => {
+    if (
+      !confirm(
+        `${customer.name} 고객의 개인정보를 삭제하시겠습니까?\n예약 기록은 통계를 위해 익명으로 남고, 되돌릴 수 없습니다.`
+      )
+    )
+      return;
+    try {
+      const result = await privacyApi.eraseCustomer(customer.id);
+      alert(`개인정보를 삭제했습니다. (예약 ${result.reservations}건 익명 처리, 메시지 ${result.messages}건 삭제)`);
+      setSelectedCustomer(null);
+      await loadCustomers();
+    } catch (err) {
+      alert(`개인정보를 삭제하지 못했습니다: ${err}`);
+    }
+  };
+
   const formatDate = (dateStr: string) => {
     const date = new Date(dateStr);
     return date.toLocaleDateString('ko-KR', {
@@ -399,10 +470,59 @@ export function CustomerManagement() {
                     rows={3}
                   />
                 </div>
+
+                <label className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300">
+                  <input
+                    type="checkbox"
+                    checked={formData.reminderOptOut}
+                    onChange={(e) => setFormData({ ...formData, reminderOptOut: e.target.checked })}
+                  />
+                  예약 알림 문자 받지 않음
+                </label>
+
+                <div className="space-y-2 p-3 rounded-lg bg-gray-50 dark:bg-gray-800/50">
+                  <p className="text-xs font-medium text-gray-500 dark:text-gray-400">개인정보 동의</p>
+                  {CONSENT_OPTIONS.map(({ key, label }) => (
+                    <label key={key} className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300">
+                      <input
+                        type="checkbox"
+                        checked={formData[key]}
+                        onChange={(e) => setFormData({ ...formData, [key]: e.target.checked })}
+                      />
+                      {label}
+                    </label>
+                  ))}
+                </div>
               </div>
 
               {/* Footer - Fixed */}
               <div className="modal-footer flex justify-end gap-3">
+                {!selectedCustomer.anonymizedAt && (
+                  <div className="mr-auto flex gap-1">
+                    <button
+                      onClick={() => handleExportDossier(selectedCustomer, 'pdf')}
+                      className="btn btn-ghost"
+                      title="개인정보 열람 자료 (PDF)"
+                    >
+                      <FileDown className="w-4 h-4" />
+                      PDF
+                    </button>
+                    <button
+                      onClick={() => handleExportDossier(selectedCustomer, 'json')}
+                      className="btn btn-ghost"
+                      title="개인정보 열람 자료 (JSON)"
+                    >
+                      JSON
+                    </button>
+                    <button
+                      onClick={() => handleErase(selectedCustomer)}
+                      className="btn btn-ghost text-red-500 hover:bg-red-50 dark:hover:bg-red-950"
+                      title="개인정보 삭제 (예약은 익명으로 보존)"
+                    >
+                      <Eraser className="w-4 h-4" />
+                    </button>
+                  </div>
+                )}
                 <button type="button" onClick={handleCancel} className="btn btn-secondary">
                   취소
                 </button>
@@ -510,6 +630,22 @@ export function CustomerManagement() {
                   </div>
                 )}
 
+                <div>
+                  <span className="text-gray-500 dark:text-gray-400 text-sm">
+                    개인정보 동의{selectedCustomer.consentVersion && ` (${selectedCustomer.consentVersion})`}
+                  </span>
+                  <div className="mt-1 space-y-0.5">
+                    {CONSENT_OPTIONS.map(({ key, atKey, label }) => (
+                      <p key={key} className="text-xs text-gray-600 dark:text-gray-300">
+                        {selectedCustomer[key] ? '✓' : '✗'} {label}
+                        {selectedCustomer[atKey] && (
+                          <span className="text-gray-400"> · {selectedCustomer[atKey]}</span>
+                        )}
+                      </p>
+                    ))}
+                  </div>
+                </div>
+
                 {/* Reservation History */}
                 <div>
                   <h4 className="font-medium mb-3 flex items-center gap-2">
@@ -546,10 +682,70 @@ export function CustomerManagement() {
                     </div>
                   )}
                 </div>
+
+                {/* Communication History */}
+                <div>
+                  <h4 className="font-medium mb-3 flex items-center gap-2">
+                    <MessageSquare className="w-4 h-4" />
+                    발송 메시지
+                  </h4>
+                  {loadingHistory ? (
+                    <div className="flex items-center justify-center py-4">
+                      <Loader2 className="w-5 h-5 animate-spin text-gray-400" />
+                    </div>
+                  ) : messageHistory.length === 0 ? (
+                    <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-4">
+                      보낸 메시지가 없습니다
+                    </p>
+                  ) : (
+                    <div className="space-y-2">
+                      {messageHistory.map((m) => (
+                        <div key={m.id} className="p-3 rounded-lg bg-white/30 dark:bg-white/5 space-y-1">
+                          <div className="flex items-center justify-between text-xs text-gray-500 dark:text-gray-400">
+                            <span>
+                              {m.sentAt ?? m.createdAt} · {MESSAGE_TEMPLATE_LABELS[m.template] ?? m.template} ·{' '}
+                              {MESSAGE_CHANNEL_LABELS[m.channel] ?? m.channel}
+                            </span>
+                            <span className={m.status === 'failed' ? 'text-red-500' : 'text-green-600'} title={m.error}>
+                              {m.status === 'failed' ? '실패' : m.status === 'sent' ? '발송' : '대기'}
+                            </span>
+                          </div>
+                          <p className="text-sm whitespace-pre-wrap">{m.body}</p>
+                        </div>
+                      ))}
+                    </div>
+                  )}
+                </div>
               </div>
 
               {/* Footer - Fixed */}
               <div className="modal-footer flex justify-end gap-3">
+                {!selectedCustomer.anonymizedAt && (
+                  <div className="mr-auto flex gap-1">
+                    <button
+                      onClick={() => handleExportDossier(selectedCustomer, 'pdf')}
+                      className="btn btn-ghost"
+                      title="개인정보 열람 자료 (PDF)"
+                    >
+                      <FileDown className="w-4 h-4" />
+                      PDF
+                    </button>
+                    <button
+                      onClick={() => handleExportDossier(selectedCustomer, 'json')}
+                      className="btn btn-ghost"
+                      title="개인정보 열람 자료 (JSON)"
+                    >
+                      JSON
+                    </button>
+                    <button
+                      onClick={() => handleErase(selectedCustomer)}
+                      className="btn btn-ghost text-red-500 hover:bg-red-50 dark:hover:bg-red-950"
+                      title="개인정보 삭제 (예약은 익명으로 보존)"
+                    >
+                      <Eraser className="w-4 h-4" />
+                    </button>
+                  </div>
+                )}
                 <button onClick={() => handleEdit(selectedCustomer)} className="btn btn-secondary">
                   <Pencil className="w-4 h-4" />
                   수정
@@ -165,6 +165,9 @@ export function AppointmentForm({ reservation, onSubmit, onCancel }: Appointment
       onSubmit(result);
     } catch (error) {
       console.error('Failed to save reservation:', error);
+      // 휴무일, 영업시간 외, 차단 시간 등 일정 관련 오류는 시간 필드에 표시
+      setErrors((prev) => ({ ...prev, time: String(error) }));
+      timeRef.current?.focus();
     } finally {
       setLoading(false);
     }
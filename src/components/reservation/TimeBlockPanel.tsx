@@ -0,0 +1,198 @@
+import { useState, useEffect, useCallback, useMemo } from 'react';
+import { Ban, Plus, Trash2, Loader2, X } from 'lucide-react';
+import { timeBlockApi, designerApi } from '../../lib/tauri';
+import type { TimeBlock, Designer } from '../../types';
+
+interface TimeBlockPanelProps {
+  date: string;
+}
+
+const emptyForm = { designerId: '', startTime: '', endTime: '', reason: '' };
+
+/**
+ * 선택한 날짜의 디자이너 예약 불가 시간 (교육, 개인 용무 등)
+ * 고객 예약과 따로 보여주고, 이 화면에서 바로 추가·삭제한다.
+ */
+export function TimeBlockPanel({ date }: TimeBlockPanelProps) {
+  const [blocks, setBlocks] = useState<TimeBlock[]>([]);
+  const [designers, setDesigners] = useState<Designer[]>([]);
+  const [showForm, setShowForm] = useState(false);
+  const [form, setForm] = useState(emptyForm);
+  const [saving, setSaving] = useState(false);
+  const [deleting, setDeleting] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const loadBlocks = useCallback(async () => {
+    try {
+      setBlocks(await timeBlockApi.getAll(date));
+    } catch (e) {
+      console.error('Failed to load time blocks:', e);
+    }
+  }, [date]);
+
+  useEffect(() => {
+    loadBlocks();
+  }, [loadBlocks]);
+
+  useEffect(() => {
+    designerApi.getActive().then(setDesigners).catch(() => {});
+  }, []);
+
+  const designerMap = useMemo(() => {
+    const map = new Map<string, string>();
+    designers.forEach((d) => map.set(d.id, d.name));
+    return map;
+  }, [designers]);
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!form.designerId || !form.startTime || !form.endTime) {
+      setError('디자이너와 시작/종료 시간을 입력해주세요');
+      return;
+    }
+    setSaving(true);
+    setError(null);
+    try {
+      await timeBlockApi.create({
+        designerId: form.designerId,
+        date,
+        startTime: form.startTime,
+        endTime: form.endTime,
+        reason: form.reason.trim() || undefined,
+      });
+      setForm(emptyForm);
+      setShowForm(false);
+      loadBlocks();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (id: string) => {
+    setDeleting(id);
+    try {
+      await timeBlockApi.delete(id);
+      loadBlocks();
+    } catch (e) {
+      console.error('Failed to delete time block:', e);
+    } finally {
+      setDeleting(null);
+    }
+  };
+
+  if (blocks.length === 0 && !showForm) {
+    return (
+      <div className="flex justify-end">
+        <button onClick={() => setShowForm(true)} className="btn btn-ghost btn-sm">
+          <Ban className="w-4 h-4" />
+          시간 차단
+        </button>
+      </div>
+    );
+  }
+
+  return (
+    <div className="glass-card p-4 space-y-3">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-semibold flex items-center gap-1.5">
+          <Ban className="w-4 h-4 text-gray-400" />
+          예약 불가 시간
+        </h3>
+        {!showForm && (
+          <button onClick={() => setShowForm(true)} className="btn btn-ghost btn-sm">
+            <Plus className="w-4 h-4" />
+            추가
+          </button>
+        )}
+      </div>
+
+      {blocks.length > 0 && (
+        <ul className="divide-y divide-black/5 dark:divide-white/5">
+          {blocks.map((block) => (
+            <li key={block.id} className="flex items-center justify-between gap-3 py-2 text-sm">
+              <div className="flex items-center gap-3 min-w-0">
+                <span className="font-medium whitespace-nowrap">
+                  {block.startTime}~{block.endTime}
+                </span>
+                <span className="text-gray-600 dark:text-gray-400">
+                  {designerMap.get(block.designerId) || '-'}
+                </span>
+                {block.reason && (
+                  <span className="text-caption truncate">{block.reason}</span>
+                )}
+              </div>
+              <button
+                onClick={() => handleDelete(block.id)}
+                disabled={deleting === block.id}
+                className="btn btn-ghost btn-sm btn-icon text-red-500 hover:text-red-600 hover:bg-red-50 dark:hover:bg-red-950"
+                title="삭제"
+              >
+                {deleting === block.id ? (
+                  <Loader2 className="w-4 h-4 animate-spin" />
+                ) : (
+                  <Trash2 className="w-4 h-4" />
+                )}
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {showForm && (
+        <form onSubmit={handleSubmit} className="grid grid-cols-1 sm:grid-cols-5 gap-2 items-end">
+          <select
+            value={form.designerId}
+            onChange={(e) => setForm({ ...form, designerId: e.target.value })}
+            className="input select sm:col-span-1"
+          >
+            <option value="">디자이너 선택</option>
+            {designers.map((d) => (
+              <option key={d.id} value={d.id}>{d.name}</option>
+            ))}
+          </select>
+          <input
+            type="time"
+            value={form.startTime}
+            onChange={(e) => setForm({ ...form, startTime: e.target.value })}
+            className="input"
+            aria-label="시작 시간"
+          />
+          <input
+            type="time"
+            value={form.endTime}
+            onChange={(e) => setForm({ ...form, endTime: e.target.value })}
+            className="input"
+            aria-label="종료 시간"
+          />
+          <input
+            type="text"
+            value={form.reason}
+            onChange={(e) => setForm({ ...form, reason: e.target.value })}
+            placeholder="사유 (선택)"
+            className="input"
+          />
+          <div className="flex gap-2">
+            <button type="submit" disabled={saving} className="btn btn-primary btn-sm flex-1">
+              {saving ? <Loader2 className="w-4 h-4 animate-spin" /> : '저장'}
+            </button>
+            <button
+              type="button"
+              onClick={() => {
+                setShowForm(false);
+                setForm(emptyForm);
+                setError(null);
+              }}
+              className="btn btn-ghost btn-sm btn-icon"
+              title="취소"
+            >
+              <X className="w-4 h-4" />
+            </button>
+          </div>
+          {error && <p className="sm:col-span-5 text-xs text-red-500">{error}</p>}
+        </form>
+      )}
+    </div>
+  );
+}
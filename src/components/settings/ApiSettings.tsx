@@ -0,0 +1,263 @@
+import { useState, useEffect } from 'react';
+import { Server, KeyRound, Plus, Copy, Trash2, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { apiServerApi } from '../../lib/tauri';
+import type { ApiKey, ApiScope, ApiServerSettings, ApiServerStatus } from '../../types';
+
+const TOGGLE_CLASS =
+  "w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-indigo-300 dark:peer-focus:ring-indigo-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-indigo-600";
+
+export function ApiSettings() {
+  const [settings, setSettings] = useState<ApiServerSettings>({ enabled: false, port: 8787, allowLan: false });
+  const [status, setStatus] = useState<ApiServerStatus | null>(null);
+  const [keys, setKeys] = useState<ApiKey[]>([]);
+  const [keyName, setKeyName] = useState('');
+  const [allowWrite, setAllowWrite] = useState(false);
+  const [newSecret, setNewSecret] = useState<string | null>(null);
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  const loadKeys = () => apiServerApi.listKeys().then(setKeys).catch(() => {});
+
+  useEffect(() => {
+    apiServerApi.getSettings().then(setSettings).catch(() => {});
+    apiServerApi.getStatus().then(setStatus).catch(() => {});
+    loadKeys();
+  }, []);
+
+  const applySettings = async (next: ApiServerSettings) => {
+    setIsWorking(true);
+    setResult(null);
+    try {
+      const nextStatus = await apiServerApi.updateSettings(next);
+      setSettings(next);
+      setStatus(nextStatus);
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleCreateKey = async () => {
+    const scopes: ApiScope[] = allowWrite ? ['read', 'write'] : ['read'];
+    setIsWorking(true);
+    setResult(null);
+    try {
+      const created = await apiServerApi.createKey(keyName, scopes);
+      setNewSecret(created.secret);
+      setKeyName('');
+      setAllowWrite(false);
+      await loadKeys();
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleRevokeKey = async (key: ApiKey) => {
+    if (!confirm(`'${key.name}' 키를 폐기하시겠습니까? 이 키를 쓰는 프로그램은 더 이상 접속할 수 없습니다.`)) return;
+    try {
+      await apiServerApi.revokeKey(key.id);
+      await loadKeys();
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    }
+  };
+
+  const copySecret = async () => {
+    if (!newSecret) return;
+    await navigator.clipboard.writeText(newSecret);
+    setResult({ success: true, message: 'API 키를 복사했습니다' });
+  };
+
+  return (
+    <div className="space-y-4">
+      {/* 서버 */}
+      <div className="glass-card">
+        <div className="flex items-center gap-3 mb-6">
+          <div className="p-2.5 rounded-xl bg-slate-100 text-slate-600 dark:bg-slate-800 dark:text-slate-300">
+            <Server className="w-5 h-5" />
+          </div>
+          <div>
+            <h3 className="font-semibold text-gray-800 dark:text-white">API 서버</h3>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              예약·고객·디자이너·통계를 JSON API(/api/v1)로 제공합니다
+            </p>
+          </div>
+        </div>
+
+        <div className="space-y-4">
+          <div className="flex items-center justify-between">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">API 서버 사용</p>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={settings.enabled}
+                onChange={(e) => applySettings({ ...settings, enabled: e.target.checked })}
+                disabled={isWorking}
+                className="sr-only peer"
+              />
+              <div className={TOGGLE_CLASS} />
+            </label>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div>
+              <p className="text-sm font-medium text-gray-900 dark:text-white">같은 네트워크에서 접속 허용</p>
+              <p className="text-xs text-gray-500 dark:text-gray-400">끄면 이 기기에서만 접속할 수 있습니다</p>
+            </div>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={settings.allowLan}
+                onChange={(e) => applySettings({ ...settings, allowLan: e.target.checked })}
+                disabled={isWorking}
+                className="sr-only peer"
+              />
+              <div className={TOGGLE_CLASS} />
+            </label>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">포트</p>
+            <input
+              type="number"
+              min={1024}
+              max={65535}
+              value={settings.port}
+              onChange={(e) => setSettings({ ...settings, port: Number(e.target.value) })}
+              onBlur={() => applySettings(settings)}
+              disabled={isWorking}
+              className="input w-28"
+            />
+          </div>
+
+          {status?.running && (
+            <div className="p-3 rounded-lg bg-gray-50 dark:bg-gray-800/50 text-xs text-gray-600 dark:text-gray-300 space-y-1">
+              <p>
+                주소: <span className="font-mono">{status.address}</span>
+              </p>
+              {status.lanUrl && (
+                <p>
+                  네트워크: <span className="font-mono">{status.lanUrl}</span>
+                </p>
+              )}
+              <p>
+                API 문서: <span className="font-mono">{status.address}/openapi.json</span>
+              </p>
+              <p>
+                캘린더 구독: <span className="font-mono">{status.lanUrl ?? status.address}/calendar.ics?key=읽기 권한 키</span>
+                <br />
+                (디자이너별: <span className="font-mono">/designers/디자이너 ID/calendar.ics</span>)
+              </p>
+            </div>
+          )}
+        </div>
+      </div>
+
+      {/* API 키 */}
+      <div className="glass-card">
+        <div className="flex items-center gap-3 mb-6">
+          <div className="p-2.5 rounded-xl bg-amber-100 text-amber-600 dark:bg-amber-900/30 dark:text-amber-400">
+            <KeyRound className="w-5 h-5" />
+          </div>
+          <div>
+            <h3 className="font-semibold text-gray-800 dark:text-white">API 키</h3>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              요청 시 Authorization: Bearer 헤더에 키를 넣어 주세요
+            </p>
+          </div>
+        </div>
+
+        <div className="space-y-4">
+          <div className="flex items-center gap-2">
+            <input
+              type="text"
+              value={keyName}
+              onChange={(e) => setKeyName(e.target.value)}
+              placeholder="키 이름 (예: 홈페이지 예약)"
+              className="input flex-1"
+            />
+            <label className="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300 whitespace-nowrap">
+              <input type="checkbox" checked={allowWrite} onChange={(e) => setAllowWrite(e.target.checked)} />
+              쓰기 허용
+            </label>
+            <button
+              onClick={handleCreateKey}
+              disabled={isWorking || !keyName.trim()}
+              className="flex items-center gap-1 py-2 px-3 bg-indigo-600 hover:bg-indigo-700 text-white
+                         rounded-lg text-sm font-medium transition-colors
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Plus className="w-4 h-4" />}
+              <span>발급</span>
+            </button>
+          </div>
+
+          {newSecret && (
+            <div className="p-3 rounded-lg bg-amber-50 dark:bg-amber-900/20 space-y-2">
+              <p className="text-xs text-amber-800 dark:text-amber-300">
+                이 키는 지금만 확인할 수 있습니다. 안전한 곳에 복사해 두세요.
+              </p>
+              <div className="flex items-center gap-2">
+                <code className="flex-1 text-xs break-all text-gray-800 dark:text-gray-200">{newSecret}</code>
+                <button onClick={copySecret} className="p-1.5 text-gray-500 hover:text-gray-800 dark:hover:text-white">
+                  <Copy className="w-4 h-4" />
+                </button>
+              </div>
+            </div>
+          )}
+
+          {keys.length === 0 ? (
+            <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">발급된 키가 없습니다</p>
+          ) : (
+            <div className="divide-y divide-gray-100 dark:divide-gray-700">
+              {keys.map((key) => (
+                <div key={key.id} className="flex items-center justify-between py-2">
+                  <div className={key.revokedAt ? 'opacity-50' : ''}>
+                    <p className="text-sm font-medium text-gray-900 dark:text-white">
+                      {key.name}{' '}
+                      <span className="text-xs font-normal text-gray-500">
+                        {key.scopes.includes('write') ? '읽기/쓰기' : '읽기'}
+                      </span>
+                    </p>
+                    <p className="text-xs text-gray-500 dark:text-gray-400 font-mono">
+                      {key.keyPrefix}… · {key.revokedAt ? `폐기됨 ${key.revokedAt}` : `최근 사용 ${key.lastUsedAt ?? '-'}`}
+                    </p>
+                  </div>
+                  {!key.revokedAt && (
+                    <button
+                      onClick={() => handleRevokeKey(key)}
+                      className="p-1.5 text-gray-400 hover:text-red-600"
+                      title="폐기"
+                    >
+                      <Trash2 className="w-4 h-4" />
+                    </button>
+                  )}
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-start gap-2 p-3 rounded-lg text-sm ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? (
+            <CheckCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          ) : (
+            <XCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          )}
+          <span className="break-all">{result.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,241 @@
+import { useState, useEffect } from 'react';
+import { CalendarClock, Save, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { backupApi } from '../../lib/tauri';
+import type {
+  AutoBackupSettings as Settings,
+  BackupFrequency,
+  BackupRunStatus,
+  BackupTrigger,
+  CloudService,
+} from '../../types';
+
+const TOGGLE_CLASS =
+  "w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-indigo-300 dark:peer-focus:ring-indigo-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-indigo-600";
+
+const SERVICE_LABELS: Record<CloudService, string> = {
+  local: '로컬',
+  icloud: 'iCloud',
+  google_drive: 'Google Drive',
+  external: '외부 저장소',
+};
+
+const TRIGGER_LABELS: Record<BackupTrigger, string> = {
+  scheduled: '주기 백업',
+  exit: '종료 시 백업',
+  before_operation: '작업 전 백업',
+};
+
+interface AutoBackupSettingsProps {
+  // 선택할 수 있는 저장 위치 (BackupSettings 에서 사용 가능한 서비스)
+  availableServices: CloudService[];
+}
+
+export function AutoBackupSettings({ availableServices }: AutoBackupSettingsProps) {
+  const [settings, setSettings] = useState<Settings | null>(null);
+  const [statuses, setStatuses] = useState<BackupRunStatus[]>([]);
+  const [isSaving, setIsSaving] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  useEffect(() => {
+    backupApi.getAutoSettings().then(setSettings).catch(() => {});
+    backupApi.getAutoStatus().then(setStatuses).catch(() => {});
+  }, []);
+
+  if (!settings) {
+    return (
+      <div className="flex justify-center py-8">
+        <Loader2 className="w-6 h-6 animate-spin text-gray-400" />
+      </div>
+    );
+  }
+
+  const toggleService = (service: CloudService) => {
+    const services = settings.services.includes(service)
+      ? settings.services.filter((s) => s !== service)
+      : [...settings.services, service];
+    setSettings({ ...settings, services });
+  };
+
+  const setRetention = (key: keyof Settings['retention'], value: number) =>
+    setSettings({ ...settings, retention: { ...settings.retention, [key]: value } });
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    setResult(null);
+    try {
+      await backupApi.updateAutoSettings(settings);
+      setResult({ success: true, message: '자동 백업 설정을 저장했습니다' });
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const toggleRow = (label: string, description: string, checked: boolean, onChange: (checked: boolean) => void) => (
+    <div className="flex items-center justify-between">
+      <div>
+        <p className="text-sm font-medium text-gray-900 dark:text-white">{label}</p>
+        <p className="text-xs text-gray-500 dark:text-gray-400">{description}</p>
+      </div>
+      <label className="relative inline-flex items-center cursor-pointer">
+        <input
+          type="checkbox"
+          checked={checked}
+          onChange={(e) => onChange(e.target.checked)}
+          className="sr-only peer"
+        />
+        <div className={TOGGLE_CLASS} />
+      </label>
+    </div>
+  );
+
+  return (
+    <div className="glass-card">
+      <div className="flex items-center gap-3 mb-6">
+        <div className="p-2.5 rounded-xl bg-indigo-100 text-indigo-600 dark:bg-indigo-900/30 dark:text-indigo-400">
+          <CalendarClock className="w-5 h-5" />
+        </div>
+        <div>
+          <h3 className="font-semibold text-gray-800 dark:text-white">자동 백업</h3>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            정해진 주기와 앱 종료·초기화 같은 작업 전에 자동으로 백업합니다
+          </p>
+        </div>
+      </div>
+
+      <div className="space-y-4">
+        {toggleRow('주기 백업', '설정한 주기마다 백업합니다', settings.enabled, (enabled) =>
+          setSettings({ ...settings, enabled })
+        )}
+
+        <div className="flex items-center justify-between">
+          <p className="text-sm font-medium text-gray-900 dark:text-white">백업 주기</p>
+          <select
+            value={settings.frequency}
+            onChange={(e) => setSettings({ ...settings, frequency: e.target.value as BackupFrequency })}
+            className="input w-28"
+          >
+            <option value="daily">매일</option>
+            <option value="weekly">매주</option>
+          </select>
+        </div>
+
+        <div className="space-y-1">
+          <p className="text-sm font-medium text-gray-900 dark:text-white">저장 위치</p>
+          <div className="flex gap-3">
+            {availableServices.map((service) => (
+              <label
+                key={service}
+                className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300 cursor-pointer"
+              >
+                <input
+                  type="checkbox"
+                  checked={settings.services.includes(service)}
+                  onChange={() => toggleService(service)}
+                  className="rounded border-gray-300 text-indigo-600 focus:ring-indigo-500"
+                />
+                <span>{SERVICE_LABELS[service]}</span>
+              </label>
+            ))}
+          </div>
+        </div>
+
+        {toggleRow('앱 종료 시 백업', '앱을 닫을 때 저장 위치마다 백업합니다', settings.onExit, (onExit) =>
+          setSettings({ ...settings, onExit })
+        )}
+        {toggleRow(
+          '작업 전 백업',
+          '전체 초기화, 일정 가져오기 전에 로컬 백업합니다 (고객 정보 삭제·파기 전에는 백업하지 않습니다)',
+          settings.beforeRiskyOperations,
+          (beforeRiskyOperations) => setSettings({ ...settings, beforeRiskyOperations })
+        )}
+
+        <div className="space-y-1">
+          <p className="text-sm font-medium text-gray-900 dark:text-white">보관 개수</p>
+          <div className="grid grid-cols-3 gap-2">
+            {(
+              [
+                ['daily', '일별'],
+                ['weekly', '주별'],
+                ['monthly', '월별'],
+              ] as const
+            ).map(([key, label]) => (
+              <label key={key} className="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-400">
+                <span>{label}</span>
+                <input
+                  type="number"
+                  min={key === 'daily' ? 1 : 0}
+                  value={settings.retention[key]}
+                  onChange={(e) => setRetention(key, Number(e.target.value))}
+                  className="input w-full"
+                />
+              </label>
+            ))}
+          </div>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            자동 백업은 날짜·주·월마다 가장 최근 것만 남기고 정리합니다. 직접 만든 백업은 지우지 않습니다.
+          </p>
+        </div>
+
+        <button
+          onClick={handleSave}
+          disabled={isSaving}
+          className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                     bg-indigo-600 hover:bg-indigo-700 text-white rounded-xl
+                     font-medium transition-colors
+                     disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isSaving ? <Loader2 className="w-4 h-4 animate-spin" /> : <Save className="w-4 h-4" />}
+          <span>저장</span>
+        </button>
+
+        {result && (
+          <div
+            className={`flex items-center gap-2 p-3 rounded-lg text-sm ${
+              result.success
+                ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+                : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+            }`}
+          >
+            {result.success ? <CheckCircle className="w-4 h-4 flex-shrink-0" /> : <XCircle className="w-4 h-4 flex-shrink-0" />}
+            <span>{result.message}</span>
+          </div>
+        )}
+
+        {/* 마지막 자동 백업 */}
+        {statuses.length > 0 && (
+          <div className="pt-4 border-t border-gray-200 dark:border-gray-700 space-y-2">
+            <h4 className="text-sm font-medium text-gray-700 dark:text-gray-300">마지막 자동 백업</h4>
+            {statuses.map((status) => (
+              <div key={status.service} className="text-xs text-gray-600 dark:text-gray-400">
+                <div className="flex items-center gap-1">
+                  {status.success ? (
+                    <CheckCircle className="w-3.5 h-3.5 text-green-600" />
+                  ) : (
+                    <XCircle className="w-3.5 h-3.5 text-red-600" />
+                  )}
+                  <span className="font-medium text-gray-800 dark:text-white">{SERVICE_LABELS[status.service]}</span>
+                  <span>
+                    · {TRIGGER_LABELS[status.trigger]} · {status.attemptedAt}
+                  </span>
+                </div>
+                {status.success ? (
+                  <p className="ml-5">
+                    {status.filename}
+                    {status.pruned > 0 && ` (오래된 백업 ${status.pruned}개 정리)`}
+                  </p>
+                ) : (
+                  <p className="ml-5 text-red-600">
+                    {status.error}
+                    {status.lastSuccessAt && ` · 마지막 성공 ${status.lastSuccessAt}`}
+                  </p>
+                )}
+              </div>
+            ))}
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
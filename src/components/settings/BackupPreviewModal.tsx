@@ -0,0 +1,286 @@
+import { useState, useEffect } from 'react';
+import { Loader2, XCircle, CheckCircle, AlertCircle } from 'lucide-react';
+import { backupApi } from '../../lib/tauri';
+import type {
+  BackupInfo,
+  BackupPreview,
+  CloudService,
+  SelectiveRestoreReport,
+} from '../../types';
+
+// 선택 복원할 수 있는 테이블 (백엔드 merge.rs 와 같은 순서)
+const MERGE_TABLES: { table: string; label: string; dated: boolean }[] = [
+  { table: 'designers', label: '디자이너', dated: false },
+  { table: 'customers', label: '고객', dated: false },
+  { table: 'reservations', label: '예약', dated: true },
+  { table: 'reservation_status_history', label: '예약 상태 이력', dated: true },
+  { table: 'time_blocks', label: '시간 차단', dated: true },
+  { table: 'holidays', label: '휴일', dated: false },
+  { table: 'business_hour_overrides', label: '특별 영업시간', dated: true },
+  { table: 'closure_periods', label: '휴업 기간', dated: true },
+];
+
+const RESOLUTION_LABELS: Record<string, string> = {
+  kept: '현재 값 유지',
+  overwritten: '백업 값으로 덮어씀',
+  failed: '실패',
+};
+
+interface BackupPreviewModalProps {
+  backup: BackupInfo;
+  service: CloudService;
+  onClose: () => void;
+  onRestored: () => void;
+}
+
+export function BackupPreviewModal({ backup, service, onClose, onRestored }: BackupPreviewModalProps) {
+  const [passphrase, setPassphrase] = useState('');
+  // 비밀번호 없는 .db 백업이 이 기기 키로 열리지 않으면 복구 키를 받는다
+  const [needsRecoveryKey, setNeedsRecoveryKey] = useState(false);
+  const [preview, setPreview] = useState<BackupPreview | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [isRestoring, setIsRestoring] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [selectedTables, setSelectedTables] = useState<string[]>([]);
+  const [dateFrom, setDateFrom] = useState('');
+  const [dateTo, setDateTo] = useState('');
+  const [overwrite, setOverwrite] = useState(false);
+  const [report, setReport] = useState<SelectiveRestoreReport | null>(null);
+
+  const loadPreview = async () => {
+    setIsLoading(true);
+    setError(null);
+    try {
+      const result = await backupApi.preview(
+        backup.filename,
+        service,
+        passphrase || undefined
+      );
+      setPreview(result);
+    } catch (e) {
+      setError(`미리보기 실패: ${e}`);
+      if (!backup.passphraseProtected) setNeedsRecoveryKey(true);
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  useEffect(() => {
+    // 비밀번호가 필요 없는 백업은 바로 연다
+    if (!backup.passphraseProtected) {
+      loadPreview();
+    }
+  }, [backup.filename]);
+
+  const rowsOf = (table: string) => preview?.tables.find((t) => t.table === table)?.rows ?? 0;
+
+  const toggleTable = (table: string) => {
+    setSelectedTables((prev) =>
+      prev.includes(table) ? prev.filter((t) => t !== table) : [...prev, table]
+    );
+  };
+
+  const handleRestore = async () => {
+    setIsRestoring(true);
+    setError(null);
+    try {
+      const result = await backupApi.restoreSelective(
+        backup.filename,
+        service,
+        {
+          tables: selectedTables,
+          dateFrom: dateFrom || undefined,
+          dateTo: dateTo || undefined,
+          overwrite,
+        },
+        passphrase || undefined
+      );
+      setReport(result);
+      onRestored();
+    } catch (e) {
+      setError(`선택 복원 실패: ${e}`);
+    } finally {
+      setIsRestoring(false);
+    }
+  };
+
+  const usesDateRange = selectedTables.some((t) => MERGE_TABLES.find((m) => m.table === t)?.dated);
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/50">
+      <div className="bg-white dark:bg-gray-800 rounded-2xl p-6 max-w-lg w-full shadow-xl max-h-[90vh] overflow-y-auto">
+        <h3 className="text-lg font-semibold text-gray-900 dark:text-white mb-1">백업 미리보기</h3>
+        <p className="text-xs text-gray-500 dark:text-gray-400 mb-4 truncate">{backup.filename}</p>
+
+        {(backup.passphraseProtected || needsRecoveryKey) && !preview && (
+          <div className="flex gap-2 mb-4">
+            <input
+              type="password"
+              value={passphrase}
+              onChange={(e) => setPassphrase(e.target.value)}
+              placeholder={backup.passphraseProtected ? '백업 비밀번호' : '복구 키 (백업을 만든 기기에서 내보낸 키)'}
+              className="input flex-1"
+              autoFocus
+            />
+            <button
+              onClick={loadPreview}
+              disabled={isLoading || passphrase.length === 0}
+              className="py-2 px-4 rounded-xl bg-indigo-600 hover:bg-indigo-700 text-white text-sm font-medium
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              열기
+            </button>
+          </div>
+        )}
+
+        {isLoading && (
+          <div className="flex items-center justify-center py-8">
+            <Loader2 className="w-6 h-6 animate-spin text-gray-400" />
+          </div>
+        )}
+
+        {error && (
+          <div className="flex items-center gap-2 p-3 mb-4 rounded-lg text-sm bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300">
+            <XCircle className="w-4 h-4 flex-shrink-0" />
+            <span>{error}</span>
+          </div>
+        )}
+
+        {preview && !report && (
+          <div className="space-y-4">
+            {/* 요약 */}
+            <dl className="grid grid-cols-2 gap-x-4 gap-y-1 text-sm">
+              <dt className="text-gray-500 dark:text-gray-400">스키마 버전</dt>
+              <dd className="text-gray-800 dark:text-white">{preview.schemaVersion}</dd>
+              <dt className="text-gray-500 dark:text-gray-400">예약 기간</dt>
+              <dd className="text-gray-800 dark:text-white">
+                {preview.reservationDateFrom
+                  ? `${preview.reservationDateFrom} ~ ${preview.reservationDateTo}`
+                  : '-'}
+              </dd>
+              <dt className="text-gray-500 dark:text-gray-400">최근 수정</dt>
+              <dd className="text-gray-800 dark:text-white">{preview.latestUpdatedAt ?? '-'}</dd>
+            </dl>
+
+            {/* 테이블 선택 */}
+            <div>
+              <h4 className="text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">선택 복원할 데이터</h4>
+              <div className="grid grid-cols-2 gap-1">
+                {MERGE_TABLES.map(({ table, label }) => (
+                  <label
+                    key={table}
+                    className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300 cursor-pointer"
+                  >
+                    <input
+                      type="checkbox"
+                      checked={selectedTables.includes(table)}
+                      onChange={() => toggleTable(table)}
+                      className="rounded border-gray-300 text-indigo-600 focus:ring-indigo-500"
+                    />
+                    <span>{label}</span>
+                    <span className="text-xs text-gray-400">{rowsOf(table)}건</span>
+                  </label>
+                ))}
+              </div>
+            </div>
+
+            {usesDateRange && (
+              <div>
+                <p className="text-xs text-gray-500 dark:text-gray-400 mb-1">
+                  기간을 지정하면 해당 날짜의 예약·일정만 복원합니다 (비워두면 전체)
+                </p>
+                <div className="grid grid-cols-2 gap-2">
+                  <input type="date" value={dateFrom} onChange={(e) => setDateFrom(e.target.value)} className="input" />
+                  <input type="date" value={dateTo} onChange={(e) => setDateTo(e.target.value)} className="input" />
+                </div>
+              </div>
+            )}
+
+            <label className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300 cursor-pointer">
+              <input
+                type="checkbox"
+                checked={overwrite}
+                onChange={(e) => setOverwrite(e.target.checked)}
+                className="rounded border-gray-300 text-indigo-600 focus:ring-indigo-500"
+              />
+              <span>같은 데이터가 있으면 백업 값으로 덮어쓰기</span>
+            </label>
+          </div>
+        )}
+
+        {report && (
+          <div className="space-y-3">
+            <div className="flex items-center gap-2 p-3 rounded-lg text-sm bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300">
+              <CheckCircle className="w-4 h-4 flex-shrink-0" />
+              <span>선택 복원 완료! 복원 전 데이터는 "{report.snapshotFilename}" 으로 보관했습니다.</span>
+            </div>
+            <table className="w-full text-xs text-gray-700 dark:text-gray-300">
+              <thead>
+                <tr className="text-gray-500 dark:text-gray-400">
+                  <th className="text-left font-medium py-1">데이터</th>
+                  <th className="text-right font-medium">추가</th>
+                  <th className="text-right font-medium">덮어씀</th>
+                  <th className="text-right font-medium">동일</th>
+                  <th className="text-right font-medium">유지</th>
+                  <th className="text-right font-medium">실패</th>
+                </tr>
+              </thead>
+              <tbody>
+                {report.tables.map((t) => (
+                  <tr key={t.table}>
+                    <td className="py-0.5">{MERGE_TABLES.find((m) => m.table === t.table)?.label ?? t.table}</td>
+                    <td className="text-right">{t.inserted}</td>
+                    <td className="text-right">{t.updated}</td>
+                    <td className="text-right">{t.unchanged}</td>
+                    <td className="text-right">{t.skipped}</td>
+                    <td className="text-right">{t.failed}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+            {report.conflicts.length > 0 && (
+              <div>
+                <h4 className="flex items-center gap-1 text-sm font-medium text-gray-700 dark:text-gray-300 mb-1">
+                  <AlertCircle className="w-4 h-4 text-amber-500" />
+                  충돌 {report.conflicts.length}건
+                </h4>
+                <ul className="max-h-40 overflow-y-auto text-xs text-gray-600 dark:text-gray-400 space-y-0.5">
+                  {report.conflicts.map((c) => (
+                    <li key={`${c.table}-${c.id}`}>
+                      {MERGE_TABLES.find((m) => m.table === c.table)?.label ?? c.table} · {c.id} ·{' '}
+                      {RESOLUTION_LABELS[c.resolution] ?? c.resolution}
+                      {c.detail && ` (${c.detail})`}
+                    </li>
+                  ))}
+                </ul>
+              </div>
+            )}
+          </div>
+        )}
+
+        <div className="flex gap-3 mt-6">
+          <button
+            onClick={onClose}
+            className="flex-1 py-2.5 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                       text-gray-700 dark:text-gray-300 font-medium
+                       hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+          >
+            {report ? '닫기' : '취소'}
+          </button>
+          {preview && !report && (
+            <button
+              onClick={handleRestore}
+              disabled={isRestoring || selectedTables.length === 0}
+              className="flex-1 flex items-center justify-center gap-2 py-2.5 px-4 rounded-xl font-medium text-white
+                         bg-indigo-600 hover:bg-indigo-700 transition-colors
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {isRestoring && <Loader2 className="w-4 h-4 animate-spin" />}
+              선택 복원
+            </button>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -10,10 +10,19 @@ import {
   XCircle,
   AlertCircle,
   Clock,
+  Lock,
+  Eye,
+  Server,
 } from 'lucide-react';
 import { backupApi } from '../../lib/tauri';
+import { BackupPreviewModal } from './BackupPreviewModal';
+import { AutoBackupSettings } from './AutoBackupSettings';
+import { BackupTargetSettings } from './BackupTargetSettings';
+import { RecoveryKeySettings } from './RecoveryKeySettings';
 import type { BackupInfo, CloudService } from '../../types';
 
+const MIN_PASSPHRASE_LENGTH = 8;
+
 interface ServiceOption {
   value: CloudService;
   label: string;
@@ -74,6 +83,14 @@ function useAvailableServices() {
         });
       }
 
+      // 폴더·WebDAV·S3 는 모든 기기에서 쓸 수 있다
+      availableServices.push({
+        value: 'external' as CloudService,
+        label: '외부 저장소',
+        icon: <Server className="w-4 h-4" />,
+        available: true,
+      });
+
       setServices(availableServices);
       setIsLoading(false);
     }
@@ -96,6 +113,12 @@ export function BackupSettings() {
     type: 'restore' | 'delete';
     backup: BackupInfo;
   } | null>(null);
+  // 다른 기기에서도 복원할 수 있도록 비밀번호 아카이브를 기본으로 한다
+  const [usePassphrase, setUsePassphrase] = useState(true);
+  const [passphrase, setPassphrase] = useState('');
+  const [passphraseConfirm, setPassphraseConfirm] = useState('');
+  const [restorePassphrase, setRestorePassphrase] = useState('');
+  const [previewBackup, setPreviewBackup] = useState<BackupInfo | null>(null);
 
   const { services, isLoading: isLoadingServices } = useAvailableServices();
   const currentService = services.find((s) => s.value === selectedService);
@@ -138,12 +161,23 @@ export function BackupSettings() {
   };
 
   const handleBackup = async () => {
+    if (usePassphrase) {
+      if (passphrase.length < MIN_PASSPHRASE_LENGTH) {
+        setMessage({ success: false, text: `백업 비밀번호는 ${MIN_PASSPHRASE_LENGTH}자 이상이어야 합니다.` });
+        return;
+      }
+      if (passphrase !== passphraseConfirm) {
+        setMessage({ success: false, text: '비밀번호 확인이 일치하지 않습니다.' });
+        return;
+      }
+    }
+
     setIsBackingUp(true);
     setMessage(null);
 
     try {
       console.log('[BackupSettings] Creating backup for service:', selectedService);
-      const backup = await backupApi.create(selectedService);
+      const backup = await backupApi.create(selectedService, usePassphrase ? passphrase : undefined);
       console.log('[BackupSettings] Backup created:', backup);
       setMessage({ success: true, text: `백업 완료: ${backup.filename}` });
       loadBackups();
@@ -156,6 +190,7 @@ export function BackupSettings() {
   };
 
   const handleRestoreClick = (backup: BackupInfo) => {
+    setRestorePassphrase('');
     setConfirmModal({ type: 'restore', backup });
   };
 
@@ -174,8 +209,16 @@ export function BackupSettings() {
       setMessage(null);
 
       try {
-        await backupApi.restore(backup.filename, selectedService);
-        setMessage({ success: true, text: '복원 완료! 앱을 다시 시작해주세요.' });
+        const result = await backupApi.restore(
+          backup.filename,
+          selectedService,
+          restorePassphrase || undefined
+        );
+        setMessage({
+          success: true,
+          text: `복원 완료! 복원 전 데이터는 로컬 백업 "${result.snapshotFilename}" 으로 보관했습니다.`,
+        });
+        loadBackups();
       } catch (error) {
         setMessage({ success: false, text: `복원 실패: ${error}` });
       } finally {
@@ -210,6 +253,16 @@ export function BackupSettings() {
 
   return (
     <>
+      {/* 미리보기·선택 복원 */}
+      {previewBackup && (
+        <BackupPreviewModal
+          backup={previewBackup}
+          service={selectedService}
+          onClose={() => setPreviewBackup(null)}
+          onRestored={loadBackups}
+        />
+      )}
+
       {/* 확인 모달 */}
       {confirmModal && (
         <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/50">
@@ -219,9 +272,23 @@ export function BackupSettings() {
             </h3>
             <p className="text-gray-600 dark:text-gray-400 mb-6">
               {confirmModal.type === 'restore'
-                ? `"${confirmModal.backup.filename}" 백업으로 복원하시겠습니까?\n\n현재 데이터가 덮어씌워집니다.`
+                ? `"${confirmModal.backup.filename}" 백업으로 복원하시겠습니까?\n\n현재 데이터가 덮어씌워집니다. (복원 전 데이터는 자동으로 로컬 백업됩니다)`
                 : `"${confirmModal.backup.filename}" 백업을 삭제하시겠습니까?`}
             </p>
+            {confirmModal.type === 'restore' && (
+              <input
+                type="password"
+                value={restorePassphrase}
+                onChange={(e) => setRestorePassphrase(e.target.value)}
+                placeholder={
+                  confirmModal.backup.passphraseProtected
+                    ? '백업 비밀번호'
+                    : '복구 키 (다른 기기에서 만든 백업인 경우)'
+                }
+                className="input w-full -mt-3 mb-6"
+                autoFocus={confirmModal.backup.passphraseProtected}
+              />
+            )}
             <div className="flex gap-3">
               <button
                 onClick={() => setConfirmModal(null)}
@@ -233,10 +300,16 @@ export function BackupSettings() {
               </button>
               <button
                 onClick={handleConfirm}
+                disabled={
+                  confirmModal.type === 'restore' &&
+                  confirmModal.backup.passphraseProtected &&
+                  restorePassphrase.length === 0
+                }
                 className={`flex-1 py-2.5 px-4 rounded-xl font-medium text-white transition-colors
                   ${confirmModal.type === 'restore'
                     ? 'bg-indigo-600 hover:bg-indigo-700'
-                    : 'bg-red-600 hover:bg-red-700'}`}
+                    : 'bg-red-600 hover:bg-red-700'}
+                  disabled:opacity-50 disabled:cursor-not-allowed`}
               >
                 {confirmModal.type === 'restore' ? '복원' : '삭제'}
               </button>
@@ -318,6 +391,49 @@ export function BackupSettings() {
           </div>
         )}
 
+        {/* 외부 저장소 설정 */}
+        {selectedService === 'external' && <BackupTargetSettings onSaved={loadBackups} />}
+
+        {/* 비밀번호 암호화 */}
+        <div className="space-y-2">
+          <label className="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={usePassphrase}
+              onChange={(e) => setUsePassphrase(e.target.checked)}
+              className="rounded border-gray-300 text-indigo-600 focus:ring-indigo-500"
+            />
+            <Lock className="w-4 h-4" />
+            <span>비밀번호로 암호화 (다른 기기에서 복원 가능)</span>
+          </label>
+          {!usePassphrase && (
+            <p className="text-xs text-amber-600 dark:text-amber-400">
+              이 기기의 키로만 암호화됩니다. 다른 기기에서 복원하려면 아래의 복구 키가 필요합니다
+            </p>
+          )}
+          {usePassphrase && (
+            <div className="grid grid-cols-2 gap-2">
+              <input
+                type="password"
+                value={passphrase}
+                onChange={(e) => setPassphrase(e.target.value)}
+                placeholder={`비밀번호 (${MIN_PASSPHRASE_LENGTH}자 이상)`}
+                className="input"
+              />
+              <input
+                type="password"
+                value={passphraseConfirm}
+                onChange={(e) => setPassphraseConfirm(e.target.value)}
+                placeholder="비밀번호 확인"
+                className="input"
+              />
+              <p className="col-span-2 text-xs text-amber-600 dark:text-amber-400">
+                비밀번호를 잊으면 백업을 복원할 수 없습니다
+              </p>
+            </div>
+          )}
+        </div>
+
         {/* 백업 버튼 */}
         <button
           onClick={handleBackup}
@@ -380,14 +496,27 @@ export function BackupSettings() {
                              bg-white/30 dark:bg-white/5 rounded-xl"
                 >
                   <div className="min-w-0 flex-1">
-                    <div className="font-medium text-sm text-gray-800 dark:text-white truncate">
-                      {backup.filename}
+                    <div className="flex items-center gap-1 font-medium text-sm text-gray-800 dark:text-white">
+                      {backup.passphraseProtected && (
+                        <Lock className="w-3.5 h-3.5 flex-shrink-0 text-indigo-500" aria-label="비밀번호 보호" />
+                      )}
+                      <span className="truncate">{backup.filename}</span>
                     </div>
                     <div className="text-xs text-gray-500 dark:text-gray-400">
                       {formatDate(backup.createdAt)} · {formatSize(backup.size)}
                     </div>
                   </div>
                   <div className="flex items-center gap-1 ml-2">
+                    <button
+                      onClick={() => setPreviewBackup(backup)}
+                      disabled={isRestoring !== null}
+                      className="p-2 rounded-lg hover:bg-white/50 dark:hover:bg-white/10
+                                 text-gray-600 dark:text-gray-400 transition-colors
+                                 disabled:opacity-50 disabled:cursor-not-allowed"
+                      title="미리보기 · 선택 복원"
+                    >
+                      <Eye className="w-4 h-4" />
+                    </button>
                     <button
                       onClick={() => handleRestoreClick(backup)}
                       disabled={isRestoring !== null}
@@ -435,6 +564,18 @@ export function BackupSettings() {
         </div>
       </div>
       </div>
+
+      {!isLoadingServices && (
+        <div className="mt-4">
+          <AutoBackupSettings
+            availableServices={services.filter((s) => s.available && !s.comingSoon).map((s) => s.value)}
+          />
+        </div>
+      )}
+
+      <div className="mt-4">
+        <RecoveryKeySettings />
+      </div>
     </>
   );
 }
@@ -0,0 +1,165 @@
+import { useState, useEffect } from 'react';
+import { Save, Loader2, CheckCircle, XCircle, PlugZap } from 'lucide-react';
+import { backupApi } from '../../lib/tauri';
+import type { BackupTargetConfig } from '../../types';
+
+type TargetType = BackupTargetConfig['type'];
+
+const EMPTY_CONFIGS: Record<TargetType, BackupTargetConfig> = {
+  none: { type: 'none' },
+  directory: { type: 'directory', path: '' },
+  webdav: { type: 'webdav', url: '', username: '', password: '' },
+  s3: { type: 's3', endpoint: '', region: '', bucket: '', prefix: '', accessKey: '', secretKey: '' },
+};
+
+interface BackupTargetSettingsProps {
+  // 설정을 저장한 뒤 백업 목록을 다시 불러온다
+  onSaved: () => void;
+}
+
+export function BackupTargetSettings({ onSaved }: BackupTargetSettingsProps) {
+  const [config, setConfig] = useState<BackupTargetConfig | null>(null);
+  const [isTesting, setIsTesting] = useState(false);
+  const [isSaving, setIsSaving] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  useEffect(() => {
+    backupApi.getTarget().then(setConfig).catch(() => setConfig(EMPTY_CONFIGS.none));
+  }, []);
+
+  if (!config) {
+    return (
+      <div className="flex justify-center py-4">
+        <Loader2 className="w-5 h-5 animate-spin text-gray-400" />
+      </div>
+    );
+  }
+
+  const handleTest = async () => {
+    setIsTesting(true);
+    setResult(null);
+    try {
+      const count = await backupApi.testTarget(config);
+      setResult({ success: true, message: `연결 성공 (백업 ${count}개)` });
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsTesting(false);
+    }
+  };
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    setResult(null);
+    try {
+      await backupApi.updateTarget(config);
+      setResult({ success: true, message: '외부 저장소 설정을 저장했습니다' });
+      onSaved();
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const field = (label: string, value: string, onChange: (value: string) => void, options?: { password?: boolean; placeholder?: string }) => (
+    <label className="block space-y-1">
+      <span className="text-xs text-gray-600 dark:text-gray-400">{label}</span>
+      <input
+        type={options?.password ? 'password' : 'text'}
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+        placeholder={options?.placeholder}
+        className="input w-full"
+      />
+    </label>
+  );
+
+  return (
+    <div className="space-y-3 p-3 rounded-lg border border-gray-200 dark:border-gray-700">
+      <div className="flex items-center justify-between">
+        <p className="text-sm font-medium text-gray-900 dark:text-white">외부 저장소</p>
+        <select
+          value={config.type}
+          onChange={(e) => {
+            setConfig(EMPTY_CONFIGS[e.target.value as TargetType]);
+            setResult(null);
+          }}
+          className="input w-36"
+        >
+          <option value="none">사용 안 함</option>
+          <option value="directory">폴더 (USB·NAS)</option>
+          <option value="webdav">WebDAV</option>
+          <option value="s3">S3 호환</option>
+        </select>
+      </div>
+
+      {config.type === 'directory' &&
+        field('폴더 경로', config.path, (path) => setConfig({ ...config, path }), {
+          placeholder: '/Volumes/USB/SalonBackup',
+        })}
+
+      {config.type === 'webdav' && (
+        <>
+          {field('주소', config.url, (url) => setConfig({ ...config, url }), {
+            placeholder: 'https://cloud.example.com/remote.php/dav/files/me/SalonBackup',
+          })}
+          <div className="grid grid-cols-2 gap-2">
+            {field('사용자 이름', config.username, (username) => setConfig({ ...config, username }))}
+            {field('비밀번호', config.password, (password) => setConfig({ ...config, password }), { password: true })}
+          </div>
+        </>
+      )}
+
+      {config.type === 's3' && (
+        <>
+          {field('엔드포인트', config.endpoint, (endpoint) => setConfig({ ...config, endpoint }), {
+            placeholder: 'http://nas.local:9000',
+          })}
+          <div className="grid grid-cols-2 gap-2">
+            {field('리전', config.region, (region) => setConfig({ ...config, region }), { placeholder: 'us-east-1' })}
+            {field('버킷', config.bucket, (bucket) => setConfig({ ...config, bucket }))}
+            {field('폴더 (선택)', config.prefix, (prefix) => setConfig({ ...config, prefix }), { placeholder: 'salon/' })}
+            {field('액세스 키', config.accessKey, (accessKey) => setConfig({ ...config, accessKey }))}
+          </div>
+          {field('시크릿 키', config.secretKey, (secretKey) => setConfig({ ...config, secretKey }), { password: true })}
+        </>
+      )}
+
+      <div className="flex gap-2">
+        <button
+          onClick={handleTest}
+          disabled={isTesting || config.type === 'none'}
+          className="flex-1 flex items-center justify-center gap-2 py-2 px-3 rounded-xl border border-gray-300 dark:border-gray-600
+                     text-sm text-gray-700 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors
+                     disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isTesting ? <Loader2 className="w-4 h-4 animate-spin" /> : <PlugZap className="w-4 h-4" />}
+          <span>연결 확인</span>
+        </button>
+        <button
+          onClick={handleSave}
+          disabled={isSaving}
+          className="flex-1 flex items-center justify-center gap-2 py-2 px-3 rounded-xl bg-indigo-600 hover:bg-indigo-700
+                     text-sm text-white font-medium transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isSaving ? <Loader2 className="w-4 h-4 animate-spin" /> : <Save className="w-4 h-4" />}
+          <span>저장</span>
+        </button>
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-center gap-2 p-2 rounded-lg text-xs ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? <CheckCircle className="w-4 h-4 flex-shrink-0" /> : <XCircle className="w-4 h-4 flex-shrink-0" />}
+          <span>{result.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
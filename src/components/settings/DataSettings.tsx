@@ -1,10 +1,16 @@
 import { useState } from 'react';
-import { ChevronRight, Cloud, FileSpreadsheet, Upload, ArrowLeft } from 'lucide-react';
+import { ChevronRight, Cloud, FileSpreadsheet, Upload, ArrowLeft, Server, Webhook, ShieldCheck, Smartphone, RefreshCw } from 'lucide-react';
 import { BackupSettings } from './BackupSettings';
 import { ExportSettings } from './ExportSettings';
+import { ImportSettings } from './ImportSettings';
+import { ApiSettings } from './ApiSettings';
+import { WebhookSettings } from './WebhookSettings';
+import { PrivacySettings } from './PrivacySettings';
+import { MigrationSettings } from './MigrationSettings';
+import { SyncSettings } from './SyncSettings';
 import { SwipeableView } from '../common/SwipeableView';
 
-type DataSubPage = 'menu' | 'backup' | 'export' | 'import';
+type DataSubPage = 'menu' | 'backup' | 'sync' | 'migration' | 'export' | 'import' | 'api' | 'webhooks' | 'privacy';
 
 interface MenuItem {
   id: DataSubPage;
@@ -24,21 +30,54 @@ const menuItems: MenuItem[] = [
     title: '백업',
     subtitle: '로컬 및 iCloud 백업, 복원',
   },
+  {
+    id: 'sync',
+    icon: <RefreshCw className="w-5 h-5" />,
+    iconBg: 'bg-sky-100 text-sky-600 dark:bg-sky-900/50 dark:text-sky-400',
+    title: '기기 간 동기화',
+    subtitle: '프런트와 디자이너 기기의 데이터 맞추기',
+  },
+  {
+    id: 'migration',
+    icon: <Smartphone className="w-5 h-5" />,
+    iconBg: 'bg-violet-100 text-violet-600 dark:bg-violet-900/50 dark:text-violet-400',
+    title: '기기 이전',
+    subtitle: '모든 데이터를 파일 하나로 옮기기',
+  },
   {
     id: 'export',
     icon: <FileSpreadsheet className="w-5 h-5" />,
     iconBg: 'bg-green-100 text-green-600 dark:bg-green-900/50 dark:text-green-400',
     title: '내보내기',
-    subtitle: 'Excel, CSV 파일로 내보내기',
+    subtitle: 'Excel, CSV, 캘린더 파일로 내보내기',
   },
   {
     id: 'import',
     icon: <Upload className="w-5 h-5" />,
     iconBg: 'bg-orange-100 text-orange-600 dark:bg-orange-900/50 dark:text-orange-400',
     title: '가져오기',
-    subtitle: '외부 데이터 가져오기',
-    disabled: true,
-    badge: '준비중',
+    subtitle: '캘린더(.ics) 일정 가져오기',
+  },
+  {
+    id: 'api',
+    icon: <Server className="w-5 h-5" />,
+    iconBg: 'bg-slate-100 text-slate-600 dark:bg-slate-800 dark:text-slate-300',
+    title: '로컬 API',
+    subtitle: '외부 프로그램 연동용 API 서버, API 키',
+  },
+  {
+    id: 'webhooks',
+    icon: <Webhook className="w-5 h-5" />,
+    iconBg: 'bg-pink-100 text-pink-600 dark:bg-pink-900/50 dark:text-pink-400',
+    title: '웹훅',
+    subtitle: '예약·고객 변경 시 다른 서비스로 알림 전송',
+  },
+  {
+    id: 'privacy',
+    icon: <ShieldCheck className="w-5 h-5" />,
+    iconBg: 'bg-emerald-100 text-emerald-600 dark:bg-emerald-900/50 dark:text-emerald-400',
+    title: '개인정보 보호',
+    subtitle: '동의서 버전, 보관 기간, 파기 기록',
   },
 ];
 
@@ -49,20 +88,20 @@ export function DataSettings() {
     switch (subPage) {
       case 'backup':
         return <BackupSettings />;
+      case 'sync':
+        return <SyncSettings />;
+      case 'migration':
+        return <MigrationSettings />;
       case 'export':
         return <ExportSettings />;
       case 'import':
-        return (
-          <div className="card p-8 text-center">
-            <Upload className="w-12 h-12 mx-auto mb-4 text-gray-400" />
-            <h3 className="font-semibold text-gray-800 dark:text-white mb-2">
-              가져오기 기능
-            </h3>
-            <p className="text-sm text-gray-500 dark:text-gray-400">
-              이 기능은 추후 업데이트에서 지원될 예정입니다.
-            </p>
-          </div>
-        );
+        return <ImportSettings />;
+      case 'api':
+        return <ApiSettings />;
+      case 'webhooks':
+        return <WebhookSettings />;
+      case 'privacy':
+        return <PrivacySettings />;
       default:
         return null;
     }
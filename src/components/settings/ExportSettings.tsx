@@ -1,7 +1,8 @@
-import { useState } from 'react';
-import { FileSpreadsheet, FileText, Download, Loader2, CheckCircle, XCircle } from 'lucide-react';
-import { exportApi } from '../../lib/tauri';
-import type { ExportPeriod } from '../../types';
+import { useState, useEffect } from 'react';
+import { format, startOfMonth, endOfMonth, subDays } from 'date-fns';
+import { FileSpreadsheet, FileText, CalendarDays, Download, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { exportApi, designerApi } from '../../lib/tauri';
+import type { ExportPeriod, Designer } from '../../types';
 
 const PERIOD_OPTIONS: { value: ExportPeriod; label: string }[] = [
   { value: 'this_month', label: '이번 달' },
@@ -9,10 +10,29 @@ const PERIOD_OPTIONS: { value: ExportPeriod; label: string }[] = [
   { value: 'all', label: '전체' },
 ];
 
+// 내보내기 기간을 .ics 내보내기용 날짜 범위로 변환
+function periodToRange(period: ExportPeriod): [string | undefined, string | undefined] {
+  const today = new Date();
+  switch (period) {
+    case 'this_month':
+      return [format(startOfMonth(today), 'yyyy-MM-dd'), format(endOfMonth(today), 'yyyy-MM-dd')];
+    case 'last_3_months':
+      return [format(subDays(today, 90), 'yyyy-MM-dd'), format(today, 'yyyy-MM-dd')];
+    default:
+      return [undefined, undefined];
+  }
+}
+
 export function ExportSettings() {
   const [isExporting, setIsExporting] = useState(false);
   const [exportPeriod, setExportPeriod] = useState<ExportPeriod>('this_month');
   const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+  const [designers, setDesigners] = useState<Designer[]>([]);
+  const [calendarDesignerId, setCalendarDesignerId] = useState<string>('');
+
+  useEffect(() => {
+    designerApi.getAll().then(setDesigners).catch(() => {});
+  }, []);
 
   const handleExportExcel = async () => {
     setIsExporting(true);
@@ -42,6 +62,21 @@ export function ExportSettings() {
     }
   };
 
+  const handleExportIcs = async () => {
+    setIsExporting(true);
+    setResult(null);
+
+    try {
+      const [dateFrom, dateTo] = periodToRange(exportPeriod);
+      const path = await exportApi.toIcs(calendarDesignerId || undefined, dateFrom, dateTo);
+      setResult({ success: true, message: `캘린더 파일이 저장되었습니다:\n${path}` });
+    } catch (error) {
+      setResult({ success: false, message: `내보내기 실패: ${error}` });
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
   return (
     <div className="glass-card">
       <div className="flex items-center gap-3 mb-6">
@@ -109,6 +144,46 @@ export function ExportSettings() {
           </button>
         </div>
 
+        {/* 캘린더 내보내기 */}
+        <div className="pt-2 border-t border-gray-200 dark:border-gray-700">
+          <label className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
+            캘린더 (.ics)
+          </label>
+          <div className="flex gap-3">
+            <select
+              value={calendarDesignerId}
+              onChange={(e) => setCalendarDesignerId(e.target.value)}
+              className="input flex-1"
+              disabled={isExporting}
+            >
+              <option value="">살롱 전체</option>
+              {designers.map((designer) => (
+                <option key={designer.id} value={designer.id}>
+                  {designer.name}
+                </option>
+              ))}
+            </select>
+            <button
+              onClick={handleExportIcs}
+              disabled={isExporting}
+              className="flex items-center justify-center gap-2 py-3 px-4
+                         bg-purple-600 hover:bg-purple-700 text-white rounded-xl
+                         font-medium transition-colors
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {isExporting ? (
+                <Loader2 className="w-4 h-4 animate-spin" />
+              ) : (
+                <CalendarDays className="w-4 h-4" />
+              )}
+              <span>ICS</span>
+            </button>
+          </div>
+          <p className="mt-1 text-xs text-gray-500 dark:text-gray-400">
+            다시 내보낸 파일을 캘린더 앱에 가져오면 기존 일정이 갱신됩니다
+          </p>
+        </div>
+
         {/* 결과 메시지 */}
         {result && (
           <div
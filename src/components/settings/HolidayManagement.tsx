@@ -2,6 +2,7 @@ import { useState, useEffect } from 'react';
 import {
   CalendarOff,
   Plus,
+  Download,
   Trash2,
   Loader2,
   CheckCircle,
@@ -65,6 +66,27 @@ export function HolidayManagement() {
     }
   };
 
+  const handleImportKoreanHolidays = async () => {
+    try {
+      const result = await businessHoursApi.importKoreanHolidays();
+      setMessage({
+        success: true,
+        text: `공휴일 ${result.added}개를 추가했습니다${result.updated > 0 ? ` (${result.updated}개 매년 반복으로 변경)` : ''}`,
+      });
+      loadHolidays();
+    } catch (error) {
+      setMessage({ success: false, text: '공휴일 불러오기에 실패했습니다' });
+    }
+  };
+
+  const formatHolidayDate = (holiday: Holiday): string => {
+    if (holiday.calendarType === 'lunar') {
+      const [year, month, day] = holiday.date.split('-').map(Number);
+      return holiday.isRecurring ? `음력 ${month}월 ${day}일` : `음력 ${year}년 ${month}월 ${day}일`;
+    }
+    return formatDate(holiday.date);
+  };
+
   const formatDate = (dateString: string): string => {
     const date = new Date(dateString);
     return date.toLocaleDateString('ko-KR', {
@@ -96,6 +118,18 @@ export function HolidayManagement() {
         휴일 추가
       </button>
 
+      {/* Import Korean Public Holidays */}
+      <button
+        onClick={handleImportKoreanHolidays}
+        className="w-full flex items-center justify-center gap-2 py-3 px-4 rounded-xl
+                   border border-gray-300 dark:border-gray-600
+                   text-gray-700 dark:text-gray-300 font-medium
+                   hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+      >
+        <Download className="w-4 h-4" />
+        공휴일 불러오기 (설날, 추석 등 음력 포함)
+      </button>
+
       {/* Add Holiday Form */}
       {showAddForm && (
         <div className="card p-4 space-y-4">
@@ -193,7 +227,7 @@ export function HolidayManagement() {
               >
                 <div>
                   <p className="font-medium text-gray-800 dark:text-white">
-                    {formatDate(holiday.date)}
+                    {formatHolidayDate(holiday)}
                   </p>
                   <div className="flex items-center gap-2 mt-0.5">
                     {holiday.description && (
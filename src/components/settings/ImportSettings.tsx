@@ -0,0 +1,285 @@
+import { useState, useEffect } from 'react';
+import { CalendarDays, FileUp, Eye, Upload, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { importApi, designerApi } from '../../lib/tauri';
+import type { Designer, IcsCalendarSummary, IcsFileInput, IcsImportResult, IcsImportAction } from '../../types';
+
+const ACTION_LABELS: Record<IcsImportAction, string> = {
+  create: '추가',
+  update: '변경',
+  unchanged: '동일',
+  skip: '건너뜀',
+};
+
+const PREVIEW_LIMIT = 50;
+
+export function ImportSettings() {
+  const [designers, setDesigners] = useState<Designer[]>([]);
+  const [files, setFiles] = useState<IcsFileInput[]>([]);
+  const [summaries, setSummaries] = useState<IcsCalendarSummary[]>([]);
+  const [mapping, setMapping] = useState<Record<string, string>>({});
+  const [dateFrom, setDateFrom] = useState('');
+  const [dateTo, setDateTo] = useState('');
+  const [preview, setPreview] = useState<IcsImportResult | null>(null);
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  useEffect(() => {
+    designerApi.getAll().then(setDesigners).catch(() => {});
+  }, []);
+
+  const handleFilesSelected = async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const selected = Array.from(event.target.files ?? []);
+    event.target.value = '';
+    if (selected.length === 0) return;
+
+    setIsWorking(true);
+    setResult(null);
+    setPreview(null);
+
+    try {
+      const loaded = await Promise.all(
+        selected.map(async (file) => ({ name: file.name, content: await file.text() }))
+      );
+      const inspected = await importApi.inspectIcs(loaded);
+      setFiles(loaded);
+      setSummaries(inspected);
+      setMapping(
+        Object.fromEntries(inspected.map((s) => [s.fileName, s.suggestedDesignerId ?? '']))
+      );
+    } catch (error) {
+      setResult({ success: false, message: `파일을 읽을 수 없습니다: ${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const runImport = async (dryRun: boolean) => {
+    setIsWorking(true);
+    setResult(null);
+
+    try {
+      const importResult = await importApi.importIcs({
+        calendars: files.map((file) => ({ ...file, designerId: mapping[file.name] || undefined })),
+        dateFrom: dateFrom || undefined,
+        dateTo: dateTo || undefined,
+        dryRun,
+      });
+      if (dryRun) {
+        setPreview(importResult);
+      } else {
+        setPreview(null);
+        setFiles([]);
+        setSummaries([]);
+        setResult({
+          success: true,
+          message: `가져오기 완료: 추가 ${importResult.created}건, 변경 ${importResult.updated}건, 건너뜀 ${importResult.skipped}건`,
+        });
+      }
+    } catch (error) {
+      setResult({ success: false, message: `가져오기 실패: ${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const designerName = (id?: string) => designers.find((d) => d.id === id)?.name ?? '-';
+
+  return (
+    <div className="glass-card">
+      <div className="flex items-center gap-3 mb-6">
+        <div className="p-2.5 rounded-xl bg-orange-100 text-orange-600 dark:bg-orange-900/30 dark:text-orange-400">
+          <CalendarDays className="w-5 h-5" />
+        </div>
+        <div>
+          <h3 className="font-semibold text-gray-800 dark:text-white">캘린더 가져오기</h3>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            Google 캘린더 등에서 내보낸 .ics 파일의 일정을 예약으로 가져옵니다
+          </p>
+        </div>
+      </div>
+
+      <div className="space-y-4">
+        {/* 파일 선택 */}
+        <label
+          className="flex items-center justify-center gap-2 py-3 px-4 rounded-xl cursor-pointer
+                     border-2 border-dashed border-gray-300 dark:border-gray-600
+                     text-gray-600 dark:text-gray-300 hover:border-orange-400 transition-colors"
+        >
+          {isWorking && files.length === 0 ? (
+            <Loader2 className="w-4 h-4 animate-spin" />
+          ) : (
+            <FileUp className="w-4 h-4" />
+          )}
+          <span className="text-sm font-medium">.ics 파일 선택</span>
+          <input
+            type="file"
+            accept=".ics,text/calendar"
+            multiple
+            className="hidden"
+            onChange={handleFilesSelected}
+            disabled={isWorking}
+          />
+        </label>
+
+        {/* 캘린더 → 디자이너 매핑 */}
+        {summaries.length > 0 && (
+          <div className="space-y-2">
+            <p className="text-sm font-medium text-gray-700 dark:text-gray-300">캘린더별 담당 디자이너</p>
+            {summaries.map((summary) => (
+              <div
+                key={summary.fileName}
+                className="flex items-center gap-3 p-3 rounded-lg bg-gray-50 dark:bg-gray-800/50"
+              >
+                <div className="flex-1 min-w-0">
+                  <p className="text-sm font-medium text-gray-800 dark:text-white truncate">
+                    {summary.calendarName || summary.fileName}
+                  </p>
+                  <p className="text-xs text-gray-500 dark:text-gray-400">
+                    일정 {summary.eventCount}건
+                    {summary.recurringCount > 0 && ` (반복 ${summary.recurringCount}개 포함)`}
+                    {summary.firstDate && ` · ${summary.firstDate} ~ ${summary.lastDate}`}
+                  </p>
+                </div>
+                <select
+                  value={mapping[summary.fileName] ?? ''}
+                  onChange={(e) => {
+                    setMapping({ ...mapping, [summary.fileName]: e.target.value });
+                    setPreview(null);
+                  }}
+                  className="input w-32"
+                  disabled={isWorking}
+                >
+                  <option value="">지정 안 함</option>
+                  {designers.map((designer) => (
+                    <option key={designer.id} value={designer.id}>
+                      {designer.name}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            ))}
+
+            {/* 기간 */}
+            <div className="grid grid-cols-2 gap-3">
+              <div>
+                <label className="block text-xs text-gray-500 dark:text-gray-400 mb-1">시작일 (선택)</label>
+                <input
+                  type="date"
+                  value={dateFrom}
+                  onChange={(e) => {
+                    setDateFrom(e.target.value);
+                    setPreview(null);
+                  }}
+                  className="input w-full"
+                />
+              </div>
+              <div>
+                <label className="block text-xs text-gray-500 dark:text-gray-400 mb-1">종료일 (선택)</label>
+                <input
+                  type="date"
+                  value={dateTo}
+                  onChange={(e) => {
+                    setDateTo(e.target.value);
+                    setPreview(null);
+                  }}
+                  className="input w-full"
+                />
+              </div>
+            </div>
+
+            <div className="grid grid-cols-2 gap-3">
+              <button
+                onClick={() => runImport(true)}
+                disabled={isWorking}
+                className="flex items-center justify-center gap-2 py-3 px-4
+                           bg-gray-600 hover:bg-gray-700 text-white rounded-xl
+                           font-medium transition-colors
+                           disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Eye className="w-4 h-4" />}
+                <span>미리보기</span>
+              </button>
+              <button
+                onClick={() => runImport(false)}
+                disabled={isWorking || !preview}
+                className="flex items-center justify-center gap-2 py-3 px-4
+                           bg-orange-600 hover:bg-orange-700 text-white rounded-xl
+                           font-medium transition-colors
+                           disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Upload className="w-4 h-4" />}
+                <span>가져오기</span>
+              </button>
+            </div>
+          </div>
+        )}
+
+        {/* 미리보기 결과 */}
+        {preview && (
+          <div className="space-y-2">
+            <p className="text-sm text-gray-700 dark:text-gray-300">
+              추가 {preview.created}건 · 변경 {preview.updated}건 · 동일 {preview.unchanged}건 · 건너뜀{' '}
+              {preview.skipped}건
+            </p>
+            <div className="max-h-72 overflow-y-auto rounded-lg border border-gray-200 dark:border-gray-700">
+              <table className="w-full text-xs">
+                <thead className="bg-gray-50 dark:bg-gray-800 text-gray-500 dark:text-gray-400">
+                  <tr>
+                    <th className="px-2 py-1.5 text-left">일시</th>
+                    <th className="px-2 py-1.5 text-left">고객</th>
+                    <th className="px-2 py-1.5 text-left">시술</th>
+                    <th className="px-2 py-1.5 text-left">디자이너</th>
+                    <th className="px-2 py-1.5 text-left">결과</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-gray-100 dark:divide-gray-700 text-gray-700 dark:text-gray-300">
+                  {preview.items.slice(0, PREVIEW_LIMIT).map((item, index) => (
+                    <tr key={`${item.reservationId ?? item.summary}-${index}`}>
+                      <td className="px-2 py-1.5 whitespace-nowrap">
+                        {item.date ? `${item.date} ${item.time}` : '-'}
+                      </td>
+                      <td className="px-2 py-1.5">
+                        {item.customerName || item.summary || '-'}
+                        {item.customerPhone && (
+                          <span className="block text-gray-400">{item.customerPhone}</span>
+                        )}
+                      </td>
+                      <td className="px-2 py-1.5">{item.serviceType || '-'}</td>
+                      <td className="px-2 py-1.5">{designerName(item.designerId)}</td>
+                      <td className="px-2 py-1.5" title={item.message}>
+                        {ACTION_LABELS[item.action]}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+            {preview.items.length > PREVIEW_LIMIT && (
+              <p className="text-xs text-gray-500 dark:text-gray-400">
+                외 {preview.items.length - PREVIEW_LIMIT}건
+              </p>
+            )}
+          </div>
+        )}
+
+        {/* 결과 메시지 */}
+        {result && (
+          <div
+            className={`flex items-start gap-2 p-3 rounded-lg text-sm ${
+              result.success
+                ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+                : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+            }`}
+          >
+            {result.success ? (
+              <CheckCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+            ) : (
+              <XCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+            )}
+            <span className="break-all">{result.message}</span>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
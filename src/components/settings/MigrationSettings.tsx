@@ -0,0 +1,257 @@
+import { useState } from 'react';
+import { PackageOpen, PackagePlus, Search, Loader2, CheckCircle, XCircle, KeyRound } from 'lucide-react';
+import { backupApi } from '../../lib/tauri';
+import type { BackupManifest } from '../../types';
+
+const MIN_PASSPHRASE_LENGTH = 8;
+
+// 화면 설정 등 localStorage 에만 있는 값도 함께 옮긴다
+function collectClientSettings(): Record<string, string> {
+  const settings: Record<string, string> = {};
+  for (let i = 0; i < localStorage.length; i++) {
+    const key = localStorage.key(i);
+    if (key) settings[key] = localStorage.getItem(key) ?? '';
+  }
+  return settings;
+}
+
+export function MigrationSettings() {
+  const [exportPassphrase, setExportPassphrase] = useState('');
+  const [exportConfirm, setExportConfirm] = useState('');
+  const [exportDest, setExportDest] = useState('');
+  const [importPath, setImportPath] = useState('');
+  const [importPassphrase, setImportPassphrase] = useState('');
+  const [manifest, setManifest] = useState<BackupManifest | null>(null);
+  const [confirmImport, setConfirmImport] = useState(false);
+  const [isWorking, setIsWorking] = useState(false);
+  const [pinResetRequired, setPinResetRequired] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  const handleExport = async () => {
+    if (exportPassphrase.length < MIN_PASSPHRASE_LENGTH) {
+      setResult({ success: false, message: `비밀번호는 ${MIN_PASSPHRASE_LENGTH}자 이상이어야 합니다.` });
+      return;
+    }
+    if (exportPassphrase !== exportConfirm) {
+      setResult({ success: false, message: '비밀번호 확인이 일치하지 않습니다.' });
+      return;
+    }
+
+    setIsWorking(true);
+    setResult(null);
+    try {
+      const exported = await backupApi.exportMigration(
+        exportPassphrase,
+        exportDest.trim() || undefined,
+        collectClientSettings()
+      );
+      setResult({
+        success: true,
+        message: `기기 이전 파일을 저장했습니다 (고객 ${exported.manifest.counts.customers}명, 예약 ${exported.manifest.counts.reservations}건):\n${exported.path}`,
+      });
+      setExportPassphrase('');
+      setExportConfirm('');
+    } catch (error) {
+      setResult({ success: false, message: `내보내기 실패: ${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleInspect = async () => {
+    setIsWorking(true);
+    setResult(null);
+    setManifest(null);
+    try {
+      setManifest(await backupApi.inspectMigration(importPath, importPassphrase));
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleImport = async () => {
+    setConfirmImport(false);
+    setIsWorking(true);
+    setResult(null);
+    try {
+      const imported = await backupApi.importMigration(importPath, importPassphrase);
+      if (imported.clientSettings) {
+        for (const [key, value] of Object.entries(imported.clientSettings)) {
+          localStorage.setItem(key, value);
+        }
+      }
+      setPinResetRequired(imported.pinResetRequired);
+      setManifest(null);
+      setImportPassphrase('');
+      setResult({
+        success: true,
+        message: `가져오기 완료! 이전 데이터는 로컬 백업 "${imported.snapshotFilename}" 으로 보관했습니다. 화면 설정을 적용하려면 앱을 다시 시작해주세요.`,
+      });
+    } catch (error) {
+      setResult({ success: false, message: `가져오기 실패: ${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  return (
+    <>
+      {/* 가져오기 확인 */}
+      {confirmImport && manifest && (
+        <div className="fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/50">
+          <div className="bg-white dark:bg-gray-800 rounded-2xl p-6 max-w-sm w-full shadow-xl">
+            <h3 className="text-lg font-semibold text-gray-900 dark:text-white mb-2">기기 이전 파일 가져오기</h3>
+            <p className="text-gray-600 dark:text-gray-400 mb-6">
+              이 기기의 모든 데이터가 파일의 데이터로 바뀝니다. (현재 데이터는 자동으로 로컬 백업됩니다)
+            </p>
+            <div className="flex gap-3">
+              <button
+                onClick={() => setConfirmImport(false)}
+                className="flex-1 py-2.5 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                           text-gray-700 dark:text-gray-300 font-medium
+                           hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+              >
+                취소
+              </button>
+              <button
+                onClick={handleImport}
+                className="flex-1 py-2.5 px-4 rounded-xl font-medium text-white bg-indigo-600 hover:bg-indigo-700 transition-colors"
+              >
+                가져오기
+              </button>
+            </div>
+          </div>
+        </div>
+      )}
+
+      <div className="glass-card space-y-6">
+        {/* 내보내기 */}
+        <div className="space-y-3">
+          <div>
+            <h4 className="font-medium text-gray-800 dark:text-white">이 기기에서 내보내기</h4>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              데이터, 첨부 파일, 설정을 비밀번호로 암호화한 파일 하나로 저장합니다. 잠금 PIN 은 옮겨지지 않습니다.
+            </p>
+          </div>
+          <div className="grid grid-cols-2 gap-2">
+            <input
+              type="password"
+              value={exportPassphrase}
+              onChange={(e) => setExportPassphrase(e.target.value)}
+              placeholder={`비밀번호 (${MIN_PASSPHRASE_LENGTH}자 이상)`}
+              className="input"
+            />
+            <input
+              type="password"
+              value={exportConfirm}
+              onChange={(e) => setExportConfirm(e.target.value)}
+              placeholder="비밀번호 확인"
+              className="input"
+            />
+          </div>
+          <input
+            type="text"
+            value={exportDest}
+            onChange={(e) => setExportDest(e.target.value)}
+            placeholder="저장할 폴더 또는 파일 경로 (비우면 다운로드 폴더)"
+            className="input w-full"
+          />
+          <button
+            onClick={handleExport}
+            disabled={isWorking || exportPassphrase.length === 0}
+            className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                       bg-indigo-600 hover:bg-indigo-700 text-white rounded-xl
+                       font-medium transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <PackagePlus className="w-4 h-4" />}
+            <span>기기 이전 파일 만들기</span>
+          </button>
+        </div>
+
+        {/* 가져오기 */}
+        <div className="pt-4 border-t border-gray-200 dark:border-gray-700 space-y-3">
+          <div>
+            <h4 className="font-medium text-gray-800 dark:text-white">새 기기로 가져오기</h4>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              이전 기기에서 만든 파일의 경로와 비밀번호를 입력하세요
+            </p>
+          </div>
+          <input
+            type="text"
+            value={importPath}
+            onChange={(e) => {
+              setImportPath(e.target.value);
+              setManifest(null);
+            }}
+            placeholder="/Users/me/Downloads/sisters_salon_migration_20260101_120000.ssbak"
+            className="input w-full"
+          />
+          <input
+            type="password"
+            value={importPassphrase}
+            onChange={(e) => {
+              setImportPassphrase(e.target.value);
+              setManifest(null);
+            }}
+            placeholder="비밀번호"
+            className="input w-full"
+          />
+          <button
+            onClick={handleInspect}
+            disabled={isWorking || !importPath.trim() || importPassphrase.length === 0}
+            className="w-full flex items-center justify-center gap-2 py-2.5 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                       text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Search className="w-4 h-4" />}
+            <span>파일 확인</span>
+          </button>
+
+          {manifest && (
+            <div className="p-3 rounded-lg bg-gray-50 dark:bg-gray-800/50 text-sm space-y-2">
+              <p className="text-gray-700 dark:text-gray-300">
+                앱 버전 {manifest.appVersion} · {new Date(manifest.createdAt).toLocaleString('ko-KR')}
+              </p>
+              <p className="text-gray-700 dark:text-gray-300">
+                고객 {manifest.counts.customers}명 · 예약 {manifest.counts.reservations}건 · 디자이너{' '}
+                {manifest.counts.designers}명
+              </p>
+              <button
+                onClick={() => setConfirmImport(true)}
+                disabled={isWorking}
+                className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                           bg-indigo-600 hover:bg-indigo-700 text-white rounded-xl
+                           font-medium transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                <PackageOpen className="w-4 h-4" />
+                <span>가져오기</span>
+              </button>
+            </div>
+          )}
+        </div>
+
+        {pinResetRequired && (
+          <div className="flex items-center gap-2 p-3 rounded-lg bg-amber-100 text-amber-800 dark:bg-amber-900/30 dark:text-amber-300 text-sm">
+            <KeyRound className="w-4 h-4 flex-shrink-0" />
+            <span>이전 기기에서 앱 잠금을 사용했습니다. 보안 설정에서 PIN 을 다시 설정해주세요.</span>
+          </div>
+        )}
+
+        {result && (
+          <div
+            className={`flex items-start gap-2 p-3 rounded-lg text-sm whitespace-pre-line ${
+              result.success
+                ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+                : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+            }`}
+          >
+            {result.success ? <CheckCircle className="w-4 h-4 flex-shrink-0 mt-0.5" /> : <XCircle className="w-4 h-4 flex-shrink-0 mt-0.5" />}
+            <span>{result.message}</span>
+          </div>
+        )}
+      </div>
+    </>
+  );
+}
@@ -0,0 +1,232 @@
+import { useState, useEffect } from 'react';
+import { ShieldCheck, Eraser, Save, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { privacyApi } from '../../lib/tauri';
+import type { PrivacyAuditReport, PrivacySettings as Settings, RetentionCandidate } from '../../types';
+
+const TOGGLE_CLASS =
+  "w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-emerald-300 dark:peer-focus:ring-emerald-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-emerald-600";
+
+export function PrivacySettings() {
+  const [settings, setSettings] = useState<Settings>({ retentionYears: 3, autoPurge: false, consentVersion: 'v1' });
+  const [candidates, setCandidates] = useState<RetentionCandidate[] | null>(null);
+  const [report, setReport] = useState<PrivacyAuditReport | null>(null);
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  const loadReport = () => privacyApi.getAuditReport().then(setReport).catch(() => {});
+
+  useEffect(() => {
+    privacyApi.getSettings().then(setSettings).catch(() => {});
+    loadReport();
+  }, []);
+
+  const run = async (action: () => Promise<void>) => {
+    setIsWorking(true);
+    setResult(null);
+    try {
+      await action();
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleSave = () =>
+    run(async () => {
+      await privacyApi.updateSettings(settings);
+      setCandidates(null);
+      setResult({ success: true, message: '개인정보 보호 설정을 저장했습니다' });
+    });
+
+  const handlePreview = () => run(async () => setCandidates(await privacyApi.previewPurge()));
+
+  const handlePurge = () => {
+    if (!candidates || candidates.length === 0) return;
+    if (!confirm(`고객 ${candidates.length}명의 개인정보를 익명 처리합니다. 되돌릴 수 없습니다. 계속하시겠습니까?`)) return;
+    run(async () => {
+      const purged = await privacyApi.runPurge();
+      setCandidates(null);
+      setResult({
+        success: true,
+        message: `고객 ${purged.customers}명, 예약 ${purged.reservations}건을 익명 처리하고 메시지 ${purged.messages}건을 삭제했습니다`,
+      });
+      await loadReport();
+    });
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="glass-card">
+        <div className="flex items-center gap-3 mb-6">
+          <div className="p-2.5 rounded-xl bg-emerald-100 text-emerald-600 dark:bg-emerald-900/30 dark:text-emerald-400">
+            <ShieldCheck className="w-5 h-5" />
+          </div>
+          <div>
+            <h3 className="font-semibold text-gray-800 dark:text-white">개인정보 보호</h3>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              오래 방문하지 않은 고객의 개인정보를 익명 처리합니다. 예약 통계는 유지됩니다
+            </p>
+          </div>
+        </div>
+
+        <div className="space-y-4">
+          <div className="flex items-center justify-between">
+            <div>
+              <p className="text-sm font-medium text-gray-900 dark:text-white">동의서 버전</p>
+              <p className="text-xs text-gray-500 dark:text-gray-400">동의를 받을 때 함께 기록됩니다</p>
+            </div>
+            <input
+              type="text"
+              value={settings.consentVersion}
+              onChange={(e) => setSettings({ ...settings, consentVersion: e.target.value })}
+              className="input w-28"
+            />
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div>
+              <p className="text-sm font-medium text-gray-900 dark:text-white">보관 기간</p>
+              <p className="text-xs text-gray-500 dark:text-gray-400">마지막 방문·예약 이후</p>
+            </div>
+            <select
+              value={settings.retentionYears}
+              onChange={(e) => setSettings({ ...settings, retentionYears: Number(e.target.value) })}
+              className="input w-28"
+            >
+              {[1, 2, 3, 4, 5, 7, 10].map((years) => (
+                <option key={years} value={years}>
+                  {years}년
+                </option>
+              ))}
+            </select>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div>
+              <p className="text-sm font-medium text-gray-900 dark:text-white">자동 파기</p>
+              <p className="text-xs text-gray-500 dark:text-gray-400">앱을 시작할 때 기간이 지난 고객을 익명 처리</p>
+            </div>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={settings.autoPurge}
+                onChange={(e) => setSettings({ ...settings, autoPurge: e.target.checked })}
+                className="sr-only peer"
+              />
+              <div className={TOGGLE_CLASS} />
+            </label>
+          </div>
+
+          <button
+            onClick={handleSave}
+            disabled={isWorking}
+            className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                       bg-emerald-600 hover:bg-emerald-700 text-white rounded-xl
+                       font-medium transition-colors
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Save className="w-4 h-4" />}
+            <span>저장</span>
+          </button>
+        </div>
+      </div>
+
+      {/* 지금 파기 */}
+      <div className="glass-card space-y-3">
+        <div className="flex items-center justify-between">
+          <h3 className="font-semibold text-gray-800 dark:text-white">보관 기간 지난 고객</h3>
+          <button
+            onClick={handlePreview}
+            disabled={isWorking}
+            className="text-sm text-emerald-600 hover:underline disabled:opacity-50"
+          >
+            확인하기
+          </button>
+        </div>
+        {candidates &&
+          (candidates.length === 0 ? (
+            <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">익명 처리할 고객이 없습니다</p>
+          ) : (
+            <>
+              <div className="max-h-60 overflow-y-auto divide-y divide-gray-100 dark:divide-gray-700 text-sm">
+                {candidates.map((candidate) => (
+                  <div key={candidate.customerId} className="flex justify-between py-1.5 text-gray-700 dark:text-gray-300">
+                    <span>{candidate.maskedName}</span>
+                    <span className="text-xs text-gray-500">
+                      마지막 {candidate.lastActivity} · 예약 {candidate.reservationCount}건
+                    </span>
+                  </div>
+                ))}
+              </div>
+              <button
+                onClick={handlePurge}
+                disabled={isWorking}
+                className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                           bg-red-600 hover:bg-red-700 text-white rounded-xl font-medium transition-colors
+                           disabled:opacity-50 disabled:cursor-not-allowed"
+              >
+                <Eraser className="w-4 h-4" />
+                <span>{candidates.length}명 익명 처리</span>
+              </button>
+            </>
+          ))}
+      </div>
+
+      {/* 파기 기록 */}
+      <div className="glass-card space-y-3">
+        <h3 className="font-semibold text-gray-800 dark:text-white">파기 기록</h3>
+        {report && report.entries.length > 0 ? (
+          <>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              {report.runs}회 실행 · 고객 {report.customers}명 · 예약 {report.reservations}건 · 메시지 {report.messages}건
+            </p>
+            <div className="max-h-72 overflow-y-auto rounded-lg border border-gray-200 dark:border-gray-700">
+              <table className="w-full text-xs">
+                <thead className="bg-gray-50 dark:bg-gray-800 text-gray-500 dark:text-gray-400">
+                  <tr>
+                    <th className="px-2 py-1.5 text-left">시각</th>
+                    <th className="px-2 py-1.5 text-left">방식</th>
+                    <th className="px-2 py-1.5 text-left">사유</th>
+                    <th className="px-2 py-1.5 text-right">예약/메시지</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-gray-100 dark:divide-gray-700 text-gray-700 dark:text-gray-300">
+                  {report.entries.map((entry) => (
+                    <tr key={entry.id}>
+                      <td className="px-2 py-1.5 whitespace-nowrap">{entry.performedAt}</td>
+                      <td className="px-2 py-1.5">{entry.trigger === 'auto' ? '자동' : entry.trigger === 'sync' ? '다른 기기' : '수동'}</td>
+                      <td className="px-2 py-1.5">{entry.details.reason ?? entry.action}</td>
+                      <td className="px-2 py-1.5 text-right">
+                        {entry.details.reservations ?? 0}/{entry.details.messages ?? 0}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </>
+        ) : (
+          <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">파기 기록이 없습니다</p>
+        )}
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-start gap-2 p-3 rounded-lg text-sm ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? (
+            <CheckCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          ) : (
+            <XCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          )}
+          <span className="break-all">{result.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,100 @@
+import { useState } from 'react';
+import { KeyRound, Copy, Loader2, XCircle } from 'lucide-react';
+import { securityApi } from '../../lib/tauri';
+
+/**
+ * 데이터베이스 암호화 키를 복구 키로 보여준다.
+ * 비밀번호 없이 만든 .db 백업을 다른 기기에서 복원하거나 salon-admin 으로 열 때 필요하다.
+ */
+export function RecoveryKeySettings() {
+  const [pin, setPin] = useState('');
+  const [recoveryKey, setRecoveryKey] = useState<string | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [copied, setCopied] = useState(false);
+
+  const handleExport = async () => {
+    setIsLoading(true);
+    setError(null);
+    try {
+      const lockEnabled = await securityApi.isLockEnabled();
+      if (lockEnabled && pin.length === 0) {
+        setError('잠금 PIN 을 입력해주세요');
+        return;
+      }
+      setRecoveryKey(await securityApi.exportDatabaseKey(lockEnabled ? pin : undefined));
+      setPin('');
+    } catch (e) {
+      setError(`복구 키를 불러오지 못했습니다: ${e}`);
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  const handleCopy = async () => {
+    if (!recoveryKey) return;
+    await navigator.clipboard.writeText(recoveryKey);
+    setCopied(true);
+  };
+
+  return (
+    <div className="glass-card">
+      <div className="flex items-center gap-3 mb-6">
+        <div className="p-2.5 rounded-xl bg-amber-100 text-amber-600 dark:bg-amber-900/30 dark:text-amber-400">
+          <KeyRound className="w-5 h-5" />
+        </div>
+        <div>
+          <h3 className="font-semibold text-gray-800 dark:text-white">복구 키</h3>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            비밀번호 없이 만든 백업은 이 기기의 키로 암호화됩니다. 기기를 바꾸거나 초기화하면 복구 키가 있어야 복원할 수 있습니다
+          </p>
+        </div>
+      </div>
+
+      {recoveryKey ? (
+        <div className="space-y-2">
+          <div className="flex items-center gap-2">
+            <code className="flex-1 min-w-0 p-2 rounded-lg bg-gray-100 dark:bg-gray-800 text-xs break-all text-gray-700 dark:text-gray-300">
+              {recoveryKey}
+            </code>
+            <button onClick={handleCopy} className="btn btn-ghost btn-sm btn-icon" title="복사">
+              <Copy className="w-4 h-4" />
+            </button>
+          </div>
+          <p className="text-xs text-amber-600 dark:text-amber-400">
+            {copied ? '복사했습니다. ' : ''}이 키가 있으면 누구나 백업을 열 수 있으니 안전한 곳에 따로 보관하세요
+          </p>
+          <button onClick={() => { setRecoveryKey(null); setCopied(false); }} className="btn btn-ghost btn-sm">
+            숨기기
+          </button>
+        </div>
+      ) : (
+        <div className="flex gap-2">
+          <input
+            type="password"
+            inputMode="numeric"
+            value={pin}
+            onChange={(e) => setPin(e.target.value)}
+            placeholder="잠금 PIN (설정한 경우)"
+            className="input flex-1"
+          />
+          <button
+            onClick={handleExport}
+            disabled={isLoading}
+            className="py-2 px-4 rounded-xl bg-indigo-600 hover:bg-indigo-700 text-white text-sm font-medium
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isLoading ? <Loader2 className="w-4 h-4 animate-spin" /> : '복구 키 보기'}
+          </button>
+        </div>
+      )}
+
+      {error && (
+        <div className="flex items-center gap-2 mt-3 text-sm text-red-600 dark:text-red-400">
+          <XCircle className="w-4 h-4 flex-shrink-0" />
+          <span>{error}</span>
+        </div>
+      )}
+    </div>
+  );
+}
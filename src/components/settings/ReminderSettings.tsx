@@ -0,0 +1,354 @@
+import { useState, useEffect } from 'react';
+import { BellRing, Send, Save, RotateCcw, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { reminderApi } from '../../lib/tauri';
+import type { Reminder, ReminderProviderConfig, ReminderSettings as Settings, ReminderStatus } from '../../types';
+
+const TOGGLE_CLASS =
+  "w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-rose-300 dark:peer-focus:ring-rose-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-rose-600";
+
+const TEMPLATE_VARIABLES = ['고객명', '날짜', '시간', '디자이너', '시술', '살롱명'];
+
+const STATUS_LABELS: Record<ReminderStatus, string> = {
+  pending: '대기',
+  sent: '발송',
+  failed: '실패',
+  cancelled: '예약 취소',
+  skipped: '건너뜀',
+};
+
+const STATUS_COLORS: Record<ReminderStatus, string> = {
+  pending: 'text-gray-600 dark:text-gray-300',
+  sent: 'text-green-600',
+  failed: 'text-red-600',
+  cancelled: 'text-gray-400',
+  skipped: 'text-gray-400',
+};
+
+const EMPTY_PROVIDERS: Record<ReminderProviderConfig['type'], ReminderProviderConfig> = {
+  file: { type: 'file' },
+  sms: { type: 'sms', apiUrl: '', apiKey: '', sender: '' },
+  alimtalk: { type: 'alimtalk', apiUrl: '', apiKey: '', senderKey: '', templateCode: '' },
+};
+
+export function ReminderSettings() {
+  const [settings, setSettings] = useState<Settings | null>(null);
+  const [preview, setPreview] = useState('');
+  const [testPhone, setTestPhone] = useState('');
+  const [reminders, setReminders] = useState<Reminder[]>([]);
+  const [statusFilter, setStatusFilter] = useState<ReminderStatus | ''>('');
+  const [isSender, setIsSender] = useState(true);
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  const loadReminders = () =>
+    reminderApi
+      .getAll(undefined, undefined, statusFilter || undefined)
+      .then(setReminders)
+      .catch(() => {});
+
+  useEffect(() => {
+    reminderApi.getSettings().then(setSettings).catch(() => {});
+    reminderApi.isSender().then(setIsSender).catch(() => {});
+  }, []);
+
+  useEffect(() => {
+    loadReminders();
+  }, [statusFilter]);
+
+  // 입력할 때마다 예시 문구 갱신
+  useEffect(() => {
+    if (!settings) return;
+    reminderApi.preview(settings).then(setPreview).catch(() => setPreview(''));
+  }, [settings?.template, settings?.salonName]);
+
+  if (!settings) {
+    return (
+      <div className="flex justify-center py-8">
+        <Loader2 className="w-6 h-6 animate-spin text-gray-400" />
+      </div>
+    );
+  }
+
+  const run = async (action: () => Promise<unknown>, successMessage: string) => {
+    setIsWorking(true);
+    setResult(null);
+    try {
+      await action();
+      setResult({ success: true, message: successMessage });
+      await loadReminders();
+      setIsSender(await reminderApi.isSender());
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const setProvider = (provider: ReminderProviderConfig) => setSettings({ ...settings, provider });
+
+  const providerField = (key: string, label: string, type = 'text') => {
+    const provider = settings.provider as unknown as Record<string, string>;
+    return (
+      <input
+        type={type}
+        value={provider[key] ?? ''}
+        onChange={(e) => setProvider({ ...settings.provider, [key]: e.target.value } as ReminderProviderConfig)}
+        placeholder={label}
+        className="input w-full"
+      />
+    );
+  };
+
+  const handleSendTest = () =>
+    run(
+      () => reminderApi.sendTest(settings, testPhone),
+      settings.provider.type === 'file' ? '앱 데이터 폴더의 reminders.log 에 기록했습니다' : '테스트 메시지를 보냈습니다'
+    );
+
+  return (
+    <div className="space-y-4">
+      <div className="glass-card">
+        <div className="flex items-center gap-3 mb-6">
+          <div className="p-2.5 rounded-xl bg-rose-100 text-rose-600 dark:bg-rose-900/30 dark:text-rose-400">
+            <BellRing className="w-5 h-5" />
+          </div>
+          <div>
+            <h3 className="font-semibold text-gray-800 dark:text-white">예약 알림</h3>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              예약 시간 전에 고객에게 안내 메시지를 자동으로 보냅니다
+            </p>
+          </div>
+        </div>
+
+        <div className="space-y-4">
+          <div className="flex items-center justify-between">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">자동 발송</p>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={settings.enabled}
+                onChange={(e) => setSettings({ ...settings, enabled: e.target.checked })}
+                className="sr-only peer"
+              />
+              <div className={TOGGLE_CLASS} />
+            </label>
+          </div>
+
+          {settings.enabled && !isSender && (
+            <div className="flex items-center justify-between gap-3 p-3 rounded-xl bg-amber-50 dark:bg-amber-900/20">
+              <p className="text-xs text-amber-700 dark:text-amber-400">
+                동기화하는 다른 기기에서 알림을 보내고 있어 이 기기에서는 보내지 않습니다
+              </p>
+              <button
+                onClick={() => run(() => reminderApi.claimSender(), '이제 이 기기에서 알림을 보냅니다')}
+                disabled={isWorking}
+                className="btn btn-ghost btn-sm flex-shrink-0"
+              >
+                이 기기에서 보내기
+              </button>
+            </div>
+          )}
+
+          <div className="flex items-center justify-between">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">예약 몇 시간 전</p>
+            <input
+              type="number"
+              min={1}
+              max={168}
+              value={settings.hoursBefore}
+              onChange={(e) => setSettings({ ...settings, hoursBefore: Number(e.target.value) })}
+              className="input w-24"
+            />
+          </div>
+
+          <div className="space-y-1">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">살롱 이름</p>
+            <input
+              type="text"
+              value={settings.salonName}
+              onChange={(e) => setSettings({ ...settings, salonName: e.target.value })}
+              className="input w-full"
+            />
+          </div>
+
+          <div className="space-y-1">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">문구</p>
+            <textarea
+              value={settings.template}
+              onChange={(e) => setSettings({ ...settings, template: e.target.value })}
+              rows={4}
+              className="input w-full resize-none"
+            />
+            <div className="flex flex-wrap gap-1">
+              {TEMPLATE_VARIABLES.map((variable) => (
+                <button
+                  key={variable}
+                  onClick={() => setSettings({ ...settings, template: `${settings.template}#{${variable}}` })}
+                  className="px-2 py-0.5 rounded bg-gray-100 dark:bg-gray-800 text-xs text-gray-600 dark:text-gray-300"
+                >
+                  {`#{${variable}}`}
+                </button>
+              ))}
+            </div>
+            {preview && (
+              <p className="p-3 rounded-lg bg-gray-50 dark:bg-gray-800/50 text-xs text-gray-600 dark:text-gray-300 whitespace-pre-wrap">
+                {preview}
+              </p>
+            )}
+          </div>
+
+          <div className="space-y-2">
+            <p className="text-sm font-medium text-gray-900 dark:text-white">발송 수단</p>
+            <select
+              value={settings.provider.type}
+              onChange={(e) => setProvider(EMPTY_PROVIDERS[e.target.value as ReminderProviderConfig['type']])}
+              className="input w-full"
+            >
+              <option value="file">파일 기록 (발송하지 않음)</option>
+              <option value="sms">문자 (SMS/LMS)</option>
+              <option value="alimtalk">카카오 알림톡</option>
+            </select>
+            {settings.provider.type !== 'file' && (
+              <>
+                {providerField('apiUrl', 'API 주소 (https://...)', 'url')}
+                {providerField('apiKey', 'API 키', 'password')}
+              </>
+            )}
+            {settings.provider.type === 'sms' && providerField('sender', '발신번호 (사전 등록된 번호)')}
+            {settings.provider.type === 'alimtalk' && (
+              <>
+                {providerField('senderKey', '발신 프로필 키')}
+                {providerField('templateCode', '승인된 템플릿 코드')}
+              </>
+            )}
+          </div>
+
+          <button
+            onClick={() => run(() => reminderApi.updateSettings(settings), '알림 설정을 저장했습니다')}
+            disabled={isWorking}
+            className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                       bg-rose-600 hover:bg-rose-700 text-white rounded-xl
+                       font-medium transition-colors
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Save className="w-4 h-4" />}
+            <span>저장</span>
+          </button>
+
+          <div className="flex items-center gap-2">
+            <input
+              type="tel"
+              value={testPhone}
+              onChange={(e) => setTestPhone(e.target.value)}
+              placeholder="테스트 받을 번호"
+              className="input flex-1"
+            />
+            <button
+              onClick={handleSendTest}
+              disabled={isWorking || !testPhone.trim()}
+              className="flex items-center gap-1 py-2 px-3 bg-gray-100 hover:bg-gray-200 dark:bg-gray-800 dark:hover:bg-gray-700
+                         text-gray-700 dark:text-gray-200 rounded-lg text-sm font-medium transition-colors
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              <Send className="w-4 h-4" />
+              <span>테스트</span>
+            </button>
+          </div>
+        </div>
+      </div>
+
+      {/* 알림 목록 */}
+      <div className="glass-card">
+        <div className="flex items-center justify-between mb-4">
+          <h3 className="font-semibold text-gray-800 dark:text-white">알림 목록</h3>
+          <div className="flex items-center gap-2">
+            <select
+              value={statusFilter}
+              onChange={(e) => setStatusFilter(e.target.value as ReminderStatus | '')}
+              className="input py-1 text-xs"
+            >
+              <option value="">전체</option>
+              {(Object.keys(STATUS_LABELS) as ReminderStatus[]).map((status) => (
+                <option key={status} value={status}>
+                  {STATUS_LABELS[status]}
+                </option>
+              ))}
+            </select>
+            <button
+              onClick={loadReminders}
+              className="p-1.5 text-gray-400 hover:text-gray-700 dark:hover:text-white"
+              title="새로고침"
+            >
+              <RotateCcw className="w-4 h-4" />
+            </button>
+          </div>
+        </div>
+        {reminders.length === 0 ? (
+          <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">알림이 없습니다</p>
+        ) : (
+          <div className="max-h-72 overflow-y-auto rounded-lg border border-gray-200 dark:border-gray-700">
+            <table className="w-full text-xs">
+              <thead className="bg-gray-50 dark:bg-gray-800 text-gray-500 dark:text-gray-400">
+                <tr>
+                  <th className="px-2 py-1.5 text-left">예약</th>
+                  <th className="px-2 py-1.5 text-left">고객</th>
+                  <th className="px-2 py-1.5 text-left">발송 예정</th>
+                  <th className="px-2 py-1.5 text-left">상태</th>
+                  <th className="px-2 py-1.5" />
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-gray-100 dark:divide-gray-700 text-gray-700 dark:text-gray-300">
+                {reminders.map((reminder) => (
+                  <tr key={reminder.id}>
+                    <td className="px-2 py-1.5 whitespace-nowrap">{reminder.reservationAt}</td>
+                    <td className="px-2 py-1.5">{reminder.customerName || reminder.recipient}</td>
+                    <td className="px-2 py-1.5 whitespace-nowrap">{reminder.sentAt ?? reminder.scheduledAt}</td>
+                    <td className={`px-2 py-1.5 ${STATUS_COLORS[reminder.status]}`} title={reminder.error}>
+                      {STATUS_LABELS[reminder.status]}
+                      {reminder.attempts > 1 && <span className="text-gray-400"> ({reminder.attempts}회)</span>}
+                    </td>
+                    <td className="px-2 py-1.5 text-right whitespace-nowrap">
+                      {reminder.status === 'pending' && (
+                        <button
+                          onClick={() => run(() => reminderApi.cancel(reminder.id), '알림을 취소했습니다')}
+                          className="text-gray-500 hover:underline"
+                        >
+                          취소
+                        </button>
+                      )}
+                      {reminder.status === 'failed' && (
+                        <button
+                          onClick={() => run(() => reminderApi.retry(reminder.id), '다시 보내기를 예약했습니다')}
+                          className="text-rose-600 hover:underline"
+                        >
+                          재시도
+                        </button>
+                      )}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-start gap-2 p-3 rounded-lg text-sm ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? (
+            <CheckCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          ) : (
+            <XCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          )}
+          <span className="break-all">{result.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
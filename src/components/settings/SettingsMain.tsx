@@ -1,8 +1,8 @@
 import { useState } from 'react';
-import { ChevronRight, Lock, Database, Clock, BarChart3, Monitor, Info, AlertTriangle, Loader2 } from 'lucide-react';
+import { ChevronRight, Lock, Database, Clock, BellRing, BarChart3, Monitor, Info, AlertTriangle, Loader2 } from 'lucide-react';
 import { utilApi } from '../../lib/tauri';
 
-export type SettingsCategory = 'security' | 'data' | 'business' | 'statistics' | 'display' | 'appInfo';
+export type SettingsCategory = 'security' | 'data' | 'business' | 'reminders' | 'statistics' | 'display' | 'appInfo';
 
 interface CategoryItem {
   id: SettingsCategory;
@@ -34,6 +34,13 @@ const categories: CategoryItem[] = [
     title: '영업 설정',
     subtitle: '영업시간, 휴일',
   },
+  {
+    id: 'reminders',
+    icon: <BellRing className="w-5 h-5" />,
+    iconBg: 'bg-rose-100 text-rose-600 dark:bg-rose-900/50 dark:text-rose-400',
+    title: '예약 알림',
+    subtitle: '문자, 알림톡 자동 발송',
+  },
   {
     id: 'statistics',
     icon: <BarChart3 className="w-5 h-5" />,
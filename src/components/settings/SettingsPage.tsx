@@ -1,9 +1,10 @@
 import { useState } from 'react';
-import { Settings, Lock, Database, Clock, BarChart3, Monitor, Info } from 'lucide-react';
+import { Settings, Lock, Database, Clock, BellRing, BarChart3, Monitor, Info } from 'lucide-react';
 import { SettingsMain, SettingsCategory } from './SettingsMain';
 import { SecuritySettings } from './SecuritySettings';
 import { DataSettings } from './DataSettings';
 import { BusinessSettings } from './BusinessSettings';
+import { ReminderSettings } from './ReminderSettings';
 import { DisplaySettings } from './DisplaySettings';
 import { AppInfoSettings } from './AppInfoSettings';
 import { StatisticsDashboard } from '../statistics/StatisticsDashboard';
@@ -39,6 +40,12 @@ const categoryInfo: Record<SettingsCategory, CategoryInfo> = {
     title: '영업 설정',
     subtitle: '영업시간, 휴일',
   },
+  reminders: {
+    icon: <BellRing className="w-5 h-5" />,
+    iconBg: 'bg-rose-100 text-rose-600 dark:bg-rose-900/50 dark:text-rose-400',
+    title: '예약 알림',
+    subtitle: '문자, 알림톡 자동 발송',
+  },
   statistics: {
     icon: <BarChart3 className="w-5 h-5" />,
     iconBg: 'bg-indigo-100 text-indigo-600 dark:bg-indigo-900/50 dark:text-indigo-400',
@@ -78,6 +85,8 @@ export function SettingsPage({ onLockSettingsChange }: SettingsPageProps) {
         return <DataSettings />;
       case 'business':
         return <BusinessSettings />;
+      case 'reminders':
+        return <ReminderSettings />;
       case 'statistics':
         return <StatisticsDashboard />;
       case 'display':
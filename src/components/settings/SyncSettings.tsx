@@ -0,0 +1,480 @@
+import { useState, useEffect } from 'react';
+import {
+  RefreshCw,
+  Save,
+  Loader2,
+  CheckCircle,
+  XCircle,
+  AlertTriangle,
+  MonitorSmartphone,
+  Wifi,
+  Search,
+  KeyRound,
+  Link2,
+  Trash2,
+} from 'lucide-react';
+import { syncApi } from '../../lib/tauri';
+import type {
+  SyncSettings as Settings,
+  SyncConflictKind,
+  SyncReport,
+  SyncStatus,
+  LanSyncStatus,
+  DiscoveredDevice,
+  PairedDevice,
+} from '../../types';
+
+const MIN_PASSPHRASE_LENGTH = 8;
+
+const TOGGLE_CLASS =
+  "w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-indigo-300 dark:peer-focus:ring-indigo-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all dark:border-gray-600 peer-checked:bg-indigo-600";
+
+const CONFLICT_LABELS: Record<SyncConflictKind, string> = {
+  double_booking: '중복 예약',
+  unique_violation: '중복 값',
+  field_overwritten: '덮어쓴 변경',
+  apply_failed: '반영 실패',
+};
+
+const TABLE_LABELS: Record<string, string> = {
+  designers: '디자이너',
+  customers: '고객',
+  reservations: '예약',
+  reservation_status_history: '예약 상태 이력',
+  time_blocks: '시간 차단',
+  business_hours: '영업시간',
+  business_hour_overrides: '특정일 영업시간',
+  holidays: '휴일',
+  closure_periods: '휴무 기간',
+  customer_consent_log: '동의 이력',
+};
+
+export function SyncSettings() {
+  const [settings, setSettings] = useState<Settings | null>(null);
+  const [status, setStatus] = useState<SyncStatus | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+  const [isSyncing, setIsSyncing] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+  const [lanStatus, setLanStatus] = useState<LanSyncStatus | null>(null);
+  const [pairedDevices, setPairedDevices] = useState<PairedDevice[]>([]);
+  const [discovered, setDiscovered] = useState<DiscoveredDevice[] | null>(null);
+  const [isDiscovering, setIsDiscovering] = useState(false);
+  const [pairingTarget, setPairingTarget] = useState('');
+  const [pairingCode, setPairingCode] = useState('');
+  const [isPairing, setIsPairing] = useState(false);
+
+  useEffect(() => {
+    syncApi.getSettings().then(setSettings).catch(() => {});
+    syncApi.getStatus().then(setStatus).catch(() => {});
+    syncApi.getLanStatus().then(setLanStatus).catch(() => {});
+    syncApi.getPairedDevices().then(setPairedDevices).catch(() => {});
+  }, []);
+
+  // 코드를 띄운 동안 상대 기기가 페어링하면 목록에 바로 보이도록 확인
+  const showingCode = !!lanStatus?.pairing;
+  useEffect(() => {
+    if (!showingCode) return;
+    const timer = setInterval(() => {
+      syncApi.getLanStatus().then(setLanStatus).catch(() => {});
+      syncApi.getPairedDevices().then(setPairedDevices).catch(() => {});
+    }, 2000);
+    return () => clearInterval(timer);
+  }, [showingCode]);
+
+  if (!settings) {
+    return (
+      <div className="flex justify-center py-8">
+        <Loader2 className="w-6 h-6 animate-spin text-gray-400" />
+      </div>
+    );
+  }
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    setResult(null);
+    try {
+      const lan = await syncApi.updateSettings({ ...settings, folder: settings.folder?.trim() || null });
+      setLanStatus(lan);
+      setResult({ success: true, message: '동기화 설정을 저장했습니다' });
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleSync = async () => {
+    setIsSyncing(true);
+    setResult(null);
+    try {
+      const report: SyncReport = await syncApi.run();
+      setResult({
+        success: true,
+        message: `동기화 완료: 기기 ${report.peers.length}대, 추가 ${report.inserted} · 변경 ${report.updated} · 삭제 ${report.deleted}`,
+      });
+    } catch (error) {
+      setResult({ success: false, message: `동기화 실패: ${error}` });
+    } finally {
+      syncApi.getStatus().then(setStatus).catch(() => {});
+      setIsSyncing(false);
+    }
+  };
+
+  const handleStartPairing = async () => {
+    try {
+      const pairing = await syncApi.startPairing();
+      setLanStatus((prev) => (prev ? { ...prev, pairing } : prev));
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    }
+  };
+
+  const handleCancelPairing = async () => {
+    await syncApi.cancelPairing().catch(() => {});
+    setLanStatus((prev) => (prev ? { ...prev, pairing: null } : prev));
+  };
+
+  const handleDiscover = async () => {
+    setIsDiscovering(true);
+    try {
+      setDiscovered(await syncApi.discover());
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsDiscovering(false);
+    }
+  };
+
+  const handlePair = async () => {
+    setIsPairing(true);
+    setResult(null);
+    try {
+      const device = await syncApi.pair(pairingTarget, pairingCode);
+      setResult({ success: true, message: `${device.deviceName} 기기와 페어링했습니다` });
+      setPairingTarget('');
+      setPairingCode('');
+      syncApi.getPairedDevices().then(setPairedDevices).catch(() => {});
+      setDiscovered((prev) => prev?.map((d) => (d.nodeId === device.nodeId ? { ...d, paired: true } : d)) ?? null);
+    } catch (error) {
+      setResult({ success: false, message: `페어링 실패: ${error}` });
+    } finally {
+      setIsPairing(false);
+    }
+  };
+
+  const handleUnpair = async (device: PairedDevice) => {
+    if (!confirm(`${device.deviceName} 기기와의 페어링을 해제할까요?`)) return;
+    try {
+      await syncApi.unpair(device.nodeId);
+      setPairedDevices((prev) => prev.filter((d) => d.nodeId !== device.nodeId));
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    }
+  };
+
+  const report = status?.report;
+
+  return (
+    <div className="glass-card space-y-4">
+      <div className="flex items-center justify-between">
+        <div>
+          <p className="text-sm font-medium text-gray-900 dark:text-white">자동 동기화</p>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            프런트와 디자이너 기기가 공유 폴더나 같은 네트워크로 예약·고객 정보를 주고받습니다
+          </p>
+        </div>
+        <label className="relative inline-flex items-center cursor-pointer">
+          <input
+            type="checkbox"
+            checked={settings.enabled}
+            onChange={(e) => setSettings({ ...settings, enabled: e.target.checked })}
+            className="sr-only peer"
+          />
+          <div className={TOGGLE_CLASS} />
+        </label>
+      </div>
+
+      <input
+        type="text"
+        value={settings.deviceName}
+        onChange={(e) => setSettings({ ...settings, deviceName: e.target.value })}
+        placeholder="이 기기의 이름 (예: 프런트, 수진 디자이너 태블릿)"
+        className="input w-full"
+      />
+      <input
+        type="text"
+        value={settings.folder ?? ''}
+        onChange={(e) => setSettings({ ...settings, folder: e.target.value })}
+        placeholder="공유 폴더 경로 (Dropbox, NAS 등 모든 기기에서 보이는 폴더)"
+        className="input w-full"
+      />
+      <input
+        type="password"
+        value={settings.passphrase}
+        onChange={(e) => setSettings({ ...settings, passphrase: e.target.value })}
+        placeholder={`동기화 비밀번호 (${MIN_PASSPHRASE_LENGTH}자 이상, 모든 기기에서 같게)`}
+        className="input w-full"
+      />
+      <div className="flex items-center justify-between">
+        <p className="text-sm font-medium text-gray-900 dark:text-white">동기화 간격</p>
+        <select
+          value={settings.intervalMinutes}
+          onChange={(e) => setSettings({ ...settings, intervalMinutes: Number(e.target.value) })}
+          className="input w-28"
+        >
+          <option value={1}>1분</option>
+          <option value={5}>5분</option>
+          <option value={15}>15분</option>
+          <option value={60}>1시간</option>
+        </select>
+      </div>
+
+      <div className="flex items-center justify-between">
+        <div>
+          <p className="text-sm font-medium text-gray-900 dark:text-white">같은 네트워크 동기화</p>
+          <p className="text-xs text-gray-500 dark:text-gray-400">
+            공유 폴더 없이 매장 와이파이 안의 기기와 직접 주고받습니다
+          </p>
+        </div>
+        <label className="relative inline-flex items-center cursor-pointer">
+          <input
+            type="checkbox"
+            checked={settings.lanEnabled}
+            onChange={(e) => setSettings({ ...settings, lanEnabled: e.target.checked })}
+            className="sr-only peer"
+          />
+          <div className={TOGGLE_CLASS} />
+        </label>
+      </div>
+      {settings.lanEnabled && (
+        <div className="flex items-center justify-between">
+          <p className="text-sm font-medium text-gray-900 dark:text-white">연결 포트</p>
+          <input
+            type="number"
+            min={0}
+            max={65535}
+            value={settings.lanPort}
+            onChange={(e) => setSettings({ ...settings, lanPort: Number(e.target.value) })}
+            placeholder="0 = 자동"
+            className="input w-28"
+          />
+        </div>
+      )}
+
+      <div className="grid grid-cols-2 gap-2">
+        <button
+          onClick={handleSave}
+          disabled={isSaving}
+          className="flex items-center justify-center gap-2 py-2.5 px-4
+                     bg-indigo-600 hover:bg-indigo-700 text-white rounded-xl
+                     font-medium transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isSaving ? <Loader2 className="w-4 h-4 animate-spin" /> : <Save className="w-4 h-4" />}
+          <span>저장</span>
+        </button>
+        <button
+          onClick={handleSync}
+          disabled={isSyncing || (!settings.folder && !settings.lanEnabled)}
+          className="flex items-center justify-center gap-2 py-2.5 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                     text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors
+                     disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isSyncing ? <Loader2 className="w-4 h-4 animate-spin" /> : <RefreshCw className="w-4 h-4" />}
+          <span>지금 동기화</span>
+        </button>
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-center gap-2 p-3 rounded-lg text-sm ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? <CheckCircle className="w-4 h-4 flex-shrink-0" /> : <XCircle className="w-4 h-4 flex-shrink-0" />}
+          <span>{result.message}</span>
+        </div>
+      )}
+
+      {/* 같은 네트워크의 기기 */}
+      {lanStatus?.running && (
+        <div className="pt-4 border-t border-gray-200 dark:border-gray-700 space-y-3 text-sm">
+          <p className="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-400">
+            <Wifi className="w-3.5 h-3.5" />
+            <span>
+              포트 {lanStatus.port}에서 연결을 받는 중
+              {!lanStatus.advertised && ' · 기기 찾기에 보이지 않으면 주소를 직접 입력해주세요'}
+            </span>
+          </p>
+
+          {lanStatus.pairing ? (
+            <div className="p-3 rounded-lg bg-sky-50 dark:bg-sky-900/20 text-center space-y-1">
+              <p className="text-xs text-sky-800 dark:text-sky-300">다른 기기에서 이 코드를 입력하세요</p>
+              <p className="text-2xl font-mono font-bold tracking-widest text-sky-900 dark:text-sky-200">
+                {lanStatus.pairing.code}
+              </p>
+              <p className="text-xs text-sky-700 dark:text-sky-400">
+                {new Date(lanStatus.pairing.expiresAt).toLocaleTimeString('ko-KR')}까지 유효
+              </p>
+              <button onClick={handleCancelPairing} className="text-xs text-gray-500 underline">
+                취소
+              </button>
+            </div>
+          ) : (
+            <button
+              onClick={handleStartPairing}
+              className="w-full flex items-center justify-center gap-2 py-2 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                         text-gray-700 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+            >
+              <KeyRound className="w-4 h-4" />
+              <span>페어링 코드 띄우기</span>
+            </button>
+          )}
+
+          <button
+            onClick={handleDiscover}
+            disabled={isDiscovering}
+            className="w-full flex items-center justify-center gap-2 py-2 px-4 rounded-xl border border-gray-300 dark:border-gray-600
+                       text-gray-700 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors
+                       disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {isDiscovering ? <Loader2 className="w-4 h-4 animate-spin" /> : <Search className="w-4 h-4" />}
+            <span>주변 기기 찾기</span>
+          </button>
+          {discovered && discovered.length === 0 && (
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              찾은 기기가 없습니다. 상대 기기에서도 같은 네트워크 동기화를 켜주세요.
+            </p>
+          )}
+          {discovered?.map((device) => (
+            <div key={device.nodeId} className="flex items-center justify-between text-xs text-gray-700 dark:text-gray-300">
+              <span className="flex items-center gap-1">
+                <MonitorSmartphone className="w-3.5 h-3.5" />
+                {device.deviceName || device.nodeId} · {device.address}
+              </span>
+              {device.paired ? (
+                <span className="text-green-600">페어링됨</span>
+              ) : (
+                <button onClick={() => setPairingTarget(device.address)} className="text-sky-600 hover:underline">
+                  페어링
+                </button>
+              )}
+            </div>
+          ))}
+
+          <div className="flex gap-2">
+            <input
+              type="text"
+              value={pairingTarget}
+              onChange={(e) => setPairingTarget(e.target.value)}
+              placeholder="상대 기기 주소 (예: 192.168.0.12:48213)"
+              className="input flex-1 min-w-0"
+            />
+            <input
+              type="text"
+              inputMode="numeric"
+              maxLength={6}
+              value={pairingCode}
+              onChange={(e) => setPairingCode(e.target.value.replace(/\D/g, ''))}
+              placeholder="코드 6자리"
+              className="input w-28"
+            />
+            <button
+              onClick={handlePair}
+              disabled={isPairing || !pairingTarget || pairingCode.length !== 6}
+              className="flex items-center gap-1 py-2 px-3 bg-sky-600 hover:bg-sky-700 text-white rounded-xl
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {isPairing ? <Loader2 className="w-4 h-4 animate-spin" /> : <Link2 className="w-4 h-4" />}
+            </button>
+          </div>
+
+          {pairedDevices.length > 0 && (
+            <div className="space-y-1">
+              <p className="text-xs font-medium text-gray-800 dark:text-white">페어링한 기기</p>
+              {pairedDevices.map((device) => (
+                <div key={device.nodeId} className="flex items-center justify-between text-xs text-gray-600 dark:text-gray-400">
+                  <span>
+                    {device.deviceName}
+                    {device.lastSyncedAt ? ` · 마지막 동기화 ${device.lastSyncedAt}` : ' · 아직 동기화하지 않음'}
+                  </span>
+                  <button
+                    onClick={() => handleUnpair(device)}
+                    className="p-1 text-gray-400 hover:text-red-600"
+                    aria-label="페어링 해제"
+                  >
+                    <Trash2 className="w-3.5 h-3.5" />
+                  </button>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      )}
+
+      {/* 마지막 동기화 */}
+      {status && (
+        <div className="pt-4 border-t border-gray-200 dark:border-gray-700 space-y-2 text-xs text-gray-600 dark:text-gray-400">
+          <div className="flex items-center gap-1">
+            {status.success ? (
+              <CheckCircle className="w-3.5 h-3.5 text-green-600" />
+            ) : (
+              <XCircle className="w-3.5 h-3.5 text-red-600" />
+            )}
+            <span className="font-medium text-gray-800 dark:text-white">마지막 동기화</span>
+            <span>· {status.attemptedAt}</span>
+          </div>
+          {status.error && (
+            <p className="ml-5 text-red-600">
+              {status.error}
+              {status.lastSuccessAt && ` · 마지막 성공 ${status.lastSuccessAt}`}
+            </p>
+          )}
+          {report && report.peers.length > 0 && (
+            <div className="ml-5 space-y-1">
+              {report.peers.map((peer) => (
+                <p key={peer.nodeId} className="flex items-center gap-1">
+                  <MonitorSmartphone className="w-3.5 h-3.5" />
+                  <span>
+                    {peer.deviceName || peer.nodeId} · {new Date(peer.generatedAt).toLocaleString('ko-KR')}
+                  </span>
+                </p>
+              ))}
+            </div>
+          )}
+          {report?.errors.map((error) => (
+            <p key={error} className="ml-5 text-red-600">
+              {error}
+            </p>
+          ))}
+
+          {/* 충돌 보고서 */}
+          {report && report.conflicts.length > 0 && (
+            <div className="space-y-2">
+              <h4 className="flex items-center gap-1 text-sm font-medium text-amber-700 dark:text-amber-400">
+                <AlertTriangle className="w-4 h-4" />
+                확인이 필요한 충돌 {report.conflicts.length}건
+              </h4>
+              {report.conflicts.map((conflict, index) => (
+                <div
+                  key={`${conflict.table}-${conflict.rowId}-${index}`}
+                  className="p-2 rounded-lg bg-amber-50 dark:bg-amber-900/20 text-amber-800 dark:text-amber-300"
+                >
+                  <p className="font-medium">
+                    {CONFLICT_LABELS[conflict.kind]} · {TABLE_LABELS[conflict.table] ?? conflict.table}
+                    {conflict.column && ` · ${conflict.column}`}
+                  </p>
+                  <p>{conflict.detail}</p>
+                </div>
+              ))}
+              <p>
+                중복 예약은 두 예약을 모두 남겨 두었습니다. 예약 화면에서 한쪽을 옮기거나 취소해주세요.
+              </p>
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
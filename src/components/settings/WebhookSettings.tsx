@@ -0,0 +1,271 @@
+import { useState, useEffect } from 'react';
+import { Webhook, Plus, Send, Trash2, RotateCcw, Copy, Loader2, CheckCircle, XCircle } from 'lucide-react';
+import { webhookApi } from '../../lib/tauri';
+import type { WebhookDelivery, WebhookEndpoint, WebhookEventType } from '../../types';
+
+const EVENT_LABELS: Record<WebhookEventType, string> = {
+  'reservation.created': '예약 생성',
+  'reservation.updated': '예약 수정',
+  'reservation.status_changed': '예약 상태 변경',
+  'customer.created': '고객 등록',
+};
+
+const ALL_EVENTS = Object.keys(EVENT_LABELS) as WebhookEventType[];
+
+export function WebhookSettings() {
+  const [endpoints, setEndpoints] = useState<WebhookEndpoint[]>([]);
+  const [deliveries, setDeliveries] = useState<WebhookDelivery[]>([]);
+  const [name, setName] = useState('');
+  const [url, setUrl] = useState('');
+  const [events, setEvents] = useState<WebhookEventType[]>(ALL_EVENTS);
+  const [isWorking, setIsWorking] = useState(false);
+  const [result, setResult] = useState<{ success: boolean; message: string } | null>(null);
+
+  const load = async () => {
+    try {
+      const [loadedEndpoints, loadedDeliveries] = await Promise.all([
+        webhookApi.getEndpoints(),
+        webhookApi.getDeliveries(undefined, 30),
+      ]);
+      setEndpoints(loadedEndpoints);
+      setDeliveries(loadedDeliveries);
+    } catch (error) {
+      console.error('Failed to load webhooks:', error);
+    }
+  };
+
+  useEffect(() => {
+    load();
+  }, []);
+
+  const run = async (action: () => Promise<unknown>, successMessage?: string) => {
+    setIsWorking(true);
+    setResult(null);
+    try {
+      await action();
+      if (successMessage) setResult({ success: true, message: successMessage });
+      await load();
+    } catch (error) {
+      setResult({ success: false, message: `${error}` });
+    } finally {
+      setIsWorking(false);
+    }
+  };
+
+  const handleCreate = () =>
+    run(async () => {
+      // 전체 선택이면 이후 추가되는 이벤트도 받도록 빈 목록(= 전체)으로 저장
+      await webhookApi.createEndpoint({
+        name,
+        url,
+        events: events.length === ALL_EVENTS.length ? [] : events,
+      });
+      setName('');
+      setUrl('');
+      setEvents(ALL_EVENTS);
+    }, '웹훅을 추가했습니다');
+
+  const handleDelete = (endpoint: WebhookEndpoint) => {
+    if (!confirm(`'${endpoint.name}' 웹훅을 삭제하시겠습니까? 전송 기록도 함께 삭제됩니다.`)) return;
+    run(() => webhookApi.deleteEndpoint(endpoint.id));
+  };
+
+  const toggleEvent = (event: WebhookEventType) =>
+    setEvents(events.includes(event) ? events.filter((e) => e !== event) : [...events, event]);
+
+  const eventSummary = (endpoint: WebhookEndpoint) =>
+    endpoint.events.includes('*')
+      ? '모든 이벤트'
+      : endpoint.events.map((e) => EVENT_LABELS[e as WebhookEventType] ?? e).join(', ');
+
+  return (
+    <div className="space-y-4">
+      <div className="glass-card">
+        <div className="flex items-center gap-3 mb-6">
+          <div className="p-2.5 rounded-xl bg-pink-100 text-pink-600 dark:bg-pink-900/30 dark:text-pink-400">
+            <Webhook className="w-5 h-5" />
+          </div>
+          <div>
+            <h3 className="font-semibold text-gray-800 dark:text-white">웹훅 주소</h3>
+            <p className="text-xs text-gray-500 dark:text-gray-400">
+              이벤트가 생기면 JSON 으로 POST 합니다. X-Salon-Signature 헤더로 서명을 확인할 수 있습니다
+            </p>
+          </div>
+        </div>
+
+        <div className="space-y-4">
+          {/* 추가 */}
+          <div className="space-y-2">
+            <input
+              type="text"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder="이름 (예: 직원 단체방)"
+              className="input w-full"
+            />
+            <input
+              type="url"
+              value={url}
+              onChange={(e) => setUrl(e.target.value)}
+              placeholder="https://example.com/webhook"
+              className="input w-full"
+            />
+            <div className="flex flex-wrap gap-3">
+              {ALL_EVENTS.map((event) => (
+                <label key={event} className="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300">
+                  <input type="checkbox" checked={events.includes(event)} onChange={() => toggleEvent(event)} />
+                  {EVENT_LABELS[event]}
+                </label>
+              ))}
+            </div>
+            <button
+              onClick={handleCreate}
+              disabled={isWorking || !name.trim() || !url.trim() || events.length === 0}
+              className="w-full flex items-center justify-center gap-2 py-2.5 px-4
+                         bg-pink-600 hover:bg-pink-700 text-white rounded-xl
+                         font-medium transition-colors
+                         disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {isWorking ? <Loader2 className="w-4 h-4 animate-spin" /> : <Plus className="w-4 h-4" />}
+              <span>추가</span>
+            </button>
+          </div>
+
+          {/* 목록 */}
+          {endpoints.length === 0 ? (
+            <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">등록된 웹훅이 없습니다</p>
+          ) : (
+            <div className="divide-y divide-gray-100 dark:divide-gray-700">
+              {endpoints.map((endpoint) => (
+                <div key={endpoint.id} className="py-3 space-y-1">
+                  <div className="flex items-center justify-between gap-2">
+                    <div className={`min-w-0 ${endpoint.isActive ? '' : 'opacity-50'}`}>
+                      <p className="text-sm font-medium text-gray-900 dark:text-white">{endpoint.name}</p>
+                      <p className="text-xs text-gray-500 dark:text-gray-400 truncate">{endpoint.url}</p>
+                      <p className="text-xs text-gray-400">{eventSummary(endpoint)}</p>
+                    </div>
+                    <div className="flex items-center gap-1 flex-shrink-0">
+                      <label className="flex items-center gap-1 text-xs text-gray-500 mr-1">
+                        <input
+                          type="checkbox"
+                          checked={endpoint.isActive}
+                          onChange={(e) =>
+                            run(() => webhookApi.updateEndpoint(endpoint.id, { isActive: e.target.checked }))
+                          }
+                        />
+                        사용
+                      </label>
+                      <button
+                        onClick={() => run(() => webhookApi.sendTest(endpoint.id), '테스트 이벤트를 보냈습니다')}
+                        disabled={isWorking || !endpoint.isActive}
+                        className="p-1.5 text-gray-400 hover:text-pink-600 disabled:opacity-40"
+                        title="테스트 전송"
+                      >
+                        <Send className="w-4 h-4" />
+                      </button>
+                      <button
+                        onClick={() =>
+                          navigator.clipboard
+                            .writeText(endpoint.secret)
+                            .then(() => setResult({ success: true, message: '서명 키를 복사했습니다' }))
+                        }
+                        className="p-1.5 text-gray-400 hover:text-gray-700 dark:hover:text-white"
+                        title="서명 키 복사"
+                      >
+                        <Copy className="w-4 h-4" />
+                      </button>
+                      <button
+                        onClick={() => handleDelete(endpoint)}
+                        className="p-1.5 text-gray-400 hover:text-red-600"
+                        title="삭제"
+                      >
+                        <Trash2 className="w-4 h-4" />
+                      </button>
+                    </div>
+                  </div>
+                </div>
+              ))}
+            </div>
+          )}
+        </div>
+      </div>
+
+      {/* 전송 기록 */}
+      <div className="glass-card">
+        <div className="flex items-center justify-between mb-4">
+          <h3 className="font-semibold text-gray-800 dark:text-white">전송 기록</h3>
+          <button
+            onClick={load}
+            className="p-1.5 text-gray-400 hover:text-gray-700 dark:hover:text-white"
+            title="새로고침"
+          >
+            <RotateCcw className="w-4 h-4" />
+          </button>
+        </div>
+        {deliveries.length === 0 ? (
+          <p className="text-sm text-gray-500 dark:text-gray-400 text-center py-2">전송 기록이 없습니다</p>
+        ) : (
+          <div className="max-h-72 overflow-y-auto rounded-lg border border-gray-200 dark:border-gray-700">
+            <table className="w-full text-xs">
+              <thead className="bg-gray-50 dark:bg-gray-800 text-gray-500 dark:text-gray-400">
+                <tr>
+                  <th className="px-2 py-1.5 text-left">시각</th>
+                  <th className="px-2 py-1.5 text-left">이벤트</th>
+                  <th className="px-2 py-1.5 text-left">대상</th>
+                  <th className="px-2 py-1.5 text-left">결과</th>
+                  <th className="px-2 py-1.5" />
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-gray-100 dark:divide-gray-700 text-gray-700 dark:text-gray-300">
+                {deliveries.map((delivery) => (
+                  <tr key={delivery.id}>
+                    <td className="px-2 py-1.5 whitespace-nowrap">{delivery.attemptedAt}</td>
+                    <td className="px-2 py-1.5">
+                      {EVENT_LABELS[delivery.eventType as WebhookEventType] ?? delivery.eventType}
+                      {delivery.attempt > 1 && <span className="text-gray-400"> ({delivery.attempt}회차)</span>}
+                    </td>
+                    <td className="px-2 py-1.5">{delivery.endpointName ?? '-'}</td>
+                    <td
+                      className={`px-2 py-1.5 ${delivery.success ? 'text-green-600' : 'text-red-600'}`}
+                      title={delivery.error}
+                    >
+                      {delivery.success ? '성공' : '실패'}
+                      {delivery.statusCode ? ` ${delivery.statusCode}` : ''}
+                    </td>
+                    <td className="px-2 py-1.5 text-right">
+                      {delivery.outboxStatus === 'failed' && !delivery.success && (
+                        <button
+                          onClick={() => run(() => webhookApi.retryDelivery(delivery.outboxId), '다시 보내기를 예약했습니다')}
+                          className="text-pink-600 hover:underline"
+                        >
+                          재시도
+                        </button>
+                      )}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </div>
+
+      {result && (
+        <div
+          className={`flex items-start gap-2 p-3 rounded-lg text-sm ${
+            result.success
+              ? 'bg-green-100 text-green-800 dark:bg-green-900/30 dark:text-green-300'
+              : 'bg-red-100 text-red-800 dark:bg-red-900/30 dark:text-red-300'
+          }`}
+        >
+          {result.success ? (
+            <CheckCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          ) : (
+            <XCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+          )}
+          <span className="break-all">{result.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -2,14 +2,39 @@ import { invoke } from '@tauri-apps/api/core';
 import type {
   Reservation,
   Designer,
+  TimeBlock,
+  CreateTimeBlockInput,
   BusinessHours,
   Holiday,
   CreateHolidayInput,
+  HolidayOccurrence,
+  HolidayImportResult,
+  LunarDate,
+  BusinessHourOverride,
+  SetBusinessHourOverrideInput,
+  ClosurePeriod,
+  CreateClosurePeriodInput,
+  EffectiveHours,
   Customer,
   CreateCustomerInput,
   CustomerReservation,
+  CustomerMessage,
   BackupInfo,
+  RestoreResult,
+  BackupPreview,
+  SelectiveRestoreOptions,
+  SelectiveRestoreReport,
+  AutoBackupSettings,
+  BackupRunStatus,
+  BackupTargetConfig,
+  BackupManifest,
+  MigrationExport,
+  MigrationImportResult,
   ExportPeriod,
+  IcsFileInput,
+  IcsCalendarSummary,
+  IcsImportInput,
+  IcsImportResult,
   CloudService,
   LockSettings,
   StatisticsSummary,
@@ -17,6 +42,33 @@ import type {
   HourlyStatistic,
   DesignerStatistic,
   StatisticsPeriod,
+  ApiServerSettings,
+  ApiServerStatus,
+  ApiKey,
+  ApiScope,
+  CreatedApiKey,
+  WebhookEndpoint,
+  CreateWebhookEndpointInput,
+  UpdateWebhookEndpointInput,
+  WebhookDelivery,
+  ReminderSettings,
+  Reminder,
+  ReminderStatus,
+  PrivacySettings,
+  ConsentRecord,
+  RetentionCandidate,
+  PurgeReport,
+  PrivacyAuditReport,
+  AnonymizeResult,
+  DossierFormat,
+  SyncSettings,
+  SyncStatus,
+  SyncReport,
+  LanSyncStatus,
+  PairingCode,
+  DiscoveredDevice,
+  PairedDevice,
+  StartupError,
 } from '../types';
 
 // 예약 생성 입력 타입
@@ -55,6 +107,16 @@ export const designerApi = {
   delete: (id: string) => invoke<void>('delete_designer', { id }),
 };
 
+// 시간 차단 관리
+export const timeBlockApi = {
+  getAll: (date?: string, dateFrom?: string, dateTo?: string, designerId?: string) =>
+    invoke<TimeBlock[]>('get_time_blocks', { date, dateFrom, dateTo, designerId }),
+  create: (data: CreateTimeBlockInput) => invoke<TimeBlock>('create_time_block', { data }),
+  update: (id: string, data: CreateTimeBlockInput) =>
+    invoke<TimeBlock>('update_time_block', { id, data }),
+  delete: (id: string) => invoke<void>('delete_time_block', { id }),
+};
+
 // 영업시간 관리
 export const businessHoursApi = {
   getAll: () => invoke<BusinessHours[]>('get_business_hours'),
@@ -63,6 +125,27 @@ export const businessHoursApi = {
   getHolidays: () => invoke<Holiday[]>('get_holidays'),
   addHoliday: (data: CreateHolidayInput) => invoke<Holiday>('add_holiday', { data }),
   deleteHoliday: (id: string) => invoke<void>('delete_holiday', { id }),
+  getHolidaysForYear: (year: number) =>
+    invoke<HolidayOccurrence[]>('get_holidays_for_year', { year }),
+  importKoreanHolidays: () => invoke<HolidayImportResult>('import_korean_holidays'),
+  solarToLunar: (date: string) => invoke<LunarDate>('convert_solar_to_lunar', { date }),
+  lunarToSolar: (year: number, month: number, day: number, isLeapMonth: boolean) =>
+    invoke<string | null>('convert_lunar_to_solar', { year, month, day, isLeapMonth }),
+  // 특정일 예외
+  getOverrides: (dateFrom?: string, dateTo?: string) =>
+    invoke<BusinessHourOverride[]>('get_business_hour_overrides', { dateFrom, dateTo }),
+  setOverride: (data: SetBusinessHourOverrideInput) =>
+    invoke<BusinessHourOverride>('set_business_hour_override', { data }),
+  deleteOverride: (id: string) => invoke<void>('delete_business_hour_override', { id }),
+  // 휴무 기간
+  getClosurePeriods: () => invoke<ClosurePeriod[]>('get_closure_periods'),
+  addClosurePeriod: (data: CreateClosurePeriodInput) =>
+    invoke<ClosurePeriod>('add_closure_period', { data }),
+  deleteClosurePeriod: (id: string) => invoke<void>('delete_closure_period', { id }),
+  // 실제 적용 영업시간
+  getEffectiveHours: (date: string) => invoke<EffectiveHours>('get_effective_hours', { date }),
+  getEffectiveHoursRange: (startDate: string, endDate: string) =>
+    invoke<EffectiveHours[]>('get_effective_hours_range', { startDate, endDate }),
 };
 
 // 고객 관리
@@ -77,6 +160,8 @@ export const customerApi = {
   getByPhone: (phone: string) => invoke<Customer | null>('get_customer_by_phone', { phone }),
   getReservations: (customerId: string) =>
     invoke<CustomerReservation[]>('get_customer_reservations', { customerId }),
+  getMessages: (customerId: string, limit?: number) =>
+    invoke<CustomerMessage[]>('get_customer_messages', { customerId, limit }),
   updateVisitStats: (customerId: string) =>
     invoke<void>('update_customer_visit_stats', { customerId }),
 };
@@ -99,15 +184,49 @@ export const exportApi = {
     invoke<string>('export_to_excel', { period }),
   toCsv: (period: ExportPeriod) =>
     invoke<string>('export_to_csv', { period }),
+  toIcs: (designerId?: string, dateFrom?: string, dateTo?: string) =>
+    invoke<string>('export_to_ics', { designerId, dateFrom, dateTo }),
   getExportPath: () => invoke<string>('get_export_path'),
 };
 
+// 가져오기
+export const importApi = {
+  inspectIcs: (files: IcsFileInput[]) =>
+    invoke<IcsCalendarSummary[]>('inspect_ics_files', { files }),
+  importIcs: (data: IcsImportInput) =>
+    invoke<IcsImportResult>('import_ics', { data }),
+};
+
 // 백업
 export const backupApi = {
   list: (service: CloudService) => invoke<BackupInfo[]>('list_backups', { service }),
-  create: (service: CloudService) => invoke<BackupInfo>('create_backup', { service }),
-  restore: (backupFilename: string, service: CloudService) =>
-    invoke<void>('restore_backup', { backupFilename, service }),
+  create: (service: CloudService, passphrase?: string) =>
+    invoke<BackupInfo>('create_backup', { service, passphrase }),
+  restore: (backupFilename: string, service: CloudService, passphrase?: string) =>
+    invoke<RestoreResult>('restore_backup', { backupFilename, service, passphrase }),
+  preview: (backupFilename: string, service: CloudService, passphrase?: string) =>
+    invoke<BackupPreview>('preview_backup', { backupFilename, service, passphrase }),
+  restoreSelective: (
+    backupFilename: string,
+    service: CloudService,
+    options: SelectiveRestoreOptions,
+    passphrase?: string
+  ) =>
+    invoke<SelectiveRestoreReport>('restore_backup_selective', { backupFilename, service, passphrase, options }),
+  exportMigration: (passphrase: string, dest?: string, clientSettings?: Record<string, string>) =>
+    invoke<MigrationExport>('export_migration_bundle', { passphrase, dest, clientSettings }),
+  inspectMigration: (path: string, passphrase: string) =>
+    invoke<BackupManifest>('inspect_migration_bundle', { path, passphrase }),
+  importMigration: (path: string, passphrase: string) =>
+    invoke<MigrationImportResult>('import_migration_bundle', { path, passphrase }),
+  getAutoSettings: () => invoke<AutoBackupSettings>('get_auto_backup_settings'),
+  updateAutoSettings: (settings: AutoBackupSettings) =>
+    invoke<void>('update_auto_backup_settings', { settings }),
+  getAutoStatus: () => invoke<BackupRunStatus[]>('get_auto_backup_status'),
+  getTarget: () => invoke<BackupTargetConfig>('get_backup_target'),
+  updateTarget: (config: BackupTargetConfig) => invoke<void>('update_backup_target', { config }),
+  // 접속해 보고 찾은 백업 개수를 돌려준다
+  testTarget: (config: BackupTargetConfig) => invoke<number>('test_backup_target', { config }),
   delete: (backupFilename: string, service: CloudService) =>
     invoke<void>('delete_backup', { backupFilename, service }),
   cleanup: (keepCount: number, service: CloudService) =>
@@ -123,6 +242,7 @@ export const securityApi = {
   removePin: () => invoke<void>('remove_lock_pin'),
   changePin: (oldPin: string, newPin: string) =>
     invoke<void>('change_lock_pin', { oldPin, newPin }),
+  exportDatabaseKey: (pin?: string) => invoke<string>('export_database_key', { pin }),
   authenticateBiometric: () => invoke<boolean>('authenticate_biometric'),
   isLockEnabled: () => invoke<boolean>('is_lock_enabled'),
   getSettings: () => invoke<LockSettings>('get_lock_settings'),
@@ -131,7 +251,79 @@ export const securityApi = {
   getBiometricType: () => invoke<string>('get_biometric_type'),
 };
 
+// 로컬 API 서버
+export const apiServerApi = {
+  getSettings: () => invoke<ApiServerSettings>('get_api_server_settings'),
+  updateSettings: (settings: ApiServerSettings) =>
+    invoke<ApiServerStatus>('update_api_server_settings', { settings }),
+  getStatus: () => invoke<ApiServerStatus>('get_api_server_status'),
+  listKeys: () => invoke<ApiKey[]>('list_api_keys'),
+  createKey: (name: string, scopes: ApiScope[]) =>
+    invoke<CreatedApiKey>('create_api_key', { name, scopes }),
+  revokeKey: (id: string) => invoke<void>('revoke_api_key', { id }),
+};
+
+// 웹훅
+export const webhookApi = {
+  getEndpoints: () => invoke<WebhookEndpoint[]>('get_webhook_endpoints'),
+  createEndpoint: (data: CreateWebhookEndpointInput) =>
+    invoke<WebhookEndpoint>('create_webhook_endpoint', { data }),
+  updateEndpoint: (id: string, data: UpdateWebhookEndpointInput) =>
+    invoke<WebhookEndpoint>('update_webhook_endpoint', { id, data }),
+  deleteEndpoint: (id: string) => invoke<void>('delete_webhook_endpoint', { id }),
+  sendTest: (id: string) => invoke<void>('send_test_webhook', { id }),
+  getDeliveries: (endpointId?: string, limit?: number) =>
+    invoke<WebhookDelivery[]>('get_webhook_deliveries', { endpointId, limit }),
+  retryDelivery: (outboxId: string) => invoke<void>('retry_webhook_delivery', { outboxId }),
+};
+
+// 예약 알림
+export const reminderApi = {
+  getSettings: () => invoke<ReminderSettings>('get_reminder_settings'),
+  updateSettings: (settings: ReminderSettings) => invoke<void>('update_reminder_settings', { settings }),
+  isSender: () => invoke<boolean>('is_reminder_sender'),
+  claimSender: () => invoke<void>('claim_reminder_sender'),
+  preview: (settings: ReminderSettings) => invoke<string>('preview_reminder_message', { settings }),
+  sendTest: (settings: ReminderSettings, phone: string) =>
+    invoke<string | null>('send_test_reminder', { settings, phone }),
+  getAll: (dateFrom?: string, dateTo?: string, status?: ReminderStatus) =>
+    invoke<Reminder[]>('get_reminders', { dateFrom, dateTo, status }),
+  cancel: (id: string) => invoke<void>('cancel_reminder', { id }),
+  retry: (id: string) => invoke<void>('retry_reminder', { id }),
+};
+
+// 개인정보 보호
+export const privacyApi = {
+  getSettings: () => invoke<PrivacySettings>('get_privacy_settings'),
+  updateSettings: (settings: PrivacySettings) => invoke<void>('update_privacy_settings', { settings }),
+  getConsentHistory: (customerId: string) =>
+    invoke<ConsentRecord[]>('get_customer_consent_history', { customerId }),
+  previewPurge: () => invoke<RetentionCandidate[]>('preview_retention_purge'),
+  runPurge: () => invoke<PurgeReport>('run_retention_purge'),
+  getAuditReport: (dateFrom?: string, dateTo?: string) =>
+    invoke<PrivacyAuditReport>('get_privacy_audit_report', { dateFrom, dateTo }),
+  exportDossier: (customerId: string, format: DossierFormat) =>
+    invoke<string>('export_customer_dossier', { customerId, format }),
+  eraseCustomer: (customerId: string) => invoke<AnonymizeResult>('erase_customer_data', { customerId }),
+};
+
+// 기기 간 동기화
+export const syncApi = {
+  getSettings: () => invoke<SyncSettings>('get_sync_settings'),
+  updateSettings: (settings: SyncSettings) => invoke<LanSyncStatus>('update_sync_settings', { settings }),
+  getStatus: () => invoke<SyncStatus | null>('get_sync_status'),
+  run: () => invoke<SyncReport>('run_sync'),
+  getLanStatus: () => invoke<LanSyncStatus>('get_lan_sync_status'),
+  startPairing: () => invoke<PairingCode>('start_lan_pairing'),
+  cancelPairing: () => invoke<void>('cancel_lan_pairing'),
+  discover: () => invoke<DiscoveredDevice[]>('discover_lan_devices'),
+  pair: (address: string, code: string) => invoke<PairedDevice>('pair_lan_device', { address, code }),
+  getPairedDevices: () => invoke<PairedDevice[]>('get_paired_devices'),
+  unpair: (nodeId: string) => invoke<void>('unpair_lan_device', { nodeId }),
+};
+
 // 유틸
 export const utilApi = {
   resetAllData: () => invoke<void>('reset_all_data'),
+  getStartupError: () => invoke<StartupError | null>('get_startup_error'),
 };
@@ -26,6 +26,27 @@ export interface Designer {
   updatedAt: string;
 }
 
+// 시간 차단 (예약이 아닌 디자이너 일정)
+export interface TimeBlock {
+  id: string;
+  designerId: string;
+  date: string;
+  startTime: string;
+  endTime: string;
+  reason?: string;
+  createdAt: string;
+  updatedAt: string;
+}
+
+// 시간 차단 생성 입력
+export interface CreateTimeBlockInput {
+  designerId: string;
+  date: string;
+  startTime: string;
+  endTime: string;
+  reason?: string;
+}
+
 // 영업시간
 export interface BusinessHours {
   id: number;
@@ -37,12 +58,16 @@ export interface BusinessHours {
   isClosed: boolean;
 }
 
-// 휴일
+// 달력 종류 (양력/음력)
+export type CalendarType = 'solar' | 'lunar';
+
+// 휴일 (calendarType 이 'lunar' 이면 date 는 음력 날짜)
 export interface Holiday {
   id: string;
   date: string;
   description?: string;
   isRecurring: boolean;
+  calendarType: CalendarType;
 }
 
 // 휴일 생성 입력
@@ -50,6 +75,69 @@ export interface CreateHolidayInput {
   date: string;
   description?: string;
   isRecurring: boolean;
+  calendarType?: CalendarType;
+}
+
+// 특정 연도의 실제 휴일 날짜 (양력)
+export interface HolidayOccurrence {
+  holidayId: string;
+  date: string;
+  description?: string;
+  calendarType: CalendarType;
+  isRecurring: boolean;
+}
+
+// 공휴일 가져오기 결과
+export interface HolidayImportResult {
+  added: number;
+  updated: number;
+}
+
+// 음력 날짜
+export interface LunarDate {
+  year: number;
+  month: number;
+  day: number;
+  isLeapMonth: boolean;
+}
+
+// 특정일 영업시간 예외
+export interface BusinessHourOverride {
+  id: string;
+  date: string;
+  openTime?: string;
+  closeTime?: string;
+  breakStart?: string;
+  breakEnd?: string;
+  isClosed: boolean;
+  description?: string;
+}
+
+// 특정일 영업시간 예외 입력
+export type SetBusinessHourOverrideInput = Omit<BusinessHourOverride, 'id'>;
+
+// 휴무 기간
+export interface ClosurePeriod {
+  id: string;
+  startDate: string;
+  endDate: string;
+  description?: string;
+}
+
+// 휴무 기간 생성 입력
+export type CreateClosurePeriodInput = Omit<ClosurePeriod, 'id'>;
+
+// 실제 적용 영업시간
+export interface EffectiveHours {
+  date: string;
+  dayOfWeek: number;
+  openTime?: string;
+  closeTime?: string;
+  breakStart?: string;
+  breakEnd?: string;
+  isClosed: boolean;
+  source: 'override' | 'closure' | 'holiday' | 'weekly';
+  description?: string;
 }
 
 // 고객
@@ -68,6 +156,15 @@ export interface Customer {
   notes?: string;
   createdAt: string;
   updatedAt: string;
+  reminderOptOut: boolean;
+  consentService: boolean;
+  consentServiceAt?: string;
+  consentMarketing: boolean;
+  consentMarketingAt?: string;
+  consentPhoto: boolean;
+  consentPhotoAt?: string;
+  consentVersion?: string;
+  anonymizedAt?: string;
 }
 
 // 고객 생성 입력
@@ -81,6 +178,10 @@ export interface CreateCustomerInput {
   preferredService?: string;
   allergies?: string;
   notes?: string;
+  reminderOptOut?: boolean;
+  consentService?: boolean;
+  consentMarketing?: boolean;
+  consentPhoto?: boolean;
 }
 
 // 고객 예약 이력
@@ -94,14 +195,85 @@ export interface CustomerReservation {
   notes?: string;
 }
 
+// 고객 커뮤니케이션 기록
+export interface CustomerMessage {
+  id: string;
+  customerId?: string;
+  reservationId?: string;
+  reservationDate?: string;
+  reservationTime?: string;
+  channel: string;
+  template: string;
+  recipient: string;
+  body: string;
+  status: 'pending' | 'sent' | 'failed';
+  providerMessageId?: string;
+  error?: string;
+  createdAt: string;
+  sentAt?: string;
+}
+
 // 디바이스 타입
 export type DeviceType = 'mobile' | 'tablet' | 'desktop';
 
 // 내보내기 기간
 export type ExportPeriod = 'this_month' | 'last_3_months' | 'all';
 
+// .ics 가져오기
+export interface IcsFileInput {
+  name: string;
+  content: string;
+}
+
+export interface IcsCalendarSummary {
+  fileName: string;
+  calendarName?: string;
+  eventCount: number;
+  recurringCount: number;
+  firstDate?: string;
+  lastDate?: string;
+  suggestedDesignerId?: string;
+}
+
+export interface IcsCalendarImport extends IcsFileInput {
+  designerId?: string;
+}
+
+export interface IcsImportInput {
+  calendars: IcsCalendarImport[];
+  dateFrom?: string;
+  dateTo?: string;
+  dryRun: boolean;
+}
+
+export type IcsImportAction = 'create' | 'update' | 'unchanged' | 'skip';
+
+export interface IcsImportItem {
+  reservationId?: string;
+  source: string;
+  summary?: string;
+  date?: string;
+  time?: string;
+  customerName?: string;
+  customerPhone?: string;
+  serviceType?: string;
+  designerId?: string;
+  status?: string;
+  action: IcsImportAction;
+  message?: string;
+}
+
+export interface IcsImportResult {
+  dryRun: boolean;
+  created: number;
+  updated: number;
+  unchanged: number;
+  skipped: number;
+  items: IcsImportItem[];
+}
+
 // 클라우드 서비스
-export type CloudService = 'icloud' | 'google_drive' | 'local';
+export type CloudService = 'icloud' | 'google_drive' | 'local' | 'external';
 
 // 백업 정보
 export interface BackupInfo {
@@ -110,6 +282,123 @@ export interface BackupInfo {
   filename: string;
   size: number;
   createdAt: string;
+  passphraseProtected: boolean;
+}
+
+export interface BackupManifest {
+  formatVersion: number;
+  appVersion: string;
+  createdAt: string;
+  schemaVersion: number;
+  counts: { customers: number; reservations: number; designers: number };
+  files: { path: string; size: number; sha256: string }[];
+}
+
+export interface RestoreResult {
+  snapshotFilename: string;
+  manifest: BackupManifest | null;
+}
+
+// 기기 이전 번들
+export interface MigrationExport {
+  path: string;
+  manifest: BackupManifest;
+}
+
+export interface MigrationImportResult extends RestoreResult {
+  // 번들에 담긴 화면 설정 (localStorage 항목)
+  clientSettings: Record<string, string> | null;
+  // 이전 기기에서 잠금을 쓰고 있었으면 PIN 을 다시 설정해야 한다
+  pinResetRequired: boolean;
+}
+
+// 자동 백업
+export type BackupFrequency = 'daily' | 'weekly';
+export type BackupTrigger = 'scheduled' | 'exit' | 'before_operation';
+
+export interface RetentionPolicy {
+  daily: number;
+  weekly: number;
+  monthly: number;
+}
+
+export interface AutoBackupSettings {
+  enabled: boolean;
+  frequency: BackupFrequency;
+  services: CloudService[];
+  onExit: boolean;
+  beforeRiskyOperations: boolean;
+  retention: RetentionPolicy;
+}
+
+// 외부 백업 저장소 (폴더, WebDAV, S3 호환)
+export type BackupTargetConfig =
+  | { type: 'none' }
+  | { type: 'directory'; path: string }
+  | { type: 'webdav'; url: string; username: string; password: string }
+  | {
+      type: 's3';
+      endpoint: string;
+      region: string;
+      bucket: string;
+      prefix: string;
+      accessKey: string;
+      secretKey: string;
+    };
+
+export interface BackupRunStatus {
+  service: CloudService;
+  trigger: BackupTrigger;
+  operation: string | null;
+  attemptedAt: string;
+  success: boolean;
+  filename: string | null;
+  error: string | null;
+  lastSuccessAt: string | null;
+  pruned: number;
+}
+
+export interface TableCount {
+  table: string;
+  rows: number;
+}
+
+export interface BackupPreview {
+  schemaVersion: number;
+  tables: TableCount[];
+  reservationDateFrom: string | null;
+  reservationDateTo: string | null;
+  latestUpdatedAt: string | null;
+  manifest: BackupManifest | null;
+}
+
+export interface SelectiveRestoreOptions {
+  tables: string[];
+  dateFrom?: string;
+  dateTo?: string;
+  overwrite: boolean;
+}
+
+export interface TableMergeResult {
+  table: string;
+  inserted: number;
+  updated: number;
+  unchanged: number;
+  skipped: number;
+  failed: number;
+}
+
+export interface MergeConflict {
+  table: string;
+  id: string;
+  resolution: 'kept' | 'overwritten' | 'failed';
+  detail: string | null;
+}
+
+export interface SelectiveRestoreReport {
+  snapshotFilename: string;
+  tables: TableMergeResult[];
+  conflicts: MergeConflict[];
 }
 
 // 잠금 설정
@@ -160,3 +449,277 @@ export interface DesignerStatistic {
   completed: number;
   completionRate: number;
 }
+
+// 로컬 API 서버 설정
+export interface ApiServerSettings {
+  enabled: boolean;
+  port: number;
+  allowLan: boolean;
+}
+
+// 로컬 API 서버 상태
+export interface ApiServerStatus {
+  running: boolean;
+  address?: string;
+  lanUrl?: string;
+}
+
+// API 키 권한
+export type ApiScope = 'read' | 'write';
+
+// API 키
+export interface ApiKey {
+  id: string;
+  name: string;
+  keyPrefix: string;
+  scopes: ApiScope[];
+  createdAt: string;
+  lastUsedAt?: string;
+  revokedAt?: string;
+}
+
+// 새로 만든 API 키 (secret 은 한 번만 표시)
+export interface CreatedApiKey {
+  key: ApiKey;
+  secret: string;
+}
+
+// 웹훅 이벤트
+export type WebhookEventType =
+  | 'reservation.created'
+  | 'reservation.updated'
+  | 'reservation.status_changed'
+  | 'customer.created';
+
+// 웹훅 수신 주소
+export interface WebhookEndpoint {
+  id: string;
+  name: string;
+  url: string;
+  secret: string;
+  events: (WebhookEventType | '*')[];
+  isActive: boolean;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface CreateWebhookEndpointInput {
+  name: string;
+  url: string;
+  events: WebhookEventType[];
+  isActive?: boolean;
+}
+
+export interface UpdateWebhookEndpointInput {
+  name?: string;
+  url?: string;
+  events?: WebhookEventType[];
+  isActive?: boolean;
+}
+
+// 웹훅 전송 기록
+export interface WebhookDelivery {
+  id: string;
+  outboxId: string;
+  endpointId: string;
+  endpointName?: string;
+  eventType: string;
+  attempt: number;
+  statusCode?: number;
+  success: boolean;
+  error?: string;
+  durationMs?: number;
+  attemptedAt: string;
+  outboxStatus?: 'pending' | 'delivered' | 'failed';
+}
+
+// 예약 알림 발송 수단
+export type ReminderProviderConfig =
+  | { type: 'file' }
+  | { type: 'sms'; apiUrl: string; apiKey: string; sender: string }
+  | { type: 'alimtalk'; apiUrl: string; apiKey: string; senderKey: string; templateCode: string };
+
+// 예약 알림 설정
+export interface ReminderSettings {
+  enabled: boolean;
+  hoursBefore: number;
+  salonName: string;
+  template: string;
+  provider: ReminderProviderConfig;
+}
+
+export type ReminderStatus = 'pending' | 'sent' | 'failed' | 'cancelled' | 'skipped';
+
+// 예약 알림
+export interface Reminder {
+  id: string;
+  reservationId: string;
+  customerName?: string;
+  recipient: string;
+  reservationAt: string;
+  scheduledAt: string;
+  status: ReminderStatus;
+  provider?: string;
+  message?: string;
+  attempts: number;
+  providerMessageId?: string;
+  error?: string;
+  sentAt?: string;
+}
+
+// 개인정보 보호 설정
+export interface PrivacySettings {
+  retentionYears: number;
+  autoPurge: boolean;
+  consentVersion: string;
+}
+
+export type ConsentType = 'service' | 'marketing' | 'photo';
+
+// 동의 변경 이력
+export interface ConsentRecord {
+  id: string;
+  consentType: ConsentType;
+  granted: boolean;
+  version: string;
+  recordedAt: string;
+}
+
+// 보관 기간이 지나 익명 처리될 고객
+export interface RetentionCandidate {
+  customerId: string;
+  maskedName: string;
+  lastActivity: string;
+  reservationCount: number;
+}
+
+// 고객 한 명 익명 처리 결과
+export interface AnonymizeResult {
+  reservations: number;
+  messages: number;
+  reminders: number;
+}
+
+export type DossierFormat = 'json' | 'pdf';
+
+export interface PurgeReport {
+  runId: string;
+  cutoffDate: string;
+  customers: number;
+  reservations: number;
+  messages: number;
+  reminders: number;
+  performedAt: string;
+}
+
+export interface PrivacyAuditEntry {
+  id: string;
+  runId: string;
+  action: string;
+  customerId?: string;
+  trigger: 'manual' | 'auto' | string;
+  details: {
+    reason?: string;
+    fields?: string[];
+    reservations?: number;
+    messages?: number;
+    reminders?: number;
+  };
+  performedAt: string;
+}
+
+// 개인정보 파기 기록
+export interface PrivacyAuditReport {
+  dateFrom?: string;
+  dateTo?: string;
+  runs: number;
+  customers: number;
+  reservations: number;
+  messages: number;
+  reminders: number;
+  entries: PrivacyAuditEntry[];
+}
+
+// 기기 간 동기화
+export interface SyncSettings {
+  enabled: boolean;
+  deviceName: string;
+  // 모든 기기가 함께 쓰는 폴더
+  folder?: string | null;
+  // 동기화 파일 암호화 비밀번호 (모든 기기가 같아야 함)
+  passphrase: string;
+  intervalMinutes: number;
+  // 같은 네트워크에서 페어링한 기기와 직접 동기화
+  lanEnabled: boolean;
+  // 0 이면 자동
+  lanPort: number;
+}
+
+export type SyncConflictKind = 'double_booking' | 'unique_violation' | 'field_overwritten' | 'apply_failed';
+
+export interface SyncConflict {
+  kind: SyncConflictKind;
+  table: string;
+  rowId: string;
+  otherRowId?: string | null;
+  column?: string | null;
+  detail: string;
+}
+
+export interface SyncPeer {
+  nodeId: string;
+  deviceName: string;
+  generatedAt: string;
+}
+
+export interface SyncReport {
+  applied: number;
+  inserted: number;
+  updated: number;
+  deleted: number;
+  conflicts: SyncConflict[];
+  peers: SyncPeer[];
+  errors: string[];
+}
+
+export interface SyncStatus {
+  attemptedAt: string;
+  success: boolean;
+  error?: string | null;
+  lastSuccessAt?: string | null;
+  report?: SyncReport | null;
+}
+
+export interface PairingCode {
+  code: string;
+  expiresAt: string;
+}
+
+export interface LanSyncStatus {
+  running: boolean;
+  port?: number | null;
+  advertised: boolean;
+  pairing?: PairingCode | null;
+}
+
+export interface DiscoveredDevice {
+  nodeId: string;
+  deviceName: string;
+  // ip:port
+  address: string;
+  paired: boolean;
+}
+
+export interface PairedDevice {
+  nodeId: string;
+  deviceName: string;
+  address?: string | null;
+  pairedAt: string;
+  lastSyncedAt?: string | null;
+}
+
+// 데이터베이스를 열지 못하고 시작했을 때의 오류
+export interface StartupError {
+  message: string;
+  dataDir?: string | null;
+}